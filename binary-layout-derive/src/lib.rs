@@ -0,0 +1,279 @@
+//! This crate implements the `#[binary_layout]` attribute macro, an alternative front-end for the
+//! [binary_layout!](https://docs.rs/binary-layout/latest/binary_layout/macro.binary_layout.html) macro
+//! of the [binary-layout](https://crates.io/crates/binary-layout) crate, and the `#[derive(LayoutAs)]` macro
+//! for newtypes.
+//!
+//! Don't depend on this crate directly. Enable the `derive` feature of binary-layout instead
+//! and use the macros as `binary_layout::attribute::binary_layout` and `binary_layout::LayoutAs`.
+
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    parenthesized, parse_macro_input, Attribute, Data, DeriveInput, Error, Expr, Fields,
+    GenericParam, Ident, Index, ItemStruct, LitInt, Member, Meta, Path, Result, Token, Type,
+    Visibility,
+};
+
+/// Defines a layout from a struct with named fields, see the
+/// [binary-layout documentation](https://docs.rs/binary-layout/latest/binary_layout/attribute/index.html).
+///
+/// The struct is replaced by the module that [binary_layout!](https://docs.rs/binary-layout/latest/binary_layout/macro.binary_layout.html)
+/// generates for the same fields.
+#[proc_macro_attribute]
+pub fn binary_layout(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as LayoutArgs);
+    let item = parse_macro_input!(item as ItemStruct);
+    expand(args, item)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Implements the `LayoutAs` trait for a newtype, i.e. a struct with exactly one field,
+/// so it can be used as a field type wrapping the type of that field, see the
+/// [binary-layout documentation](https://docs.rs/binary-layout/latest/binary_layout/trait.LayoutAs.html).
+///
+/// The generated conversions can't fail, so `ReadError` and `WriteError` are `Infallible`.
+#[proc_macro_derive(LayoutAs)]
+pub fn derive_layout_as(item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as DeriveInput);
+    expand_layout_as(item)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Arguments of the attribute on the struct, e.g. `#[binary_layout(BigEndian, assert_size(8), assert_impl(Send, Sync))]`
+struct LayoutArgs {
+    endianness: Ident,
+    assert_size: Option<Expr>,
+    assert_impl: Option<Punctuated<Path, Token![,]>>,
+}
+
+impl Parse for LayoutArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let endianness = input.parse()?;
+        let mut assert_size = None;
+        let mut assert_impl = None;
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let key: Ident = input.parse()?;
+            let content;
+            if key == "assert_size" && assert_size.is_none() && assert_impl.is_none() {
+                parenthesized!(content in input);
+                assert_size = Some(content.parse()?);
+            } else if key == "assert_impl" && assert_impl.is_none() {
+                parenthesized!(content in input);
+                assert_impl = Some(Punctuated::parse_separated_nonempty(&content)?);
+            } else {
+                return Err(Error::new(
+                    key.span(),
+                    "Expected `assert_size(<<Size>>)` or `assert_impl(<<Trait>>, ...)` after the endianness",
+                ));
+            }
+        }
+        Ok(Self {
+            endianness,
+            assert_size,
+            assert_impl,
+        })
+    }
+}
+
+/// Arguments of the attribute on a field, e.g. `#[binary_layout(as u8, where 1..)]`, `#[binary_layout(@ 0x40)]` or `#[binary_layout(len(payload))]`
+#[derive(Default)]
+struct FieldArgs {
+    /// `@ <<Offset>>` pinning the field to an offset
+    pinned_offset: Option<LitInt>,
+    underlying_type: Option<Type>,
+    /// `len(...)` or `checksum(...)` binding of the field
+    binding: Option<(Ident, TokenStream2)>,
+    range: Option<Expr>,
+}
+
+const FIELD_ARGS_ERROR: &str = "Expected `@ <<Offset>>`, `as <<UnderlyingType>>`, `len(<<TailField>>)`, `checksum(<<Algorithm>>, <<Range>>)` or `where <<Range>>`";
+
+impl Parse for FieldArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut args = Self::default();
+        while !input.is_empty() {
+            if input.peek(Token![@]) && args.pinned_offset.is_none() {
+                input.parse::<Token![@]>()?;
+                args.pinned_offset = Some(input.parse()?);
+            } else if input.peek(Token![as]) && args.underlying_type.is_none() {
+                input.parse::<Token![as]>()?;
+                args.underlying_type = Some(input.parse()?);
+            } else if input.peek(Token![where]) && args.range.is_none() {
+                input.parse::<Token![where]>()?;
+                args.range = Some(input.parse()?);
+            } else if input.peek(Ident) && args.binding.is_none() {
+                let key: Ident = input.parse()?;
+                if key != "len" && key != "checksum" {
+                    return Err(Error::new(key.span(), FIELD_ARGS_ERROR));
+                }
+                let content;
+                parenthesized!(content in input);
+                args.binding = Some((key, content.parse()?));
+            } else {
+                return Err(input.error(FIELD_ARGS_ERROR));
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(args)
+    }
+}
+
+fn expand(args: LayoutArgs, item: ItemStruct) -> Result<TokenStream2> {
+    check_attributes(&item.attrs)?;
+    if let Some(where_clause) = &item.generics.where_clause {
+        return Err(Error::new_spanned(
+            where_clause,
+            "Layouts can't have where clauses",
+        ));
+    }
+    for param in &item.generics.params {
+        if !matches!(param, GenericParam::Const(_)) {
+            return Err(Error::new_spanned(
+                param,
+                "Layouts can only have const generic parameters",
+            ));
+        }
+    }
+    let fields = match &item.fields {
+        Fields::Named(fields) => &fields.named,
+        _ => {
+            return Err(Error::new_spanned(
+                &item,
+                "Layouts must be defined as structs with named fields",
+            ))
+        }
+    };
+
+    let mut field_tokens = Vec::new();
+    for field in fields {
+        let mut field_args = FieldArgs::default();
+        let mut name_attr = None;
+        for attr in &field.attrs {
+            if attr.path().is_ident("binary_layout") {
+                field_args = attr.parse_args()?;
+            } else if attr.path().is_ident("name") {
+                name_attr = Some(attr);
+            } else if !attr.path().is_ident("doc") {
+                return Err(Error::new_spanned(
+                    attr,
+                    "Unsupported attribute on a layout field",
+                ));
+            }
+        }
+        if !matches!(field.vis, Visibility::Inherited) {
+            return Err(Error::new_spanned(
+                &field.vis,
+                "Layout fields can't have a visibility, their accessors are always public",
+            ));
+        }
+        let ident = field.ident.as_ref().expect("Named fields have identifiers");
+        let ty = &field.ty;
+        let underlying_type = field_args
+            .underlying_type
+            .map(|underlying_type| quote!(as #underlying_type));
+        let binding = field_args.binding.map(|(key, args)| quote!(= #key(#args)));
+        let range = field_args.range.map(|range| quote!(where #range));
+        let pinned_offset = field_args
+            .pinned_offset
+            .map(|pinned_offset| quote!(@ #pinned_offset));
+        field_tokens
+            .push(quote!(#name_attr #ident #pinned_offset: #ty #underlying_type #binding #range));
+    }
+
+    let vis = match &item.vis {
+        Visibility::Inherited => quote!(pub(self)),
+        vis => vis.to_token_stream(),
+    };
+    let name = &item.ident;
+    let params = &item.generics.params;
+    let generics = if params.is_empty() {
+        None
+    } else {
+        Some(quote!(<#params>))
+    };
+    let endianness = &args.endianness;
+    let assert_size = args
+        .assert_size
+        .map(|expected_size| quote!(, assert_size(#expected_size)));
+    let assert_impl = args
+        .assert_impl
+        .map(|traits| quote!(, assert_impl(#traits)));
+
+    Ok(quote! {
+        ::binary_layout::binary_layout!(#vis #name #generics, #endianness, {
+            #(#field_tokens),*
+        } #assert_size #assert_impl);
+    })
+}
+
+fn expand_layout_as(item: DeriveInput) -> Result<TokenStream2> {
+    let fields = match &item.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &item.ident,
+                "LayoutAs can only be derived for structs",
+            ))
+        }
+    };
+    if fields.len() != 1 {
+        return Err(Error::new_spanned(
+            fields,
+            "LayoutAs can only be derived for structs with exactly one field",
+        ));
+    }
+    let field = fields
+        .iter()
+        .next()
+        .expect("Checked that there is one field");
+    let underlying_type = &field.ty;
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(0)),
+    };
+    let name = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::binary_layout::LayoutAs<#underlying_type> for #name #ty_generics #where_clause {
+            type ReadError = ::core::convert::Infallible;
+            type WriteError = ::core::convert::Infallible;
+
+            #[inline(always)]
+            fn try_read(v: #underlying_type) -> ::core::result::Result<Self, Self::ReadError> {
+                ::core::result::Result::Ok(Self { #member: v })
+            }
+
+            #[inline(always)]
+            fn try_write(v: Self) -> ::core::result::Result<#underlying_type, Self::WriteError> {
+                ::core::result::Result::Ok(v.#member)
+            }
+        }
+    })
+}
+
+fn check_attributes(attrs: &[Attribute]) -> Result<()> {
+    for attr in attrs {
+        if !matches!(&attr.meta, Meta::NameValue(meta) if meta.path.is_ident("doc")) {
+            return Err(Error::new_spanned(
+                attr,
+                "Unsupported attribute on a layout, only doc comments are allowed",
+            ));
+        }
+    }
+    Ok(())
+}
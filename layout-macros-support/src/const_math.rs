@@ -0,0 +1,110 @@
+//! Const arithmetic on field offsets and sizes.
+//!
+//! Layout front-ends compute the offset of each field at compile time by adding up the sizes of the
+//! fields before it. Field sizes are `Option<usize>` because open ended fields (e.g. `[u8]`) don't have
+//! a static size, and the functions in this module implement the `const` arithmetic needed for this.
+
+// TODO This only exists because Option<usize>::unwrap() isn't const. Remove this once it is.
+/// Unwraps the size of a field, i.e. an `Option<usize>`, in a `const` context.
+///
+/// This is used to get the offset of a field, which is the accumulated size of all fields before it.
+/// If any of those fields doesn't have a static size, then the field can't have a static offset
+/// and this function fails compilation (when used in a `const` context) with a corresponding error message.
+///
+/// # Example
+/// ```
+/// use layout_macros_support::const_math::unwrap_field_size;
+///
+/// const OFFSET: usize = unwrap_field_size(Some(4));
+/// assert_eq!(4, OFFSET);
+/// ```
+#[inline(always)]
+pub const fn unwrap_field_size(opt: Option<usize>) -> usize {
+    match opt {
+        Some(x) => x,
+        None => {
+            panic!("Error: Fields without a static size (e.g. open-ended byte arrays) can only be used at the end of a layout");
+        }
+    }
+}
+
+/// Adds the size of a field to its offset to get the offset of the field after it.
+/// Returns `None` if the field doesn't have a static size.
+///
+/// # Example
+/// ```
+/// use layout_macros_support::const_math::option_usize_add;
+///
+/// const NEXT_OFFSET: Option<usize> = option_usize_add(2, Some(4));
+/// assert_eq!(Some(6), NEXT_OFFSET);
+/// assert_eq!(None, option_usize_add(2, None));
+/// ```
+#[inline(always)]
+pub const fn option_usize_add(lhs: usize, rhs: Option<usize>) -> Option<usize> {
+    match (lhs, rhs) {
+        (lhs, Some(rhs)) => Some(lhs + rhs),
+        (_, None) => None,
+    }
+}
+
+/// Adds the size of a field to its offset, counting fields without a static size as zero-sized.
+/// When applied to the last field of a layout, this gives the size of the fixed-size part of the layout,
+/// i.e. the size of the layout without its open ended tail field (if it has one).
+///
+/// # Example
+/// ```
+/// use layout_macros_support::const_math::fixed_size_add;
+///
+/// assert_eq!(6, fixed_size_add(2, Some(4)));
+/// assert_eq!(2, fixed_size_add(2, None));
+/// ```
+#[inline(always)]
+pub const fn fixed_size_add(offset: usize, size: Option<usize>) -> usize {
+    match size {
+        Some(size) => offset + size,
+        None => offset,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unwrap_field_size_some() {
+        assert_eq!(0, unwrap_field_size(Some(0)));
+        assert_eq!(10, unwrap_field_size(Some(10)));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Error: Fields without a static size (e.g. open-ended byte arrays) can only be used at the end of a layout"
+    )]
+    fn unwrap_field_size_none() {
+        unwrap_field_size(None);
+    }
+
+    #[test]
+    fn option_usize_add_some() {
+        assert_eq!(Some(0), option_usize_add(0, Some(0)));
+        assert_eq!(Some(5), option_usize_add(2, Some(3)));
+    }
+
+    #[test]
+    fn option_usize_add_none() {
+        assert_eq!(None, option_usize_add(0, None));
+        assert_eq!(None, option_usize_add(2, None));
+    }
+
+    #[test]
+    fn fixed_size_add_some() {
+        assert_eq!(0, fixed_size_add(0, Some(0)));
+        assert_eq!(5, fixed_size_add(2, Some(3)));
+    }
+
+    #[test]
+    fn fixed_size_add_none() {
+        assert_eq!(0, fixed_size_add(0, None));
+        assert_eq!(2, fixed_size_add(2, None));
+    }
+}
@@ -1,7 +1,14 @@
 /// An enum representing the endianness used in a layout for accessing primitive integer fields.
+///
+/// This is the type of [Endianness::KIND]. It is exported so that code generic over the endianness,
+/// e.g. an alternative front-end, can tell at runtime which endianness a layout uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EndianKind {
+    /// Big endian, see [BigEndian]
     Big,
+    /// Little endian, see [LittleEndian]
     Little,
+    /// The endianness of the target platform, see [NativeEndian]
     Native,
 }
 
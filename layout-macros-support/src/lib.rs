@@ -0,0 +1,26 @@
+//! This crate contains the stable core that [binary-layout](https://crates.io/crates/binary-layout) layouts are built on:
+//! - the [Field] trait with the metadata of a layout field, i.e. its offset and size,
+//! - the [Endianness] marker types,
+//! - and [const arithmetic](const_math) to compute field offsets at compile time.
+//!
+//! You probably don't want to use this crate directly. If you want to define layouts, use the
+//! [binary_layout!](https://docs.rs/binary-layout/latest/binary_layout/macro.binary_layout.html) macro
+//! from the binary-layout crate, which re-exports everything in here.
+//!
+//! This crate is meant for alternative front-ends that generate layouts, for example proc macros or
+//! code generators working from an interface description, and want to target the same core as the
+//! [binary_layout!](https://docs.rs/binary-layout/latest/binary_layout/macro.binary_layout.html) macro
+//! without depending on its `macro_rules!` implementation.
+//!
+//! This crate is `#[no_std]` compatible.
+
+#![no_std]
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+
+pub mod const_math;
+mod endianness;
+mod field;
+
+pub use endianness::{BigEndian, EndianKind, Endianness, LittleEndian, NativeEndian};
+pub use field::Field;
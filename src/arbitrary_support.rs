@@ -0,0 +1,335 @@
+//! Support code for the `arbitrary` and `proptest` features, see [binary_layout!](crate::binary_layout!).
+//!
+//! Fields are filled with valid values where possible: Fields with copy access whose type implements [Arbitrary]
+//! (e.g. `bool`, `NonZeroU32` or custom types implementing it) are written through their [FieldCopyAccess] API,
+//! nested layouts are filled field by field, and all other fields are filled with arbitrary bytes.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use crate::fields::primitive::NestedViewInfo;
+use crate::{
+    EndianKind, Endianness, Field, FieldCopyAccess, FieldKind, LayoutInfo, PrimitiveField,
+};
+
+/// Implemented by the `NestedView` marker type of each layout to fill a storage with arbitrary but valid data for the layout.
+#[doc(hidden)]
+pub trait ArbitraryLayout: NestedViewInfo {
+    /// Fill all fields of the layout in `storage` with arbitrary values
+    fn fill_arbitrary(u: &mut Unstructured<'_>, storage: &mut [u8]) -> Result<()>;
+
+    /// Read all fields of the layout from `original` and write them to `copy`, panicking if a field doesn't round trip
+    fn round_trip(original: &[u8], copy: &mut [u8]);
+}
+
+/// Create a storage for the layout `L` and fill it with arbitrary but valid data.
+/// Layouts ending with an open ended field get a tail of arbitrary length.
+#[doc(hidden)]
+pub fn arbitrary_storage<L: ArbitraryLayout>(u: &mut Unstructured<'_>) -> Result<Vec<u8>> {
+    let tail_len = match L::SIZE {
+        Some(_) => 0,
+        None => u.arbitrary_len::<u8>()?,
+    };
+    let mut storage = alloc::vec![0; L::LAYOUT.fixed_size() + tail_len];
+    L::fill_arbitrary(u, &mut storage)?;
+    Ok(storage)
+}
+
+/// Fills field `F` with an arbitrary value. The [binary_layout!](crate::binary_layout!) macro uses autoref specialization
+/// to call [FillArbitraryValue::fill_field] if the field type implements [Arbitrary], [FillArbitraryNested::fill_field]
+/// for nested layouts and [FillArbitraryBytes::fill_field] otherwise.
+#[doc(hidden)]
+pub struct FieldFiller<F>(PhantomData<F>);
+
+impl<F> FieldFiller<F> {
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait FillArbitraryValue {
+    fn fill_field(&self, u: &mut Unstructured<'_>, storage: &mut [u8]) -> Result<()>;
+}
+
+impl<F> FillArbitraryValue for &&FieldFiller<F>
+where
+    F: FieldCopyAccess,
+    F::HighLevelType: for<'a> Arbitrary<'a>,
+{
+    fn fill_field(&self, u: &mut Unstructured<'_>, storage: &mut [u8]) -> Result<()> {
+        let value = F::HighLevelType::arbitrary(u)?;
+        F::try_write(storage, value).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[doc(hidden)]
+pub trait FillArbitraryNested {
+    fn fill_field(&self, u: &mut Unstructured<'_>, storage: &mut [u8]) -> Result<()>;
+}
+
+impl<N, E, const OFFSET_: usize> FillArbitraryNested for &FieldFiller<PrimitiveField<N, E, OFFSET_>>
+where
+    N: ArbitraryLayout,
+    E: Endianness,
+{
+    fn fill_field(&self, u: &mut Unstructured<'_>, storage: &mut [u8]) -> Result<()> {
+        N::fill_arbitrary(u, field_bytes::<PrimitiveField<N, E, OFFSET_>>(storage))
+    }
+}
+
+#[doc(hidden)]
+pub trait FillArbitraryBytes {
+    fn fill_field(&self, u: &mut Unstructured<'_>, storage: &mut [u8]) -> Result<()>;
+}
+
+impl<F: Field> FillArbitraryBytes for FieldFiller<F> {
+    fn fill_field(&self, u: &mut Unstructured<'_>, storage: &mut [u8]) -> Result<()> {
+        u.fill_buffer(field_bytes::<F>(storage))
+    }
+}
+
+fn field_bytes<F: Field>(storage: &mut [u8]) -> &mut [u8] {
+    match F::SIZE {
+        Some(size) => &mut storage[F::OFFSET..(F::OFFSET + size)],
+        None => &mut storage[F::OFFSET..],
+    }
+}
+
+fn field_range<F: Field>(storage: &[u8]) -> core::ops::Range<usize> {
+    match F::SIZE {
+        Some(size) => F::OFFSET..(F::OFFSET + size),
+        None => F::OFFSET..storage.len(),
+    }
+}
+
+/// Copies field `F` from one storage to another. The [binary_layout!](crate::binary_layout!) macro uses autoref specialization
+/// to call [RoundTripValue::round_trip] for fields with copy access, which reads the value and writes it back,
+/// [RoundTripNested::round_trip] for nested layouts and [RoundTripBytes::round_trip] otherwise.
+#[doc(hidden)]
+pub struct FieldRoundTrip<F>(PhantomData<F>);
+
+impl<F> FieldRoundTrip<F> {
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait RoundTripValue {
+    fn round_trip(&self, layout: &str, field: &str, original: &[u8], copy: &mut [u8]);
+}
+
+impl<F> RoundTripValue for &&FieldRoundTrip<F>
+where
+    F: FieldCopyAccess,
+    F::ReadError: Debug,
+    F::WriteError: Debug,
+{
+    fn round_trip(&self, layout: &str, field: &str, original: &[u8], copy: &mut [u8]) {
+        let value = F::try_read(original).unwrap_or_else(|error| {
+            panic!(
+                "Reading field `{}` of layout `{}` failed: {:?}",
+                field, layout, error
+            )
+        });
+        F::try_write(copy, value).unwrap_or_else(|error| {
+            panic!(
+                "Writing field `{}` of layout `{}` failed: {:?}",
+                field, layout, error
+            )
+        });
+        if let Err(error) = F::try_read(copy) {
+            panic!(
+                "Reading back field `{}` of layout `{}` failed: {:?}",
+                field, layout, error
+            );
+        }
+        let range = field_range::<F>(original);
+        assert_eq!(
+            original[range.clone()],
+            copy[range],
+            "Field `{}` of layout `{}` didn't round trip",
+            field,
+            layout,
+        );
+    }
+}
+
+#[doc(hidden)]
+pub trait RoundTripNested {
+    fn round_trip(&self, layout: &str, field: &str, original: &[u8], copy: &mut [u8]);
+}
+
+impl<N, E, const OFFSET_: usize> RoundTripNested for &FieldRoundTrip<PrimitiveField<N, E, OFFSET_>>
+where
+    N: ArbitraryLayout,
+    E: Endianness,
+{
+    fn round_trip(&self, _layout: &str, _field: &str, original: &[u8], copy: &mut [u8]) {
+        let range = field_range::<PrimitiveField<N, E, OFFSET_>>(original);
+        N::round_trip(&original[range.clone()], &mut copy[range]);
+    }
+}
+
+#[doc(hidden)]
+pub trait RoundTripBytes {
+    fn round_trip(&self, layout: &str, field: &str, original: &[u8], copy: &mut [u8]);
+}
+
+impl<F: Field> RoundTripBytes for FieldRoundTrip<F> {
+    fn round_trip(&self, _layout: &str, _field: &str, original: &[u8], copy: &mut [u8]) {
+        let range = field_range::<F>(original);
+        copy[range.clone()].copy_from_slice(&original[range]);
+    }
+}
+
+/// Generate storages with random valid data for the layout `L` and check that each field round trips, see [binary_layout_tests!](crate::binary_layout_tests!).
+#[doc(hidden)]
+pub fn round_trip_test<L: ArbitraryLayout>(iterations: usize) {
+    // xorshift64, so that the test is deterministic and doesn't need a random number generator
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    let mut next_byte = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state as u8
+    };
+    let entropy_len = 2 * L::LAYOUT.fixed_size() + 64;
+
+    let mut num_tested = 0;
+    for _ in 0..iterations {
+        let entropy: Vec<u8> = (0..entropy_len).map(|_| next_byte()).collect();
+        let original = match arbitrary_storage::<L>(&mut Unstructured::new(&entropy)) {
+            Ok(original) => original,
+            Err(_) => continue,
+        };
+        let mut copy = alloc::vec![0; original.len()];
+        L::round_trip(&original, &mut copy);
+        assert_eq!(
+            original,
+            copy,
+            "Layout `{}` didn't round trip",
+            L::LAYOUT.name()
+        );
+        check_decoding(L::LAYOUT, &copy);
+        num_tested += 1;
+    }
+    assert!(
+        num_tested > 0,
+        "Couldn't generate valid data for layout `{}`",
+        L::LAYOUT.name()
+    );
+}
+
+/// Decode the integer and floating point fields of `layout` byte by byte and check that the values
+/// match the values read through the layout. Fields of custom types are skipped.
+fn check_decoding(layout: &LayoutInfo, storage: &[u8]) {
+    for field in layout.fields() {
+        let bytes = match field.bytes(storage) {
+            Some(bytes) => bytes,
+            None => continue,
+        };
+        let expected = match field.kind() {
+            FieldKind::Nested(nested) => {
+                check_decoding(nested, bytes);
+                continue;
+            }
+            _ if field.type_name().contains(" as ") => continue,
+            FieldKind::UnsignedInt | FieldKind::SignedInt | FieldKind::Float => {
+                decode_manually(field.kind(), field.endianness(), bytes)
+            }
+            _ => continue,
+        };
+        assert_eq!(
+            expected,
+            field.value(storage).to_string(),
+            "Field `{}` of layout `{}` doesn't match its bytes",
+            field.name(),
+            layout.name(),
+        );
+    }
+}
+
+fn decode_manually(kind: FieldKind, endianness: EndianKind, bytes: &[u8]) -> String {
+    let little_endian = match endianness {
+        EndianKind::Little => true,
+        EndianKind::Big => false,
+        EndianKind::Native => cfg!(target_endian = "little"),
+    };
+    let mut value: u128 = 0;
+    for (index, byte) in bytes.iter().enumerate() {
+        let shift = if little_endian {
+            8 * index
+        } else {
+            8 * (bytes.len() - 1 - index)
+        };
+        value |= u128::from(*byte) << shift;
+    }
+    let unused_bits = 128 - 8 * bytes.len() as u32;
+    match (kind, bytes.len()) {
+        (FieldKind::SignedInt, _) => format!("{}", ((value << unused_bits) as i128) >> unused_bits),
+        (FieldKind::Float, 4) => format!("{:?}", f32::from_bits(value as u32)),
+        (FieldKind::Float, _) => format!("{:?}", f64::from_bits(value as u64)),
+        _ => format!("{}", value),
+    }
+}
+
+/// A proptest strategy generating valid storages for the layout `L`, see [arbitrary_storage].
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+pub fn storage_strategy<L: ArbitraryLayout>() -> impl proptest::strategy::Strategy<Value = Vec<u8>>
+{
+    use proptest::strategy::Strategy;
+
+    // Generating a value can take more bytes than the value occupies in the layout, and if the entropy runs out,
+    // `arbitrary` falls back to zeroes. Provide enough entropy so that this doesn't happen too often.
+    // Generating can still fail if a custom type generates a value its `LayoutAs::try_write` rejects, those values are filtered out.
+    let min_entropy = 2 * L::LAYOUT.fixed_size() + 16;
+    proptest::collection::vec(
+        proptest::arbitrary::any::<u8>(),
+        min_entropy..=(min_entropy + 256),
+    )
+    .prop_filter_map("Invalid data for the layout", |entropy| {
+        arbitrary_storage::<L>(&mut Unstructured::new(&entropy)).ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_manually_handles_endianness_and_sign() {
+        assert_eq!(
+            "258",
+            decode_manually(FieldKind::UnsignedInt, EndianKind::Little, &[2, 1])
+        );
+        assert_eq!(
+            "513",
+            decode_manually(FieldKind::UnsignedInt, EndianKind::Big, &[2, 1])
+        );
+        assert_eq!(
+            "-2",
+            decode_manually(FieldKind::SignedInt, EndianKind::Big, &[0xff, 0xfe])
+        );
+        assert_eq!(
+            "1.5",
+            decode_manually(FieldKind::Float, EndianKind::Big, &[0x3f, 0xc0, 0, 0])
+        );
+        assert_eq!(
+            "-0.0",
+            decode_manually(
+                FieldKind::Float,
+                EndianKind::Little,
+                &[0, 0, 0, 0, 0, 0, 0, 0x80]
+            )
+        );
+    }
+}
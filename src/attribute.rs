@@ -0,0 +1,80 @@
+//! The `#[binary_layout]` attribute macro is an alternative front-end for the [binary_layout!](crate::binary_layout!) macro.
+//! It is only available with the `derive` feature.
+//!
+//! Instead of the field list syntax of [binary_layout!](crate::binary_layout!), the layout is written as a struct with named fields.
+//! The struct is replaced by the same module with a `View` and field accessors that [binary_layout!](crate::binary_layout!)
+//! would generate. Since the layout is written in regular Rust syntax, IDEs can offer completion, go-to-definition
+//! and renaming for the field types, which they often can't do inside of a `macro_rules!` invocation.
+//!
+//! ```
+//! use binary_layout::prelude::*;
+//! use binary_layout::attribute::binary_layout;
+//!
+//! #[binary_layout(BigEndian)]
+//! pub struct icmp_packet {
+//!   packet_type: u8,
+//!   code: u8,
+//!   checksum: u16,
+//!   rest_of_header: [u8; 4],
+//!   data_section: [u8],
+//! }
+//!
+//! // equivalent to
+//! // binary_layout!(pub icmp_packet, BigEndian, {
+//! //   packet_type: u8,
+//! //   code: u8,
+//! //   checksum: u16,
+//! //   rest_of_header: [u8; 4],
+//! //   data_section: [u8],
+//! // });
+//!
+//! let mut view = icmp_packet::View::new(vec![0; 10]);
+//! view.code_mut().write(3);
+//! assert_eq!(1, icmp_packet::code::OFFSET);
+//! ```
+//!
+//! The attribute takes the endianness of the layout and optionally an `assert_size(<<Size>>)` [size assertion](crate::binary_layout!#size-assertion)
+//! and an `assert_impl(<<Trait>>, ...)` [trait assertion](crate::binary_layout!#trait-assertions),
+//! e.g. `#[binary_layout(LittleEndian, assert_size(8), assert_impl(Send, Sync))]`.
+//!
+//! Fields can use [custom types](crate#custom-field-types), [ranges](crate#range-constrained-fields),
+//! [pinned offsets](crate::binary_layout!#pinned-offsets) (`#[binary_layout(@ <<Offset>>)]`) and
+//! [length](crate::binary_layout!#length-fields) and [checksum](crate::binary_layout!#checksum-fields) bindings
+//! (`#[binary_layout(len(<<TailField>>))]`, `#[binary_layout(checksum(<<Algorithm>>, <<Range>>))]`) with a `#[binary_layout(...)]`
+//! attribute, and they can have a `#[name = "..."]` attribute like in [binary_layout!](crate::binary_layout!#field-names).
+//! Const generic parameters of the struct turn it into a [layout template](crate::binary_layout!#layout-templates).
+//!
+//! ```
+//! use binary_layout::prelude::*;
+//! use binary_layout::attribute::binary_layout;
+//!
+//! #[binary_layout(LittleEndian, assert_size(7))]
+//! pub struct header {
+//!   // same as `is_compressed: bool as u8` in binary_layout!
+//!   #[binary_layout(as u8)]
+//!   is_compressed: bool,
+//!   // same as `version: u16 where 1..=3` in binary_layout!
+//!   #[binary_layout(where 1..=3)]
+//!   version: u16,
+//!   #[name = "type"]
+//!   header_type: u32,
+//! }
+//!
+//! #[binary_layout(LittleEndian)]
+//! pub struct block<const BLOCK_SIZE: usize> {
+//!   data: [u8; BLOCK_SIZE],
+//! }
+//! ```
+//!
+//! The visibility of the struct becomes the visibility of the generated module. Unlike [binary_layout!](crate::binary_layout!),
+//! which generates public modules by default, a struct without visibility generates a private module.
+//!
+//! Layouts defined with the attribute can be [nested](crate#nesting) in and [included](crate::binary_layout!#including-layouts) by
+//! other layouts like any other layout. The attribute doesn't support open ended field lists with optional or conditional fields,
+//! validation or variants yet; use [binary_layout!](crate::binary_layout!) for those.
+//!
+//! Note that `use binary_layout::attribute::binary_layout` shadows the [binary_layout!](crate::binary_layout!) macro from the prelude,
+//! because attribute macros and function-like macros share a namespace. If you need both in the same module,
+//! refer to one of them by its full path, e.g. `#[binary_layout::attribute::binary_layout(BigEndian)]`.
+
+pub use binary_layout_derive::binary_layout;
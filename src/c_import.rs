@@ -0,0 +1,843 @@
+//! Importing layouts from C struct declarations. This module is only available with the `c-import` feature.
+//!
+//! [import] converts the `struct` declarations of a C header into [binary_layout!](crate::binary_layout!) definitions,
+//! computing the field offsets like a C compiler would for a `#[repr(C)]` struct on a common 32 or 64 bit platform.
+//! Where the compiler would insert padding bytes to align a member, the definition gets a `_paddingN: [u8; N]` field,
+//! and each of them is listed in [CStruct::padding], so that a format described by a vendor header can be checked for
+//! accidental padding before it is defined as a layout.
+//!
+//! The importer is intentionally simple and only understands the subset of C used for plain data structs:
+//! - Members of the fixed width integer types (`uint8_t` to `uint64_t`, `int8_t` to `int64_t`), `char`, `short`, `int`,
+//!   `long long` and their `signed` and `unsigned` variants, `float`, `double`, and `bool` or `_Bool` (as `bool as u8`).
+//!   `long` is rejected because its size differs between platforms.
+//! - Arrays of these types, e.g. `uint32_t counters[4]` or `char name[NAME_LEN]`, where `NAME_LEN` can be a `#define`d integer constant.
+//!   Arrays of `char` become byte arrays `[u8; N]`. A flexible array member of bytes at the end of a struct, e.g. `uint8_t data[]`,
+//!   becomes an open ended byte array `[u8]`.
+//! - Members whose type is a struct declared earlier in the same header, which become nested layouts (`super::other::NestedView`).
+//! - `typedef`s for structs and for the supported types.
+//! - `__attribute__((packed))`, `#pragma pack(N)`, `#pragma pack(push, N)`, `#pragma pack(pop)` and `#pragma pack()`.
+//!
+//! Other preprocessor directives are ignored, i.e. conditional compilation and macros are not evaluated.
+//! Function declarations and other declarations outside of structs are skipped.
+//! Pointers, bit fields, unions, enums and nested struct definitions are rejected with an error, because there is no portable layout for them.
+//!
+//! # Example
+//! ```
+//! use binary_layout::c_import;
+//! use binary_layout::EndianKind;
+//!
+//! let header = r"
+//!     #include <stdint.h>
+//!
+//!     typedef struct {
+//!         uint8_t version;
+//!         uint32_t length;
+//!         char name[6];
+//!     } record_t;
+//! ";
+//! let structs = c_import::import(header, EndianKind::Little).unwrap();
+//! assert_eq!(1, structs.len());
+//! assert_eq!(16, structs[0].size());
+//! // Padding after `version` to align `length`, and at the end to align the size of the struct
+//! let padding: Vec<(usize, usize)> = structs[0].padding().iter().map(|p| (p.offset(), p.size())).collect();
+//! assert_eq!(vec![(1, 3), (14, 2)], padding);
+//! assert!(structs[0].definition().contains("    _padding0: [u8; 3], // inserted for alignment\n"));
+//! ```
+//! The generated definitions can be written to a file in a build script and included like the output of the [codegen](crate::codegen) module.
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+use crate::utils::definition::{field_name, write_definition, DefinitionField};
+use crate::EndianKind;
+
+/// A struct imported from a C header by [import]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CStruct {
+    name: String,
+    size: usize,
+    alignment: usize,
+    definition: String,
+    padding: Vec<CPadding>,
+}
+
+impl CStruct {
+    /// The name of the struct, which is also used as the name of the layout.
+    /// For `typedef struct tag { ... } alias;`, this is the name of the typedef, i.e. `alias`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The size of the struct in number of bytes as the C compiler computes it, i.e. including padding
+    /// but not including a flexible array member at the end
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The alignment of the struct in number of bytes
+    pub fn alignment(&self) -> usize {
+        self.alignment
+    }
+
+    /// The [binary_layout!](crate::binary_layout!) invocation defining a layout with the same field offsets as the C struct
+    pub fn definition(&self) -> &str {
+        &self.definition
+    }
+
+    /// The padding fields that were inserted into the layout because the C compiler would align the following member
+    /// or the size of the struct. This is empty for structs without padding, e.g. packed structs.
+    pub fn padding(&self) -> &[CPadding] {
+        &self.padding
+    }
+}
+
+/// Padding bytes that [import] inserted into a layout, see [CStruct::padding]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CPadding {
+    field: String,
+    offset: usize,
+    size: usize,
+}
+
+impl CPadding {
+    /// The name of the byte array field holding the padding in the layout, e.g. `_padding0`
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// The offset of the padding in the struct in number of bytes
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The number of padding bytes
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// This error is returned from [import] if a C header contains declarations that can't be imported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CImportError {
+    line: usize,
+    message: String,
+}
+
+impl CImportError {
+    /// The line of the header the error happened in, counted from 1
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// A description of the problem
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for CImportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.line, self.message)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl crate::utils::error::Error for CImportError {}
+
+/// Import the structs declared in the C header `source` as layouts with the given endianness, see the [module documentation](self)
+/// for the supported subset of C. The structs are returned in the order they are declared in.
+pub fn import(source: &str, endianness: EndianKind) -> Result<Vec<CStruct>, CImportError> {
+    let tokens = tokenize(source)?;
+    Importer {
+        tokens: &tokens,
+        position: 0,
+        endianness,
+        constants: BTreeMap::new(),
+        types: BTreeMap::new(),
+        pack: None,
+        pack_stack: Vec::new(),
+        structs: Vec::new(),
+    }
+    .import()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Identifier,
+    Number,
+    Literal,
+    Directive,
+    Punct,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    line: usize,
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token<'_>>, CImportError> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut position = 0;
+    let mut line = 1;
+    let mut line_start = true;
+    while position < bytes.len() {
+        let start = position;
+        let start_line = line;
+        let c = bytes[position];
+        let kind = match c {
+            b'\n' => {
+                line += 1;
+                line_start = true;
+                position += 1;
+                continue;
+            }
+            _ if c.is_ascii_whitespace() => {
+                position += 1;
+                continue;
+            }
+            b'/' if bytes.get(position + 1) == Some(&b'/') => {
+                while position < bytes.len() && bytes[position] != b'\n' {
+                    position += 1;
+                }
+                continue;
+            }
+            b'/' if bytes.get(position + 1) == Some(&b'*') => {
+                let end = source[position + 2..].find("*/").ok_or(CImportError {
+                    line,
+                    message: "Unterminated comment".to_string(),
+                })?;
+                line += source[position..position + 2 + end].matches('\n').count();
+                position += end + 4;
+                continue;
+            }
+            b'#' if line_start => {
+                // Directives end at the end of the line, unless it is continued with a backslash
+                while position < bytes.len()
+                    && !(bytes[position] == b'\n' && bytes[position - 1] != b'\\')
+                {
+                    if bytes[position] == b'\n' {
+                        line += 1;
+                    }
+                    position += 1;
+                }
+                TokenKind::Directive
+            }
+            b'"' | b'\'' => {
+                position += 1;
+                while position < bytes.len() && bytes[position] != c {
+                    if bytes[position] == b'\\' {
+                        position += 1;
+                    }
+                    position += 1;
+                }
+                if position >= bytes.len() {
+                    return Err(CImportError {
+                        line,
+                        message: "Unterminated literal".to_string(),
+                    });
+                }
+                position += 1;
+                TokenKind::Literal
+            }
+            _ if c.is_ascii_alphabetic() || c == b'_' => {
+                while position < bytes.len()
+                    && (bytes[position].is_ascii_alphanumeric() || bytes[position] == b'_')
+                {
+                    position += 1;
+                }
+                TokenKind::Identifier
+            }
+            _ if c.is_ascii_digit() => {
+                while position < bytes.len()
+                    && (bytes[position].is_ascii_alphanumeric() || bytes[position] == b'.')
+                {
+                    position += 1;
+                }
+                TokenKind::Number
+            }
+            _ => {
+                // Advance by a whole character to stay on a char boundary
+                position += source[position..].chars().next().map_or(1, char::len_utf8);
+                TokenKind::Punct
+            }
+        };
+        line_start = false;
+        tokens.push(Token {
+            kind,
+            text: &source[start..position],
+            line: start_line,
+        });
+    }
+    Ok(tokens)
+}
+
+/// Parse an integer literal like `16`, `0x10`, `010` or `16u`
+fn parse_integer(text: &str) -> Option<usize> {
+    let text = text.trim_end_matches(['u', 'U', 'l', 'L']);
+    if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        usize::from_str_radix(hex, 16).ok()
+    } else if text.len() > 1 && text.starts_with('0') {
+        usize::from_str_radix(&text[1..], 8).ok()
+    } else {
+        text.parse().ok()
+    }
+}
+
+/// A type that members of imported structs can have
+#[derive(Debug, Clone)]
+enum CType {
+    Primitive {
+        rust: &'static str,
+        size: usize,
+    },
+    Struct {
+        name: String,
+        size: usize,
+        alignment: usize,
+        open_ended: bool,
+    },
+}
+
+impl CType {
+    fn size(&self) -> usize {
+        match self {
+            CType::Primitive { size, .. } | CType::Struct { size, .. } => *size,
+        }
+    }
+
+    fn alignment(&self) -> usize {
+        match self {
+            // Primitive types are aligned to their size on all common platforms
+            CType::Primitive { size, .. } => *size,
+            CType::Struct { alignment, .. } => *alignment,
+        }
+    }
+}
+
+fn primitive_type(name: &str) -> Option<CType> {
+    let (rust, size) = match name {
+        "uint8_t" | "unsigned char" | "char" => ("u8", 1),
+        "int8_t" | "signed char" => ("i8", 1),
+        "uint16_t" | "unsigned short" | "unsigned short int" => ("u16", 2),
+        "int16_t" | "short" | "short int" | "signed short" | "signed short int" => ("i16", 2),
+        "uint32_t" | "unsigned" | "unsigned int" => ("u32", 4),
+        "int32_t" | "int" | "signed" | "signed int" => ("i32", 4),
+        "uint64_t" | "unsigned long long" | "unsigned long long int" => ("u64", 8),
+        "int64_t" | "long long" | "long long int" | "signed long long" | "signed long long int" => {
+            ("i64", 8)
+        }
+        "float" => ("f32", 4),
+        "double" => ("f64", 8),
+        "bool" | "_Bool" => ("bool as u8", 1),
+        _ => return None,
+    };
+    Some(CType::Primitive { rust, size })
+}
+
+struct Member<'a> {
+    name: &'a str,
+    ctype: CType,
+    /// `None` for a single value, `Some(None)` for a flexible array member, `Some(Some(n))` for an array of `n` elements
+    array: Option<Option<usize>>,
+    line: usize,
+}
+
+struct Importer<'t, 'a> {
+    tokens: &'t [Token<'a>],
+    position: usize,
+    endianness: EndianKind,
+    constants: BTreeMap<&'a str, usize>,
+    /// Structs and typedefs declared so far, keyed by their name as used in member declarations, e.g. `struct tag` or `alias`
+    types: BTreeMap<String, CType>,
+    /// The maximum alignment set by `#pragma pack`
+    pack: Option<usize>,
+    pack_stack: Vec<Option<usize>>,
+    structs: Vec<CStruct>,
+}
+
+impl<'t, 'a> Importer<'t, 'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.position).copied()
+    }
+
+    fn peek_is(&self, text: &str) -> bool {
+        self.peek().map_or(false, |token| token.text == text)
+    }
+
+    fn line(&self) -> usize {
+        self.peek()
+            .or_else(|| self.tokens.last().copied())
+            .map_or(1, |token| token.line)
+    }
+
+    fn error(&self, message: impl Into<String>) -> CImportError {
+        CImportError {
+            line: self.line(),
+            message: message.into(),
+        }
+    }
+
+    fn next(&mut self) -> Result<Token<'a>, CImportError> {
+        let token = self
+            .peek()
+            .ok_or_else(|| self.error("Unexpected end of the header"))?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn accept(&mut self, text: &str) -> bool {
+        let found = self.peek_is(text);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, text: &str) -> Result<(), CImportError> {
+        if self.accept(text) {
+            Ok(())
+        } else {
+            Err(self.error(format!("Expected `{}`", text)))
+        }
+    }
+
+    fn identifier(&mut self) -> Result<&'a str, CImportError> {
+        match self.peek() {
+            Some(token) if token.kind == TokenKind::Identifier => {
+                self.position += 1;
+                Ok(token.text)
+            }
+            _ => Err(self.error("Expected an identifier")),
+        }
+    }
+
+    fn import(mut self) -> Result<Vec<CStruct>, CImportError> {
+        while let Some(token) = self.peek() {
+            match (token.kind, token.text) {
+                (TokenKind::Directive, directive) => {
+                    self.directive(directive)?;
+                    self.position += 1;
+                }
+                // `extern "C" {` blocks of headers shared with C++, the closing brace is skipped below
+                (TokenKind::Identifier, "extern")
+                    if self.tokens.get(self.position + 1).map(|t| t.kind)
+                        == Some(TokenKind::Literal)
+                        && self.tokens.get(self.position + 2).map(|t| t.text) == Some("{") =>
+                {
+                    self.position += 3;
+                }
+                (TokenKind::Punct, "}" | ";") => self.position += 1,
+                (TokenKind::Identifier, "typedef") => {
+                    self.position += 1;
+                    self.typedef()?;
+                }
+                (TokenKind::Identifier, "struct")
+                    if self.tokens[self.position..]
+                        .iter()
+                        .take_while(|t| t.text != ";")
+                        .any(|t| t.text == "{") =>
+                {
+                    self.position += 1;
+                    self.struct_definition(false)?;
+                    self.expect(";")?;
+                }
+                _ => self.skip_declaration(),
+            }
+        }
+        Ok(self.structs)
+    }
+
+    /// Skip a declaration we don't need, e.g. a function prototype or an enum, up to the `;` ending it
+    fn skip_declaration(&mut self) {
+        let mut depth = 0usize;
+        while let Some(token) = self.peek() {
+            self.position += 1;
+            match token.text {
+                "{" | "(" | "[" => depth += 1,
+                "}" | ")" | "]" => depth = depth.saturating_sub(1),
+                ";" if depth == 0 => return,
+                _ => {}
+            }
+        }
+    }
+
+    fn directive(&mut self, directive: &'a str) -> Result<(), CImportError> {
+        let mut words = directive[1..].split_whitespace();
+        match words.next() {
+            Some("define") => {
+                let name = words.next().unwrap_or("");
+                let value: String = words
+                    .collect::<String>()
+                    .chars()
+                    .filter(|c| !matches!(c, '(' | ')'))
+                    .collect();
+                // Only integer constants are used, e.g. for array lengths
+                if let Some(value) = parse_integer(&value) {
+                    self.constants.insert(name, value);
+                }
+            }
+            Some("pragma") if words.next().map_or(false, |word| word.starts_with("pack")) => {
+                let arguments: String = directive
+                    .split_once("pack")
+                    .map_or("", |(_, rest)| rest)
+                    .chars()
+                    .filter(|c| !c.is_whitespace() && !matches!(c, '(' | ')'))
+                    .collect();
+                let mut arguments = arguments.split(',').filter(|arg| !arg.is_empty());
+                match arguments.next() {
+                    None => self.pack = None,
+                    Some("pop") => self.pack = self.pack_stack.pop().unwrap_or(None),
+                    Some("push") => {
+                        self.pack_stack.push(self.pack);
+                        if let Some(value) = arguments.next() {
+                            self.pack = Some(self.pack_value(value)?);
+                        }
+                    }
+                    Some(value) => self.pack = Some(self.pack_value(value)?),
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn pack_value(&self, value: &str) -> Result<usize, CImportError> {
+        parse_integer(value)
+            .filter(|value| value.is_power_of_two())
+            .ok_or_else(|| self.error(format!("Invalid `#pragma pack` value `{}`", value)))
+    }
+
+    fn typedef(&mut self) -> Result<(), CImportError> {
+        if self.peek_is("struct")
+            && self.tokens[self.position..]
+                .iter()
+                .take_while(|t| t.text != ";")
+                .any(|t| t.text == "{")
+        {
+            self.position += 1;
+            self.struct_definition(true)?;
+            self.expect(";")?;
+            return Ok(());
+        }
+        let line = self.line();
+        let mut words = Vec::new();
+        while !self.peek_is(";") {
+            let token = self.next()?;
+            if token.kind != TokenKind::Identifier {
+                // Typedefs for function pointers and arrays aren't needed for structs we can import
+                self.skip_declaration();
+                return Ok(());
+            }
+            words.push(token.text);
+        }
+        self.expect(";")?;
+        if let Some((alias, type_words)) = words.split_last() {
+            if let Ok(ctype) = self.resolve_type(type_words, line) {
+                self.types.insert(alias.to_string(), ctype);
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse `[tag] [attributes] { members } [attributes]` after the `struct` keyword and add the struct.
+    /// For typedefs, this also parses the name of the typedef following the struct, which is then used as the name of the layout.
+    fn struct_definition(&mut self, typedef: bool) -> Result<(), CImportError> {
+        let line = self.line();
+        let mut packed = self.attributes()?;
+        let tag = if self.peek_is("{") {
+            None
+        } else {
+            Some(self.identifier()?)
+        };
+        packed |= self.attributes()?;
+        self.expect("{")?;
+        let mut members = Vec::new();
+        while !self.accept("}") {
+            self.members(&mut members)?;
+        }
+        packed |= self.attributes()?;
+        let alias = if typedef {
+            Some(self.identifier()?)
+        } else {
+            None
+        };
+        let name = alias
+            .or(tag)
+            .ok_or_else(|| self.error("Expected a name for the struct"))?;
+
+        let max_alignment = if packed { Some(1) } else { self.pack };
+        let imported = self.layout(name.to_string(), &members, max_alignment, line)?;
+        let ctype = CType::Struct {
+            name: imported.name.clone(),
+            size: imported.size,
+            alignment: imported.alignment,
+            open_ended: members.last().map_or(false, |m| m.array == Some(None)),
+        };
+        if let Some(tag) = tag {
+            self.types.insert(format!("struct {}", tag), ctype.clone());
+        }
+        if let Some(alias) = alias {
+            self.types.insert(alias.to_string(), ctype);
+        }
+        self.structs.push(imported);
+        Ok(())
+    }
+
+    /// Parse `__attribute__((...))` lists and return whether they make the struct packed
+    fn attributes(&mut self) -> Result<bool, CImportError> {
+        let mut packed = false;
+        loop {
+            if self.accept("__packed") {
+                packed = true;
+            } else if self.accept("__attribute__") || self.accept("__attribute") {
+                self.expect("(")?;
+                self.expect("(")?;
+                loop {
+                    match self.identifier()? {
+                        "packed" | "__packed__" => packed = true,
+                        other => {
+                            return Err(self.error(format!(
+                                "Unsupported attribute `{}`, only `packed` is supported",
+                                other
+                            )))
+                        }
+                    }
+                    if !self.accept(",") {
+                        break;
+                    }
+                }
+                self.expect(")")?;
+                self.expect(")")?;
+            } else {
+                return Ok(packed);
+            }
+        }
+    }
+
+    /// Parse a member declaration like `uint8_t a, b[4];`
+    fn members(&mut self, members: &mut Vec<Member<'a>>) -> Result<(), CImportError> {
+        let line = self.line();
+        let mut words = Vec::new();
+        loop {
+            let token = self.next()?;
+            match token.text {
+                "struct" if self.peek_is("{") || self.tokens.get(self.position + 1).map(|t| t.text) == Some("{") => {
+                    return Err(self.error("Nested struct definitions aren't supported, declare the struct before the struct using it"));
+                }
+                "union" => return Err(self.error("Unions aren't supported")),
+                "enum" => {
+                    return Err(self.error(
+                        "Enums aren't supported because their size depends on the compiler, use a fixed width integer type",
+                    ))
+                }
+                "*" => return Err(self.error("Pointers aren't supported")),
+                "const" | "volatile" => {}
+                _ if token.kind == TokenKind::Identifier => words.push(token.text),
+                _ => {
+                    self.position -= 1;
+                    break;
+                }
+            }
+        }
+        let (name, type_words) = words
+            .split_last()
+            .filter(|(_, type_words)| !type_words.is_empty())
+            .ok_or_else(|| self.error("Expected a member declaration"))?;
+        let ctype = self.resolve_type(type_words, line)?;
+        let mut name = *name;
+        loop {
+            let array = if self.accept("[") {
+                if self.accept("]") {
+                    Some(None)
+                } else {
+                    let length = self.array_length()?;
+                    self.expect("]")?;
+                    Some(Some(length))
+                }
+            } else {
+                None
+            };
+            if self.peek_is("[") {
+                return Err(self.error("Multidimensional arrays aren't supported"));
+            }
+            if self.peek_is(":") {
+                return Err(self.error("Bit fields aren't supported"));
+            }
+            members.push(Member {
+                name,
+                ctype: ctype.clone(),
+                array,
+                line,
+            });
+            if self.accept(";") {
+                return Ok(());
+            }
+            self.expect(",")?;
+            if self.peek_is("*") {
+                return Err(self.error("Pointers aren't supported"));
+            }
+            name = self.identifier()?;
+        }
+    }
+
+    fn array_length(&mut self) -> Result<usize, CImportError> {
+        let token = self.next()?;
+        let length = match token.kind {
+            TokenKind::Number => parse_integer(token.text),
+            TokenKind::Identifier => self.constants.get(token.text).copied(),
+            _ => None,
+        };
+        length.ok_or_else(|| CImportError {
+            line: token.line,
+            message: format!(
+                "Array lengths must be integer literals or `#define`d integer constants, found `{}`",
+                token.text
+            ),
+        })
+    }
+
+    fn resolve_type(&self, words: &[&str], line: usize) -> Result<CType, CImportError> {
+        let name = words.join(" ");
+        primitive_type(&name)
+            .or_else(|| self.types.get(&name).cloned())
+            .ok_or_else(|| CImportError {
+                line,
+                message: if words.contains(&"long") && !name.contains("long long") {
+                    format!(
+                        "The type `{}` has a different size on different platforms, use a fixed width integer type",
+                        name
+                    )
+                } else {
+                    format!("Unknown type `{}`", name)
+                },
+            })
+    }
+
+    /// Compute the layout of a struct like a C compiler and write its definition
+    fn layout(
+        &self,
+        name: String,
+        members: &[Member<'a>],
+        max_alignment: Option<usize>,
+        line: usize,
+    ) -> Result<CStruct, CImportError> {
+        let error = |line, message: String| CImportError { line, message };
+        if members.is_empty() {
+            return Err(error(line, "Empty structs aren't supported".to_string()));
+        }
+        let mut fields = Vec::new();
+        let mut padding = Vec::new();
+        let mut offset = 0;
+        let mut struct_alignment = 1;
+        let mut add_padding =
+            |fields: &mut Vec<DefinitionField>, offset: &mut usize, alignment: usize| {
+                let misalignment = *offset % alignment;
+                if misalignment != 0 {
+                    let size = alignment - misalignment;
+                    let field = format!("_padding{}", padding.len());
+                    fields.push(DefinitionField {
+                        name: field.clone(),
+                        field_type: format!("[u8; {}]", size),
+                        comment: Some("inserted for alignment"),
+                    });
+                    padding.push(CPadding {
+                        field,
+                        offset: *offset,
+                        size,
+                    });
+                    *offset += size;
+                }
+            };
+        for (index, member) in members.iter().enumerate() {
+            let alignment = max_alignment.map_or(member.ctype.alignment(), |max| {
+                member.ctype.alignment().min(max)
+            });
+            struct_alignment = struct_alignment.max(alignment);
+            add_padding(&mut fields, &mut offset, alignment);
+            let field_type = match (&member.ctype, member.array) {
+                (
+                    CType::Struct {
+                        open_ended: true,
+                        name,
+                        ..
+                    },
+                    _,
+                ) => {
+                    return Err(error(
+                        member.line,
+                        format!(
+                            "`{}` ends with a flexible array member and can't be used as a member type",
+                            name
+                        ),
+                    ));
+                }
+                (CType::Struct { name, .. }, None) => format!("super::{}::NestedView", name),
+                (CType::Struct { .. }, Some(_)) => {
+                    return Err(error(
+                        member.line,
+                        "Arrays of structs aren't supported".to_string(),
+                    ))
+                }
+                (CType::Primitive { rust, .. }, None) => rust.to_string(),
+                (CType::Primitive { size: 1, rust }, Some(None)) if !rust.contains(" as ") => {
+                    if index + 1 != members.len() {
+                        return Err(error(
+                            member.line,
+                            "Flexible array members must be the last member".to_string(),
+                        ));
+                    }
+                    "[u8]".to_string()
+                }
+                (CType::Primitive { .. }, Some(None)) => {
+                    return Err(error(
+                        member.line,
+                        "Only flexible array members of bytes are supported".to_string(),
+                    ))
+                }
+                (CType::Primitive { rust, .. }, Some(Some(_))) if rust.contains(" as ") => {
+                    return Err(error(
+                        member.line,
+                        format!("Arrays of `{}` aren't supported", rust),
+                    ))
+                }
+                (CType::Primitive { rust, .. }, Some(Some(length))) => {
+                    format!("[{}; {}]", rust, length)
+                }
+            };
+            let name = field_name(member.name).map_err(|message| error(member.line, message))?;
+            fields.push(DefinitionField {
+                name,
+                field_type,
+                comment: None,
+            });
+            offset += member.ctype.size()
+                * match member.array {
+                    None => 1,
+                    Some(length) => length.unwrap_or(0),
+                };
+        }
+        let size = if members.last().map_or(false, |m| m.array == Some(None)) {
+            // C doesn't add trailing padding after a flexible array member, it overlaps it instead
+            let misalignment = offset % struct_alignment;
+            offset + (struct_alignment - misalignment) % struct_alignment
+        } else {
+            add_padding(&mut fields, &mut offset, struct_alignment);
+            offset
+        };
+
+        let definition = write_definition(&name, self.endianness, fields);
+        Ok(CStruct {
+            name,
+            size,
+            alignment: struct_alignment,
+            definition,
+            padding,
+        })
+    }
+}
@@ -0,0 +1,293 @@
+use core::ops::{Bound, RangeBounds};
+
+use crate::{Error, ErrorKind, Field, FieldCopyAccess};
+
+/// A checksum algorithm for [checksum fields](crate::binary_layout!#checksum-fields).
+///
+/// The data covered by a checksum field is fed into a new instance of the algorithm in one or more
+/// calls to [Checksum::update], and [Checksum::finish] then returns the checksum, which is stored in the checksum field.
+/// The bytes of the checksum field itself are fed as zeroes, so checksums can cover the field they're stored in.
+///
+/// # Example
+/// ```
+/// use binary_layout::{prelude::*, Checksum};
+///
+/// /// Sum of all bytes, modulo 256
+/// #[derive(Default)]
+/// struct ByteSum(u8);
+///
+/// impl Checksum for ByteSum {
+///     type Output = u8;
+///
+///     fn update(&mut self, data: &[u8]) {
+///         for byte in data {
+///             self.0 = self.0.wrapping_add(*byte);
+///         }
+///     }
+///
+///     fn finish(self) -> u8 {
+///         self.0
+///     }
+/// }
+///
+/// binary_layout!(frame, BigEndian, {
+///   sum: u8 = checksum(ByteSum, ..),
+///   payload: [u8],
+/// });
+///
+/// # fn main() {
+/// let mut view = frame::View::new([0, 1, 2, 3]);
+/// view.finalize().unwrap();
+/// assert_eq!(6, view.sum().read());
+/// assert!(view.verify().is_ok());
+/// # }
+/// ```
+pub trait Checksum: Default {
+    /// The type of the checksum. This must be the type of the checksum field.
+    type Output;
+
+    /// Feed data covered by the checksum into the algorithm
+    fn update(&mut self, data: &[u8]);
+
+    /// Return the checksum of all the data that was fed into the algorithm
+    fn finish(self) -> Self::Output;
+}
+
+/// Compute the checksum `C` over `pseudo_header` followed by the given byte range of `storage`,
+/// with the bytes of the checksum field `F` treated as zeroes.
+fn compute<F: Field, C: Checksum>(
+    storage: &[u8],
+    pseudo_header: &[u8],
+    range: impl RangeBounds<usize>,
+) -> C::Output {
+    let start = match range.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => end + 1,
+        Bound::Excluded(end) => *end,
+        Bound::Unbounded => storage.len(),
+    };
+    let field_size = F::SIZE.expect("Checksum fields must have a static size");
+    let field_start = F::OFFSET.max(start).min(end);
+    let field_end = (F::OFFSET + field_size).max(start).min(end);
+
+    let mut checksum = C::default();
+    checksum.update(pseudo_header);
+    checksum.update(&storage[start..field_start]);
+    let mut zeroes = field_end - field_start;
+    while zeroes > 0 {
+        let chunk = zeroes.min(16);
+        checksum.update(&[0; 16][..chunk]);
+        zeroes -= chunk;
+    }
+    checksum.update(&storage[field_end..end]);
+    checksum.finish()
+}
+
+/// Compute the checksum `C` and write it to the checksum field `F`. Used by the generated `View::finalize()` method.
+pub fn write_checksum<F, C>(
+    storage: &mut [u8],
+    pseudo_header: &[u8],
+    range: impl RangeBounds<usize>,
+) -> Result<(), Error>
+where
+    F: FieldCopyAccess<HighLevelType = C::Output>,
+    F::WriteError: Into<Error>,
+    C: Checksum,
+{
+    let checksum = compute::<F, C>(storage, pseudo_header, range);
+    F::try_write(storage, checksum).map_err(Into::into)
+}
+
+/// Check that the checksum field `F` holds the checksum `C`. Used by the generated `View::verify()` method.
+pub fn verify_checksum<F, C>(
+    storage: &[u8],
+    pseudo_header: &[u8],
+    range: impl RangeBounds<usize>,
+) -> Result<(), Error>
+where
+    F: FieldCopyAccess<HighLevelType = C::Output>,
+    F::ReadError: Into<Error>,
+    C: Checksum,
+    C::Output: PartialEq,
+{
+    if F::try_read(storage).map_err(Into::into)? == compute::<F, C>(storage, pseudo_header, range) {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorKind::ChecksumMismatch))
+    }
+}
+
+/// The 16-bit ones' complement checksum of [RFC 1071](https://www.rfc-editor.org/rfc/rfc1071) used by IPv4, ICMP, TCP and UDP.
+///
+/// The checksum is computed over the data in network byte order, so checksum fields using it should be `u16` fields of a
+/// [BigEndian](crate::BigEndian) layout. Data can be fed in chunks of any length, including odd lengths.
+///
+/// TCP and UDP checksums additionally cover a pseudo header with data from the IP header. It can be built with
+/// [InternetChecksum::ipv4_pseudo_header] or [InternetChecksum::ipv6_pseudo_header] and passed to the generated
+/// `View::finalize_with_pseudo_header()` and `View::verify_with_pseudo_header()` methods.
+/// Note that UDP transmits a computed checksum of zero as `0xffff`, which isn't done automatically.
+///
+/// # Example
+/// ```
+/// use binary_layout::{prelude::*, InternetChecksum};
+///
+/// binary_layout!(udp_header, BigEndian, {
+///   source_port: u16,
+///   destination_port: u16,
+///   length: u16,
+///   checksum: u16 = checksum(InternetChecksum, ..),
+///   payload: [u8],
+/// });
+///
+/// # fn main() {
+/// let mut view = udp_header::View::new(vec![0; 10]);
+/// view.source_port_mut().write(1234);
+/// view.destination_port_mut().write(53);
+/// view.length_mut().write(10);
+/// view.payload_mut().copy_from_slice(b"hi");
+///
+/// let pseudo_header = InternetChecksum::ipv4_pseudo_header([10, 0, 0, 1], [10, 0, 0, 2], 17, 10);
+/// view.finalize_with_pseudo_header(&pseudo_header).unwrap();
+/// assert!(view.verify_with_pseudo_header(&pseudo_header).is_ok());
+/// assert!(view.verify().is_err());
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct InternetChecksum {
+    sum: u64,
+    odd_byte: Option<u8>,
+}
+
+impl InternetChecksum {
+    /// Compute the internet checksum of the given data
+    pub fn checksum(data: &[u8]) -> u16 {
+        let mut checksum = Self::default();
+        checksum.update(data);
+        checksum.finish()
+    }
+
+    /// The pseudo header covered by TCP and UDP checksums over IPv4, see [RFC 768](https://www.rfc-editor.org/rfc/rfc768).
+    /// `length` is the length of the TCP or UDP header including its payload.
+    pub fn ipv4_pseudo_header(
+        source: [u8; 4],
+        destination: [u8; 4],
+        protocol: u8,
+        length: u16,
+    ) -> [u8; 12] {
+        let mut pseudo_header = [0; 12];
+        pseudo_header[0..4].copy_from_slice(&source);
+        pseudo_header[4..8].copy_from_slice(&destination);
+        pseudo_header[9] = protocol;
+        pseudo_header[10..12].copy_from_slice(&length.to_be_bytes());
+        pseudo_header
+    }
+
+    /// The pseudo header covered by TCP and UDP checksums over IPv6, see [RFC 8200](https://www.rfc-editor.org/rfc/rfc8200#section-8.1).
+    /// `length` is the length of the TCP or UDP header including its payload.
+    pub fn ipv6_pseudo_header(
+        source: [u8; 16],
+        destination: [u8; 16],
+        next_header: u8,
+        length: u32,
+    ) -> [u8; 40] {
+        let mut pseudo_header = [0; 40];
+        pseudo_header[0..16].copy_from_slice(&source);
+        pseudo_header[16..32].copy_from_slice(&destination);
+        pseudo_header[32..36].copy_from_slice(&length.to_be_bytes());
+        pseudo_header[39] = next_header;
+        pseudo_header
+    }
+}
+
+impl Checksum for InternetChecksum {
+    type Output = u16;
+
+    fn update(&mut self, mut data: &[u8]) {
+        if let Some(high) = self.odd_byte.take() {
+            match data.split_first() {
+                Some((low, rest)) => {
+                    self.sum += u64::from(u16::from_be_bytes([high, *low]));
+                    data = rest;
+                }
+                None => {
+                    self.odd_byte = Some(high);
+                    return;
+                }
+            }
+        }
+        let mut words = data.chunks_exact(2);
+        for word in &mut words {
+            self.sum += u64::from(u16::from_be_bytes([word[0], word[1]]));
+        }
+        self.odd_byte = words.remainder().first().copied();
+    }
+
+    fn finish(self) -> u16 {
+        let mut sum = self.sum;
+        if let Some(high) = self.odd_byte {
+            sum += u64::from(u16::from_be_bytes([high, 0]));
+        }
+        while sum > 0xffff {
+            sum = (sum & 0xffff) + (sum >> 16);
+        }
+        !(sum as u16)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc_1071_example() {
+        // Example from section 3 of RFC 1071, whose ones' complement sum is 0xddf2
+        let data = [0x00, 0x01, 0xf2, 0x03, 0xf4, 0xf5, 0xf6, 0xf7];
+        assert_eq!(!0xddf2, InternetChecksum::checksum(&data));
+    }
+
+    #[test]
+    fn odd_length() {
+        assert_eq!(!0x0102u16, InternetChecksum::checksum(&[1, 2]));
+        assert_eq!(!0x0402u16, InternetChecksum::checksum(&[1, 2, 3]));
+        assert_eq!(!0x0100u16, InternetChecksum::checksum(&[1]));
+    }
+
+    #[test]
+    fn chunks_of_any_length() {
+        let data: [u8; 9] = [0x45, 0x00, 0x00, 0x73, 0x00, 0x00, 0x40, 0x00, 0x40];
+        let expected = InternetChecksum::checksum(&data);
+        for split in 0..=data.len() {
+            for second_split in split..=data.len() {
+                let mut checksum = InternetChecksum::default();
+                checksum.update(&data[..split]);
+                checksum.update(&data[split..second_split]);
+                checksum.update(&data[second_split..]);
+                assert_eq!(expected, checksum.finish());
+            }
+        }
+    }
+
+    #[test]
+    fn ipv4_header() {
+        // IPv4 header from https://en.wikipedia.org/wiki/Internet_checksum#Calculating_the_IPv4_header_checksum
+        let header = [
+            0x45, 0x00, 0x00, 0x73, 0x00, 0x00, 0x40, 0x00, 0x40, 0x11, 0x00, 0x00, 0xc0, 0xa8,
+            0x00, 0x01, 0xc0, 0xa8, 0x00, 0xc7,
+        ];
+        assert_eq!(0xb861, InternetChecksum::checksum(&header));
+    }
+
+    #[test]
+    fn carries() {
+        // 0xffff + 0x0002 = 0x10001, the carry is added back in
+        assert_eq!(
+            !0x0002u16,
+            InternetChecksum::checksum(&[0xff, 0xff, 0x00, 0x02])
+        );
+        assert_eq!(0, InternetChecksum::checksum(&[0xff, 0xff]));
+    }
+}
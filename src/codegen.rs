@@ -0,0 +1,59 @@
+//! Generating layouts from standalone layout definition files in build scripts. This module is only available with the `std` feature.
+//!
+//! A layout definition file (e.g. `header.layout`) contains a single layout written with the syntax of the [binary_layout!](crate::binary_layout!) macro,
+//! with or without the surrounding `binary_layout!(...);`, like it is parsed by [DynLayout::parse]. This allows sharing one schema file
+//! between the Rust implementation, which includes the generated code, and other tooling like documentation generators,
+//! which can read it with [DynLayout::parse] at runtime.
+//!
+//! Call [generate] from the `build.rs` of your crate, with `binary-layout` as a build dependency:
+//! ```no_run
+//! // in fn main() of build.rs
+//! let out_dir = std::path::PathBuf::from(std::env::var_os("OUT_DIR").unwrap());
+//! binary_layout::codegen::generate(["schema/header.layout"], out_dir.join("layouts.rs")).unwrap();
+//! ```
+//! and include the generated code into a module of your crate:
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/layouts.rs"));
+//! ```
+//! The generated code is the [binary_layout!](crate::binary_layout!) invocation written by [DynLayout::to_definition],
+//! so the layouts offer the same API as layouts defined with the macro directly.
+
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+
+use crate::DynLayout;
+
+/// Read the layout definition files `files` and write the Rust code defining their layouts to `output`.
+///
+/// This prints `cargo:rerun-if-changed` for each of the files, so that cargo runs the build script again when one of them changes.
+/// It fails with an error of kind [InvalidData](io::ErrorKind::InvalidData) naming the file and position if a file can't be parsed,
+/// see [DynLayout::parse] for the supported syntax.
+pub fn generate<P: AsRef<Path>>(
+    files: impl IntoIterator<Item = P>,
+    output: impl AsRef<Path>,
+) -> io::Result<()> {
+    let mut code =
+        String::from("// This file is generated by binary_layout::codegen, don't edit it.\n");
+    for file in files {
+        let file = file.as_ref();
+        println!("cargo:rerun-if-changed={}", file.display());
+        let layout = DynLayout::parse(&std::fs::read_to_string(file)?).map_err(|error| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}:{}", file.display(), error),
+            )
+        })?;
+        let definition = layout
+            .to_definition()
+            .expect("Parsed layouts have the same endianness for all fields");
+        write!(
+            code,
+            "\n// Generated from {}\n{}",
+            file.display(),
+            definition
+        )
+        .expect("Writing to a String can't fail");
+    }
+    std::fs::write(output, code)
+}
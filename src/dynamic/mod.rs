@@ -0,0 +1,566 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+mod parse;
+
+pub use parse::DynLayoutParseError;
+
+use crate::{
+    BigEndian, EndianKind, Endianness, FieldKind, FieldReadExt, FieldWriteExt, LittleEndian,
+    NativeEndian, PrimitiveField, Storage, StorageMut,
+};
+
+/// A layout that is defined at runtime, e.g. from a format description in a config file, instead of with [binary_layout!](crate::binary_layout!).
+///
+/// It is constructed from a list of fields with their name, [type](DynFieldType) and [endianness](EndianKind). Like for layouts defined with the macro,
+/// the fields are stored one after another without padding and the offsets are computed from the sizes of the fields before them.
+/// Only the last field may be an open ended byte array.
+///
+/// The fields are accessed through a [DynView] by their name. Reading or writing a field with a type that doesn't match its declared type
+/// returns an error instead of reinterpreting the data, and the values are converted from and to the endianness of the field.
+///
+/// # Example
+/// ```
+/// use binary_layout::{DynFieldType, DynLayout, EndianKind};
+///
+/// let layout = DynLayout::new("header", [
+///     ("magic", DynFieldType::U32, EndianKind::Big),
+///     ("version", DynFieldType::U16, EndianKind::Little),
+///     ("payload", DynFieldType::OpenEndedBytes, EndianKind::Little),
+/// ]).unwrap();
+/// assert_eq!(6, layout.field("payload").unwrap().offset());
+/// assert_eq!(None, layout.size());
+///
+/// let mut storage = [0; 8];
+/// let mut view = layout.view(&mut storage);
+/// view.write("magic", 0xCAFE_BABEu32).unwrap();
+/// view.write("version", 3u16).unwrap();
+/// view.bytes_mut("payload").unwrap().copy_from_slice(&[1, 2]);
+/// assert_eq!(3, view.read::<u16>("version").unwrap());
+/// assert!(view.read::<u32>("version").is_err());
+/// assert_eq!([0xCA, 0xFE, 0xBA, 0xBE, 3, 0, 1, 2], storage);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynLayout {
+    name: String,
+    fields: Vec<DynField>,
+    size: Option<usize>,
+    fixed_size: usize,
+}
+
+impl DynLayout {
+    /// Create a layout with the given name and fields. The fields are given as tuples of their name, type and endianness, in the order they are stored.
+    ///
+    /// This fails with a [DynLayoutError] if two fields have the same name or an open ended byte array isn't the last field.
+    pub fn new<N: Into<String>>(
+        name: impl Into<String>,
+        fields: impl IntoIterator<Item = (N, DynFieldType, EndianKind)>,
+    ) -> Result<Self, DynLayoutError> {
+        let mut layout = Self {
+            name: name.into(),
+            fields: Vec::new(),
+            size: Some(0),
+            fixed_size: 0,
+        };
+        for (name, field_type, endianness) in fields {
+            let name = name.into();
+            if layout.field(&name).is_some() {
+                return Err(DynLayoutError::DuplicateField { name });
+            }
+            if let Some(last) = layout.fields.last() {
+                if last.size().is_none() {
+                    return Err(DynLayoutError::OpenEndedFieldNotLast {
+                        name: last.name.clone(),
+                    });
+                }
+            }
+            layout.fields.push(DynField {
+                name,
+                field_type,
+                endianness,
+                offset: layout.fixed_size,
+            });
+            match field_type.size() {
+                Some(size) => {
+                    layout.fixed_size += size;
+                    layout.size = Some(layout.fixed_size);
+                }
+                None => layout.size = None,
+            }
+        }
+        Ok(layout)
+    }
+
+    /// Parse a layout definition written with the same syntax as for the [binary_layout!](crate::binary_layout!) macro,
+    /// e.g. from a schema shipped in a data file, so that it has the same field offsets as the layout defined with the macro.
+    /// The definition can be wrapped in `binary_layout!(...);` or be just its arguments, and it may contain `//` comments.
+    /// A visibility like `pub` before the name of the layout is ignored.
+    ///
+    /// The supported field types are the primitive integer and float types as well as `[u8; N]` and `[u8]`,
+    /// other features of the macro like nested layouts, `where` clauses or `owned` structs aren't supported.
+    /// This fails with a [DynLayoutParseError] pointing at the problem if the definition can't be parsed or isn't a valid layout.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::DynLayout;
+    ///
+    /// let layout = DynLayout::parse("
+    ///     binary_layout!(header, LittleEndian, {
+    ///         magic: [u8; 4],
+    ///         version: u16, // incremented on breaking changes
+    ///         payload: [u8],
+    ///     });
+    /// ").unwrap();
+    /// assert_eq!(6, layout.field("payload").unwrap().offset());
+    ///
+    /// let error = DynLayout::parse("header, LittleEndian, { magic: u24 }").unwrap_err();
+    /// assert_eq!("1:32: Unsupported field type `u24`", error.to_string());
+    /// ```
+    pub fn parse(definition: &str) -> Result<Self, DynLayoutParseError> {
+        parse::parse(definition)
+    }
+
+    /// Write this layout as a definition for the [binary_layout!](crate::binary_layout!) macro, i.e. the inverse of [DynLayout::parse].
+    /// The generated module is `pub` and the macro is called by its full path, so that the definition can be included into any module.
+    ///
+    /// This fails with [DynLayoutError::MixedEndianness] if the fields don't all have the same endianness, since the macro only supports one
+    /// endianness per layout. Layouts without fields are written as little endian.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::DynLayout;
+    ///
+    /// let layout = DynLayout::parse("header, BigEndian, { magic: [u8; 4], version: u16 }").unwrap();
+    /// assert_eq!(
+    ///     "::binary_layout::binary_layout!(pub header, BigEndian, {\n    magic: [u8; 4],\n    version: u16,\n});\n",
+    ///     layout.to_definition().unwrap(),
+    /// );
+    /// ```
+    pub fn to_definition(&self) -> Result<String, DynLayoutError> {
+        let endianness = self
+            .fields
+            .first()
+            .map_or(EndianKind::Little, |field| field.endianness);
+        if self
+            .fields
+            .iter()
+            .any(|field| field.endianness != endianness)
+        {
+            return Err(DynLayoutError::MixedEndianness);
+        }
+        let endianness = match endianness {
+            EndianKind::Big => "BigEndian",
+            EndianKind::Little => "LittleEndian",
+            EndianKind::Native => "NativeEndian",
+        };
+        let mut definition = alloc::format!(
+            "::binary_layout::binary_layout!(pub {}, {}, {{\n",
+            self.name,
+            endianness
+        );
+        for field in &self.fields {
+            definition += &alloc::format!("    {}: {},\n", field.name, field.field_type);
+        }
+        definition += "});\n";
+        Ok(definition)
+    }
+
+    /// The name of the layout
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The size of the layout in number of bytes, or `None` if it ends with an open ended byte array
+    pub fn size(&self) -> Option<usize> {
+        self.size
+    }
+
+    /// The size of all fields except for an open ended byte array at the end, i.e. the minimal size of a storage for this layout
+    pub fn fixed_size(&self) -> usize {
+        self.fixed_size
+    }
+
+    /// The fields of the layout in the order they are stored
+    pub fn fields(&self) -> &[DynField] {
+        &self.fields
+    }
+
+    /// The field with the given name, if there is one
+    pub fn field(&self, name: &str) -> Option<&DynField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Create a view to access the fields of this layout in `storage`
+    pub fn view<S: Storage>(&self, storage: S) -> DynView<'_, S> {
+        DynView {
+            layout: self,
+            storage,
+        }
+    }
+
+    fn checked_field(
+        &self,
+        name: &str,
+        storage_len: usize,
+    ) -> Result<(&DynField, usize, usize), DynLayoutError> {
+        let field = self
+            .field(name)
+            .ok_or_else(|| DynLayoutError::UnknownField { name: name.into() })?;
+        // Open ended byte arrays extend until the end of the storage
+        let (needed, end) = match field.size() {
+            Some(size) => (field.offset + size, field.offset + size),
+            None => (field.offset, storage_len),
+        };
+        if storage_len < needed {
+            return Err(DynLayoutError::OutOfBounds {
+                field: field.name.clone(),
+                needed,
+                available: storage_len,
+            });
+        }
+        Ok((field, field.offset, end))
+    }
+
+    fn typed_field<T: DynValue>(
+        &self,
+        name: &str,
+        storage_len: usize,
+    ) -> Result<(EndianKind, usize, usize), DynLayoutError> {
+        let (field, start, end) = self.checked_field(name, storage_len)?;
+        if field.field_type != T::TYPE {
+            return Err(DynLayoutError::TypeMismatch {
+                field: field.name.clone(),
+                field_type: field.field_type,
+                requested: T::TYPE,
+            });
+        }
+        Ok((field.endianness, start, end))
+    }
+}
+
+/// Parses a layout definition, see [DynLayout::parse]
+impl FromStr for DynLayout {
+    type Err = DynLayoutParseError;
+
+    fn from_str(definition: &str) -> Result<Self, Self::Err> {
+        Self::parse(definition)
+    }
+}
+
+/// A field of a [DynLayout]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynField {
+    name: String,
+    field_type: DynFieldType,
+    endianness: EndianKind,
+    offset: usize,
+}
+
+impl DynField {
+    /// The name of the field
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The type of the field
+    pub fn field_type(&self) -> DynFieldType {
+        self.field_type
+    }
+
+    /// The endianness the field is stored with
+    pub fn endianness(&self) -> EndianKind {
+        self.endianness
+    }
+
+    /// The offset of the field from the start of the layout in number of bytes
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The size of the field in number of bytes, or `None` for an open ended byte array
+    pub fn size(&self) -> Option<usize> {
+        self.field_type.size()
+    }
+}
+
+/// The type of a field of a [DynLayout]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DynFieldType {
+    /// [u8]
+    U8,
+    /// [u16]
+    U16,
+    /// [u32]
+    U32,
+    /// [u64]
+    U64,
+    /// [u128]
+    U128,
+    /// [i8]
+    I8,
+    /// [i16]
+    I16,
+    /// [i32]
+    I32,
+    /// [i64]
+    I64,
+    /// [i128]
+    I128,
+    /// [f32]
+    F32,
+    /// [f64]
+    F64,
+    /// A fixed size byte array `[u8; N]` with the given size
+    Bytes(usize),
+    /// An open ended byte array `[u8]`, which extends until the end of the storage. It can only be the last field of a layout.
+    OpenEndedBytes,
+}
+
+impl DynFieldType {
+    /// The size of a field of this type in number of bytes, or `None` for an open ended byte array
+    pub const fn size(self) -> Option<usize> {
+        match self {
+            Self::U8 | Self::I8 => Some(1),
+            Self::U16 | Self::I16 => Some(2),
+            Self::U32 | Self::I32 | Self::F32 => Some(4),
+            Self::U64 | Self::I64 | Self::F64 => Some(8),
+            Self::U128 | Self::I128 => Some(16),
+            Self::Bytes(size) => Some(size),
+            Self::OpenEndedBytes => None,
+        }
+    }
+
+    /// The kind of data a field of this type stores, like [FieldInfo::kind](crate::FieldInfo::kind) for layouts defined with the macro
+    pub const fn kind(self) -> FieldKind {
+        match self {
+            Self::U8 | Self::U16 | Self::U32 | Self::U64 | Self::U128 => FieldKind::UnsignedInt,
+            Self::I8 | Self::I16 | Self::I32 | Self::I64 | Self::I128 => FieldKind::SignedInt,
+            Self::F32 | Self::F64 => FieldKind::Float,
+            Self::Bytes(_) => FieldKind::ByteArray,
+            Self::OpenEndedBytes => FieldKind::OpenEndedByteArray,
+        }
+    }
+}
+
+/// Formats the type like it is written in a layout definition, e.g. `u16` or `[u8; 4]`
+impl Display for DynFieldType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::U8 => write!(f, "u8"),
+            Self::U16 => write!(f, "u16"),
+            Self::U32 => write!(f, "u32"),
+            Self::U64 => write!(f, "u64"),
+            Self::U128 => write!(f, "u128"),
+            Self::I8 => write!(f, "i8"),
+            Self::I16 => write!(f, "i16"),
+            Self::I32 => write!(f, "i32"),
+            Self::I64 => write!(f, "i64"),
+            Self::I128 => write!(f, "i128"),
+            Self::F32 => write!(f, "f32"),
+            Self::F64 => write!(f, "f64"),
+            Self::Bytes(size) => write!(f, "[u8; {}]", size),
+            Self::OpenEndedBytes => write!(f, "[u8]"),
+        }
+    }
+}
+
+/// Implemented for the types that can be read from and written to the number fields of a [DynLayout],
+/// i.e. the primitive integer and float types. Each of them can only access fields of the matching [DynFieldType].
+pub trait DynValue: Copy + sealed::Sealed {
+    /// The type of the fields this type can access
+    const TYPE: DynFieldType;
+
+    #[doc(hidden)]
+    fn read<E: Endianness>(storage: &[u8]) -> Self;
+
+    #[doc(hidden)]
+    fn write<E: Endianness>(storage: &mut [u8], value: Self);
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! dyn_value {
+    ($type:ty, $dyn_type:ident) => {
+        impl sealed::Sealed for $type {}
+
+        impl DynValue for $type {
+            const TYPE: DynFieldType = DynFieldType::$dyn_type;
+
+            #[inline(always)]
+            fn read<E: Endianness>(storage: &[u8]) -> Self {
+                PrimitiveField::<$type, E, 0>::read(storage)
+            }
+
+            #[inline(always)]
+            fn write<E: Endianness>(storage: &mut [u8], value: Self) {
+                PrimitiveField::<$type, E, 0>::write(storage, value)
+            }
+        }
+    };
+}
+
+dyn_value!(u8, U8);
+dyn_value!(u16, U16);
+dyn_value!(u32, U32);
+dyn_value!(u64, U64);
+dyn_value!(u128, U128);
+dyn_value!(i8, I8);
+dyn_value!(i16, I16);
+dyn_value!(i32, I32);
+dyn_value!(i64, I64);
+dyn_value!(i128, I128);
+dyn_value!(f32, F32);
+dyn_value!(f64, F64);
+
+/// A view to the fields of a [DynLayout] in a storage, returned by [DynLayout::view].
+/// Fields are accessed by their name, and each access checks the type of the field and the bounds of the storage.
+pub struct DynView<'a, S> {
+    layout: &'a DynLayout,
+    storage: S,
+}
+
+impl<'a, S: Storage> DynView<'a, S> {
+    /// The layout of this view
+    pub fn layout(&self) -> &'a DynLayout {
+        self.layout
+    }
+
+    /// Read the value of the field with the given name, e.g. `view.read::<u32>("magic")`.
+    ///
+    /// This fails with a [DynLayoutError] if there is no such field, its type doesn't match `T` or the storage is too small for it.
+    pub fn read<T: DynValue>(&self, name: &str) -> Result<T, DynLayoutError> {
+        let storage = self.storage.as_bytes();
+        let (endianness, start, _) = self.layout.typed_field::<T>(name, storage.len())?;
+        let storage = &storage[start..];
+        Ok(match endianness {
+            EndianKind::Big => T::read::<BigEndian>(storage),
+            EndianKind::Little => T::read::<LittleEndian>(storage),
+            EndianKind::Native => T::read::<NativeEndian>(storage),
+        })
+    }
+
+    /// Borrow the data of the field with the given name, e.g. of a byte array field.
+    ///
+    /// This fails with a [DynLayoutError] if there is no such field or the storage is too small for it.
+    pub fn bytes(&self, name: &str) -> Result<&[u8], DynLayoutError> {
+        let storage = self.storage.as_bytes();
+        let (_, start, end) = self.layout.checked_field(name, storage.len())?;
+        Ok(&storage[start..end])
+    }
+
+    /// Return the storage underlying this view
+    pub fn into_storage(self) -> S {
+        self.storage
+    }
+}
+
+impl<'a, S: StorageMut> DynView<'a, S> {
+    /// Write a value to the field with the given name, e.g. `view.write("magic", 0xCAFE_BABEu32)`.
+    ///
+    /// This fails with a [DynLayoutError] if there is no such field, its type doesn't match `T` or the storage is too small for it.
+    pub fn write<T: DynValue>(&mut self, name: &str, value: T) -> Result<(), DynLayoutError> {
+        let storage = self.storage.as_bytes_mut();
+        let (endianness, start, _) = self.layout.typed_field::<T>(name, storage.len())?;
+        let storage = &mut storage[start..];
+        match endianness {
+            EndianKind::Big => T::write::<BigEndian>(storage, value),
+            EndianKind::Little => T::write::<LittleEndian>(storage, value),
+            EndianKind::Native => T::write::<NativeEndian>(storage, value),
+        }
+        Ok(())
+    }
+
+    /// Mutably borrow the data of the field with the given name, e.g. of a byte array field.
+    ///
+    /// This fails with a [DynLayoutError] if there is no such field or the storage is too small for it.
+    pub fn bytes_mut(&mut self, name: &str) -> Result<&mut [u8], DynLayoutError> {
+        let storage = self.storage.as_bytes_mut();
+        let (_, start, end) = self.layout.checked_field(name, storage.len())?;
+        Ok(&mut storage[start..end])
+    }
+}
+
+/// This error is returned when defining a [DynLayout] with invalid fields or when accessing a field of a [DynView] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DynLayoutError {
+    /// The layout has two fields with the same name
+    DuplicateField {
+        /// Name of the field
+        name: String,
+    },
+    /// An open ended byte array isn't the last field of the layout
+    OpenEndedFieldNotLast {
+        /// Name of the open ended field
+        name: String,
+    },
+    /// The layout doesn't have a field with the given name
+    UnknownField {
+        /// The name that was looked up
+        name: String,
+    },
+    /// The field was accessed with a type that doesn't match its type
+    TypeMismatch {
+        /// Name of the field
+        field: String,
+        /// The type of the field in the layout
+        field_type: DynFieldType,
+        /// The type it was accessed with
+        requested: DynFieldType,
+    },
+    /// The fields of the layout have different endianness, which can't be written as a definition for the macro, see [DynLayout::to_definition]
+    MixedEndianness,
+    /// The storage is too small for the field
+    OutOfBounds {
+        /// Name of the field
+        field: String,
+        /// Number of bytes needed
+        needed: usize,
+        /// Number of bytes available in the storage
+        available: usize,
+    },
+}
+
+impl Display for DynLayoutError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateField { name } => write!(f, "Duplicate field `{}`", name),
+            Self::OpenEndedFieldNotLast { name } => write!(
+                f,
+                "The open ended field `{}` must be the last field of the layout",
+                name
+            ),
+            Self::UnknownField { name } => write!(f, "Unknown field `{}`", name),
+            Self::MixedEndianness => write!(
+                f,
+                "The fields of the layout don't all have the same endianness"
+            ),
+            Self::TypeMismatch {
+                field,
+                field_type,
+                requested,
+            } => write!(
+                f,
+                "Field `{}` has type `{}` but was accessed as `{}`",
+                field, field_type, requested
+            ),
+            Self::OutOfBounds {
+                field,
+                needed,
+                available,
+            } => write!(
+                f,
+                "Field `{}` needs {} bytes but the storage only has {} bytes",
+                field, needed, available
+            ),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl crate::utils::error::Error for DynLayoutError {}
@@ -0,0 +1,249 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+use super::{DynFieldType, DynLayout, DynLayoutError};
+use crate::EndianKind;
+
+/// This error is returned from [DynLayout::parse] if a layout definition can't be parsed.
+/// It contains the position of the problem in the definition, counted from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynLayoutParseError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl DynLayoutParseError {
+    /// The line of the definition the error happened in, counted from 1
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column of the definition the error happened in, counted from 1
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// A description of the problem
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for DynLayoutParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl crate::utils::error::Error for DynLayoutParseError {}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error_at(&self, position: usize, message: impl Into<String>) -> DynLayoutParseError {
+        let before = &self.input[..position];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        DynLayoutParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message: message.into(),
+        }
+    }
+
+    fn error(&self, message: impl Into<String>) -> DynLayoutParseError {
+        self.error_at(self.position, message)
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    /// Skip whitespace and `//` comments
+    fn skip_whitespace(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.position += rest.len() - trimmed.len();
+            if trimmed.starts_with("//") {
+                self.position += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn peek(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        self.rest().starts_with(token)
+    }
+
+    fn accept(&mut self, token: &str) -> bool {
+        let found = self.peek(token);
+        if found {
+            self.position += token.len();
+        }
+        found
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), DynLayoutParseError> {
+        if self.accept(token) {
+            Ok(())
+        } else {
+            Err(self.error(alloc::format!("Expected `{}`", token)))
+        }
+    }
+
+    fn identifier(&mut self) -> Result<&'a str, DynLayoutParseError> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        if len == 0 || rest.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(self.error("Expected an identifier"));
+        }
+        self.position += len;
+        Ok(&rest[..len])
+    }
+
+    fn number(&mut self) -> Result<usize, DynLayoutParseError> {
+        self.skip_whitespace();
+        let start = self.position;
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '_'))
+            .unwrap_or(rest.len());
+        self.position += len;
+        let digits: String = rest[..len].chars().filter(|c| *c != '_').collect();
+        digits
+            .parse()
+            .map_err(|_| self.error_at(start, "Expected a number"))
+    }
+
+    fn endianness(&mut self) -> Result<EndianKind, DynLayoutParseError> {
+        self.skip_whitespace();
+        let start = self.position;
+        match self.identifier()? {
+            "BigEndian" => Ok(EndianKind::Big),
+            "LittleEndian" => Ok(EndianKind::Little),
+            "NativeEndian" => Ok(EndianKind::Native),
+            other => Err(self.error_at(
+                start,
+                alloc::format!(
+                    "Unknown endianness `{}`, expected `BigEndian`, `LittleEndian` or `NativeEndian`",
+                    other
+                ),
+            )),
+        }
+    }
+
+    fn field_type(&mut self) -> Result<DynFieldType, DynLayoutParseError> {
+        self.skip_whitespace();
+        let start = self.position;
+        if self.accept("[") {
+            let element = self.identifier()?;
+            if element != "u8" {
+                return Err(
+                    self.error_at(start, "Only byte arrays `[u8; N]` and `[u8]` are supported")
+                );
+            }
+            if self.accept("]") {
+                return Ok(DynFieldType::OpenEndedBytes);
+            }
+            self.expect(";")?;
+            let size = self.number()?;
+            self.expect("]")?;
+            return Ok(DynFieldType::Bytes(size));
+        }
+        Ok(match self.identifier()? {
+            "u8" => DynFieldType::U8,
+            "u16" => DynFieldType::U16,
+            "u32" => DynFieldType::U32,
+            "u64" => DynFieldType::U64,
+            "u128" => DynFieldType::U128,
+            "i8" => DynFieldType::I8,
+            "i16" => DynFieldType::I16,
+            "i32" => DynFieldType::I32,
+            "i64" => DynFieldType::I64,
+            "i128" => DynFieldType::I128,
+            "f32" => DynFieldType::F32,
+            "f64" => DynFieldType::F64,
+            other => {
+                return Err(
+                    self.error_at(start, alloc::format!("Unsupported field type `{}`", other))
+                )
+            }
+        })
+    }
+
+    fn layout(&mut self) -> Result<DynLayout, DynLayoutParseError> {
+        self.accept("::");
+        self.accept("binary_layout::");
+        let wrapped = self.accept("binary_layout!");
+        if wrapped {
+            self.expect("(")?;
+        }
+        let name = match self.identifier()? {
+            // The visibility of the layout doesn't matter at runtime
+            "pub" => {
+                if self.accept("(") {
+                    self.identifier()?;
+                    self.expect(")")?;
+                }
+                self.identifier()?
+            }
+            name => name,
+        };
+        self.expect(",")?;
+        let endianness = self.endianness()?;
+        self.expect(",")?;
+        self.expect("{")?;
+        let mut fields = Vec::new();
+        let mut positions = Vec::new();
+        while !self.peek("}") {
+            self.skip_whitespace();
+            positions.push(self.position);
+            let field_name = self.identifier()?;
+            self.expect(":")?;
+            let field_type = self.field_type()?;
+            fields.push((field_name, field_type, endianness));
+            if !self.accept(",") && !self.peek("}") {
+                return Err(self.error(
+                    "Expected `,` or `}` after the field, other features of binary_layout! aren't supported",
+                ));
+            }
+        }
+        self.expect("}")?;
+        if wrapped {
+            self.accept(",");
+            self.expect(")")?;
+            self.accept(";");
+        }
+        self.skip_whitespace();
+        if !self.rest().is_empty() {
+            return Err(self.error("Expected the end of the layout definition"));
+        }
+        DynLayout::new(name, fields.iter().copied()).map_err(|error| {
+            // Point at the field that caused the error
+            let name = match &error {
+                DynLayoutError::DuplicateField { name }
+                | DynLayoutError::OpenEndedFieldNotLast { name } => Some(name),
+                _ => None,
+            };
+            let position = name
+                .and_then(|name| fields.iter().rposition(|field| field.0 == name))
+                .map_or(0, |index| positions[index]);
+            self.error_at(position, error.to_string())
+        })
+    }
+}
+
+pub(super) fn parse(input: &str) -> Result<DynLayout, DynLayoutParseError> {
+    Parser { input, position: 0 }.layout()
+}
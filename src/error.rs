@@ -0,0 +1,374 @@
+use core::convert::Infallible;
+use core::fmt::{self, Display, Formatter};
+
+use crate::fields::{bool::InvalidBoolError, char::InvalidCharError};
+use crate::InvalidDosDateTimeError;
+use crate::{
+    InvalidEnumValueError, LayoutInfo, NonZeroIsZeroError, RangedFieldError, TlvWriteError,
+    UpdateError, Utf16TooLongError, ValidationError, VarBytesTooLongError, WrappedFieldError,
+};
+
+/// The kind of an [Error], i.e. what went wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The storage is too small for the data
+    OutOfBounds {
+        /// Number of bytes needed
+        needed: usize,
+        /// Number of bytes available in the storage
+        available: usize,
+    },
+    /// A value is too long to be stored, e.g. in a [VarBytes](crate::VarBytes) or [Utf16](crate::Utf16) field
+    ValueTooLong {
+        /// Length of the value in number of bytes
+        len: usize,
+    },
+    /// A non-zero integer field, e.g. [NonZeroU32](core::num::NonZeroU32), was zero, see [NonZeroIsZeroError]
+    NonZeroIsZero,
+    /// A `bool` field wasn't `0` or `1`
+    InvalidBool,
+    /// A `char` field wasn't a valid unicode scalar value
+    InvalidChar,
+    /// An enum field defined with [layout_enum!](crate::layout_enum!) didn't match any of its variants, see [InvalidEnumValueError]
+    InvalidEnumValue,
+    /// A [range constrained field](crate#range-constrained-fields) was outside of its range, see [RangedFieldError]
+    OutOfRange {
+        /// The allowed range as written in the layout definition
+        range: &'static str,
+    },
+    /// The conversion of a custom field type failed in [LayoutAs](crate::LayoutAs)
+    LayoutAs,
+    /// A validation check of a layout failed, see [ValidationError]
+    Validation {
+        /// The check that failed, as written in the layout definition
+        check: &'static str,
+    },
+    /// A [checksum field](crate::binary_layout!#checksum-fields) doesn't match the data it covers
+    ChecksumMismatch,
+    /// Data can't be converted between two layouts because they differ in more than their endianness, see [LayoutInfo::convert_to]
+    IncompatibleLayouts,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds { needed, available } => write!(
+                f,
+                "Needed {} bytes but the storage only has {} bytes",
+                needed, available
+            ),
+            Self::ValueTooLong { len } => write!(f, "A value of {} bytes is too long", len),
+            Self::NonZeroIsZero => write!(f, "Non-zero integer is zero"),
+            Self::InvalidBool => write!(f, "Invalid bool value"),
+            Self::InvalidChar => write!(f, "Invalid char value"),
+            Self::InvalidEnumValue => write!(f, "Invalid enum value"),
+            Self::OutOfRange { range } => {
+                write!(f, "Value is outside of the allowed range `{}`", range)
+            }
+            Self::LayoutAs => write!(f, "Error converting a custom field type"),
+            Self::Validation { check } => write!(f, "Validation failed: `{}`", check),
+            Self::ChecksumMismatch => write!(f, "Checksum mismatch"),
+            Self::IncompatibleLayouts => write!(f, "The layouts are incompatible"),
+        }
+    }
+}
+
+/// An error type that all errors of this crate can be converted into, for application code
+/// that doesn't want to deal with each of them separately.
+///
+/// Besides the [ErrorKind], it carries the name of the layout, the name of the field and the offset of the field
+/// if they are known. Errors returned from accessing a field can be annotated with them using [ErrorContextExt::field_context].
+///
+/// Errors returned from [LayoutAs](crate::LayoutAs) implementations of custom types can be converted as well
+/// if they implement `Into<binary_layout::Error>`, e.g. by implementing `From<MyError> for binary_layout::Error`
+/// using [Error::new] with [ErrorKind::LayoutAs].
+///
+/// # Example
+/// ```
+/// use binary_layout::{prelude::*, Error, ErrorContextExt, ErrorKind};
+///
+/// binary_layout!(my_layout, LittleEndian, {
+///   version: u8 where 1..=3,
+///   block_size: core::num::NonZeroU16,
+/// });
+///
+/// fn block_size(storage: &[u8]) -> Result<u16, Error> {
+///   let view = my_layout::View::new(storage);
+///   view.version().try_read().field_context(&my_layout::LAYOUT, "version")?;
+///   let block_size = view.block_size().try_read().field_context(&my_layout::LAYOUT, "block_size")?;
+///   Ok(block_size.get())
+/// }
+///
+/// let error = block_size(&[1, 0, 0]).unwrap_err();
+/// assert_eq!(ErrorKind::NonZeroIsZero, error.kind());
+/// assert_eq!(Some("my_layout"), error.layout());
+/// assert_eq!(Some("block_size"), error.field());
+/// assert_eq!(Some(1), error.offset());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Error {
+    kind: ErrorKind,
+    layout: Option<&'static str>,
+    field: Option<&'static str>,
+    offset: Option<usize>,
+}
+
+impl Error {
+    /// Create an error of the given kind without information about the layout or field it happened in
+    pub const fn new(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            layout: None,
+            field: None,
+            offset: None,
+        }
+    }
+
+    /// What went wrong
+    pub const fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The name of the layout the error happened in, if known
+    pub const fn layout(&self) -> Option<&'static str> {
+        self.layout
+    }
+
+    /// The name of the field the error happened in, if known
+    pub const fn field(&self) -> Option<&'static str> {
+        self.field
+    }
+
+    /// The offset of the field the error happened in, if known
+    pub const fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Annotate the error with the layout it happened in.
+    pub fn in_layout(mut self, layout: &LayoutInfo) -> Self {
+        self.layout = Some(layout.name());
+        self
+    }
+
+    /// Annotate the error with the layout it happened in and the field with the given name.
+    /// The offset of the field is looked up in the reflection data of the layout.
+    pub fn in_field(mut self, layout: &LayoutInfo, field: &str) -> Self {
+        self = self.in_layout(layout);
+        if let Some(field) = layout.field(field) {
+            self.field = Some(field.name());
+            self.offset = Some(field.offset());
+        }
+        self
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if let Some(field) = self.field {
+            write!(f, " in field `{}`", field)?;
+        }
+        if let Some(offset) = self.offset {
+            write!(f, " at offset {}", offset)?;
+        }
+        if let Some(layout) = self.layout {
+            write!(f, " of layout `{}`", layout)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl crate::utils::error::Error for Error {}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(error: Infallible) -> Self {
+        match error {}
+    }
+}
+
+impl From<NonZeroIsZeroError> for Error {
+    fn from(error: NonZeroIsZeroError) -> Self {
+        Self {
+            offset: Some(error.offset()),
+            ..Self::new(ErrorKind::NonZeroIsZero)
+        }
+    }
+}
+
+impl From<InvalidBoolError> for Error {
+    fn from(_: InvalidBoolError) -> Self {
+        Self::new(ErrorKind::InvalidBool)
+    }
+}
+
+impl From<InvalidCharError> for Error {
+    fn from(_: InvalidCharError) -> Self {
+        Self::new(ErrorKind::InvalidChar)
+    }
+}
+
+impl From<InvalidDosDateTimeError> for Error {
+    fn from(_: InvalidDosDateTimeError) -> Self {
+        Self::new(ErrorKind::LayoutAs)
+    }
+}
+
+impl<U> From<InvalidEnumValueError<U>> for Error {
+    fn from(_: InvalidEnumValueError<U>) -> Self {
+        Self::new(ErrorKind::InvalidEnumValue)
+    }
+}
+
+impl<P: Into<Error>> From<RangedFieldError<P>> for Error {
+    fn from(error: RangedFieldError<P>) -> Self {
+        match error {
+            RangedFieldError::PrimitiveAccessError(error) => error.into(),
+            RangedFieldError::OutOfRange { range } => Self::new(ErrorKind::OutOfRange { range }),
+        }
+    }
+}
+
+impl<P: Into<Error>, L: Into<Error>> From<WrappedFieldError<P, L>> for Error {
+    fn from(error: WrappedFieldError<P, L>) -> Self {
+        match error {
+            WrappedFieldError::PrimitiveAccessError(error) => error.into(),
+            WrappedFieldError::LayoutAsError(error) => error.into(),
+        }
+    }
+}
+
+impl<R: Into<Error>, W: Into<Error>> From<UpdateError<R, W>> for Error {
+    fn from(error: UpdateError<R, W>) -> Self {
+        match error {
+            UpdateError::ReadError(error) => error.into(),
+            UpdateError::WriteError(error) => error.into(),
+        }
+    }
+}
+
+impl From<ValidationError> for Error {
+    fn from(error: ValidationError) -> Self {
+        Self {
+            layout: Some(error.layout()),
+            ..Self::new(ErrorKind::Validation {
+                check: error.check(),
+            })
+        }
+    }
+}
+
+impl From<VarBytesTooLongError> for Error {
+    fn from(error: VarBytesTooLongError) -> Self {
+        Self::new(ErrorKind::ValueTooLong {
+            len: error.data_len(),
+        })
+    }
+}
+
+impl From<Utf16TooLongError> for Error {
+    fn from(error: Utf16TooLongError) -> Self {
+        Self::new(ErrorKind::ValueTooLong {
+            len: 2 * error.data_len(),
+        })
+    }
+}
+
+impl From<TlvWriteError> for Error {
+    fn from(error: TlvWriteError) -> Self {
+        match error {
+            TlvWriteError::ValueTooLong { len } => Self::new(ErrorKind::ValueTooLong { len }),
+            TlvWriteError::StorageTooSmall { needed, available } => {
+                Self::new(ErrorKind::OutOfBounds { needed, available })
+            }
+        }
+    }
+}
+
+/// This extension trait adds [ErrorContextExt::field_context] to [Result] types whose error can be converted into an [Error].
+pub trait ErrorContextExt<T> {
+    /// Convert the error into an [Error] and annotate it with the layout and field it happened in, see [Error::in_field].
+    fn field_context(self, layout: &LayoutInfo, field: &str) -> Result<T, Error>;
+}
+
+impl<T, E: Into<Error>> ErrorContextExt<T> for Result<T, E> {
+    fn field_context(self, layout: &LayoutInfo, field: &str) -> Result<T, Error> {
+        self.map_err(|error| error.into().in_field(layout, field))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn display() {
+        use alloc::format;
+
+        assert_eq!(
+            "Non-zero integer is zero",
+            format!("{}", Error::new(ErrorKind::NonZeroIsZero))
+        );
+        let error = Error {
+            kind: ErrorKind::OutOfRange { range: "1..=3" },
+            layout: Some("header"),
+            field: Some("version"),
+            offset: Some(2),
+        };
+        assert_eq!(
+            "Value is outside of the allowed range `1..=3` in field `version` at offset 2 of layout `header`",
+            format!("{}", error)
+        );
+    }
+
+    #[test]
+    fn nested_conversion() {
+        let error: WrappedFieldError<Infallible, InvalidBoolError> =
+            WrappedFieldError::LayoutAsError(InvalidBoolError(()));
+        let error: UpdateError<_, Infallible> = UpdateError::ReadError(error);
+        assert_eq!(ErrorKind::InvalidBool, Error::from(error).kind());
+    }
+
+    #[test]
+    fn non_zero_is_zero_error_keeps_offset() {
+        let error = Error::from(NonZeroIsZeroError { offset: 5 });
+        assert_eq!(ErrorKind::NonZeroIsZero, error.kind());
+        assert_eq!(Some(5), error.offset());
+    }
+
+    #[cfg(any(feature = "std", feature = "core-error"))]
+    #[test]
+    fn error_trait() {
+        fn assert_error<E: crate::utils::error::Error>() {}
+        assert_error::<Error>();
+        assert_error::<NonZeroIsZeroError>();
+        assert_error::<InvalidBoolError>();
+        assert_error::<InvalidCharError>();
+        assert_error::<InvalidEnumValueError<u8>>();
+        assert_error::<RangedFieldError<NonZeroIsZeroError>>();
+        assert_error::<WrappedFieldError<Infallible, InvalidBoolError>>();
+        assert_error::<UpdateError<NonZeroIsZeroError, Infallible>>();
+        assert_error::<ValidationError>();
+        assert_error::<VarBytesTooLongError>();
+        assert_error::<Utf16TooLongError>();
+        assert_error::<InvalidDosDateTimeError>();
+        assert_error::<TlvWriteError>();
+        assert_error::<crate::RecordsFullError>();
+    }
+
+    #[test]
+    fn validation_error_keeps_layout() {
+        let error = Error::from(ValidationError::__new("header", "check"));
+        assert_eq!(ErrorKind::Validation { check: "check" }, error.kind());
+        assert_eq!(Some("header"), error.layout());
+        assert_eq!(None, error.field());
+    }
+}
@@ -1,12 +1,20 @@
 //! This module contains an example use case for defining a layout: ICMP packets.
+//!
+//! With the `protocols` feature, the [protocols](https://docs.rs/binary-layout/latest/binary_layout/example/protocols/index.html) module additionally offers ready-made layouts for common network protocols,
+//! and with the `disk-formats` feature, the [disk_formats](https://docs.rs/binary-layout/latest/binary_layout/example/disk_formats/index.html) module offers layouts for common on-disk structures like partition tables.
 
-use crate::prelude::*;
+use crate::{prelude::*, InternetChecksum};
+
+#[cfg(feature = "disk-formats")]
+pub mod disk_formats;
+#[cfg(feature = "protocols")]
+pub mod protocols;
 
 // See https://en.wikipedia.org/wiki/Internet_Control_Message_Protocol for ICMP packet layout
 binary_layout!(icmp_packet, BigEndian, {
   packet_type: u8,
   code: u8,
-  checksum: u16,
+  checksum: u16 = checksum(InternetChecksum, ..),
   rest_of_header: [u8; 4],
   data_section: [u8], // open ended byte array, matches until the end of the packet
 });
@@ -0,0 +1,158 @@
+//! Layouts for common on-disk structures: MBR partition tables, GPT headers and FAT boot sectors.
+//! This module is only available with the `disk-formats` feature.
+//!
+//! # Example
+//! ```
+//! use binary_layout::prelude::*;
+//! use binary_layout::example::disk_formats::mbr;
+//!
+//! # fn main() {
+//! let mut sector = [0; 512];
+//! sector[446 + 4] = 0x83; // Linux partition
+//! sector[446 + 8] = 0x00;
+//! sector[446 + 9] = 0x08; // starting at LBA 2048
+//! sector[510] = 0x55;
+//! sector[511] = 0xaa;
+//!
+//! let mbr = mbr::View::new(&sector);
+//! assert!(mbr.validate().is_ok());
+//! assert_eq!(0x83, mbr.partition1().partition_type().read());
+//! assert_eq!(2048, mbr.partition1().first_lba().read());
+//! # }
+//! ```
+
+use crate::{prelude::*, Checksum};
+
+/// The CRC-32 checksum (IEEE 802.3, as used by zlib) used by the [GPT header](gpt_header).
+///
+/// It can also be used for [checksum fields](crate::binary_layout!#checksum-fields) of other layouts.
+#[derive(Debug, Clone, Copy)]
+pub struct Crc32 {
+    crc: u32,
+}
+
+impl Default for Crc32 {
+    fn default() -> Self {
+        Self { crc: 0xffff_ffff }
+    }
+}
+
+impl Checksum for Crc32 {
+    type Output = u32;
+
+    fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            self.crc ^= u32::from(*byte);
+            for _ in 0..8 {
+                let mask = (self.crc & 1).wrapping_neg();
+                self.crc = (self.crc >> 1) ^ (0xedb8_8320 & mask);
+            }
+        }
+    }
+
+    fn finish(self) -> u32 {
+        !self.crc
+    }
+}
+
+/// Value of the boot signature at the end of [MBRs](mbr) and [FAT boot sectors](fat_boot_sector)
+pub const BOOT_SIGNATURE: u16 = 0xaa55;
+
+// Partition entry of an MBR, see https://en.wikipedia.org/wiki/Master_boot_record#PTE
+binary_layout!(mbr_partition_entry, LittleEndian, {
+  status: u8,
+  chs_first: [u8; 3],
+  partition_type: u8,
+  chs_last: [u8; 3],
+  first_lba: u32,
+  sector_count: u32,
+}, assert_size(16));
+
+// Classic master boot record in the first sector of a disk, see https://en.wikipedia.org/wiki/Master_boot_record
+binary_layout!(mbr, LittleEndian, {
+  bootstrap_code: [u8; 440],
+  disk_signature: u32,
+  reserved: u16,
+  partition1: mbr_partition_entry::NestedView,
+  partition2: mbr_partition_entry::NestedView,
+  partition3: mbr_partition_entry::NestedView,
+  partition4: mbr_partition_entry::NestedView,
+  boot_signature: u16,
+}, assert_size(512), validate |view| {
+  view.boot_signature().read() == BOOT_SIGNATURE,
+});
+
+// GPT header in the second sector of a disk, see https://uefi.org/specs/UEFI/2.10/05_GUID_Partition_Table_Format.html
+// The header CRC covers the header with the size given in `header_size`, which is 92 bytes in all current revisions.
+binary_layout!(gpt_header, LittleEndian, {
+  signature: [u8; 8],
+  revision: u32,
+  header_size: u32,
+  header_crc32: u32 = checksum(Crc32, ..FIXED_SIZE),
+  reserved: u32,
+  current_lba: u64,
+  backup_lba: u64,
+  first_usable_lba: u64,
+  last_usable_lba: u64,
+  disk_guid: [u8; 16],
+  partition_entries_lba: u64,
+  number_of_partition_entries: u32,
+  partition_entry_size: u32,
+  partition_entries_crc32: u32,
+}, assert_size(92), validate |view| {
+  view.signature() == b"EFI PART",
+  view.header_size().read() as usize == FIXED_SIZE,
+});
+
+// Entry of the GPT partition entry array, see https://uefi.org/specs/UEFI/2.10/05_GUID_Partition_Table_Format.html
+// The name is stored as UTF-16LE.
+binary_layout!(gpt_partition_entry, LittleEndian, {
+  partition_type_guid: [u8; 16],
+  unique_partition_guid: [u8; 16],
+  first_lba: u64,
+  last_lba: u64,
+  attributes: u64,
+  name: [u8; 72],
+}, assert_size(128));
+
+// Boot sector of a FAT12 or FAT16 file system with the BIOS parameter block, see https://en.wikipedia.org/wiki/Design_of_the_FAT_file_system
+binary_layout!(fat_boot_sector, LittleEndian, {
+  jump_boot: [u8; 3],
+  oem_name: [u8; 8],
+  bytes_per_sector: u16,
+  sectors_per_cluster: u8,
+  reserved_sector_count: u16,
+  number_of_fats: u8,
+  root_entry_count: u16,
+  total_sectors_16: u16,
+  media: u8,
+  fat_size_16: u16,
+  sectors_per_track: u16,
+  number_of_heads: u16,
+  hidden_sectors: u32,
+  total_sectors_32: u32,
+  drive_number: u8,
+  reserved: u8,
+  extended_boot_signature: u8,
+  volume_id: u32,
+  volume_label: [u8; 11],
+  file_system_type: [u8; 8],
+  boot_code: [u8; 448],
+  boot_signature: u16,
+}, assert_size(512), validate |view| {
+  view.boot_signature().read() == BOOT_SIGNATURE,
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc32() {
+        // Check value from https://reveng.sourceforge.io/crc-catalogue/17plus.htm#crc.cat.crc-32-iso-hdlc
+        let mut crc = Crc32::default();
+        crc.update(b"123456789");
+        assert_eq!(0xcbf4_3926, crc.finish());
+        assert_eq!(0, Crc32::default().finish());
+    }
+}
@@ -0,0 +1,140 @@
+//! Layouts for common network protocol headers. This module is only available with the `protocols` feature.
+//!
+//! Header fields that pack several values into the bits of one integer, e.g. the version and header length of IPv4,
+//! are defined as a single integer field and documented on the layout.
+//!
+//! # Example
+//! ```
+//! use binary_layout::prelude::*;
+//! use binary_layout::example::protocols::{ether_type, ethernet_frame, ip_protocol, ipv4_header, udp_header};
+//!
+//! # fn main() {
+//! let frame: &[u8] = &[
+//!     // Ethernet
+//!     0x02, 0, 0, 0, 0, 2, 0x02, 0, 0, 0, 0, 1, 0x08, 0x00,
+//!     // IPv4
+//!     0x45, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x40, 0x00, 0x40, 0x11, 0x00, 0x00,
+//!     192, 168, 0, 1, 192, 168, 0, 2,
+//!     // UDP
+//!     0x04, 0xd2, 0x00, 0x35, 0x00, 0x0b, 0x00, 0x00, b'a', b'b', b'c',
+//! ];
+//! let frame = ethernet_frame::View::new(frame);
+//! assert_eq!(ether_type::IPV4, frame.ether_type().read());
+//!
+//! let ip = ipv4_header::View::new(frame.payload());
+//! assert_eq!(4, ip.version_ihl().read() >> 4);
+//! assert_eq!(ip_protocol::UDP, ip.protocol().read());
+//!
+//! let udp = udp_header::View::new(ip.options_and_payload());
+//! assert_eq!(53, udp.destination_port().read());
+//! assert_eq!(b"abc", udp.payload());
+//! # }
+//! ```
+
+use crate::{prelude::*, InternetChecksum};
+
+/// Values of the [ether_type](ethernet_frame::ether_type) field of Ethernet frames
+pub mod ether_type {
+    /// The payload is an [IPv4 packet](super::ipv4_header)
+    pub const IPV4: u16 = 0x0800;
+    /// The payload is an ARP packet
+    pub const ARP: u16 = 0x0806;
+    /// The payload is an [IPv6 packet](super::ipv6_header)
+    pub const IPV6: u16 = 0x86dd;
+}
+
+/// Values of the [protocol](ipv4_header::protocol) field of IPv4 headers and the [next_header](ipv6_header::next_header) field of IPv6 headers
+pub mod ip_protocol {
+    /// The payload is an [ICMP message](super::icmp_message)
+    pub const ICMP: u8 = 1;
+    /// The payload is a [TCP segment](super::tcp_header)
+    pub const TCP: u8 = 6;
+    /// The payload is a [UDP datagram](super::udp_header)
+    pub const UDP: u8 = 17;
+    /// The payload is an ICMPv6 message
+    pub const ICMPV6: u8 = 58;
+}
+
+// Ethernet II frame without the trailing frame check sequence, which network interfaces usually handle.
+// See https://en.wikipedia.org/wiki/Ethernet_frame
+binary_layout!(ethernet_frame, BigEndian, {
+  destination: [u8; 6],
+  source: [u8; 6],
+  ether_type: u16,
+  payload: [u8],
+}, assert_size(14));
+
+// IPv4 header, see https://www.rfc-editor.org/rfc/rfc791
+// - `version_ihl`: version (upper 4 bits, always 4) and header length in 32-bit words (lower 4 bits)
+// - `dscp_ecn`: differentiated services code point (upper 6 bits) and explicit congestion notification (lower 2 bits)
+// - `flags_fragment_offset`: flags (upper 3 bits) and fragment offset in units of 8 bytes (lower 13 bits)
+// The header checksum covers the 20 byte header without options.
+binary_layout!(ipv4_header, BigEndian, {
+  version_ihl: u8,
+  dscp_ecn: u8,
+  total_length: u16,
+  identification: u16,
+  flags_fragment_offset: u16,
+  time_to_live: u8,
+  protocol: u8,
+  header_checksum: u16 = checksum(InternetChecksum, ..FIXED_SIZE),
+  source: [u8; 4],
+  destination: [u8; 4],
+  options_and_payload: [u8],
+}, assert_size(20));
+
+// IPv6 header, see https://www.rfc-editor.org/rfc/rfc8200
+// - `version_traffic_class_flow_label`: version (upper 4 bits, always 6), traffic class (next 8 bits) and flow label (lower 20 bits)
+binary_layout!(ipv6_header, BigEndian, {
+  version_traffic_class_flow_label: u32,
+  payload_length: u16 = len(payload),
+  next_header: u8,
+  hop_limit: u8,
+  source: [u8; 16],
+  destination: [u8; 16],
+  payload: [u8],
+}, assert_size(40));
+
+// UDP header, see https://www.rfc-editor.org/rfc/rfc768
+// The checksum covers a pseudo header, see `InternetChecksum::ipv4_pseudo_header` and `InternetChecksum::ipv6_pseudo_header`.
+binary_layout!(udp_header, BigEndian, {
+  source_port: u16,
+  destination_port: u16,
+  length: u16,
+  checksum: u16 = checksum(InternetChecksum, ..),
+  payload: [u8],
+}, assert_size(8));
+
+// TCP header, see https://www.rfc-editor.org/rfc/rfc9293
+// - `data_offset_flags`: header length in 32-bit words (upper 4 bits), reserved bits and the control flags (lower 8 bits: CWR, ECE, URG, ACK, PSH, RST, SYN, FIN)
+// The checksum covers a pseudo header, see `InternetChecksum::ipv4_pseudo_header` and `InternetChecksum::ipv6_pseudo_header`.
+binary_layout!(tcp_header, BigEndian, {
+  source_port: u16,
+  destination_port: u16,
+  sequence_number: u32,
+  acknowledgment_number: u32,
+  data_offset_flags: u16,
+  window_size: u16,
+  checksum: u16 = checksum(InternetChecksum, ..),
+  urgent_pointer: u16,
+  options_and_payload: [u8],
+}, assert_size(20));
+
+// Body of ICMP echo request and echo reply messages, see https://www.rfc-editor.org/rfc/rfc792
+binary_layout!(icmp_echo, BigEndian, {
+  identifier: u16,
+  sequence_number: u16,
+  data: [u8],
+});
+
+// ICMP message, see https://www.rfc-editor.org/rfc/rfc792
+// Unlike `example::icmp_packet`, this gives typed access to the body of echo messages through `View::variant()`.
+binary_layout!(icmp_message, BigEndian, {
+  message_type: u8,
+  code: u8,
+  checksum: u16 = checksum(InternetChecksum, ..),
+  body: [u8],
+}, assert_size(4), variants body by message_type {
+  0 => EchoReply(icmp_echo),
+  8 => EchoRequest(icmp_echo),
+});
@@ -11,8 +11,8 @@ impl core::fmt::Display for InvalidBoolError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for InvalidBoolError {}
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl crate::utils::error::Error for InvalidBoolError {}
 
 impl LayoutAs<u8> for bool {
     type ReadError = InvalidBoolError;
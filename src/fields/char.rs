@@ -11,8 +11,8 @@ impl core::fmt::Display for InvalidCharError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for InvalidCharError {}
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl crate::utils::error::Error for InvalidCharError {}
 
 impl LayoutAs<u32> for char {
     type ReadError = InvalidCharError;
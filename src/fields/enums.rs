@@ -0,0 +1,28 @@
+use core::fmt::Debug;
+
+/// This error is thrown when trying to read an enum defined with [layout_enum!](crate::layout_enum!)
+/// from a value that doesn't belong to any of its variants.
+/// Enums with a fallback variant never throw this error.
+#[derive(Debug)]
+pub struct InvalidEnumValueError<U>(U);
+
+impl<U> InvalidEnumValueError<U> {
+    #[doc(hidden)]
+    pub const fn __new(value: U) -> Self {
+        Self(value)
+    }
+
+    /// The value that was read from the storage
+    pub fn value(&self) -> &U {
+        &self.0
+    }
+}
+
+impl<U: Debug> core::fmt::Display for InvalidEnumValueError<U> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "InvalidEnumValueError: {:?}", self.0)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl<U: Debug> crate::utils::error::Error for InvalidEnumValueError<U> {}
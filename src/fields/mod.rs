@@ -1,104 +1,20 @@
-use super::endianness::Endianness;
+use crate::reflection::LayoutInfo;
+use crate::storage::Storage;
+
+pub use layout_macros_support::Field;
 
 pub mod bool;
 pub mod char;
+pub mod enums;
 pub mod primitive;
+pub mod ranged;
+pub mod time;
 pub mod wrapped;
 
-///
-/// A field represents one of the fields in the data layout and offers accessors
-/// for it. It remembers the offset of the field in its const generic parameter
-/// and the accessors use that to access the field.
-///
-/// A field does not hold any data storage, so if you use this API directly, you have to pass in
-/// the storage pointer for each call. If you want an API object that remembers the storage,
-/// take a look at the [FieldView](crate::FieldView) based API instead.
-///
-/// By itself, [Field] only offers the things common to all fields, but there
-/// are additional traits for fields that fulfill certain properties:
-/// - [FieldCopyAccess](crate::FieldCopyAccess) for fields that read/write data by copying it to/from the storage. This includes primitive types like [i8] or [u16].
-///   This trait offers [try_read](crate::FieldCopyAccess::try_read) and [try_write](crate::FieldCopyAccess::try_write) to read or write such fields.
-///   For types whose read or write operations don't throw errors, there are also the [read](crate::FieldReadExt::read) and [write](crate::FieldWriteExt) convenience methods.
-/// - [FieldSliceAccess](crate::FieldSliceAccess) for fields that read/write data by creating sub-slices over the storage. This includes, for example, byte arrays
-///   and this trait offers [data](crate::FieldSliceAccess::data) and [data_mut](crate::FieldSliceAccess::data_mut) to access such fields.
-///
-/// # Example:
-/// ```
-/// use binary_layout::prelude::*;
-///
-/// binary_layout!(my_layout, LittleEndian, {
-///   field_one: u16,
-///   another_field: [u8; 16],
-///   something_else: u32,
-///   tail_data: [u8],
-/// });
-///
-/// fn func(storage_data: &mut [u8]) {
-///   // read some data
-///   let format_version_header: u16 = my_layout::field_one::read(storage_data);
-///   // equivalent: let format_version_header = u16::from_le_bytes((&storage_data[0..2]).try_into().unwrap());
-///
-///   // write some data
-///   my_layout::something_else::write(storage_data, 10);
-///   // equivalent: data_slice[18..22].copy_from_slice(&10u32.to_le_bytes());
-///
-///   // access a data region
-///   let tail_data: &[u8] = my_layout::tail_data::data(storage_data);
-///   // equivalent: let tail_data: &[u8] = &data_slice[22..];
-///
-///   // and modify it
-///   my_layout::tail_data::data_mut(storage_data)[..5].copy_from_slice(&[1, 2, 3, 4, 5]);
-///   // equivalent: data_slice[18..22].copy_from_slice(&[1, 2, 3, 4, 5]);
-/// }
-/// ```
-pub trait Field {
-    /// The endianness of the field. Can be [LittleEndian](crate::LittleEndian) or [BigEndian](crate::BigEndian).
-    type Endian: Endianness;
-
-    /// The offset of the field in the layout.
-    ///
-    /// # Example
-    /// ```
-    /// use binary_layout::prelude::*;
-    ///
-    /// binary_layout!(my_layout, LittleEndian, {
-    ///   field1: u16,
-    ///   field2: i32,
-    ///   field3: u8,
-    /// });
-    ///
-    /// assert_eq!(0, my_layout::field1::OFFSET);
-    /// assert_eq!(2, my_layout::field2::OFFSET);
-    /// assert_eq!(6, my_layout::field3::OFFSET);
-    /// ```
-    const OFFSET: usize;
-
-    /// The size of the field in the layout.
-    /// This can be None if it is an open ended field like a byte slice
-    ///
-    /// # Example
-    /// ```
-    /// use binary_layout::prelude::*;
-    ///
-    /// binary_layout!(my_layout, LittleEndian, {
-    ///   field1: u16,
-    ///   field2: i32,
-    ///   field3: u8,
-    ///   tail: [u8],
-    /// });
-    ///
-    /// assert_eq!(Some(2), my_layout::field1::SIZE);
-    /// assert_eq!(Some(4), my_layout::field2::SIZE);
-    /// assert_eq!(Some(1), my_layout::field3::SIZE);
-    /// assert_eq!(None, my_layout::tail::SIZE);
-    /// ```
-    const SIZE: Option<usize>;
-}
-
 #[doc(hidden)]
 pub trait StorageIntoFieldView<S>
 where
-    S: AsRef<[u8]>,
+    S: Storage,
 {
     type View;
     fn into_view(storage: S) -> Self::View;
@@ -109,3 +25,103 @@ pub trait StorageToFieldView<S> {
     type View;
     fn view(storage: S) -> Self::View;
 }
+
+/// Maps a field type to the same field type placed at a different offset.
+/// This allows decoding a field from a buffer that only contains the bytes of that field.
+#[doc(hidden)]
+pub trait FieldAtOffset<const NEW_OFFSET: usize>: Field {
+    type Moved: Field;
+}
+
+/// Whether field `F` is zero sized, e.g. a `()` or `[T; 0]` field or a nested layout without fields.
+/// Layouts use this to skip zero sized fields when implementing their `LayoutField` marker trait, because zero sized fields
+/// following each other have the same type.
+#[doc(hidden)]
+pub struct ZeroSizedField<F: ?Sized>(core::marker::PhantomData<F>);
+
+impl<F: Field + ?Sized> ZeroSizedField<F> {
+    pub const VALUE: bool = matches!(F::SIZE, Some(0));
+}
+
+/// Panic with a message naming the layout, the field and its offset if `storage` is too small to access field `F`.
+/// Used by the accessors of the generated `View` so that short storage doesn't cause a bare slice index panic.
+#[doc(hidden)]
+#[inline(always)]
+#[track_caller]
+pub fn check_field_bounds<F: Field>(storage: &[u8], layout: &str, field: &str) {
+    let needed = F::OFFSET + F::SIZE.unwrap_or(0);
+    if storage.len() < needed {
+        field_out_of_bounds(layout, field, F::OFFSET, needed, storage.len());
+    }
+}
+
+/// Whether `storage` is large enough to access field `F`, i.e. whether [check_field_bounds] would succeed.
+/// Used by generated methods that handle short storage without panicking, e.g. `View::variant()`.
+#[doc(hidden)]
+#[inline(always)]
+pub fn field_in_bounds<F: Field>(storage: &[u8]) -> bool {
+    storage.len() >= F::OFFSET + F::SIZE.unwrap_or(0)
+}
+
+/// Like [check_field_bounds], but for the generic `View::get::<F>()` accessors, which don't know the name of `F`.
+/// The name is looked up in the reflection data of the layout if the storage is too small.
+#[doc(hidden)]
+#[inline(always)]
+#[track_caller]
+pub fn check_generic_field_bounds<F: Field>(storage: &[u8], layout: &LayoutInfo) {
+    let needed = F::OFFSET + F::SIZE.unwrap_or(0);
+    if storage.len() < needed {
+        let field = layout
+            .fields()
+            .iter()
+            .find(|field| field.offset() == F::OFFSET && field.size() == F::SIZE)
+            .map_or_else(core::any::type_name::<F>, |field| field.name());
+        field_out_of_bounds(layout.name(), field, F::OFFSET, needed, storage.len());
+    }
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn field_out_of_bounds(
+    layout: &str,
+    field: &str,
+    offset: usize,
+    needed: usize,
+    available: usize,
+) -> ! {
+    panic!(
+        "Needed {} bytes to access field `{}` at offset {} of layout `{}`, but the storage only has {} bytes",
+        needed, field, offset, layout, available
+    );
+}
+
+/// Panic with a message naming the layout if a view is created at a base offset beyond the end of the storage.
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub fn base_out_of_bounds(layout: &str, base: usize, available: usize) -> ! {
+    panic!(
+        "Tried to create a view of layout `{}` at base offset {}, but the storage only has {} bytes",
+        layout, base, available
+    );
+}
+
+/// Split `storage` into a view for field `F` and the bytes after it, assuming that `storage` starts where the field starts.
+#[doc(hidden)]
+#[inline(always)]
+pub fn split_field_mut<'a, F>(
+    storage: &'a mut [u8],
+) -> (
+    <F::Moved as StorageToFieldView<&'a mut [u8]>>::View,
+    &'a mut [u8],
+)
+where
+    F: FieldAtOffset<0>,
+    F::Moved: StorageToFieldView<&'a mut [u8]>,
+{
+    let size = F::SIZE.unwrap_or(storage.len());
+    let (field, rest) = storage.split_at_mut(size);
+    (F::Moved::view(field), rest)
+}
@@ -0,0 +1,413 @@
+use core::convert::Infallible;
+
+use super::{FieldCopyAccess, FieldReadExt, FieldWriteExt, PrimitiveField};
+use crate::fields::primitive::view::FieldView;
+use crate::fields::{Field, StorageIntoFieldView, StorageToFieldView};
+use crate::reflection::{FieldKind, FieldKindInfo};
+use crate::storage::Storage;
+use crate::Endianness;
+
+/// Implemented for the element types of [number arrays](crate#number-arrays-t-n-and-t), e.g. `[u32; N]` or `[f64]`.
+/// These are the primitive integer types with more than one byte and the primitive float types.
+/// Byte arrays are [separate field types](crate#fixed-size-byte-arrays-u8-n).
+pub trait ArrayElement: Copy + Default + sealed::Sealed {
+    /// Size of one element in the storage in number of bytes
+    const SIZE: usize;
+
+    #[doc(hidden)]
+    const KIND: &'static FieldKind;
+
+    #[doc(hidden)]
+    fn read<E: Endianness>(storage: &[u8]) -> Self;
+
+    #[doc(hidden)]
+    fn write<E: Endianness>(storage: &mut [u8], value: Self);
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! array_element {
+    ($type:ty, $kind:ident) => {
+        impl sealed::Sealed for $type {}
+
+        impl ArrayElement for $type {
+            const SIZE: usize = core::mem::size_of::<$type>();
+            const KIND: &'static FieldKind = &FieldKind::$kind;
+
+            #[inline(always)]
+            fn read<E: Endianness>(storage: &[u8]) -> Self {
+                PrimitiveField::<$type, E, 0>::read(storage)
+            }
+
+            #[inline(always)]
+            fn write<E: Endianness>(storage: &mut [u8], value: Self) {
+                PrimitiveField::<$type, E, 0>::write(storage, value)
+            }
+        }
+    };
+}
+
+array_element!(i16, SignedInt);
+array_element!(i32, SignedInt);
+array_element!(i64, SignedInt);
+array_element!(i128, SignedInt);
+array_element!(u16, UnsignedInt);
+array_element!(u32, UnsignedInt);
+array_element!(u64, UnsignedInt);
+array_element!(u128, UnsignedInt);
+array_element!(f32, Float);
+array_element!(f64, Float);
+
+/// This trait is implemented for [number arrays](crate#number-arrays-t-n-and-t), i.e. fields of type `[T; N]` or `[T]`
+/// with an [ArrayElement] type `T`. It gives access to single elements by an index only known at runtime,
+/// converting each element from or to the endianness of the layout.
+pub trait FieldArrayAccess: Field {
+    /// The type of the elements
+    type Element: ArrayElement;
+
+    /// The number of elements of the array in the given data region. For open ended arrays `[T]`, this is the number of
+    /// complete elements between the start of the field and the end of the data region.
+    fn len(storage: &[u8]) -> usize;
+
+    /// Returns true if the array has no elements in the given data region
+    #[inline(always)]
+    fn is_empty(storage: &[u8]) -> bool {
+        Self::len(storage) == 0
+    }
+
+    /// Read the element with the given index from a given data region, assuming the defined layout, using the [Field] API.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::{prelude::*, FieldArrayAccess};
+    ///
+    /// binary_layout!(my_layout, BigEndian, {
+    ///   //... other fields ...
+    ///   some_array_field: [u32; 4],
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &[u8], index: usize) -> u32 {
+    ///   my_layout::some_array_field::read_at(storage_data, index)
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds, like indexing a slice.
+    #[inline(always)]
+    #[track_caller]
+    fn read_at(storage: &[u8], index: usize) -> Self::Element {
+        let start = element_start::<Self>(storage, index);
+        Self::Element::read::<Self::Endian>(&storage[start..])
+    }
+
+    /// Write the element with the given index to a given data region, assuming the defined layout, using the [Field] API.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::{prelude::*, FieldArrayAccess};
+    ///
+    /// binary_layout!(my_layout, BigEndian, {
+    ///   //... other fields ...
+    ///   some_array_field: [u32; 4],
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8], index: usize) {
+    ///   my_layout::some_array_field::write_at(storage_data, index, 10);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds, like indexing a slice.
+    #[inline(always)]
+    #[track_caller]
+    fn write_at(storage: &mut [u8], index: usize, value: Self::Element) {
+        let start = element_start::<Self>(storage, index);
+        Self::Element::write::<Self::Endian>(&mut storage[start..], value)
+    }
+}
+
+/// Offset of the element with the given index in the storage. Checks that the element is within the bounds of the array.
+#[inline(always)]
+#[track_caller]
+pub(crate) fn element_start<F: FieldArrayAccess + ?Sized>(storage: &[u8], index: usize) -> usize {
+    let len = F::len(storage);
+    assert!(
+        index < len,
+        "Index {} is out of bounds of an array with {} elements",
+        index,
+        len,
+    );
+    F::OFFSET + index * F::Element::SIZE
+}
+
+/// Field type `[T; N]`:
+/// This field represents a [fixed size number array](crate#number-arrays-t-n-and-t).
+/// Since the elements need to be converted from or to the endianness of the layout, the array is copied out of or into the storage as a whole.
+/// Single elements can be accessed with [FieldArrayAccess].
+impl<T: ArrayElement, E: Endianness, const N: usize, const OFFSET_: usize> FieldCopyAccess
+    for PrimitiveField<[T; N], E, OFFSET_>
+{
+    /// See [FieldCopyAccess::ReadError]
+    type ReadError = Infallible;
+    /// See [FieldCopyAccess::WriteError]
+    type WriteError = Infallible;
+    /// See [FieldCopyAccess::HighLevelType]
+    type HighLevelType = [T; N];
+
+    /// Copy the elements out of a given data region, assuming the defined layout, using the [Field] API.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_field: [u16; 3],
+    ///     //... other fields
+    /// });
+    ///
+    /// fn func(storage_data: &[u8]) {
+    ///     let some_field: [u16; 3] = my_layout::some_field::read(storage_data);
+    /// }
+    /// ```
+    #[inline(always)]
+    fn try_read(storage: &[u8]) -> Result<[T; N], Infallible> {
+        let mut value = [T::default(); N];
+        for (value, bytes) in value
+            .iter_mut()
+            .zip(storage[Self::OFFSET..(Self::OFFSET + N * T::SIZE)].chunks_exact(T::SIZE))
+        {
+            *value = T::read::<E>(bytes);
+        }
+        Ok(value)
+    }
+
+    /// Copy elements into a given data region, assuming the defined layout, using the [Field] API.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_field: [u16; 3],
+    ///     //... other fields
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) {
+    ///     my_layout::some_field::write(storage_data, [1, 2, 3]);
+    /// }
+    /// ```
+    #[inline(always)]
+    fn try_write(storage: &mut [u8], value: [T; N]) -> Result<(), Infallible> {
+        for (bytes, value) in storage[Self::OFFSET..(Self::OFFSET + N * T::SIZE)]
+            .chunks_exact_mut(T::SIZE)
+            .zip(value)
+        {
+            T::write::<E>(bytes, value);
+        }
+        Ok(())
+    }
+}
+
+impl<T: ArrayElement, E: Endianness, const N: usize, const OFFSET_: usize> Field
+    for PrimitiveField<[T; N], E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = Some(N * T::SIZE);
+}
+
+impl<T: ArrayElement, E: Endianness, const N: usize, const OFFSET_: usize> FieldArrayAccess
+    for PrimitiveField<[T; N], E, OFFSET_>
+{
+    type Element = T;
+
+    #[inline(always)]
+    fn len(_storage: &[u8]) -> usize {
+        N
+    }
+}
+
+impl<T: ArrayElement, E: Endianness, const N: usize, const OFFSET_: usize> FieldKindInfo
+    for PrimitiveField<[T; N], E, OFFSET_>
+{
+    const KIND: FieldKind = FieldKind::Array {
+        element: T::KIND,
+        element_size: T::SIZE,
+    };
+}
+
+/// Field type `[T]`:
+/// This field represents an [open ended number array](crate#number-arrays-t-n-and-t).
+/// Like open ended byte arrays, it can only occur as the last field of a layout and extends until the end of the storage.
+/// Its elements can be accessed with [FieldArrayAccess].
+impl<T: ArrayElement, E: Endianness, const OFFSET_: usize> Field
+    for PrimitiveField<[T], E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = None;
+}
+
+impl<T: ArrayElement, E: Endianness, const OFFSET_: usize> FieldArrayAccess
+    for PrimitiveField<[T], E, OFFSET_>
+{
+    type Element = T;
+
+    #[inline(always)]
+    fn len(storage: &[u8]) -> usize {
+        storage.len().saturating_sub(Self::OFFSET) / T::SIZE
+    }
+}
+
+impl<T: ArrayElement, E: Endianness, const OFFSET_: usize> FieldKindInfo
+    for PrimitiveField<[T], E, OFFSET_>
+{
+    const KIND: FieldKind = FieldKind::Array {
+        element: T::KIND,
+        element_size: T::SIZE,
+    };
+}
+
+impl<'a, T: ArrayElement, E: Endianness, const N: usize, const OFFSET_: usize>
+    StorageToFieldView<&'a [u8]> for PrimitiveField<[T; N], E, OFFSET_>
+{
+    type View = FieldView<&'a [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<'a, T: ArrayElement, E: Endianness, const N: usize, const OFFSET_: usize>
+    StorageToFieldView<&'a mut [u8]> for PrimitiveField<[T; N], E, OFFSET_>
+{
+    type View = FieldView<&'a mut [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<S: Storage, T: ArrayElement, E: Endianness, const N: usize, const OFFSET_: usize>
+    StorageIntoFieldView<S> for PrimitiveField<[T; N], E, OFFSET_>
+{
+    type View = FieldView<S, Self>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<'a, T: ArrayElement, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
+    for PrimitiveField<[T], E, OFFSET_>
+{
+    type View = FieldView<&'a [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<'a, T: ArrayElement, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a mut [u8]>
+    for PrimitiveField<[T], E, OFFSET_>
+{
+    type View = FieldView<&'a mut [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<S: Storage, T: ArrayElement, E: Endianness, const OFFSET_: usize> StorageIntoFieldView<S>
+    for PrimitiveField<[T], E, OFFSET_>
+{
+    type View = FieldView<S, Self>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+    use crate::prelude::*;
+    use crate::{FieldArrayAccess, PrimitiveField};
+
+    #[test]
+    fn test_array() {
+        let mut storage = [0; 1024];
+
+        type Field1 = PrimitiveField<[u16; 2], LittleEndian, 5>;
+        type Field2 = PrimitiveField<[i32; 2], BigEndian, 9>;
+        type Field3 = PrimitiveField<[f32; 1], NativeEndian, 17>;
+
+        Field1::write(&mut storage, [0x0102, 0x0304]);
+        Field2::write(&mut storage, [-2, 3]);
+        Field3::write(&mut storage, [1.5]);
+
+        assert_eq!([0x0102, 0x0304], Field1::read(&storage));
+        assert_eq!([-2, 3], Field2::read(&storage));
+        assert_eq!([1.5], Field3::read(&storage));
+        assert_eq!(&[2, 1, 4, 3], &storage[5..9]);
+        assert_eq!(&[0xff, 0xff, 0xff, 0xfe, 0, 0, 0, 3], &storage[9..17]);
+        assert_eq!(&1.5f32.to_ne_bytes(), &storage[17..21]);
+
+        assert_eq!(Some(4), Field1::SIZE);
+        assert_eq!(Some(8), Field2::SIZE);
+        assert_eq!(Some(4), Field3::SIZE);
+    }
+
+    #[test]
+    fn test_array_element_access() {
+        let mut storage = [0; 1024];
+
+        type Field1 = PrimitiveField<[u16; 3], BigEndian, 5>;
+
+        Field1::write_at(&mut storage, 1, 0x0102);
+        assert_eq!(0x0102, Field1::read_at(&storage, 1));
+        assert_eq!(0, Field1::read_at(&storage, 2));
+        assert_eq!(3, Field1::len(&storage));
+        assert_eq!(&[0, 0, 1, 2, 0, 0], &storage[5..11]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 3 is out of bounds of an array with 3 elements")]
+    fn test_array_element_out_of_bounds() {
+        type Field1 = PrimitiveField<[u16; 3], BigEndian, 5>;
+        Field1::read_at(&[0; 1024], 3);
+    }
+
+    #[test]
+    fn test_open_ended_array() {
+        let mut storage = [0; 10];
+
+        type Field1 = PrimitiveField<[u32], LittleEndian, 1>;
+
+        assert_eq!(2, Field1::len(&storage));
+        assert!(!Field1::is_empty(&storage));
+        Field1::write_at(&mut storage, 1, 0x01020304);
+        assert_eq!(0x01020304, Field1::read_at(&storage, 1));
+        assert_eq!(&[0, 0, 0, 0, 0, 4, 3, 2, 1, 0], &storage);
+        assert_eq!(None, Field1::SIZE);
+        assert!(Field1::is_empty(&storage[..4]));
+    }
+}
@@ -64,7 +64,13 @@ pub trait FieldCopyAccess: Field {
 }
 
 macro_rules! impl_field_traits {
-    ($type: ty) => {
+    ($type: ty, $kind: ident) => {
+        impl<E: Endianness, const OFFSET_: usize> FieldKindInfo
+            for PrimitiveField<$type, E, OFFSET_>
+        {
+            const KIND: FieldKind = FieldKind::$kind;
+        }
+
         impl<E: Endianness, const OFFSET_: usize> Field for PrimitiveField<$type, E, OFFSET_> {
             /// See [Field::Endian]
             type Endian = E;
@@ -96,7 +102,7 @@ macro_rules! impl_field_traits {
             }
         }
 
-        impl<S: AsRef<[u8]>, E: Endianness, const OFFSET_: usize> StorageIntoFieldView<S>
+        impl<S: Storage, E: Endianness, const OFFSET_: usize> StorageIntoFieldView<S>
             for PrimitiveField<$type, E, OFFSET_>
         {
             type View = FieldView<S, Self>;
@@ -109,11 +115,18 @@ macro_rules! impl_field_traits {
     };
 }
 
+#[cfg(feature = "f128")]
+mod primitive_f128;
 mod primitive_float;
 mod primitive_int;
 mod primitive_nonzero_int;
+mod primitive_signed_bytes;
 mod primitive_unit;
 mod read_write_ext;
+mod segmented_ext;
 
+#[cfg(feature = "f128")]
+pub use primitive_f128::F128;
 pub use primitive_nonzero_int::NonZeroIsZeroError;
 pub use read_write_ext::{FieldReadExt, FieldWriteExt};
+pub use segmented_ext::FieldSegmentedExt;
@@ -0,0 +1,312 @@
+use core::convert::Infallible;
+use core::fmt::{self, Debug, Formatter};
+
+use super::{FieldCopyAccess, PrimitiveField};
+use crate::fields::primitive::view::FieldView;
+use crate::fields::{Field, StorageIntoFieldView, StorageToFieldView};
+use crate::reflection::{FieldKind, FieldKindInfo};
+use crate::storage::Storage;
+use crate::{EndianKind, Endianness};
+
+const F64_MANTISSA_BITS: u32 = 52;
+const F64_EXPONENT_BIAS: i32 = 1023;
+const F128_MANTISSA_BITS: u32 = 112;
+const F128_EXPONENT_BIAS: i32 = 16383;
+const F128_EXPONENT_MASK: u128 = 0x7fff;
+const F128_MANTISSA_MASK: u128 = (1 << F128_MANTISSA_BITS) - 1;
+
+/// A 16-byte IEEE 754 binary128 ("quad precision") floating point number, for formats that store such values.
+/// This requires the `f128` feature.
+///
+/// Rust doesn't have a stable `f128` type yet, so this type only stores the bits of the number and offers conversions:
+/// [F128::from_bits] and [F128::to_bits] give access to the raw bits, [F128::from_f64] converts an `f64` without loss
+/// and [F128::to_f64] converts to the nearest `f64`. Fields of this type use the endianness of their layout
+/// and can be used like `f32` or `f64` fields. Once `f128` is stabilized, it can be supported as a field type as well.
+///
+/// # Example
+/// ```
+/// use binary_layout::{prelude::*, F128};
+///
+/// binary_layout!(measurement, BigEndian, {
+///   id: u32,
+///   value: F128,
+/// });
+///
+/// fn main() {
+///   let mut view = measurement::View::new([0; 20]);
+///   view.value_mut().write(F128::from_f64(1.5));
+///   assert_eq!(1.5, view.value().read().to_f64());
+///   assert_eq!(&[0x3f, 0xff, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], &view.into_storage()[4..]);
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct F128(u128);
+
+impl F128 {
+    /// Create a number from its IEEE 754 binary128 bit pattern
+    pub const fn from_bits(bits: u128) -> Self {
+        Self(bits)
+    }
+
+    /// The IEEE 754 binary128 bit pattern of the number
+    pub const fn to_bits(self) -> u128 {
+        self.0
+    }
+
+    /// Convert an `f64` into a quad precision number. This conversion is exact, `NaN` payloads are kept.
+    pub fn from_f64(value: f64) -> Self {
+        let bits = value.to_bits();
+        let sign = u128::from(bits >> 63) << 127;
+        let exponent = ((bits >> F64_MANTISSA_BITS) & 0x7ff) as i32;
+        let mantissa = bits & ((1 << F64_MANTISSA_BITS) - 1);
+        let shift = F128_MANTISSA_BITS - F64_MANTISSA_BITS;
+        let (exponent, mantissa) = match exponent {
+            0x7ff => (F128_EXPONENT_MASK, u128::from(mantissa) << shift),
+            0 if mantissa == 0 => (0, 0),
+            0 => {
+                // Subnormal f64 numbers are normal numbers in binary128
+                let normalize = mantissa.leading_zeros() - (63 - F64_MANTISSA_BITS);
+                let mantissa = (mantissa << normalize) & ((1 << F64_MANTISSA_BITS) - 1);
+                let exponent = 1 - F64_EXPONENT_BIAS - normalize as i32;
+                (
+                    (exponent + F128_EXPONENT_BIAS) as u128,
+                    u128::from(mantissa) << shift,
+                )
+            }
+            exponent => (
+                (exponent - F64_EXPONENT_BIAS + F128_EXPONENT_BIAS) as u128,
+                u128::from(mantissa) << shift,
+            ),
+        };
+        Self(sign | exponent << F128_MANTISSA_BITS | mantissa)
+    }
+
+    /// Convert the number to the nearest `f64`, rounding ties to even.
+    /// Numbers that are too large for an `f64` become infinite and numbers that are too small become zero.
+    pub fn to_f64(self) -> f64 {
+        let sign = ((self.0 >> 127) as u64) << 63;
+        let exponent = ((self.0 >> F128_MANTISSA_BITS) & F128_EXPONENT_MASK) as i32;
+        let mantissa = self.0 & F128_MANTISSA_MASK;
+        let shift = F128_MANTISSA_BITS - F64_MANTISSA_BITS;
+        const INFINITY: u64 = 0x7ff << F64_MANTISSA_BITS;
+        let bits = if exponent == F128_EXPONENT_MASK as i32 {
+            if mantissa == 0 {
+                INFINITY
+            } else {
+                // Keep the upper bits of the NaN payload, but make sure it stays a NaN
+                match (mantissa >> shift) as u64 {
+                    0 => INFINITY | 1 << (F64_MANTISSA_BITS - 1),
+                    payload => INFINITY | payload,
+                }
+            }
+        } else if exponent == 0 {
+            // Zero, or a subnormal binary128 number that is way too small for an f64
+            0
+        } else {
+            let exponent = exponent - F128_EXPONENT_BIAS;
+            let significand = mantissa | 1 << F128_MANTISSA_BITS;
+            if exponent > F64_EXPONENT_BIAS {
+                INFINITY
+            } else if exponent > -F64_EXPONENT_BIAS {
+                let significand = round_shift(significand, shift);
+                // Rounding can overflow into the next exponent, which the addition takes care of
+                let biased = ((exponent + F64_EXPONENT_BIAS) as u64) << F64_MANTISSA_BITS;
+                (biased + (significand as u64 - (1 << F64_MANTISSA_BITS))).min(INFINITY)
+            } else {
+                let subnormal_shift = shift as i32 + (1 - F64_EXPONENT_BIAS) - exponent;
+                round_shift(significand, subnormal_shift as u32) as u64
+            }
+        };
+        f64::from_bits(sign | bits)
+    }
+}
+
+/// Shift `value` right by `shift` bits, rounding to nearest, ties to even
+fn round_shift(value: u128, shift: u32) -> u128 {
+    if shift >= 128 {
+        return 0;
+    }
+    let quotient = value >> shift;
+    let remainder = value & ((1 << shift) - 1);
+    let half = (1 << shift) >> 1;
+    if remainder > half || (remainder == half && half != 0 && quotient & 1 == 1) {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+impl From<f64> for F128 {
+    fn from(value: f64) -> Self {
+        Self::from_f64(value)
+    }
+}
+
+impl From<f32> for F128 {
+    fn from(value: f32) -> Self {
+        Self::from_f64(f64::from(value))
+    }
+}
+
+/// Formats the number as its nearest `f64`
+impl Debug for F128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "F128({:?})", self.to_f64())
+    }
+}
+
+impl<E: Endianness, const OFFSET_: usize> FieldCopyAccess for PrimitiveField<F128, E, OFFSET_> {
+    /// See [FieldCopyAccess::ReadError]
+    type ReadError = Infallible;
+    /// See [FieldCopyAccess::WriteError]
+    type WriteError = Infallible;
+    /// See [FieldCopyAccess::HighLevelType]
+    type HighLevelType = F128;
+
+    /// Read the quad precision field from a given data region, assuming the defined layout, using the [Field] API.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::{prelude::*, F128};
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_field: F128,
+    ///     //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &[u8]) -> f64 {
+    ///     my_layout::some_field::read(storage_data).to_f64()
+    /// }
+    /// # fn main() {}
+    /// ```
+    #[inline(always)]
+    fn try_read(storage: &[u8]) -> Result<F128, Infallible> {
+        let value: [u8; 16] = storage[Self::OFFSET..(Self::OFFSET + 16)]
+            .try_into()
+            .unwrap();
+        let bits = match E::KIND {
+            EndianKind::Big => u128::from_be_bytes(value),
+            EndianKind::Little => u128::from_le_bytes(value),
+            EndianKind::Native => u128::from_ne_bytes(value),
+        };
+        Ok(F128(bits))
+    }
+
+    /// Write the quad precision field to a given data region, assuming the defined layout, using the [Field] API.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::{prelude::*, F128};
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_field: F128,
+    ///     //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) {
+    ///     my_layout::some_field::write(storage_data, F128::from_f64(10.0));
+    /// }
+    /// # fn main() {}
+    /// ```
+    #[inline(always)]
+    fn try_write(storage: &mut [u8], value: F128) -> Result<(), Infallible> {
+        let value_as_bytes = match E::KIND {
+            EndianKind::Big => value.0.to_be_bytes(),
+            EndianKind::Little => value.0.to_le_bytes(),
+            EndianKind::Native => value.0.to_ne_bytes(),
+        };
+        storage[Self::OFFSET..(Self::OFFSET + 16)].copy_from_slice(&value_as_bytes);
+        Ok(())
+    }
+}
+
+impl_field_traits!(F128, Float);
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+    use super::F128;
+    use crate::prelude::*;
+    use crate::PrimitiveField;
+
+    #[test]
+    fn metadata() {
+        type Field1 = PrimitiveField<F128, LittleEndian, 5>;
+        assert_eq!(Some(16), Field1::SIZE);
+        assert_eq!(5, Field1::OFFSET);
+    }
+
+    #[test]
+    fn read_write() {
+        let mut storage = [0; 40];
+        type Field1 = PrimitiveField<F128, LittleEndian, 2>;
+        type Field2 = PrimitiveField<F128, BigEndian, 20>;
+
+        Field1::write(&mut storage, F128::from_f64(-2.0));
+        Field2::write(&mut storage, F128::from_f64(0.1));
+        assert_eq!(-2.0, Field1::read(&storage).to_f64());
+        assert_eq!(0.1, Field2::read(&storage).to_f64());
+        assert_eq!(&[0x00, 0xc0], &storage[16..18]);
+        assert_eq!(&[0x3f, 0xfb, 0x99, 0x99], &storage[20..24]);
+    }
+
+    #[test]
+    fn f64_round_trip() {
+        for value in [
+            0.0,
+            -0.0,
+            1.0,
+            -1.5,
+            core::f64::consts::PI,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            f64::MIN_POSITIVE / 3.0,
+            f64::from_bits(1),
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ] {
+            assert_eq!(
+                value.to_bits(),
+                F128::from_f64(value).to_f64().to_bits(),
+                "{}",
+                value
+            );
+        }
+        assert!(F128::from_f64(f64::NAN).to_f64().is_nan());
+    }
+
+    #[test]
+    fn bits() {
+        assert_eq!(0x3fff << 112, F128::from_f64(1.0).to_bits());
+        assert_eq!(1u128 << 127, F128::from_f64(-0.0).to_bits());
+        assert_eq!(0x7fff << 112, F128::from_f64(f64::INFINITY).to_bits());
+        // Smallest f64 subnormal is 2^-1074
+        assert_eq!(
+            ((16383 - 1074) as u128) << 112,
+            F128::from_f64(f64::from_bits(1)).to_bits()
+        );
+    }
+
+    #[test]
+    fn to_f64_rounding() {
+        let one = F128::from_f64(1.0).to_bits();
+        // 1 + 2^-53 is exactly between 1 and the next f64, and rounds to even
+        assert_eq!(1.0, F128::from_bits(one | 1 << 59).to_f64());
+        // Anything above the tie rounds up
+        assert_eq!(
+            1.0 + f64::EPSILON,
+            F128::from_bits(one | 1 << 59 | 1).to_f64()
+        );
+        // Rounding up can carry into the exponent
+        assert_eq!(2.0, F128::from_bits(one | ((1 << 112) - 1)).to_f64());
+        // Too large and too small numbers
+        assert_eq!(f64::INFINITY, F128::from_bits(0x7ffe << 112).to_f64());
+        assert_eq!(0.0, F128::from_bits(1 << 112).to_f64());
+        assert_eq!(
+            f64::from_bits(1),
+            F128::from_bits(((16383 - 1074) as u128) << 112 | 1).to_f64()
+        );
+    }
+}
@@ -1,9 +1,11 @@
 use core::convert::Infallible;
 
 use super::{FieldCopyAccess, PrimitiveField};
-use crate::endianness::{EndianKind, Endianness};
 use crate::fields::primitive::view::FieldView;
 use crate::fields::{Field, StorageIntoFieldView, StorageToFieldView};
+use crate::reflection::{FieldKind, FieldKindInfo};
+use crate::storage::Storage;
+use crate::{EndianKind, Endianness};
 
 macro_rules! float_field {
     ($type:ty) => {
@@ -97,7 +99,7 @@ macro_rules! float_field {
             }
         }
 
-        impl_field_traits!($type);
+        impl_field_traits!($type, Float);
     };
 }
 
@@ -1,12 +1,14 @@
 use core::convert::Infallible;
 
 use super::{FieldCopyAccess, PrimitiveField};
-use crate::endianness::{EndianKind, Endianness};
 use crate::fields::primitive::view::FieldView;
 use crate::fields::{Field, StorageIntoFieldView, StorageToFieldView};
+use crate::reflection::{FieldKind, FieldKindInfo};
+use crate::storage::Storage;
+use crate::{EndianKind, Endianness};
 
 macro_rules! int_field {
-    ($type:ty) => {
+    ($type:ty, $kind:ident) => {
         impl<E: Endianness, const OFFSET_: usize> FieldCopyAccess for PrimitiveField<$type, E, OFFSET_> {
             /// See [FieldCopyAccess::ReadError]
             type ReadError = Infallible;
@@ -83,20 +85,20 @@ macro_rules! int_field {
             }
         }
 
-        impl_field_traits!($type);
+        impl_field_traits!($type, $kind);
     };
 }
 
-int_field!(i8);
-int_field!(i16);
-int_field!(i32);
-int_field!(i64);
-int_field!(i128);
-int_field!(u8);
-int_field!(u16);
-int_field!(u32);
-int_field!(u64);
-int_field!(u128);
+int_field!(i8, SignedInt);
+int_field!(i16, SignedInt);
+int_field!(i32, SignedInt);
+int_field!(i64, SignedInt);
+int_field!(i128, SignedInt);
+int_field!(u8, UnsignedInt);
+int_field!(u16, UnsignedInt);
+int_field!(u32, UnsignedInt);
+int_field!(u64, UnsignedInt);
+int_field!(u128, UnsignedInt);
 
 #[cfg(test)]
 mod tests {
@@ -1,12 +1,14 @@
 use core::convert::Infallible;
 
 use super::{FieldCopyAccess, PrimitiveField};
-use crate::endianness::{EndianKind, Endianness};
 use crate::fields::primitive::view::FieldView;
 use crate::fields::{Field, StorageIntoFieldView, StorageToFieldView};
+use crate::reflection::{FieldKind, FieldKindInfo};
+use crate::storage::Storage;
+use crate::{EndianKind, Endianness};
 
 macro_rules! nonzero_int_field {
-    ($type:ty, $zero_type:ty) => {
+    ($type:ty, $zero_type:ty, $kind:ident) => {
         impl<E: Endianness, const OFFSET_: usize> FieldCopyAccess for PrimitiveField<$type, E, OFFSET_> {
             /// See [FieldCopyAccess::ReadError]
             type ReadError = NonZeroIsZeroError;
@@ -44,7 +46,7 @@ macro_rules! nonzero_int_field {
                         EndianKind::Little => <$zero_type>::from_le_bytes(value),
                         EndianKind::Native => <$zero_type>::from_ne_bytes(value)
                     };
-                    <$type>::new(value).ok_or(NonZeroIsZeroError(()))
+                    <$type>::new(value).ok_or(NonZeroIsZeroError { offset: Self::OFFSET })
                 }
             }
 
@@ -84,34 +86,51 @@ macro_rules! nonzero_int_field {
             }
         }
 
-        impl_field_traits!($type);
+        impl_field_traits!($type, $kind);
     };
 }
 
 /// This error is thrown when trying to read a non-zero integer type, e.g. [NonZeroU32](core::num::NonZeroU32),
 /// but the data being read was actually zero.
-#[derive(Debug)]
-pub struct NonZeroIsZeroError(pub(crate) ());
+///
+/// The error contains the offset of the field, so that logs can pinpoint the corrupt field.
+/// To also get the name of the field, convert it into an [Error](crate::Error) with [ErrorContextExt::field_context](crate::ErrorContextExt::field_context).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonZeroIsZeroError {
+    pub(crate) offset: usize,
+}
+
+impl NonZeroIsZeroError {
+    /// Offset of the field that was zero, in number of bytes from the start of the storage it was read from.
+    /// For fields of nested layouts, this is the offset in the nested layout.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
 
 impl core::fmt::Display for NonZeroIsZeroError {
     fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(fmt, "NonZeroIsZeroError")
+        write!(
+            fmt,
+            "NonZeroIsZeroError: The field at offset {} is zero",
+            self.offset
+        )
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for NonZeroIsZeroError {}
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl crate::utils::error::Error for NonZeroIsZeroError {}
 
-nonzero_int_field!(core::num::NonZeroI8, i8);
-nonzero_int_field!(core::num::NonZeroI16, i16);
-nonzero_int_field!(core::num::NonZeroI32, i32);
-nonzero_int_field!(core::num::NonZeroI64, i64);
-nonzero_int_field!(core::num::NonZeroI128, i128);
-nonzero_int_field!(core::num::NonZeroU8, u8);
-nonzero_int_field!(core::num::NonZeroU16, u16);
-nonzero_int_field!(core::num::NonZeroU32, u32);
-nonzero_int_field!(core::num::NonZeroU64, u64);
-nonzero_int_field!(core::num::NonZeroU128, u128);
+nonzero_int_field!(core::num::NonZeroI8, i8, SignedInt);
+nonzero_int_field!(core::num::NonZeroI16, i16, SignedInt);
+nonzero_int_field!(core::num::NonZeroI32, i32, SignedInt);
+nonzero_int_field!(core::num::NonZeroI64, i64, SignedInt);
+nonzero_int_field!(core::num::NonZeroI128, i128, SignedInt);
+nonzero_int_field!(core::num::NonZeroU8, u8, UnsignedInt);
+nonzero_int_field!(core::num::NonZeroU16, u16, UnsignedInt);
+nonzero_int_field!(core::num::NonZeroU32, u32, UnsignedInt);
+nonzero_int_field!(core::num::NonZeroU64, u64, UnsignedInt);
+nonzero_int_field!(core::num::NonZeroU128, u128, UnsignedInt);
 
 #[cfg(test)]
 mod tests {
@@ -159,7 +178,7 @@ mod tests {
 
                     assert_eq!(value1, Field1::try_read(&storage).unwrap());
                     assert_eq!(value2, Field2::try_read(&storage).unwrap());
-                    assert!(matches!(Field3::try_read(&storage), Err(NonZeroIsZeroError(_))));
+                    assert_eq!(Err(NonZeroIsZeroError { offset: 150 }), Field3::try_read(&storage));
 
                     assert_eq!(value1, $type::new($underlying_type::$endian_fn((&storage[5..(5+$expected_size)]).try_into().unwrap())).unwrap());
                     assert_eq!(value2, $type::new($underlying_type::$endian_fn((&storage[123..(123+$expected_size)]).try_into().unwrap())).unwrap());
@@ -186,7 +205,7 @@ mod tests {
 
                     assert_eq!(value1, Field1::try_read(&storage).unwrap());
                     assert_eq!(value2, Field2::try_read(&storage).unwrap());
-                    assert!(matches!(Field3::try_read(&storage), Err(NonZeroIsZeroError(_))));
+                    assert_eq!(Err(NonZeroIsZeroError { offset: 150 }), Field3::try_read(&storage));
 
                     assert_eq!(value1, $type::new($underlying_type::$endian_fn((&storage[5..(5+$expected_size)]).try_into().unwrap())).unwrap());
                     assert_eq!(value2, $type::new($underlying_type::$endian_fn((&storage[123..(123+$expected_size)]).try_into().unwrap())).unwrap());
@@ -213,7 +232,7 @@ mod tests {
 
                     assert_eq!(value1, view.field1().try_read().unwrap());
                     assert_eq!(value2, view.field2().try_read().unwrap());
-                    assert!(matches!(view.field3().try_read(), Err(NonZeroIsZeroError(_))));
+                    assert_eq!(Err(NonZeroIsZeroError { offset: 2 * $expected_size }), view.field3().try_read());
 
                     assert_eq!(value1, $type::new($underlying_type::$endian_fn((&storage[0..($expected_size)]).try_into().unwrap())).unwrap());
                     assert_eq!(value2, $type::new($underlying_type::$endian_fn((&storage[$expected_size..(2*$expected_size)]).try_into().unwrap())).unwrap());
@@ -240,7 +259,7 @@ mod tests {
 
                     assert_eq!(value1, view.field1().try_read().unwrap());
                     assert_eq!(value2, view.field2().try_read().unwrap());
-                    assert!(matches!(view.field3().try_read(), Err(NonZeroIsZeroError(_))));
+                    assert_eq!(Err(NonZeroIsZeroError { offset: 2 * $expected_size }), view.field3().try_read());
 
                     assert_eq!(value1, $type::new($underlying_type::$endian_fn((&storage[0..($expected_size)]).try_into().unwrap())).unwrap());
                     assert_eq!(value2, $type::new($underlying_type::$endian_fn((&storage[$expected_size..(2*$expected_size)]).try_into().unwrap())).unwrap());
@@ -0,0 +1,152 @@
+use core::convert::Infallible;
+
+use super::{FieldCopyAccess, PrimitiveField};
+use crate::fields::primitive::view::FieldView;
+use crate::fields::{Field, StorageIntoFieldView, StorageToFieldView};
+use crate::reflection::{FieldKind, FieldKindInfo};
+use crate::storage::Storage;
+use crate::Endianness;
+
+/// Field type `[i8; N]`:
+/// This field represents a [fixed size signed byte array](crate#fixed-size-signed-byte-arrays-i8-n).
+/// Since signed bytes can't be borrowed from the byte storage without `unsafe`, they are copied out of or into the storage as a whole.
+/// With the `bytemuck` feature, the array can also be borrowed as `&[i8]` with [FieldPodSliceAccess](https://docs.rs/binary-layout/latest/binary_layout/trait.FieldPodSliceAccess.html).
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldCopyAccess
+    for PrimitiveField<[i8; N], E, OFFSET_>
+{
+    /// See [FieldCopyAccess::ReadError]
+    type ReadError = Infallible;
+    /// See [FieldCopyAccess::WriteError]
+    type WriteError = Infallible;
+    /// See [FieldCopyAccess::HighLevelType]
+    type HighLevelType = [i8; N];
+
+    /// Copy the signed bytes out of a given data region, assuming the defined layout, using the [Field] API.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_field: [i8; 5],
+    ///     //... other fields
+    /// });
+    ///
+    /// fn func(storage_data: &[u8]) {
+    ///     let some_field: [i8; 5] = my_layout::some_field::read(storage_data);
+    /// }
+    /// ```
+    #[inline(always)]
+    fn try_read(storage: &[u8]) -> Result<[i8; N], Infallible> {
+        let mut value = [0; N];
+        for (value, byte) in value
+            .iter_mut()
+            .zip(&storage[Self::OFFSET..(Self::OFFSET + N)])
+        {
+            *value = *byte as i8;
+        }
+        Ok(value)
+    }
+
+    /// Copy signed bytes into a given data region, assuming the defined layout, using the [Field] API.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_field: [i8; 5],
+    ///     //... other fields
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) {
+    ///     my_layout::some_field::write(storage_data, [-2, -1, 0, 1, 2]);
+    /// }
+    /// ```
+    #[inline(always)]
+    fn try_write(storage: &mut [u8], value: [i8; N]) -> Result<(), Infallible> {
+        for (byte, value) in storage[Self::OFFSET..(Self::OFFSET + N)]
+            .iter_mut()
+            .zip(value)
+        {
+            *byte = value as u8;
+        }
+        Ok(())
+    }
+}
+
+impl<E: Endianness, const N: usize, const OFFSET_: usize> Field
+    for PrimitiveField<[i8; N], E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = Some(N);
+}
+
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldKindInfo
+    for PrimitiveField<[i8; N], E, OFFSET_>
+{
+    const KIND: FieldKind = FieldKind::SignedByteArray;
+}
+
+impl<'a, E: Endianness, const N: usize, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
+    for PrimitiveField<[i8; N], E, OFFSET_>
+{
+    type View = FieldView<&'a [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<'a, E: Endianness, const N: usize, const OFFSET_: usize> StorageToFieldView<&'a mut [u8]>
+    for PrimitiveField<[i8; N], E, OFFSET_>
+{
+    type View = FieldView<&'a mut [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<S: Storage, E: Endianness, const N: usize, const OFFSET_: usize> StorageIntoFieldView<S>
+    for PrimitiveField<[i8; N], E, OFFSET_>
+{
+    type View = FieldView<S, Self>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::PrimitiveField;
+
+    #[test]
+    fn test_signed_bytes() {
+        let mut storage = [0; 1024];
+
+        type Field1 = PrimitiveField<[i8; 2], LittleEndian, 5>;
+        type Field2 = PrimitiveField<[i8; 3], BigEndian, 7>;
+
+        Field1::write(&mut storage, [-1, 2]);
+        Field2::write(&mut storage, [-128, 0, 127]);
+
+        assert_eq!([-1, 2], Field1::read(&storage));
+        assert_eq!([-128, 0, 127], Field2::read(&storage));
+        assert_eq!(&[0xff, 2, 0x80, 0, 0x7f], &storage[5..10]);
+
+        assert_eq!(Some(2), Field1::SIZE);
+        assert_eq!(Some(3), Field2::SIZE);
+    }
+}
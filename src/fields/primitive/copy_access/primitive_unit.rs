@@ -1,9 +1,11 @@
 use core::convert::Infallible;
 
 use super::{FieldCopyAccess, PrimitiveField};
-use crate::endianness::Endianness;
 use crate::fields::primitive::view::FieldView;
 use crate::fields::{Field, StorageIntoFieldView, StorageToFieldView};
+use crate::reflection::{FieldKind, FieldKindInfo};
+use crate::storage::Storage;
+use crate::Endianness;
 
 impl<E: Endianness, const OFFSET_: usize> FieldCopyAccess for PrimitiveField<(), E, OFFSET_> {
     /// See [FieldCopyAccess::ReadError]
@@ -81,7 +83,7 @@ impl<E: Endianness, const OFFSET_: usize> FieldCopyAccess for PrimitiveField<(),
     }
 }
 
-impl_field_traits!(());
+impl_field_traits!((), Unit);
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,184 @@
+use core::marker::PhantomData;
+
+use crate::fields::{primitive::copy_access::FieldCopyAccess, Field, FieldAtOffset};
+use crate::storage::{SegmentedStorage, SegmentedStorageMut};
+
+/// The largest size of a field that can be accessed in segmented storage, i.e. the size of [u128] and [i128].
+const MAX_FIELD_SIZE: usize = 16;
+
+/// This extension trait adds methods to read and write fields with copy access
+/// from [SegmentedStorage], i.e. from data that isn't stored in one contiguous memory region.
+/// The bytes of the field are gathered from (or scattered to) the segments they're spread across.
+/// This works for fields of up to 16 bytes. Using it for larger fields, e.g. a `[u8; 32]`, fails to compile.
+///
+/// # Example:
+/// ```
+/// use binary_layout::{prelude::*, FieldSegmentedExt};
+///
+/// binary_layout!(my_layout, BigEndian, {
+///   //... other fields ...
+///   some_integer_field: u32,
+///   //... other fields ...
+/// });
+///
+/// let mut segments = [vec![0; 3], vec![0; 5]];
+/// my_layout::some_integer_field::try_write_segmented(&mut segments[..], 0x01020304).unwrap();
+/// assert_eq!([vec![1, 2, 3], vec![4, 0, 0, 0, 0]], segments);
+/// assert_eq!(Ok(0x01020304), my_layout::some_integer_field::try_read_segmented(&segments[..]));
+/// ```
+pub trait FieldSegmentedExt: FieldCopyAccess {
+    /// Read the field from a given segmented data region, assuming the defined layout.
+    /// See [FieldCopyAccess::try_read].
+    fn try_read_segmented<S: SegmentedStorage + ?Sized>(
+        storage: &S,
+    ) -> Result<Self::HighLevelType, Self::ReadError>;
+
+    /// Write the field to a given segmented data region, assuming the defined layout.
+    /// See [FieldCopyAccess::try_write].
+    fn try_write_segmented<S: SegmentedStorageMut + ?Sized>(
+        storage: &mut S,
+        v: Self::HighLevelType,
+    ) -> Result<(), Self::WriteError>;
+}
+
+impl<F> FieldSegmentedExt for F
+where
+    F: FieldCopyAccess + FieldAtOffset<0>,
+    <F as FieldAtOffset<0>>::Moved: FieldCopyAccess<
+        HighLevelType = F::HighLevelType,
+        ReadError = F::ReadError,
+        WriteError = F::WriteError,
+    >,
+{
+    fn try_read_segmented<S: SegmentedStorage + ?Sized>(
+        storage: &S,
+    ) -> Result<Self::HighLevelType, Self::ReadError> {
+        let mut buffer = [0; MAX_FIELD_SIZE];
+        let buffer = &mut buffer[..field_size::<F>()];
+        storage.read_bytes(F::OFFSET, buffer);
+        <F as FieldAtOffset<0>>::Moved::try_read(buffer)
+    }
+
+    fn try_write_segmented<S: SegmentedStorageMut + ?Sized>(
+        storage: &mut S,
+        v: Self::HighLevelType,
+    ) -> Result<(), Self::WriteError> {
+        let mut buffer = [0; MAX_FIELD_SIZE];
+        let buffer = &mut buffer[..field_size::<F>()];
+        <F as FieldAtOffset<0>>::Moved::try_write(buffer, v)?;
+        storage.write_bytes(F::OFFSET, buffer);
+        Ok(())
+    }
+}
+
+fn field_size<F: Field>() -> usize {
+    FieldSize::<F>::VALUE
+}
+
+struct FieldSize<F>(PhantomData<F>);
+
+impl<F: Field> FieldSize<F> {
+    const VALUE: usize = match F::SIZE {
+        Some(size) => {
+            assert!(
+                size <= MAX_FIELD_SIZE,
+                "Segmented storage only supports fields of up to 16 bytes"
+            );
+            size
+        }
+        None => panic!("Fields with copy access always have a size"),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::FieldSegmentedExt;
+    use core::convert::TryInto;
+
+    binary_layout!(layout, LittleEndian, {
+        first: u8,
+        second: i64,
+        third: u128,
+        fourth: bool as u8,
+    });
+
+    #[test]
+    fn spread_across_segments() {
+        let mut segments: [[u8; 3]; 10] = [[0; 3]; 10];
+        layout::first::try_write_segmented(&mut segments[..], 5).unwrap();
+        layout::second::try_write_segmented(&mut segments[..], -1234567890123).unwrap();
+        layout::third::try_write_segmented(&mut segments[..], u128::MAX - 5).unwrap();
+        layout::fourth::try_write_segmented(&mut segments[..], true).unwrap();
+
+        let mut flat = [0; 30];
+        for (byte, segment_byte) in flat.iter_mut().zip(segments.iter().flatten()) {
+            *byte = *segment_byte;
+        }
+        assert_eq!(5, flat[0]);
+        assert_eq!(
+            -1234567890123,
+            i64::from_le_bytes(flat[1..9].try_into().unwrap())
+        );
+        assert_eq!(
+            u128::MAX - 5,
+            u128::from_le_bytes(flat[9..25].try_into().unwrap())
+        );
+        assert_eq!(1, flat[25]);
+
+        assert_eq!(Ok(5), layout::first::try_read_segmented(&segments[..]));
+        assert_eq!(
+            Ok(-1234567890123),
+            layout::second::try_read_segmented(&segments[..])
+        );
+        assert_eq!(
+            Ok(u128::MAX - 5),
+            layout::third::try_read_segmented(&segments[..])
+        );
+        assert!(layout::fourth::try_read_segmented(&segments[..]).unwrap());
+    }
+
+    binary_layout!(byte_arrays, LittleEndian, {
+        first: [u8; 3],
+        second: [u8; 16],
+    });
+
+    #[test]
+    fn byte_arrays() {
+        let mut segments: [[u8; 5]; 4] = [[0; 5]; 4];
+        byte_arrays::first::try_write_segmented(&mut segments[..], [1, 2, 3]).unwrap();
+        byte_arrays::second::try_write_segmented(&mut segments[..], [4; 16]).unwrap();
+        assert_eq!([1, 2, 3, 4, 4], segments[0]);
+        assert_eq!(
+            Ok([1, 2, 3]),
+            byte_arrays::first::try_read_segmented(&segments[..])
+        );
+        assert_eq!(
+            Ok([4; 16]),
+            byte_arrays::second::try_read_segmented(&segments[..])
+        );
+    }
+
+    #[test]
+    fn contiguous_slice() {
+        let mut data = [0; 26];
+        data[0] = 5;
+        data[25] = 1;
+        let storage: &[u8] = &data;
+        assert_eq!(Ok(5), layout::first::try_read_segmented(&storage));
+        assert!(layout::fourth::try_read_segmented(&storage).unwrap());
+    }
+
+    #[test]
+    fn read_error() {
+        let segments: [&[u8]; 2] = [&[0; 25], &[2]];
+        assert!(layout::fourth::try_read_segmented(&segments[..]).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Segmented storage is too small")]
+    fn storage_too_small() {
+        let segments: [&[u8]; 2] = [&[0; 10], &[0; 10]];
+        let _ = layout::third::try_read_segmented(&segments[..]);
+    }
+}
@@ -0,0 +1,115 @@
+use core::marker::PhantomData;
+use core::slice::ChunksExact;
+
+use super::{BorrowingNestedView, NestedViewInfo};
+
+#[track_caller]
+fn frame_size(size: Option<usize>) -> usize {
+    match size {
+        Some(0) => panic!("Frames must have a size of at least one byte"),
+        Some(size) => size,
+        None => panic!("Frames must have a fixed size"),
+    }
+}
+
+/// Iterator over views to consecutive frames of a layout with a fixed size in a byte buffer, e.g. the records of a capture file.
+/// It is returned by the `iter_frames` function the [binary_layout!](crate::binary_layout!) macro generates for each layout.
+/// Bytes at the end of the buffer that don't make up a whole frame aren't returned, see [Frames::remainder].
+///
+/// # Example:
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(sample, LittleEndian, {
+///   timestamp: u32,
+///   value: i16,
+/// });
+///
+/// fn main() {
+///   let buffer = [1, 0, 0, 0, 10, 0, 2, 0, 0, 0, 20, 0, 3, 0];
+///   let mut frames = sample::iter_frames(&buffer);
+///   assert_eq!(2, frames.len());
+///   assert_eq!(10, frames.next().unwrap().value().read());
+///   assert_eq!(2, frames.next().unwrap().timestamp().read());
+///   assert!(frames.next().is_none());
+///   assert_eq!(&[3, 0], frames.remainder());
+/// }
+/// ```
+///
+/// Layouts without a fixed size can't be iterated as frames:
+/// ```should_panic
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(packet, LittleEndian, {
+///   len: u16,
+///   payload: [u8],
+/// });
+///
+/// fn main() {
+///   packet::iter_frames(&[0u8; 16]).count();
+/// }
+/// ```
+pub struct Frames<'a, N> {
+    frames: ChunksExact<'a, u8>,
+    _p: PhantomData<N>,
+}
+
+impl<'a, N: NestedViewInfo> Frames<'a, N> {
+    /// The size of a frame in number of bytes, i.e. the size of the layout `N`
+    ///
+    /// # Panics
+    /// Panics if the layout doesn't have a fixed size or is empty.
+    #[inline]
+    #[track_caller]
+    pub fn frame_size() -> usize {
+        frame_size(N::SIZE)
+    }
+
+    /// Iterate over the frames of layout `N` in `buffer`, see [Frames]. Usually called through `my_layout::iter_frames(buffer)`.
+    ///
+    /// # Panics
+    /// Panics if the layout doesn't have a fixed size or is empty.
+    #[inline]
+    #[track_caller]
+    pub fn new(buffer: &'a [u8]) -> Self {
+        Self {
+            frames: buffer.chunks_exact(Self::frame_size()),
+            _p: PhantomData,
+        }
+    }
+
+    /// Returns the bytes at the end of the buffer that don't make up a whole frame,
+    /// e.g. a frame that was only partially written when the buffer was captured.
+    #[inline]
+    pub fn remainder(&self) -> &'a [u8] {
+        self.frames.remainder()
+    }
+}
+
+impl<'a, N: BorrowingNestedView<&'a [u8]>> Iterator for Frames<'a, N> {
+    type Item = <N as BorrowingNestedView<&'a [u8]>>::View;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.frames.next().map(N::view)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.frames.nth(n).map(N::view)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.frames.size_hint()
+    }
+}
+
+impl<'a, N: BorrowingNestedView<&'a [u8]>> DoubleEndedIterator for Frames<'a, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.frames.next_back().map(N::view)
+    }
+}
+
+impl<'a, N: BorrowingNestedView<&'a [u8]>> ExactSizeIterator for Frames<'a, N> {}
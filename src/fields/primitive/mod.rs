@@ -1,16 +1,35 @@
 use core::marker::PhantomData;
 
-use crate::endianness::Endianness;
+use crate::fields::{Field, FieldAtOffset};
+use crate::Endianness;
 
+mod array;
 mod copy_access;
+mod frames;
 mod nested_access;
+#[cfg(feature = "bytemuck")]
+mod pod_slice;
+mod records;
 mod slice_access;
+mod utf16;
+mod var_bytes;
 mod view;
 
-pub use copy_access::{FieldCopyAccess, FieldReadExt, FieldWriteExt, NonZeroIsZeroError};
+pub use array::{ArrayElement, FieldArrayAccess};
+#[cfg(feature = "f128")]
+pub use copy_access::F128;
+pub use copy_access::{
+    FieldCopyAccess, FieldReadExt, FieldSegmentedExt, FieldWriteExt, NonZeroIsZeroError,
+};
+pub use frames::Frames;
 pub use nested_access::{BorrowingNestedView, NestedViewInfo, OwningNestedView};
+#[cfg(feature = "bytemuck")]
+pub use pod_slice::{FieldPodSliceAccess, PodSliceError};
+pub use records::{RecordCount, Records, RecordsFullError, RecordsIter, RecordsView};
 pub use slice_access::FieldSliceAccess;
-pub use view::FieldView;
+pub use utf16::{Utf16, Utf16Str, Utf16StrMut, Utf16TooLongError};
+pub use var_bytes::{VarBytes, VarBytesMut, VarBytesTooLongError};
+pub use view::{FieldView, UpdateError};
 
 /// A [PrimitiveField] is a [Field](crate::Field) that directly represents a primitive type like [u8], [i16], ...
 /// See [Field](crate::Field) for more info on this API.
@@ -48,3 +67,12 @@ pub struct PrimitiveField<T: ?Sized, E: Endianness, const OFFSET_: usize> {
     _p1: PhantomData<T>,
     _p2: PhantomData<E>,
 }
+
+impl<T: ?Sized, E: Endianness, const OFFSET_: usize, const NEW_OFFSET: usize>
+    FieldAtOffset<NEW_OFFSET> for PrimitiveField<T, E, OFFSET_>
+where
+    PrimitiveField<T, E, OFFSET_>: Field,
+    PrimitiveField<T, E, NEW_OFFSET>: Field,
+{
+    type Moved = PrimitiveField<T, E, NEW_OFFSET>;
+}
@@ -1,5 +1,7 @@
 use super::super::{StorageIntoFieldView, StorageToFieldView};
 use super::{Endianness, PrimitiveField};
+use crate::reflection::{FieldKind, FieldKindInfo, LayoutInfo};
+use crate::storage::Storage;
 use crate::utils::data::Data;
 use crate::Field;
 
@@ -8,11 +10,14 @@ use crate::Field;
 // Then, the code in this module here creates implementations of [Field], [StorageToFieldView]
 // and [StorageIntoFieldView] for it so that it can be used as a field in other layouts.
 
-/// Internal type. Don't use this in user code.
-/// S is expected to be a non-reference type that can own things, e.g. `Data<S>`
+/// Creates a view of a layout that takes ownership of its storage. This is implemented by the `NestedView` type
+/// the [binary_layout!](crate::binary_layout!) macro generates for each layout, with `View` being the layout's `View<S>`.
+/// It allows generic code to accept "a layout" as a type parameter, see [NestedViewInfo] for an example.
+///
+/// S is expected to be a non-reference type that can own things, e.g. [`Data<S>`](crate::Data)
 pub trait OwningNestedView<S>
 where
-    S: AsRef<[u8]>,
+    S: Storage,
 {
     /// A type representing an owning view of the nested field.
     type View;
@@ -21,7 +26,10 @@ where
     fn into_view(storage: S) -> Self::View;
 }
 
-/// Internal type. Don't use this in user code.
+/// Creates a view of a layout over borrowed storage. This is implemented by the `NestedView` type
+/// the [binary_layout!](crate::binary_layout!) macro generates for each layout, with `View` being the layout's `View<S>`.
+/// It allows generic code to accept "a layout" as a type parameter, see [NestedViewInfo] for an example.
+///
 /// S is expected to be a reference type, e.g. &[u8] or &mut [u8]
 pub trait BorrowingNestedView<S> {
     /// A type representing a borrowing view of the nested field.
@@ -31,10 +39,53 @@ pub trait BorrowingNestedView<S> {
     fn view(storage: S) -> Self::View;
 }
 
-/// Internal trait. Don't use this in user code.
+/// Metadata of a layout. This is implemented by the `NestedView` type the [binary_layout!](crate::binary_layout!) macro
+/// generates for each layout, so that generic code can accept "any layout" as a type parameter and work with its size
+/// and [reflection data](LayoutInfo), or create views with [BorrowingNestedView] and [OwningNestedView].
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use binary_layout::{BorrowingNestedView, NestedViewInfo};
+///
+/// binary_layout!(icmp_header, BigEndian, {
+///   packet_type: u8,
+///   code: u8,
+///   checksum: u16,
+/// });
+///
+/// binary_layout!(udp_header, BigEndian, {
+///   source_port: u16,
+///   dest_port: u16,
+///   length: u16,
+///   checksum: u16,
+/// });
+///
+/// /// Sum of the bytes of the header with layout `N` at the start of `packet`
+/// fn header_sum<N: NestedViewInfo>(packet: &[u8]) -> u32 {
+///   let size = N::SIZE.expect("Headers have a fixed size");
+///   packet[..size].iter().map(|byte| u32::from(*byte)).sum()
+/// }
+///
+/// /// A view of the header with layout `N` at the start of `packet`
+/// fn header_view<'a, N: BorrowingNestedView<&'a [u8]>>(packet: &'a [u8]) -> N::View {
+///   N::view(packet)
+/// }
+///
+/// # fn main() {
+/// let packet = [8, 0, 1, 2, 3, 4, 5, 6];
+/// assert_eq!(11, header_sum::<icmp_header::NestedView>(&packet));
+/// assert_eq!(29, header_sum::<udp_header::NestedView>(&packet));
+/// assert_eq!(0x0304, header_view::<udp_header::NestedView>(&packet).length().read());
+/// assert_eq!("udp_header", udp_header::NestedView::LAYOUT.name());
+/// # }
+/// ```
 pub trait NestedViewInfo {
-    /// Size of the nested field
+    /// Size of the layout, or `None` if it ends with an open ended field
     const SIZE: Option<usize>;
+
+    /// Reflection data of the layout
+    const LAYOUT: &'static LayoutInfo;
 }
 
 // TODO FieldNestedAccess may be useful for the field API, but commented out for now since the field API doesn't support nesting yet
@@ -144,7 +195,7 @@ where
     }
 }
 
-impl<S: AsRef<[u8]>, N: OwningNestedView<Data<S>>, E: Endianness, const OFFSET_: usize>
+impl<S: Storage, N: OwningNestedView<Data<S>>, E: Endianness, const OFFSET_: usize>
     StorageIntoFieldView<S> for PrimitiveField<N, E, OFFSET_>
 where
     Self: Field,
@@ -160,3 +211,9 @@ where
         }
     }
 }
+
+impl<N: NestedViewInfo, E: Endianness, const OFFSET_: usize> FieldKindInfo
+    for PrimitiveField<N, E, OFFSET_>
+{
+    const KIND: FieldKind = FieldKind::Nested(N::LAYOUT);
+}
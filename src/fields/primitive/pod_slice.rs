@@ -0,0 +1,201 @@
+use bytemuck::{Pod, PodCastError};
+
+use super::super::Field;
+use super::PrimitiveField;
+use crate::utils::target_endian::matches_target_endianness;
+use crate::Endianness;
+
+/// This error is returned from [FieldPodSliceAccess] if a byte array field can't be reinterpreted as a slice of the requested type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PodSliceError {
+    /// The layout isn't stored with native endianness or the endianness of the target platform
+    EndiannessMismatch,
+    /// The field isn't aligned correctly for the requested type
+    Misaligned,
+    /// The size of the field isn't a multiple of the size of the requested type
+    SizeMismatch,
+}
+
+impl core::fmt::Display for PodSliceError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::EndiannessMismatch => write!(
+                fmt,
+                "The field isn't stored with the endianness of the target platform"
+            ),
+            Self::Misaligned => write!(
+                fmt,
+                "The field isn't aligned correctly for the requested type"
+            ),
+            Self::SizeMismatch => write!(
+                fmt,
+                "The size of the field isn't a multiple of the size of the requested type"
+            ),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl crate::utils::error::Error for PodSliceError {}
+
+impl From<PodCastError> for PodSliceError {
+    fn from(error: PodCastError) -> Self {
+        match error {
+            PodCastError::TargetAlignmentGreaterAndInputNotAligned
+            | PodCastError::AlignmentMismatch => Self::Misaligned,
+            PodCastError::OutputSliceWouldHaveSlop | PodCastError::SizeMismatch => {
+                Self::SizeMismatch
+            }
+        }
+    }
+}
+
+/// This trait is implemented for fixed size byte array fields `[u8; N]` and `[i8; N]` if the `bytemuck` feature is enabled.
+/// It allows borrowing the data of a field that holds an array of plain old data types as a typed slice, e.g. as `&[u32]`, without copying it.
+///
+/// This only works if the layout is stored with native endianness (or the endianness of the target platform),
+/// the field is aligned correctly for the requested type and its size is a multiple of the size of the requested type.
+/// Single byte types like `i8` can be borrowed from layouts with any endianness.
+/// Otherwise, a [PodSliceError] is returned and the data can still be accessed as bytes.
+///
+/// # Example:
+/// ```
+/// use binary_layout::{prelude::*, FieldPodSliceAccess};
+///
+/// binary_layout!(my_layout, NativeEndian, {
+///   samples: [u8; 16],
+/// });
+///
+/// #[repr(C, align(4))]
+/// struct Aligned([u8; 16]);
+///
+/// fn main() {
+///   let mut storage = Aligned([0; 16]);
+///   my_layout::samples::as_mut_slice_of::<u32>(&mut storage.0).unwrap()[1] = 5;
+///   assert_eq!(&[0, 5, 0, 0], my_layout::samples::as_slice_of::<u32>(&storage.0).unwrap());
+/// }
+/// ```
+pub trait FieldPodSliceAccess: Field {
+    /// Borrow the data of the field as a slice of `T` with read access.
+    fn as_slice_of<T: Pod>(storage: &[u8]) -> Result<&[T], PodSliceError>;
+
+    /// Borrow the data of the field as a slice of `T` with write access.
+    fn as_mut_slice_of<T: Pod>(storage: &mut [u8]) -> Result<&mut [T], PodSliceError>;
+}
+
+macro_rules! impl_pod_slice_access {
+    ($type:ty) => {
+        impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldPodSliceAccess
+            for PrimitiveField<$type, E, OFFSET_>
+        {
+            fn as_slice_of<T: Pod>(storage: &[u8]) -> Result<&[T], PodSliceError> {
+                check_endianness::<E, T>()?;
+                Ok(bytemuck::try_cast_slice(
+                    &storage[Self::OFFSET..(Self::OFFSET + N)],
+                )?)
+            }
+
+            fn as_mut_slice_of<T: Pod>(storage: &mut [u8]) -> Result<&mut [T], PodSliceError> {
+                check_endianness::<E, T>()?;
+                Ok(bytemuck::try_cast_slice_mut(
+                    &mut storage[Self::OFFSET..(Self::OFFSET + N)],
+                )?)
+            }
+        }
+    };
+}
+
+impl_pod_slice_access!([u8; N]);
+impl_pod_slice_access!([i8; N]);
+
+/// Single byte types like `i8` don't have a byte order, so they can be borrowed from layouts with any endianness
+fn check_endianness<E: Endianness, T>() -> Result<(), PodSliceError> {
+    if core::mem::size_of::<T>() > 1 && !matches_target_endianness(E::KIND) {
+        return Err(PodSliceError::EndiannessMismatch);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::{FieldPodSliceAccess, PodSliceError, PrimitiveField};
+
+    #[repr(C, align(8))]
+    struct Aligned([u8; 16]);
+
+    type Native = PrimitiveField<[u8; 8], NativeEndian, 4>;
+    type Odd = PrimitiveField<[u8; 6], NativeEndian, 0>;
+    #[cfg(target_endian = "little")]
+    type NonNative = PrimitiveField<[u8; 8], BigEndian, 0>;
+    #[cfg(target_endian = "big")]
+    type NonNative = PrimitiveField<[u8; 8], LittleEndian, 0>;
+
+    #[test]
+    fn read() {
+        let mut storage = Aligned([0; 16]);
+        storage.0[4..8].copy_from_slice(&1u32.to_ne_bytes());
+        storage.0[8..12].copy_from_slice(&2u32.to_ne_bytes());
+        assert_eq!(&[1, 2], Native::as_slice_of::<u32>(&storage.0).unwrap());
+        assert_eq!(
+            &[1u32.to_ne_bytes(), 2u32.to_ne_bytes()],
+            Native::as_slice_of::<[u8; 4]>(&storage.0).unwrap()
+        );
+    }
+
+    #[test]
+    fn write() {
+        let mut storage = Aligned([0; 16]);
+        Native::as_mut_slice_of::<u16>(&mut storage.0)
+            .unwrap()
+            .copy_from_slice(&[1, 2, 3, 4]);
+        assert_eq!(&1u16.to_ne_bytes(), &storage.0[4..6]);
+        assert_eq!(&4u16.to_ne_bytes(), &storage.0[10..12]);
+    }
+
+    #[test]
+    fn misaligned() {
+        let storage = Aligned([0; 16]);
+        assert_eq!(
+            Err(PodSliceError::Misaligned),
+            Native::as_slice_of::<u64>(&storage.0)
+        );
+    }
+
+    #[test]
+    fn size_mismatch() {
+        let storage = Aligned([0; 16]);
+        assert_eq!(
+            Err(PodSliceError::SizeMismatch),
+            Odd::as_slice_of::<u32>(&storage.0)
+        );
+    }
+
+    #[test]
+    fn endianness_mismatch() {
+        let mut storage = Aligned([0; 16]);
+        assert_eq!(
+            Err(PodSliceError::EndiannessMismatch),
+            NonNative::as_slice_of::<u32>(&storage.0)
+        );
+        assert_eq!(
+            Err(PodSliceError::EndiannessMismatch),
+            NonNative::as_mut_slice_of::<u32>(&mut storage.0)
+        );
+    }
+
+    #[test]
+    fn signed_bytes() {
+        type Signed = PrimitiveField<[i8; 4], BigEndian, 2>;
+        let mut storage = [0; 8];
+        Signed::as_mut_slice_of::<i8>(&mut storage)
+            .unwrap()
+            .copy_from_slice(&[-1, -2, 3, 4]);
+        assert_eq!([0, 0, 0xff, 0xfe, 3, 4, 0, 0], storage);
+        assert_eq!(
+            &[-1, -2, 3, 4],
+            Signed::as_slice_of::<i8>(&storage).unwrap()
+        );
+        assert_eq!([-1, -2, 3, 4], Signed::read(&storage));
+    }
+}
@@ -0,0 +1,670 @@
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+use core::ops::{Bound, RangeBounds};
+
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::{BorrowingNestedView, FieldReadExt, FieldWriteExt, NestedViewInfo, PrimitiveField};
+use crate::reflection::{FieldKind, FieldKindInfo};
+use crate::storage::{ResizableStorage, Storage, StorageMut};
+use crate::utils::data::Data;
+use crate::Endianness;
+
+/// Field type for a count of type `C` followed by that many records of the nested layout `N`,
+/// see [count-prefixed records](crate#count-prefixed-records-recordsc-n).
+///
+/// `N` is the `NestedView` type of a layout with a fixed size. The count is stored with the endianness of the layout
+/// containing the field. Like open ended byte arrays, the field extends until the end of the storage and can only
+/// be the last field of a layout. Its accessors return a [RecordsView] that allows indexing and iterating
+/// over the records and keeps the count in sync when records are added or removed.
+///
+/// This type is only used as a marker in layout definitions, it can't be instantiated.
+///
+/// # Example:
+/// ```
+/// use binary_layout::{prelude::*, Records};
+///
+/// binary_layout!(entry, LittleEndian, {
+///   id: u32,
+///   flags: u8,
+/// });
+///
+/// binary_layout!(directory, LittleEndian, {
+///   version: u8,
+///   entries: Records<u16, entry::NestedView>,
+/// });
+///
+/// fn main() {
+///   let mut storage = [0; 13];
+///   let mut view = directory::View::new(&mut storage);
+///   view.entries_mut().push().unwrap().id_mut().write(10);
+///   view.entries_mut().push().unwrap().id_mut().write(20);
+///
+///   let entries = view.entries();
+///   assert_eq!(2, entries.len());
+///   assert_eq!(20, entries.get(1).unwrap().id().read());
+///   assert_eq!(vec![10, 20], entries.iter().map(|entry| entry.id().read()).collect::<Vec<_>>());
+/// }
+/// ```
+pub enum Records<C, N> {
+    #[doc(hidden)]
+    _Phantom(PhantomData<(C, N)>, core::convert::Infallible),
+}
+
+/// Implemented for the integer types that can be used as count of [Records] fields.
+#[doc(hidden)]
+pub trait RecordCount<E: Endianness>: Sized {
+    const SIZE: usize;
+
+    fn read(storage: &[u8]) -> usize;
+
+    fn write(storage: &mut [u8], count: usize) -> Option<()>;
+}
+
+impl<C, E: Endianness> RecordCount<E> for C
+where
+    PrimitiveField<C, E, 0>: FieldReadExt<HighLevelType = C> + FieldWriteExt<HighLevelType = C>,
+    C: TryFrom<usize>,
+    usize: TryFrom<C>,
+{
+    const SIZE: usize = unwrap_size(<PrimitiveField<C, E, 0> as Field>::SIZE);
+
+    #[inline(always)]
+    fn read(storage: &[u8]) -> usize {
+        usize::try_from(PrimitiveField::<C, E, 0>::read(storage)).unwrap_or(usize::MAX)
+    }
+
+    #[inline(always)]
+    fn write(storage: &mut [u8], count: usize) -> Option<()> {
+        PrimitiveField::<C, E, 0>::write(storage, C::try_from(count).ok()?);
+        Some(())
+    }
+}
+
+const fn unwrap_size(size: Option<usize>) -> usize {
+    match size {
+        Some(size) => size,
+        None => panic!("Counts and records of a Records field must have a fixed size"),
+    }
+}
+
+/// This error is returned from [RecordsView::push] if there is no space for another record,
+/// either because the storage is full or because the count can't be represented by the count type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordsFullError;
+
+impl core::fmt::Display for RecordsFullError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "There is no space for another record")
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl crate::utils::error::Error for RecordsFullError {}
+
+/// A view to the records of a [Records] field. It is returned from the accessors of such fields.
+///
+/// The number of records is read from the count stored in the field. If the count is larger than the number of records
+/// that fit into the storage, the records that are out of bounds are inaccessible, i.e. [RecordsView::get] returns `None` for them
+/// and iterating stops before them.
+pub struct RecordsView<S, C, N, E> {
+    storage: S,
+    _p: PhantomData<(C, N, E)>,
+}
+
+impl<S: Storage, C: RecordCount<E>, N: NestedViewInfo, E: Endianness> RecordsView<S, C, N, E> {
+    const RECORD_SIZE: usize = unwrap_size(N::SIZE);
+
+    /// The number of records as stored in the count
+    #[inline]
+    pub fn len(&self) -> usize {
+        C::read(self.storage.as_bytes())
+    }
+
+    /// Returns true if the count is zero
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of records that fit into the storage
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.storage
+            .as_bytes()
+            .len()
+            .saturating_sub(C::SIZE)
+            .checked_div(Self::RECORD_SIZE)
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Returns a view to the record with the given index, or `None` if the index is out of bounds
+    #[inline]
+    pub fn get<'a>(&'a self, index: usize) -> Option<<N as BorrowingNestedView<&'a [u8]>>::View>
+    where
+        N: BorrowingNestedView<&'a [u8]>,
+    {
+        self.record_range(index)
+            .map(|(start, end)| N::view(&self.storage.as_bytes()[start..end]))
+    }
+
+    /// Iterate over views to the records
+    #[inline]
+    pub fn iter(&self) -> RecordsIter<'_, N> {
+        self.iter_range(..)
+    }
+
+    /// Iterate over views to the records with an index in `range`, e.g. `records.iter_range(10..20)`.
+    /// The range is clamped to the accessible records, so indices that are out of bounds are skipped like with [RecordsView::iter].
+    #[inline]
+    pub fn iter_range(&self, range: impl RangeBounds<usize>) -> RecordsIter<'_, N> {
+        let len = self.len().min(self.capacity());
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        }
+        .min(len);
+        let start = start.min(end);
+        let records = &self.storage.as_bytes()[C::SIZE..];
+        RecordsIter {
+            records: &records[(start * Self::RECORD_SIZE)..(end * Self::RECORD_SIZE)],
+            remaining: end - start,
+            step: 1,
+            _p: PhantomData,
+        }
+    }
+
+    /// Iterate over views to every `step`-th record, starting with the first one.
+    /// This is the same as `records.iter().step_by(step)`, see [RecordsIter::step_by].
+    ///
+    /// # Panics
+    /// Panics if `step` is zero.
+    #[inline]
+    pub fn iter_step_by(&self, step: usize) -> RecordsIter<'_, N> {
+        self.iter().step_by(step)
+    }
+
+    /// Returns the index of and a view to the first record for which `predicate` returns true, or `None` if there is no such record.
+    /// To modify the record found, pass its index to [RecordsView::get_mut].
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::{prelude::*, Records};
+    ///
+    /// binary_layout!(dir_entry, LittleEndian, {
+    ///   inode: u32,
+    ///   name: [u8; 8],
+    /// });
+    ///
+    /// binary_layout!(directory, LittleEndian, {
+    ///   entries: Records<u16, dir_entry::NestedView>,
+    /// });
+    ///
+    /// fn lookup(storage: &[u8], name: &[u8; 8]) -> Option<u32> {
+    ///   let view = directory::View::new(storage);
+    ///   let entries = view.entries();
+    ///   let (_index, entry) = entries.find(|entry| entry.name() == name)?;
+    ///   Some(entry.inode().read())
+    /// }
+    /// # fn main() {}
+    /// ```
+    #[inline]
+    pub fn find<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&<N as BorrowingNestedView<&'a [u8]>>::View) -> bool,
+    ) -> Option<(usize, <N as BorrowingNestedView<&'a [u8]>>::View)>
+    where
+        N: BorrowingNestedView<&'a [u8]>,
+    {
+        self.iter()
+            .enumerate()
+            .find(|(_, record)| predicate(record))
+    }
+
+    /// Binary searches the records for the one `compare` returns [Ordering::Equal] for, like [slice::binary_search_by].
+    /// The records must be sorted consistently with `compare`, e.g. with [RecordsView::sort_by_key].
+    /// Returns `Ok` with the index of a matching record, or `Err` with the index a record would have to be inserted at to keep the records sorted.
+    /// Records that are out of bounds of the storage aren't searched.
+    pub fn binary_search_by<'a>(
+        &'a self,
+        mut compare: impl FnMut(&<N as BorrowingNestedView<&'a [u8]>>::View) -> Ordering,
+    ) -> Result<usize, usize>
+    where
+        N: BorrowingNestedView<&'a [u8]>,
+    {
+        let mut low = 0;
+        let mut high = self.len().min(self.capacity());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let record = self.get(mid).expect("Index is within the capacity");
+            match compare(&record) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
+    /// Binary searches the records for the one `key` returns `b` for, like [slice::binary_search_by_key].
+    /// The records must be sorted by that key, e.g. with [RecordsView::sort_by_key].
+    /// This needs `O(log(n))` views into the storage and doesn't copy any records.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::{prelude::*, Records};
+    ///
+    /// binary_layout!(cell, BigEndian, {
+    ///   key: u32,
+    ///   child_page: u32,
+    /// });
+    ///
+    /// binary_layout!(page, BigEndian, {
+    ///   cells: Records<u16, cell::NestedView>,
+    /// });
+    ///
+    /// fn child_page(storage: &[u8], key: u32) -> Option<u32> {
+    ///   let view = page::View::new(storage);
+    ///   let cells = view.cells();
+    ///   let index = cells.binary_search_by_key(&key, |cell| cell.key().read()).ok()?;
+    ///   Some(cells.get(index)?.child_page().read())
+    /// }
+    /// # fn main() {}
+    /// ```
+    #[inline]
+    pub fn binary_search_by_key<'a, B: Ord>(
+        &'a self,
+        b: &B,
+        mut key: impl FnMut(&<N as BorrowingNestedView<&'a [u8]>>::View) -> B,
+    ) -> Result<usize, usize>
+    where
+        N: BorrowingNestedView<&'a [u8]>,
+    {
+        self.binary_search_by(|record| key(record).cmp(b))
+    }
+
+    fn record_range(&self, index: usize) -> Option<(usize, usize)> {
+        if index >= self.len() || index >= self.capacity() {
+            return None;
+        }
+        let start = C::SIZE + index * Self::RECORD_SIZE;
+        Some((start, start + Self::RECORD_SIZE))
+    }
+}
+
+impl<S: StorageMut, C: RecordCount<E>, N: NestedViewInfo, E: Endianness> RecordsView<S, C, N, E> {
+    /// Returns a view with write access to the record with the given index, or `None` if the index is out of bounds
+    #[inline]
+    pub fn get_mut<'a>(
+        &'a mut self,
+        index: usize,
+    ) -> Option<<N as BorrowingNestedView<&'a mut [u8]>>::View>
+    where
+        N: BorrowingNestedView<&'a mut [u8]>,
+    {
+        self.record_range(index)
+            .map(move |(start, end)| N::view(&mut self.storage.as_bytes_mut()[start..end]))
+    }
+
+    /// Append a record after the existing ones and increment the count.
+    /// The bytes of the new record are zeroed and a view with write access to it is returned.
+    pub fn push<'a>(
+        &'a mut self,
+    ) -> Result<<N as BorrowingNestedView<&'a mut [u8]>>::View, RecordsFullError>
+    where
+        N: BorrowingNestedView<&'a mut [u8]>,
+    {
+        let index = self.len();
+        if index >= self.capacity() {
+            return Err(RecordsFullError);
+        }
+        let storage = self.storage.as_bytes_mut();
+        C::write(storage, index + 1).ok_or(RecordsFullError)?;
+        let start = C::SIZE + index * Self::RECORD_SIZE;
+        let record = &mut storage[start..(start + Self::RECORD_SIZE)];
+        record.fill(0);
+        Ok(N::view(record))
+    }
+
+    /// Shorten the records to `len` records by decreasing the count. This has no effect if there are already `len` or less records.
+    /// The bytes of the removed records aren't modified.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            C::write(self.storage.as_bytes_mut(), len)
+                .expect("A count smaller than an existing count must be representable");
+        }
+    }
+
+    /// Remove all records by setting the count to zero
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Sort the records by the key `key` computes for them, reordering the records within the storage.
+    /// The sort is stable, i.e. records with equal keys keep their order. `key` is called once for each record
+    /// and the keys are cached, which needs the `alloc` feature. Records that are out of bounds of the storage aren't sorted.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::{prelude::*, Records};
+    ///
+    /// binary_layout!(entry, BigEndian, {
+    ///   key: u16,
+    ///   value: u8,
+    /// });
+    ///
+    /// binary_layout!(table, BigEndian, {
+    ///   entries: Records<u8, entry::NestedView>,
+    /// });
+    ///
+    /// fn main() {
+    ///   let mut storage = [3, 0, 30, 1, 0, 10, 2, 0, 20, 3];
+    ///   table::View::new(&mut storage).entries_mut().sort_by_key(|entry| entry.key().read());
+    ///   assert_eq!([3, 0, 10, 2, 0, 20, 3, 0, 30, 1], storage);
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn sort_by_key<K: Ord>(
+        &mut self,
+        mut key: impl FnMut(&<N as BorrowingNestedView<&[u8]>>::View) -> K,
+    ) where
+        N: for<'b> BorrowingNestedView<&'b [u8]>,
+    {
+        let (records, len) = self.records_mut();
+        let mut order: alloc::vec::Vec<(K, usize)> = records
+            .chunks_exact(Self::RECORD_SIZE)
+            .take(len)
+            .map(|record| key(&N::view(record)))
+            .zip(0..)
+            .collect();
+        order.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+        // Move each record to its sorted position by following the cycles of the permutation.
+        // `source[index]` is the index the record at `index` is taken from, or `None` once it is in place.
+        let mut source: alloc::vec::Vec<Option<usize>> =
+            order.into_iter().map(|(_, index)| Some(index)).collect();
+        for start in 0..len {
+            let mut index = start;
+            while let Some(next) = source[index].take() {
+                if next == start {
+                    break;
+                }
+                swap_records(records, Self::RECORD_SIZE, index, next);
+                index = next;
+            }
+        }
+    }
+
+    /// Sort the records by the key `key` computes for them, reordering the records within the storage.
+    /// The sort is unstable, i.e. records with equal keys may be reordered, but it doesn't allocate.
+    /// `key` is called `O(n * log(n))` times. Records that are out of bounds of the storage aren't sorted.
+    pub fn sort_unstable_by_key<K: Ord>(
+        &mut self,
+        mut key: impl FnMut(&<N as BorrowingNestedView<&[u8]>>::View) -> K,
+    ) where
+        N: for<'b> BorrowingNestedView<&'b [u8]>,
+    {
+        let (records, len) = self.records_mut();
+        let size = Self::RECORD_SIZE;
+        let mut key_at = |records: &[u8], index: usize| {
+            key(&N::view(&records[(index * size)..((index + 1) * size)]))
+        };
+        // Heapsort, so that the records can be sorted in place without allocating
+        for root in (0..(len / 2)).rev() {
+            sift_down(records, size, &mut key_at, root, len);
+        }
+        for end in (1..len).rev() {
+            swap_records(records, size, 0, end);
+            sift_down(records, size, &mut key_at, 0, end);
+        }
+    }
+
+    /// The bytes of the records that are within the bounds of the storage, and their number
+    fn records_mut(&mut self) -> (&mut [u8], usize) {
+        let len = self.len().min(self.capacity());
+        let records = &mut self.storage.as_bytes_mut()[C::SIZE..];
+        (&mut records[..(len * Self::RECORD_SIZE)], len)
+    }
+}
+
+/// Restore the max-heap property for the subtree at `root` among the first `end` records
+fn sift_down<K: Ord>(
+    records: &mut [u8],
+    record_size: usize,
+    key_at: &mut impl FnMut(&[u8], usize) -> K,
+    mut root: usize,
+    end: usize,
+) {
+    loop {
+        let mut child = 2 * root + 1;
+        if child >= end {
+            return;
+        }
+        if child + 1 < end && key_at(records, child) < key_at(records, child + 1) {
+            child += 1;
+        }
+        if key_at(records, root) >= key_at(records, child) {
+            return;
+        }
+        swap_records(records, record_size, root, child);
+        root = child;
+    }
+}
+
+/// Swap the records with indices `a` and `b`
+fn swap_records(records: &mut [u8], record_size: usize, a: usize, b: usize) {
+    if a == b {
+        return;
+    }
+    let (low, high) = if a < b { (a, b) } else { (b, a) };
+    let (head, tail) = records.split_at_mut(high * record_size);
+    head[(low * record_size)..((low + 1) * record_size)].swap_with_slice(&mut tail[..record_size]);
+}
+
+impl<S: ResizableStorage, C: RecordCount<E>, N: NestedViewInfo, E: Endianness>
+    RecordsView<S, C, N, E>
+{
+    /// Like [RecordsView::push], but if there is no space for another record, the storage is grown by one record instead of failing.
+    /// This is available for records over [resizable storage](crate::ResizableStorage), e.g. the records returned from the `into_${field_name}()`
+    /// accessor of a view over `&mut Vec<u8>`. The count is kept in sync, but [length fields](crate::binary_layout!#length-fields)
+    /// of an enclosing layout are only updated by its `View::finalize()`.
+    ///
+    /// This only fails if the incremented count can't be represented by the count type.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::{prelude::*, Records};
+    ///
+    /// binary_layout!(entry, LittleEndian, {
+    ///   id: u32,
+    /// });
+    ///
+    /// binary_layout!(directory, LittleEndian, {
+    ///   version: u8,
+    ///   entries: Records<u8, entry::NestedView>,
+    /// });
+    ///
+    /// # #[cfg(feature = "alloc")]
+    /// fn main() {
+    ///   let mut storage = vec![1, 0];
+    ///   let mut entries = directory::View::new(&mut storage).into_entries();
+    ///   entries.push_record().unwrap().id_mut().write(10);
+    ///   entries.push_record().unwrap().id_mut().write(20);
+    ///   assert_eq!(vec![1, 2, 10, 0, 0, 0, 20, 0, 0, 0], storage);
+    /// }
+    /// # #[cfg(not(feature = "alloc"))]
+    /// # fn main() {}
+    /// ```
+    pub fn push_record<'a>(
+        &'a mut self,
+    ) -> Result<<N as BorrowingNestedView<&'a mut [u8]>>::View, RecordsFullError>
+    where
+        N: BorrowingNestedView<&'a mut [u8]>,
+    {
+        let index = self.len();
+        if index < self.capacity() {
+            return self.push();
+        }
+        let old_len = self.storage.as_bytes().len();
+        let start = index
+            .checked_mul(Self::RECORD_SIZE)
+            .and_then(|offset| offset.checked_add(C::SIZE))
+            .ok_or(RecordsFullError)?;
+        let end = start
+            .checked_add(Self::RECORD_SIZE)
+            .ok_or(RecordsFullError)?;
+        self.storage.resize(end);
+        if C::write(self.storage.as_bytes_mut(), index + 1).is_none() {
+            self.storage.resize(old_len);
+            return Err(RecordsFullError);
+        }
+        let record = &mut self.storage.as_bytes_mut()[start..end];
+        record.fill(0);
+        Ok(N::view(record))
+    }
+}
+
+/// Iterator over views to the records of a [Records] field, see [RecordsView::iter].
+pub struct RecordsIter<'a, N> {
+    /// The records starting at the next record to be returned
+    records: &'a [u8],
+    remaining: usize,
+    /// Distance between the records returned, in number of records
+    step: usize,
+    _p: PhantomData<N>,
+}
+
+impl<'a, N: NestedViewInfo> RecordsIter<'a, N> {
+    /// Returns an iterator that only returns every `step`-th record of this iterator, starting with the next one.
+    /// Like [Iterator::step_by], but the records in between are skipped without creating views to them.
+    /// Applying it to an iterator that is already stepping multiplies the steps.
+    ///
+    /// # Panics
+    /// Panics if `step` is zero.
+    #[inline]
+    pub fn step_by(self, step: usize) -> Self {
+        assert!(step != 0, "Step must not be zero");
+        // Every `step`-th of the records this iterator would return, including the next one, is returned
+        let remaining = match self.remaining {
+            0 => 0,
+            remaining => (remaining - 1) / step + 1,
+        };
+        Self {
+            records: self.records,
+            remaining,
+            step: self.step.saturating_mul(step),
+            _p: PhantomData,
+        }
+    }
+
+    /// Skip `n` records (i.e. `n` steps) without creating views to them
+    fn skip_records(&mut self, n: usize) {
+        if n >= self.remaining {
+            self.records = &[];
+            self.remaining = 0;
+        } else {
+            let offset = n * self.step * unwrap_size(N::SIZE);
+            self.records = &self.records[offset..];
+            self.remaining -= n;
+        }
+    }
+}
+
+impl<'a, N: NestedViewInfo + BorrowingNestedView<&'a [u8]>> Iterator for RecordsIter<'a, N> {
+    type Item = <N as BorrowingNestedView<&'a [u8]>>::View;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let record = &self.records[..unwrap_size(N::SIZE)];
+        self.skip_records(1);
+        Some(N::view(record))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.skip_records(n);
+        self.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, N: NestedViewInfo + BorrowingNestedView<&'a [u8]>> ExactSizeIterator
+    for RecordsIter<'a, N>
+{
+}
+
+/// Field type `Records<C, N>`:
+/// This field represents [count-prefixed records](crate#count-prefixed-records-recordsc-n).
+/// In this impl, we define accessors for such fields.
+impl<C, N, E: Endianness, const OFFSET_: usize> Field
+    for PrimitiveField<Records<C, N>, E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = None;
+}
+
+impl<'a, C, N, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
+    for PrimitiveField<Records<C, N>, E, OFFSET_>
+{
+    type View = RecordsView<&'a [u8], C, N, E>;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        RecordsView {
+            storage: &storage[Self::OFFSET..],
+            _p: PhantomData,
+        }
+    }
+}
+
+impl<'a, C, N, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a mut [u8]>
+    for PrimitiveField<Records<C, N>, E, OFFSET_>
+{
+    type View = RecordsView<&'a mut [u8], C, N, E>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        RecordsView {
+            storage: &mut storage[Self::OFFSET..],
+            _p: PhantomData,
+        }
+    }
+}
+
+impl<S: Storage, C, N, E: Endianness, const OFFSET_: usize> StorageIntoFieldView<S>
+    for PrimitiveField<Records<C, N>, E, OFFSET_>
+{
+    type View = RecordsView<Data<S>, C, N, E>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        RecordsView {
+            storage: Data::from(storage).into_subregion(Self::OFFSET..),
+            _p: PhantomData,
+        }
+    }
+}
+
+impl<C, N: NestedViewInfo, E: Endianness, const OFFSET_: usize> FieldKindInfo
+    for PrimitiveField<Records<C, N>, E, OFFSET_>
+{
+    const KIND: FieldKind = FieldKind::Records(N::LAYOUT);
+}
@@ -1,9 +1,11 @@
-use core::convert::TryFrom;
+use core::convert::{Infallible, TryFrom};
 
 use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
-use super::PrimitiveField;
-use crate::endianness::Endianness;
+use super::{FieldCopyAccess, PrimitiveField};
+use crate::reflection::{FieldKind, FieldKindInfo};
+use crate::storage::Storage;
 use crate::utils::data::Data;
+use crate::Endianness;
 
 /// This trait is implemented for fields with "slice access",
 /// i.e. fields that are read/write directly without a copy
@@ -128,7 +130,7 @@ impl<'a, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a mut [u8]>
     }
 }
 
-impl<S: AsRef<[u8]>, E: Endianness, const OFFSET_: usize> StorageIntoFieldView<S>
+impl<S: Storage, E: Endianness, const OFFSET_: usize> StorageIntoFieldView<S>
     for PrimitiveField<[u8], E, OFFSET_>
 {
     type View = Data<S>;
@@ -192,6 +194,63 @@ impl<'a, E: Endianness, const N: usize, const OFFSET_: usize> FieldSliceAccess<'
         <&mut [u8; N]>::try_from(&mut storage[Self::OFFSET..(Self::OFFSET + N)]).unwrap()
     }
 }
+
+/// Field type `[u8; N]`:
+/// Besides slice access, fixed size byte arrays can also be copied out of or into the storage as a whole.
+/// This is what allows wrapping them into custom types with [LayoutAs](crate::LayoutAs), e.g. `id: Uuid as [u8; 16]`.
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldCopyAccess
+    for PrimitiveField<[u8; N], E, OFFSET_>
+{
+    /// See [FieldCopyAccess::ReadError]
+    type ReadError = Infallible;
+    /// See [FieldCopyAccess::WriteError]
+    type WriteError = Infallible;
+    /// See [FieldCopyAccess::HighLevelType]
+    type HighLevelType = [u8; N];
+
+    /// Copy the data of the byte array out of a given data region, assuming the defined layout, using the [Field] API.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_field: [u8; 5],
+    ///     //... other fields
+    /// });
+    ///
+    /// fn func(storage_data: &[u8]) {
+    ///     let some_field: [u8; 5] = my_layout::some_field::read(storage_data);
+    /// }
+    /// ```
+    #[inline(always)]
+    fn try_read(storage: &[u8]) -> Result<[u8; N], Infallible> {
+        Ok(*<Self as FieldSliceAccess>::data(storage))
+    }
+
+    /// Copy a byte array into a given data region, assuming the defined layout, using the [Field] API.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_field: [u8; 5],
+    ///     //... other fields
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) {
+    ///     my_layout::some_field::write(storage_data, [1, 2, 3, 4, 5]);
+    /// }
+    /// ```
+    #[inline(always)]
+    fn try_write(storage: &mut [u8], value: [u8; N]) -> Result<(), Infallible> {
+        *<Self as FieldSliceAccess>::data_mut(storage) = value;
+        Ok(())
+    }
+}
 impl<E: Endianness, const N: usize, const OFFSET_: usize> Field
     for PrimitiveField<[u8; N], E, OFFSET_>
 {
@@ -224,7 +283,7 @@ impl<'a, E: Endianness, const N: usize, const OFFSET_: usize> StorageToFieldView
     }
 }
 
-impl<S: AsRef<[u8]>, E: Endianness, const N: usize, const OFFSET_: usize> StorageIntoFieldView<S>
+impl<S: Storage, E: Endianness, const N: usize, const OFFSET_: usize> StorageIntoFieldView<S>
     for PrimitiveField<[u8; N], E, OFFSET_>
 {
     type View = Data<S>;
@@ -235,6 +294,16 @@ impl<S: AsRef<[u8]>, E: Endianness, const N: usize, const OFFSET_: usize> Storag
     }
 }
 
+impl<E: Endianness, const OFFSET_: usize> FieldKindInfo for PrimitiveField<[u8], E, OFFSET_> {
+    const KIND: FieldKind = FieldKind::OpenEndedByteArray;
+}
+
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldKindInfo
+    for PrimitiveField<[u8; N], E, OFFSET_>
+{
+    const KIND: FieldKind = FieldKind::ByteArray;
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::float_cmp)]
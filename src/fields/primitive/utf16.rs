@@ -0,0 +1,455 @@
+use core::char::DecodeUtf16Error;
+use core::fmt::{self, Debug, Display, Formatter, Write};
+
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::{FieldSliceAccess, PrimitiveField};
+use crate::reflection::{FieldKind, FieldKindInfo};
+use crate::storage::Storage;
+use crate::utils::data::Data;
+use crate::{EndianKind, Endianness};
+
+/// Field type for UTF-16 strings stored in a buffer of `N` code units (i.e. `2 * N` bytes), see [UTF-16 strings](crate#utf-16-strings-utf16n).
+///
+/// The code units are stored with the endianness of the layout, so Windows formats storing UTF-16LE names use a
+/// `LittleEndian` layout. The string ends at the first NUL code unit, or at the end of the buffer if it doesn't contain one,
+/// and unused code units at the end of the buffer are filled with NUL when writing.
+/// With `Utf16<N, true>`, strings must be NUL terminated, so writing reserves one code unit for the terminator
+/// and can store at most `N - 1` code units.
+///
+/// Reading gives a [Utf16Str] that decodes the string into `char`s or, with the `alloc` feature, into a `String`.
+/// Writing gives a [Utf16StrMut] that encodes a `&str` into the buffer.
+///
+/// This type is only used as a marker in layout definitions, it can't be instantiated.
+///
+/// # Example:
+/// ```
+/// use binary_layout::{prelude::*, Utf16};
+///
+/// binary_layout!(my_layout, LittleEndian, {
+///   name: Utf16<6>,
+///   attributes: u16,
+/// });
+///
+/// fn main() {
+///   let mut storage = [0; 14];
+///   let mut view = my_layout::View::new(&mut storage);
+///   view.name_mut().set("file").unwrap();
+///   view.attributes_mut().write(0x20);
+///
+///   assert_eq!("file", view.name());
+///   assert_eq!(12, my_layout::attributes::OFFSET);
+///   assert_eq!(&[b'f', 0, b'i', 0, b'l', 0, b'e', 0, 0, 0, 0, 0, 0x20, 0], &storage);
+/// }
+/// ```
+pub enum Utf16<const N: usize, const NUL_TERMINATED: bool = false> {}
+
+/// This error is returned when trying to store a string in a [Utf16] field that doesn't have space for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utf16TooLongError {
+    len: usize,
+    capacity: usize,
+}
+
+impl core::fmt::Display for Utf16TooLongError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            fmt,
+            "Tried to store {} UTF-16 code units in a field with a capacity of {} code units",
+            self.len, self.capacity
+        )
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl crate::utils::error::Error for Utf16TooLongError {}
+
+impl Utf16TooLongError {
+    /// The number of UTF-16 code units of the string that didn't fit into the field
+    pub fn data_len(&self) -> usize {
+        self.len
+    }
+
+    /// The maximal number of UTF-16 code units the field can store
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+/// Gives read access to a [Utf16] field, see [FieldSliceAccess::data].
+///
+/// The string can be decoded with [Utf16Str::chars] or, with the `alloc` feature, [Utf16Str::try_to_string].
+/// Its [Display] implementation replaces invalid code units with [REPLACEMENT_CHARACTER](char::REPLACEMENT_CHARACTER).
+#[derive(Clone, Copy)]
+pub struct Utf16Str<'a> {
+    bytes: &'a [u8],
+    endianness: EndianKind,
+}
+
+impl<'a> Utf16Str<'a> {
+    #[inline]
+    fn new(bytes: &'a [u8], endianness: EndianKind) -> Self {
+        Self {
+            bytes: &bytes[..2 * logical_len(bytes)],
+            endianness,
+        }
+    }
+
+    /// The encoded bytes of the string, without its NUL terminator or padding
+    #[inline]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// The number of UTF-16 code units of the string
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bytes.len() / 2
+    }
+
+    /// Returns true if the string is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Iterate over the UTF-16 code units of the string, converted from the endianness of the layout
+    #[inline]
+    pub fn units(&self) -> impl Iterator<Item = u16> + 'a {
+        let endianness = self.endianness;
+        self.bytes
+            .chunks_exact(2)
+            .map(move |bytes| decode_unit(endianness, bytes))
+    }
+
+    /// Decode the string into `char`s. Unpaired surrogates are returned as errors.
+    #[inline]
+    pub fn chars(&self) -> impl Iterator<Item = Result<char, DecodeUtf16Error>> + 'a {
+        core::char::decode_utf16(self.units())
+    }
+
+    /// Decode the string into a `String`, failing if it contains unpaired surrogates.
+    /// Use [ToString::to_string](alloc::string::ToString::to_string) to replace them with [REPLACEMENT_CHARACTER](char::REPLACEMENT_CHARACTER) instead.
+    #[cfg(feature = "alloc")]
+    pub fn try_to_string(&self) -> Result<alloc::string::String, DecodeUtf16Error> {
+        self.chars().collect()
+    }
+}
+
+impl Display for Utf16Str<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for c in self.chars() {
+            f.write_char(c.unwrap_or(char::REPLACEMENT_CHARACTER))?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for Utf16Str<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        for c in self.chars() {
+            match c {
+                Ok(c) => {
+                    for c in c.escape_debug() {
+                        f.write_char(c)?;
+                    }
+                }
+                Err(error) => write!(f, "\\u{{{:x}}}", error.unpaired_surrogate())?,
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+impl PartialEq<str> for Utf16Str<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.units().eq(other.encode_utf16())
+    }
+}
+
+impl PartialEq<&str> for Utf16Str<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl PartialEq<Utf16Str<'_>> for &str {
+    fn eq(&self, other: &Utf16Str<'_>) -> bool {
+        *other == **self
+    }
+}
+
+/// Gives write access to a [Utf16] field, see [FieldSliceAccess::data_mut].
+pub struct Utf16StrMut<'a, const NUL_TERMINATED: bool> {
+    storage: &'a mut [u8],
+    endianness: EndianKind,
+}
+
+impl<'a, const NUL_TERMINATED: bool> Utf16StrMut<'a, NUL_TERMINATED> {
+    /// The string currently stored in the field
+    #[inline]
+    pub fn data(&self) -> Utf16Str<'_> {
+        Utf16Str::new(self.storage, self.endianness)
+    }
+
+    /// The maximal number of UTF-16 code units the field can store, excluding the NUL terminator for `Utf16<N, true>`
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        let units = self.storage.len() / 2;
+        if NUL_TERMINATED {
+            units.saturating_sub(1)
+        } else {
+            units
+        }
+    }
+
+    /// Encode `value` into the field and fill the remaining code units with NUL.
+    /// This fails with a [Utf16TooLongError] and doesn't modify the field if the encoded string is longer than [Utf16StrMut::capacity].
+    ///
+    /// Note that strings containing NUL characters can't be read back completely because reading stops at the first NUL.
+    pub fn set(&mut self, value: &str) -> Result<(), Utf16TooLongError> {
+        let len = value.encode_utf16().count();
+        if len > self.capacity() {
+            return Err(Utf16TooLongError {
+                len,
+                capacity: self.capacity(),
+            });
+        }
+        let (content, padding) = self.storage.split_at_mut(2 * len);
+        for (bytes, unit) in content.chunks_exact_mut(2).zip(value.encode_utf16()) {
+            bytes.copy_from_slice(&encode_unit(self.endianness, unit));
+        }
+        padding.fill(0);
+        Ok(())
+    }
+
+    /// Remove the string stored in the field, i.e. fill it with NUL
+    #[inline]
+    pub fn clear(&mut self) {
+        self.storage.fill(0);
+    }
+}
+
+impl<const NUL_TERMINATED: bool> Debug for Utf16StrMut<'_, NUL_TERMINATED> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.data(), f)
+    }
+}
+
+#[inline(always)]
+fn decode_unit(endianness: EndianKind, bytes: &[u8]) -> u16 {
+    let bytes = [bytes[0], bytes[1]];
+    match endianness {
+        EndianKind::Big => u16::from_be_bytes(bytes),
+        EndianKind::Little => u16::from_le_bytes(bytes),
+        EndianKind::Native => u16::from_ne_bytes(bytes),
+    }
+}
+
+#[inline(always)]
+fn encode_unit(endianness: EndianKind, unit: u16) -> [u8; 2] {
+    match endianness {
+        EndianKind::Big => unit.to_be_bytes(),
+        EndianKind::Little => unit.to_le_bytes(),
+        EndianKind::Native => unit.to_ne_bytes(),
+    }
+}
+
+/// The number of code units before the first NUL code unit
+#[inline(always)]
+fn logical_len(storage: &[u8]) -> usize {
+    storage
+        .chunks_exact(2)
+        .position(|unit| unit == [0, 0])
+        .unwrap_or(storage.len() / 2)
+}
+
+/// Field type `Utf16<N>`:
+/// This field represents a [UTF-16 string](crate#utf-16-strings-utf16n).
+/// In this impl, we define accessors for such fields.
+impl<'a, E: Endianness, const N: usize, const NUL_TERMINATED: bool, const OFFSET_: usize>
+    FieldSliceAccess<'a> for PrimitiveField<Utf16<N, NUL_TERMINATED>, E, OFFSET_>
+{
+    type SliceType = Utf16Str<'a>;
+    type MutSliceType = Utf16StrMut<'a, NUL_TERMINATED>;
+
+    /// Get read access to the string stored in the field using the [Field] API.
+    /// See also [FieldSliceAccess::data].
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::{prelude::*, Utf16};
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_field: Utf16<16>,
+    ///     //... other fields
+    /// });
+    ///
+    /// fn func(storage_data: &[u8]) -> String {
+    ///     my_layout::some_field::data(storage_data).to_string()
+    /// }
+    /// # fn main() {}
+    /// ```
+    #[inline(always)]
+    fn data(storage: &'a [u8]) -> Utf16Str<'a> {
+        Utf16Str::new(&storage[Self::OFFSET..(Self::OFFSET + 2 * N)], E::KIND)
+    }
+
+    /// Get write access to the field using the [Field] API.
+    /// See also [FieldSliceAccess::data_mut].
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::{prelude::*, Utf16, Utf16TooLongError};
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_field: Utf16<16>,
+    ///     //... other fields
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) -> Result<(), Utf16TooLongError> {
+    ///     my_layout::some_field::data_mut(storage_data).set("some name")
+    /// }
+    /// # fn main() {}
+    /// ```
+    #[inline(always)]
+    fn data_mut(storage: &'a mut [u8]) -> Utf16StrMut<'a, NUL_TERMINATED> {
+        Utf16StrMut {
+            storage: &mut storage[Self::OFFSET..(Self::OFFSET + 2 * N)],
+            endianness: E::KIND,
+        }
+    }
+}
+impl<E: Endianness, const N: usize, const NUL_TERMINATED: bool, const OFFSET_: usize> Field
+    for PrimitiveField<Utf16<N, NUL_TERMINATED>, E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = Some(2 * N);
+}
+impl<'a, E: Endianness, const N: usize, const NUL_TERMINATED: bool, const OFFSET_: usize>
+    StorageToFieldView<&'a [u8]> for PrimitiveField<Utf16<N, NUL_TERMINATED>, E, OFFSET_>
+{
+    type View = Utf16Str<'a>;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        Self::data(storage)
+    }
+}
+
+impl<'a, E: Endianness, const N: usize, const NUL_TERMINATED: bool, const OFFSET_: usize>
+    StorageToFieldView<&'a mut [u8]> for PrimitiveField<Utf16<N, NUL_TERMINATED>, E, OFFSET_>
+{
+    type View = Utf16StrMut<'a, NUL_TERMINATED>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        Self::data_mut(storage)
+    }
+}
+
+impl<
+        S: Storage,
+        E: Endianness,
+        const N: usize,
+        const NUL_TERMINATED: bool,
+        const OFFSET_: usize,
+    > StorageIntoFieldView<S> for PrimitiveField<Utf16<N, NUL_TERMINATED>, E, OFFSET_>
+{
+    type View = Data<S>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        let len = logical_len(&storage.as_bytes()[Self::OFFSET..(Self::OFFSET + 2 * N)]);
+        Data::from(storage).into_subregion(Self::OFFSET..(Self::OFFSET + 2 * len))
+    }
+}
+
+impl<E: Endianness, const N: usize, const NUL_TERMINATED: bool, const OFFSET_: usize> FieldKindInfo
+    for PrimitiveField<Utf16<N, NUL_TERMINATED>, E, OFFSET_>
+{
+    const KIND: FieldKind = FieldKind::Utf16String;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::{PrimitiveField, Utf16, Utf16TooLongError};
+
+    type NameLe = PrimitiveField<Utf16<4>, LittleEndian, 1>;
+    type NameBe = PrimitiveField<Utf16<4>, BigEndian, 1>;
+    type Terminated = PrimitiveField<Utf16<4, true>, LittleEndian, 0>;
+
+    #[test]
+    fn metadata() {
+        assert_eq!(1, NameLe::OFFSET);
+        assert_eq!(Some(8), NameLe::SIZE);
+    }
+
+    #[test]
+    fn read() {
+        let storage = [1, b'a', 0, b'b', 0, 0, 0, b'c', 0, 1];
+        assert_eq!("ab", NameLe::data(&storage));
+        assert_eq!(2, NameLe::data(&storage).len());
+        let storage = [1, 0, b'a', 0, b'b', 0, b'c', 0, b'd', 1];
+        assert_eq!("abcd", NameBe::data(&storage));
+        let storage = [1, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+        assert!(NameLe::data(&storage).is_empty());
+    }
+
+    #[test]
+    fn write() {
+        let mut storage = [1; 10];
+        NameLe::data_mut(&mut storage).set("äb").unwrap();
+        assert_eq!([1, 0xe4, 0, b'b', 0, 0, 0, 0, 0, 1], storage);
+        NameBe::data_mut(&mut storage).set("a😀").unwrap();
+        assert_eq!([1, 0, b'a', 0xd8, 0x3d, 0xde, 0x00, 0, 0, 1], storage);
+        assert_eq!("a😀", NameBe::data(&storage));
+        NameBe::data_mut(&mut storage).clear();
+        assert_eq!([1, 0, 0, 0, 0, 0, 0, 0, 0, 1], storage);
+    }
+
+    #[test]
+    fn write_too_long() {
+        let mut storage = [1; 10];
+        let error = NameLe::data_mut(&mut storage).set("abc😀").unwrap_err();
+        assert_eq!(5, error.data_len());
+        assert_eq!(4, error.capacity());
+        assert_eq!([1; 10], storage);
+    }
+
+    #[test]
+    fn nul_terminated() {
+        let mut storage = [1; 8];
+        assert_eq!(3, Terminated::data_mut(&mut storage).capacity());
+        assert_eq!(
+            Err(Utf16TooLongError {
+                len: 4,
+                capacity: 3
+            }),
+            Terminated::data_mut(&mut storage).set("abcd")
+        );
+        Terminated::data_mut(&mut storage).set("abc").unwrap();
+        assert_eq!([b'a', 0, b'b', 0, b'c', 0, 0, 0], storage);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn invalid_surrogates() {
+        use alloc::{format, string::ToString, vec::Vec};
+
+        let storage = [0, 0x3d, 0xd8, b'a', 0, 0, 0, 0, 0, 0];
+        let chars: Vec<_> = NameLe::data(&storage).chars().collect();
+        assert_eq!(0xd83d, chars[0].clone().unwrap_err().unpaired_surrogate());
+        assert_eq!(Ok('a'), chars[1]);
+        assert_eq!("\u{fffd}a", NameLe::data(&storage).to_string());
+        assert_eq!("\"\\u{d83d}a\"", format!("{:?}", NameLe::data(&storage)));
+        assert!(NameLe::data(&storage).try_to_string().is_err());
+    }
+}
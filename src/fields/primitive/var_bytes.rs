@@ -0,0 +1,320 @@
+use core::fmt::{self, Debug, Formatter};
+
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::{FieldSliceAccess, PrimitiveField};
+use crate::reflection::{FieldKind, FieldKindInfo};
+use crate::storage::Storage;
+use crate::utils::data::Data;
+use crate::Endianness;
+
+/// Field type for byte arrays with a variable length of up to `N` bytes, see [variable length byte arrays](crate#variable-length-byte-arrays-varbytesn).
+///
+/// The field always reserves `N` bytes in the layout, so the fields after it still have a constant offset.
+/// Unused bytes at the end of the field are filled with the `PADDING` byte, and the logical length of the field is the
+/// length of its data without those trailing padding bytes. The default padding is `0`, which matches fields
+/// like zero padded names. Fields padded with spaces, like the strings in a SCSI INQUIRY response, can use `VarBytes<N, b' '>`.
+///
+/// Note that the logical length can't be stored exactly if the data itself ends with the padding byte.
+/// Such trailing bytes will be considered padding when reading the field.
+///
+/// This type is only used as a marker in layout definitions, it can't be instantiated.
+///
+/// # Example:
+/// ```
+/// use binary_layout::{prelude::*, VarBytes};
+///
+/// binary_layout!(my_layout, LittleEndian, {
+///   name: VarBytes<8>,
+///   inode: u32,
+/// });
+///
+/// fn main() {
+///   let mut storage = [0; 12];
+///   let mut view = my_layout::View::new(&mut storage);
+///   view.name_mut().set(b"file").unwrap();
+///   view.inode_mut().write(5);
+///
+///   assert_eq!(b"file", view.name());
+///   assert_eq!(8, my_layout::inode::OFFSET);
+///   assert_eq!(&[b'f', b'i', b'l', b'e', 0, 0, 0, 0, 5, 0, 0, 0], &storage);
+/// }
+/// ```
+pub enum VarBytes<const N: usize, const PADDING: u8 = 0> {}
+
+/// This error is returned when trying to store more data in a [VarBytes] field than it has space for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VarBytesTooLongError {
+    len: usize,
+    capacity: usize,
+}
+
+impl core::fmt::Display for VarBytesTooLongError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            fmt,
+            "Tried to store {} bytes in a field with a capacity of {} bytes",
+            self.len, self.capacity
+        )
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl crate::utils::error::Error for VarBytesTooLongError {}
+
+impl VarBytesTooLongError {
+    /// The length of the data that didn't fit into the field
+    pub fn data_len(&self) -> usize {
+        self.len
+    }
+
+    /// The maximal number of bytes the field can store
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+/// Gives write access to a [VarBytes] field, see [FieldSliceAccess::data_mut].
+pub struct VarBytesMut<'a, const PADDING: u8> {
+    storage: &'a mut [u8],
+}
+
+impl<'a, const PADDING: u8> VarBytesMut<'a, PADDING> {
+    /// The data currently stored in the field, without its padding
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        &self.storage[..logical_len(self.storage, PADDING)]
+    }
+
+    /// The logical length of the data currently stored in the field
+    #[inline]
+    pub fn len(&self) -> usize {
+        logical_len(self.storage, PADDING)
+    }
+
+    /// Returns true if the field doesn't store any data
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The maximal number of bytes the field can store
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.storage.len()
+    }
+
+    /// Store `data` in the field and fill the remaining bytes with padding.
+    /// This fails with a [VarBytesTooLongError] and doesn't modify the field if `data` is longer than [VarBytesMut::capacity].
+    #[inline]
+    pub fn set(&mut self, data: &[u8]) -> Result<(), VarBytesTooLongError> {
+        if data.len() > self.storage.len() {
+            return Err(VarBytesTooLongError {
+                len: data.len(),
+                capacity: self.storage.len(),
+            });
+        }
+        let (content, padding) = self.storage.split_at_mut(data.len());
+        content.copy_from_slice(data);
+        padding.fill(PADDING);
+        Ok(())
+    }
+
+    /// Remove the data stored in the field, i.e. fill it with padding
+    #[inline]
+    pub fn clear(&mut self) {
+        self.storage.fill(PADDING);
+    }
+}
+
+impl<const PADDING: u8> Debug for VarBytesMut<'_, PADDING> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.data(), f)
+    }
+}
+
+#[inline(always)]
+fn logical_len(storage: &[u8], padding: u8) -> usize {
+    storage
+        .iter()
+        .rposition(|&byte| byte != padding)
+        .map_or(0, |index| index + 1)
+}
+
+/// Field type `VarBytes<N>`:
+/// This field represents a [variable length byte array](crate#variable-length-byte-arrays-varbytesn).
+/// In this impl, we define accessors for such fields.
+impl<'a, E: Endianness, const N: usize, const PADDING: u8, const OFFSET_: usize>
+    FieldSliceAccess<'a> for PrimitiveField<VarBytes<N, PADDING>, E, OFFSET_>
+{
+    type SliceType = &'a [u8];
+    type MutSliceType = VarBytesMut<'a, PADDING>;
+
+    /// Borrow the data stored in the field, without its padding, with read access using the [Field] API.
+    /// See also [FieldSliceAccess::data].
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::{prelude::*, VarBytes};
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_field: VarBytes<16>,
+    ///     //... other fields
+    /// });
+    ///
+    /// fn func(storage_data: &[u8]) {
+    ///     let some_field: &[u8] = my_layout::some_field::data(storage_data);
+    /// }
+    /// # fn main() {}
+    /// ```
+    #[inline(always)]
+    fn data(storage: &'a [u8]) -> &'a [u8] {
+        let region = &storage[Self::OFFSET..(Self::OFFSET + N)];
+        &region[..logical_len(region, PADDING)]
+    }
+
+    /// Get write access to the field using the [Field] API.
+    /// See also [FieldSliceAccess::data_mut].
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::{prelude::*, VarBytes, VarBytesTooLongError};
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_field: VarBytes<16>,
+    ///     //... other fields
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) -> Result<(), VarBytesTooLongError> {
+    ///     my_layout::some_field::data_mut(storage_data).set(b"some data")
+    /// }
+    /// # fn main() {}
+    /// ```
+    #[inline(always)]
+    fn data_mut(storage: &'a mut [u8]) -> VarBytesMut<'a, PADDING> {
+        VarBytesMut {
+            storage: &mut storage[Self::OFFSET..(Self::OFFSET + N)],
+        }
+    }
+}
+impl<E: Endianness, const N: usize, const PADDING: u8, const OFFSET_: usize> Field
+    for PrimitiveField<VarBytes<N, PADDING>, E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = Some(N);
+}
+impl<'a, E: Endianness, const N: usize, const PADDING: u8, const OFFSET_: usize>
+    StorageToFieldView<&'a [u8]> for PrimitiveField<VarBytes<N, PADDING>, E, OFFSET_>
+{
+    type View = &'a [u8];
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        Self::data(storage)
+    }
+}
+
+impl<'a, E: Endianness, const N: usize, const PADDING: u8, const OFFSET_: usize>
+    StorageToFieldView<&'a mut [u8]> for PrimitiveField<VarBytes<N, PADDING>, E, OFFSET_>
+{
+    type View = VarBytesMut<'a, PADDING>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        Self::data_mut(storage)
+    }
+}
+
+impl<S: Storage, E: Endianness, const N: usize, const PADDING: u8, const OFFSET_: usize>
+    StorageIntoFieldView<S> for PrimitiveField<VarBytes<N, PADDING>, E, OFFSET_>
+{
+    type View = Data<S>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        let len = logical_len(
+            &storage.as_bytes()[Self::OFFSET..(Self::OFFSET + N)],
+            PADDING,
+        );
+        Data::from(storage).into_subregion(Self::OFFSET..(Self::OFFSET + len))
+    }
+}
+
+impl<E: Endianness, const N: usize, const PADDING: u8, const OFFSET_: usize> FieldKindInfo
+    for PrimitiveField<VarBytes<N, PADDING>, E, OFFSET_>
+{
+    const KIND: FieldKind = FieldKind::VarBytes;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::{PrimitiveField, VarBytes, VarBytesTooLongError};
+
+    type Name = PrimitiveField<VarBytes<6>, LittleEndian, 2>;
+    type SpacePadded = PrimitiveField<VarBytes<4, b' '>, LittleEndian, 0>;
+
+    #[test]
+    fn metadata() {
+        assert_eq!(2, Name::OFFSET);
+        assert_eq!(Some(6), Name::SIZE);
+    }
+
+    #[test]
+    fn read() {
+        let storage = [1, 1, b'a', b'b', 0, 0, 0, 0, 1];
+        assert_eq!(b"ab", Name::data(&storage));
+        let storage = [1, 1, b'a', 0, b'b', b'c', b'd', b'e', 1];
+        assert_eq!(b"a\0bcde", Name::data(&storage));
+        let storage = [1, 1, 0, 0, 0, 0, 0, 0, 1];
+        assert_eq!(b"", Name::data(&storage));
+    }
+
+    #[test]
+    fn write() {
+        let mut storage = [1; 9];
+        Name::data_mut(&mut storage).set(b"abc").unwrap();
+        assert_eq!([1, 1, b'a', b'b', b'c', 0, 0, 0, 1], storage);
+        Name::data_mut(&mut storage).set(b"abcdef").unwrap();
+        assert_eq!([1, 1, b'a', b'b', b'c', b'd', b'e', b'f', 1], storage);
+        Name::data_mut(&mut storage).clear();
+        assert_eq!([1, 1, 0, 0, 0, 0, 0, 0, 1], storage);
+    }
+
+    #[test]
+    fn write_too_long() {
+        let mut storage = [1; 9];
+        let error = Name::data_mut(&mut storage).set(b"abcdefg").unwrap_err();
+        assert_eq!(7, error.data_len());
+        assert_eq!(6, error.capacity());
+        assert!(matches!(
+            Name::data_mut(&mut storage).set(b"abcdefgh"),
+            Err(VarBytesTooLongError { .. })
+        ));
+        assert_eq!([1; 9], storage);
+    }
+
+    #[test]
+    fn mut_accessors() {
+        let mut storage = [0; 9];
+        let mut field = Name::data_mut(&mut storage);
+        assert!(field.is_empty());
+        assert_eq!(6, field.capacity());
+        field.set(b"xy").unwrap();
+        assert_eq!(2, field.len());
+        assert_eq!(b"xy", field.data());
+    }
+
+    #[test]
+    fn custom_padding() {
+        let mut storage = [0; 4];
+        SpacePadded::data_mut(&mut storage).set(b"ab").unwrap();
+        assert_eq!(*b"ab  ", storage);
+        assert_eq!(b"ab", SpacePadded::data(&storage));
+    }
+}
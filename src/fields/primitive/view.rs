@@ -1,6 +1,10 @@
 use core::marker::PhantomData;
 
-use crate::{Field, FieldCopyAccess, FieldReadExt, FieldWriteExt};
+use super::array::element_start;
+use crate::{
+    ArrayElement, Field, FieldArrayAccess, FieldCopyAccess, FieldReadExt, FieldWriteExt, Storage,
+    StorageMut,
+};
 
 /// A field view represents the field metadata stored in a [Field] plus it stores the underlying
 /// storage data it operates on, either as a reference to a slice `&[u8]`, `&mut [u8]`, or as
@@ -43,6 +47,8 @@ use crate::{Field, FieldCopyAccess, FieldReadExt, FieldWriteExt};
 /// ```
 pub struct FieldView<S, F: Field> {
     storage: S,
+    #[cfg(feature = "tracing")]
+    pub(crate) trace: Option<&'static crate::tracing_support::FieldTrace>,
     _p: PhantomData<F>,
 }
 
@@ -55,11 +61,52 @@ impl<S, F: Field> FieldView<S, F> {
     pub fn new(storage: S) -> Self {
         Self {
             storage,
+            #[cfg(feature = "tracing")]
+            trace: None,
             _p: PhantomData,
         }
     }
+
+    #[cfg(feature = "tracing")]
+    #[inline(always)]
+    fn trace_read(&self)
+    where
+        S: Storage,
+    {
+        crate::tracing_support::trace_read::<F>(self.trace, self.storage.as_bytes());
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    #[inline(always)]
+    fn trace_read(&self) {}
+
+    /// Call `write` with the storage and emit a trace event with the old and new value of the field afterwards
+    #[inline(always)]
+    fn traced_write<R>(&mut self, write: impl FnOnce(&mut [u8]) -> R) -> R
+    where
+        S: StorageMut,
+    {
+        #[cfg(feature = "tracing")]
+        let old = crate::tracing_support::before_write::<F>(self.trace, self.storage.as_bytes());
+        let result = write(self.storage.as_bytes_mut());
+        #[cfg(feature = "tracing")]
+        crate::tracing_support::trace_write::<F>(old, self.storage.as_bytes());
+        result
+    }
 }
-impl<S: AsRef<[u8]>, F: FieldReadExt> FieldView<S, F> {
+impl<S: Clone, F: Field> Clone for FieldView<S, F> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            storage: self.storage.clone(),
+            #[cfg(feature = "tracing")]
+            trace: self.trace,
+            _p: PhantomData,
+        }
+    }
+}
+
+impl<S: Storage, F: FieldReadExt> FieldView<S, F> {
     /// Read the field from a given data region, assuming the defined layout, using the [FieldView] API.
     ///
     /// # Example
@@ -79,10 +126,11 @@ impl<S: AsRef<[u8]>, F: FieldReadExt> FieldView<S, F> {
     /// ```
     #[inline(always)]
     pub fn read(&self) -> F::HighLevelType {
-        F::read(self.storage.as_ref())
+        self.trace_read();
+        F::read(self.storage.as_bytes())
     }
 }
-impl<S: AsMut<[u8]>, F: FieldWriteExt> FieldView<S, F> {
+impl<S: StorageMut, F: FieldWriteExt> FieldView<S, F> {
     /// Write the field to a given data region, assuming the defined layout, using the [FieldView] API.
     ///
     /// # Example
@@ -102,10 +150,10 @@ impl<S: AsMut<[u8]>, F: FieldWriteExt> FieldView<S, F> {
     /// ```
     #[inline(always)]
     pub fn write(&mut self, v: F::HighLevelType) {
-        F::write(self.storage.as_mut(), v)
+        self.traced_write(|storage| F::write(storage, v))
     }
 }
-impl<S: AsRef<[u8]>, F: FieldCopyAccess> FieldView<S, F> {
+impl<S: Storage, F: FieldCopyAccess> FieldView<S, F> {
     /// Read the field from a given data region, assuming the defined layout, using the [FieldView] API.
     ///
     /// # Example
@@ -127,10 +175,11 @@ impl<S: AsRef<[u8]>, F: FieldCopyAccess> FieldView<S, F> {
     /// ```
     #[inline(always)]
     pub fn try_read(&self) -> Result<F::HighLevelType, F::ReadError> {
-        F::try_read(self.storage.as_ref())
+        self.trace_read();
+        F::try_read(self.storage.as_bytes())
     }
 }
-impl<S: AsMut<[u8]>, F: FieldCopyAccess> FieldView<S, F> {
+impl<S: StorageMut, F: FieldCopyAccess> FieldView<S, F> {
     /// Write the field to a given data region, assuming the defined layout, using the [FieldView] API.
     ///
     /// # Example
@@ -154,6 +203,229 @@ impl<S: AsMut<[u8]>, F: FieldCopyAccess> FieldView<S, F> {
     /// ```
     #[inline(always)]
     pub fn try_write(&mut self, v: F::HighLevelType) -> Result<(), F::WriteError> {
-        F::try_write(self.storage.as_mut(), v)
+        self.traced_write(|storage| F::try_write(storage, v))
+    }
+}
+impl<S: StorageMut, F> FieldView<S, F>
+where
+    F: FieldReadExt + FieldWriteExt<HighLevelType = <F as FieldReadExt>::HighLevelType>,
+{
+    /// Read the field, compute a new value from it using `f`, and write that new value back to the field, using the [FieldView] API.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///   //... other fields ...
+    ///   sequence_number: u32,
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) {
+    ///   let mut view = my_layout::View::new(storage_data);
+    ///   view.sequence_number_mut().update(|v| v + 1);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn update(
+        &mut self,
+        f: impl FnOnce(<F as FieldReadExt>::HighLevelType) -> <F as FieldReadExt>::HighLevelType,
+    ) {
+        self.trace_read();
+        let value = F::read(self.storage.as_bytes());
+        self.traced_write(|storage| F::write(storage, f(value)))
+    }
+
+    /// Write a new value to the field and return the value it had before, using the [FieldView] API.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///   //... other fields ...
+    ///   sequence_number: u32,
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8], next: u32) -> u32 {
+    ///   let mut view = my_layout::View::new(storage_data);
+    ///   view.sequence_number_mut().replace(next)
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn replace(
+        &mut self,
+        value: <F as FieldReadExt>::HighLevelType,
+    ) -> <F as FieldReadExt>::HighLevelType {
+        self.trace_read();
+        let previous = F::read(self.storage.as_bytes());
+        self.traced_write(|storage| F::write(storage, value));
+        previous
+    }
+}
+impl<S: StorageMut, F: FieldCopyAccess> FieldView<S, F> {
+    /// Read the field, compute a new value from it using `f`, and write that new value back to the field, using the [FieldView] API.
+    /// This is the variant of [FieldView::update] for fields where reading or writing can fail.
+    /// If reading fails, `f` isn't called and the field isn't written.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::{prelude::*, UpdateError};
+    /// use core::convert::Infallible;
+    /// use core::num::NonZeroU32;
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///   //... other fields ...
+    ///   some_integer_field: core::num::NonZeroU32,
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) -> Result<(), UpdateError<NonZeroIsZeroError, Infallible>> {
+    ///   let mut view = my_layout::View::new(storage_data);
+    ///   view.some_integer_field_mut().try_update(|v| NonZeroU32::new(v.get() * 2).unwrap())?;
+    ///   Ok(())
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn try_update(
+        &mut self,
+        f: impl FnOnce(F::HighLevelType) -> F::HighLevelType,
+    ) -> Result<(), UpdateError<F::ReadError, F::WriteError>> {
+        self.trace_read();
+        let value = F::try_read(self.storage.as_bytes()).map_err(UpdateError::ReadError)?;
+        self.traced_write(|storage| F::try_write(storage, f(value)))
+            .map_err(UpdateError::WriteError)
+    }
+
+    /// Write a new value to the field and return the value it had before, using the [FieldView] API.
+    /// This is the variant of [FieldView::replace] for fields where reading or writing can fail.
+    /// If reading fails, the field isn't written.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::{prelude::*, UpdateError};
+    /// use core::convert::Infallible;
+    /// use core::num::NonZeroU32;
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///   //... other fields ...
+    ///   some_integer_field: core::num::NonZeroU32,
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) -> Result<NonZeroU32, UpdateError<NonZeroIsZeroError, Infallible>> {
+    ///   let mut view = my_layout::View::new(storage_data);
+    ///   view.some_integer_field_mut().try_replace(NonZeroU32::new(5).unwrap())
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn try_replace(
+        &mut self,
+        value: F::HighLevelType,
+    ) -> Result<F::HighLevelType, UpdateError<F::ReadError, F::WriteError>> {
+        self.trace_read();
+        let previous = F::try_read(self.storage.as_bytes()).map_err(UpdateError::ReadError)?;
+        self.traced_write(|storage| F::try_write(storage, value))
+            .map_err(UpdateError::WriteError)?;
+        Ok(previous)
+    }
+}
+impl<S: Storage, F: FieldArrayAccess> FieldView<S, F> {
+    /// The number of elements of the array, see [FieldArrayAccess::len]
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        F::len(self.storage.as_bytes())
+    }
+
+    /// Returns true if the array has no elements
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        F::is_empty(self.storage.as_bytes())
     }
+
+    /// Read the element with the given index from the array, using the [FieldView] API.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(my_layout, BigEndian, {
+    ///   //... other fields ...
+    ///   samples: [i16],
+    /// });
+    ///
+    /// fn func(storage_data: &[u8]) -> i32 {
+    ///   let view = my_layout::View::new(storage_data);
+    ///   (0..view.samples().len()).map(|index| i32::from(view.samples().read_at(index))).sum()
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds, like indexing a slice.
+    #[inline(always)]
+    #[track_caller]
+    pub fn read_at(&self, index: usize) -> F::Element {
+        let start = element_start::<F>(self.storage.as_bytes(), index);
+        F::Element::read::<F::Endian>(&self.storage.as_bytes()[start..])
+    }
+}
+
+impl<S: StorageMut, F: FieldArrayAccess> FieldView<S, F> {
+    /// Write the element with the given index to the array, using the [FieldView] API.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///   //... other fields ...
+    ///   histogram: [u32; 16],
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8], bucket: usize) {
+    ///   let mut view = my_layout::View::new(storage_data);
+    ///   let count = view.histogram().read_at(bucket);
+    ///   view.histogram_mut().write_at(bucket, count + 1);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds, like indexing a slice.
+    #[inline(always)]
+    #[track_caller]
+    pub fn write_at(&mut self, index: usize, value: F::Element) {
+        let start = element_start::<F>(self.storage.as_bytes(), index);
+        F::Element::write::<F::Endian>(&mut self.storage.as_bytes_mut()[start..], value);
+    }
+}
+
+/// The error being thrown from [FieldView::try_update] and [FieldView::try_replace].
+#[derive(Debug)]
+pub enum UpdateError<ReadError, WriteError> {
+    /// An error happened when reading the current value of the field.
+    ReadError(ReadError),
+    /// An error happened when writing the new value of the field.
+    WriteError(WriteError),
+}
+
+impl<ReadError: core::fmt::Display, WriteError: core::fmt::Display> core::fmt::Display
+    for UpdateError<ReadError, WriteError>
+{
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ReadError(error) => write!(fmt, "Error reading the field: {}", error),
+            Self::WriteError(error) => write!(fmt, "Error writing the field: {}", error),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl<
+        ReadError: core::fmt::Debug + core::fmt::Display,
+        WriteError: core::fmt::Debug + core::fmt::Display,
+    > crate::utils::error::Error for UpdateError<ReadError, WriteError>
+{
 }
@@ -0,0 +1,247 @@
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use crate::reflection::{FieldKind, FieldKindInfo};
+use crate::storage::Storage;
+
+use super::{
+    primitive::{FieldCopyAccess, FieldView},
+    Field, FieldAtOffset, StorageIntoFieldView, StorageToFieldView,
+};
+
+/// Describes the values allowed in a [RangedField].
+///
+/// The [binary_layout!](crate::binary_layout!) macro implements this for a hidden marker type
+/// for each field declared with a range constraint like `priority: u8 where 0..=7`.
+pub trait ValueRange<T> {
+    /// The range as written in the layout definition, e.g. `0..=7`
+    const RANGE: &'static str;
+
+    /// Returns true if `value` is inside of the range
+    fn contains(value: &T) -> bool;
+}
+
+/// This error is returned when reading or writing a [RangedField] fails.
+#[derive(Debug)]
+pub enum RangedFieldError<PrimitiveAccessError> {
+    /// An error happened when reading or writing the underlying field
+    PrimitiveAccessError(PrimitiveAccessError),
+    /// The value read from or written to the field is outside of its allowed range
+    OutOfRange {
+        /// The allowed range as written in the layout definition, see [ValueRange::RANGE]
+        range: &'static str,
+    },
+}
+
+impl<PrimitiveAccessError: core::fmt::Display> core::fmt::Display
+    for RangedFieldError<PrimitiveAccessError>
+{
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::PrimitiveAccessError(error) => write!(
+                fmt,
+                "Error accessing (reading or writing) the primitive data type: {}",
+                error
+            ),
+            Self::OutOfRange { range } => {
+                write!(fmt, "Value is outside of the allowed range `{}`", range)
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl<PrimitiveAccessError: Debug + core::fmt::Display> crate::utils::error::Error
+    for RangedFieldError<PrimitiveAccessError>
+{
+}
+
+/// A [RangedField] is a [Field] that only allows values inside of a given range.
+/// Reading a value outside of the range from the storage or trying to write one to it returns
+/// a [RangedFieldError::OutOfRange] error. See [Field] for more info on this API.
+///
+/// Fields get declared as [RangedField] by adding a range after their type in the [binary_layout!](crate::binary_layout!) macro.
+/// Any range expression works, e.g. `0..=7`, `1..` or `..100`.
+///
+/// # Example:
+/// ```
+/// use binary_layout::{prelude::*, RangedFieldError};
+///
+/// binary_layout!(my_layout, BigEndian, {
+///   // ... other fields ...
+///   priority: u8 where 0..=7,
+///   // ... other fields ...
+/// });
+///
+/// fn func(storage_data: &mut [u8]) -> Result<(), RangedFieldError<core::convert::Infallible>> {
+///   // write some data
+///   my_layout::priority::try_write(storage_data, 5)?;
+///   assert!(matches!(
+///     my_layout::priority::try_write(storage_data, 8),
+///     Err(RangedFieldError::OutOfRange { .. }),
+///   ));
+///
+///   // read some data
+///   let read_data: u8 = my_layout::priority::try_read(storage_data)?;
+///   assert_eq!(5, read_data);
+///
+///   Ok(())
+/// }
+///
+/// # fn main() {
+/// #   let mut storage = [0; 1024];
+/// #   func(&mut storage).unwrap();
+/// # }
+/// ```
+pub struct RangedField<F: FieldCopyAccess, R: ValueRange<F::HighLevelType>> {
+    _p1: PhantomData<F>,
+    _p2: PhantomData<R>,
+}
+
+impl<F: FieldCopyAccess, R: ValueRange<F::HighLevelType>> Field for RangedField<F, R> {
+    /// See [Field::Endian]
+    type Endian = F::Endian;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = F::OFFSET;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = F::SIZE;
+}
+
+impl<F: FieldCopyAccess + FieldKindInfo, R: ValueRange<F::HighLevelType>> FieldKindInfo
+    for RangedField<F, R>
+{
+    const KIND: FieldKind = F::KIND;
+}
+
+impl<F, R, const NEW_OFFSET: usize> FieldAtOffset<NEW_OFFSET> for RangedField<F, R>
+where
+    F: FieldCopyAccess + FieldAtOffset<NEW_OFFSET>,
+    F::Moved: FieldCopyAccess<HighLevelType = F::HighLevelType>,
+    R: ValueRange<F::HighLevelType>,
+{
+    type Moved = RangedField<F::Moved, R>;
+}
+
+impl<'a, F, R> StorageToFieldView<&'a [u8]> for RangedField<F, R>
+where
+    F: FieldCopyAccess + StorageToFieldView<&'a [u8]>,
+    R: ValueRange<F::HighLevelType>,
+{
+    type View = FieldView<&'a [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<'a, F, R> StorageToFieldView<&'a mut [u8]> for RangedField<F, R>
+where
+    F: FieldCopyAccess + StorageToFieldView<&'a mut [u8]>,
+    R: ValueRange<F::HighLevelType>,
+{
+    type View = FieldView<&'a mut [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<S, F, R> StorageIntoFieldView<S> for RangedField<F, R>
+where
+    S: Storage,
+    F: FieldCopyAccess + StorageIntoFieldView<S>,
+    R: ValueRange<F::HighLevelType>,
+{
+    type View = FieldView<S, Self>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<F: FieldCopyAccess, R: ValueRange<F::HighLevelType>> FieldCopyAccess for RangedField<F, R> {
+    /// See [FieldCopyAccess::ReadError]
+    type ReadError = RangedFieldError<F::ReadError>;
+    /// See [FieldCopyAccess::WriteError]
+    type WriteError = RangedFieldError<F::WriteError>;
+    /// See [FieldCopyAccess::HighLevelType]
+    type HighLevelType = F::HighLevelType;
+
+    /// Read the field from a given data region, returning an error if the value is outside of the allowed range.
+    #[inline(always)]
+    fn try_read(storage: &[u8]) -> Result<Self::HighLevelType, Self::ReadError> {
+        let value = F::try_read(storage).map_err(RangedFieldError::PrimitiveAccessError)?;
+        if R::contains(&value) {
+            Ok(value)
+        } else {
+            Err(RangedFieldError::OutOfRange { range: R::RANGE })
+        }
+    }
+
+    /// Write the field to a given data region, returning an error and leaving the data region unchanged
+    /// if the value is outside of the allowed range.
+    #[inline(always)]
+    fn try_write(storage: &mut [u8], value: Self::HighLevelType) -> Result<(), Self::WriteError> {
+        if !R::contains(&value) {
+            return Err(RangedFieldError::OutOfRange { range: R::RANGE });
+        }
+        F::try_write(storage, value).map_err(RangedFieldError::PrimitiveAccessError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LittleEndian, PrimitiveField};
+
+    struct Priority;
+    impl ValueRange<u16> for Priority {
+        const RANGE: &'static str = "10..=20";
+
+        fn contains(value: &u16) -> bool {
+            (10..=20).contains(value)
+        }
+    }
+
+    type Field1 = RangedField<PrimitiveField<u16, LittleEndian, 3>, Priority>;
+
+    #[test]
+    fn metadata() {
+        assert_eq!(3, Field1::OFFSET);
+        assert_eq!(Some(2), Field1::SIZE);
+    }
+
+    #[test]
+    fn read_write_in_range() {
+        let mut storage = [0; 8];
+        Field1::try_write(&mut storage, 10).unwrap();
+        assert_eq!(10, Field1::try_read(&storage).unwrap());
+        Field1::try_write(&mut storage, 20).unwrap();
+        assert_eq!(20, Field1::try_read(&storage).unwrap());
+        assert_eq!(&[0, 0, 0, 20, 0, 0, 0, 0], &storage);
+    }
+
+    #[test]
+    fn write_out_of_range() {
+        let mut storage = [0; 8];
+        for value in [0, 9, 21, u16::MAX] {
+            assert!(matches!(
+                Field1::try_write(&mut storage, value),
+                Err(RangedFieldError::OutOfRange { range: "10..=20" })
+            ));
+        }
+        assert_eq!([0; 8], storage);
+    }
+
+    #[test]
+    fn read_out_of_range() {
+        let storage = [0, 0, 0, 21, 0, 0, 0, 0];
+        assert!(matches!(
+            Field1::try_read(&storage),
+            Err(RangedFieldError::OutOfRange { range: "10..=20" })
+        ));
+    }
+}
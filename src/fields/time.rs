@@ -0,0 +1,382 @@
+//! Ready-made field types for the timestamp encodings used by Windows and DOS based formats, see [timestamps](crate#timestamps-filetime-dosdate-dostime-dosdatetime).
+
+use crate::LayoutAs;
+use core::convert::Infallible;
+
+/// Number of 100ns intervals between the FILETIME epoch (1601-01-01) and the unix epoch (1970-01-01)
+const UNIX_EPOCH_TICKS: i128 = 116_444_736_000_000_000;
+const TICKS_PER_SECOND: i128 = 10_000_000;
+
+/// A Windows `FILETIME` timestamp, i.e. the number of 100ns intervals since 1601-01-01 00:00:00 UTC.
+/// It is stored as a 64-bit integer using the `FileTime as u64` notation.
+///
+/// With the `std` feature, it can be converted from and to [SystemTime](https://doc.rust-lang.org/std/time/struct.SystemTime.html).
+/// Without it, [FileTime::unix_seconds] and [FileTime::subsec_nanos] give access to the point in time it represents.
+///
+/// # Example
+/// ```
+/// use binary_layout::{prelude::*, FileTime};
+///
+/// binary_layout!(file_info, LittleEndian, {
+///   creation_time: FileTime as u64,
+///   size: u64,
+/// });
+///
+/// fn main() {
+///   let mut view = file_info::View::new([0; 16]);
+///   view.creation_time_mut().write(FileTime::from_unix(1_000_000_000, 0).unwrap());
+///   assert_eq!(1_000_000_000, view.creation_time().read().unix_seconds());
+///   assert_eq!(126_444_736_000_000_000, view.creation_time().read().ticks());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct FileTime(u64);
+
+impl FileTime {
+    /// The unix epoch, 1970-01-01 00:00:00 UTC
+    pub const UNIX_EPOCH: FileTime = FileTime(UNIX_EPOCH_TICKS as u64);
+
+    /// Create a timestamp from the number of 100ns intervals since 1601-01-01 00:00:00 UTC
+    pub const fn from_ticks(ticks: u64) -> Self {
+        Self(ticks)
+    }
+
+    /// The number of 100ns intervals since 1601-01-01 00:00:00 UTC
+    pub const fn ticks(&self) -> u64 {
+        self.0
+    }
+
+    /// Create a timestamp from a unix timestamp with the given number of seconds and nanoseconds.
+    /// The nanoseconds are rounded down to the 100ns precision of the timestamp.
+    /// Returns `None` if `nanos` isn't smaller than one second or if the point in time can't be represented.
+    pub fn from_unix(seconds: i64, nanos: u32) -> Option<Self> {
+        if nanos >= 1_000_000_000 {
+            return None;
+        }
+        let ticks =
+            i128::from(seconds) * TICKS_PER_SECOND + i128::from(nanos / 100) + UNIX_EPOCH_TICKS;
+        u64::try_from(ticks).ok().map(Self)
+    }
+
+    /// The number of whole seconds since the unix epoch, negative for timestamps before 1970
+    pub fn unix_seconds(&self) -> i64 {
+        ((i128::from(self.0) - UNIX_EPOCH_TICKS).div_euclid(TICKS_PER_SECOND)) as i64
+    }
+
+    /// The nanoseconds on top of [FileTime::unix_seconds]
+    pub fn subsec_nanos(&self) -> u32 {
+        ((i128::from(self.0) - UNIX_EPOCH_TICKS).rem_euclid(TICKS_PER_SECOND) * 100) as u32
+    }
+
+    /// Convert the timestamp into a [SystemTime](std::time::SystemTime).
+    /// Returns `None` if the platform can't represent the point in time.
+    #[cfg(feature = "std")]
+    pub fn to_system_time(&self) -> Option<std::time::SystemTime> {
+        let offset = i128::from(self.0) - UNIX_EPOCH_TICKS;
+        let duration = std::time::Duration::new(
+            (offset.unsigned_abs() / TICKS_PER_SECOND as u128) as u64,
+            (offset.unsigned_abs() % TICKS_PER_SECOND as u128) as u32 * 100,
+        );
+        if offset >= 0 {
+            std::time::SystemTime::UNIX_EPOCH.checked_add(duration)
+        } else {
+            std::time::SystemTime::UNIX_EPOCH.checked_sub(duration)
+        }
+    }
+
+    /// Create a timestamp from a [SystemTime](std::time::SystemTime), rounding down to 100ns precision.
+    /// Returns `None` if the point in time can't be represented, i.e. if it is before 1601 or too far in the future.
+    #[cfg(feature = "std")]
+    pub fn from_system_time(time: std::time::SystemTime) -> Option<Self> {
+        let ticks = match time.duration_since(std::time::SystemTime::UNIX_EPOCH) {
+            Ok(duration) => UNIX_EPOCH_TICKS + duration_ticks(duration)?,
+            Err(error) => UNIX_EPOCH_TICKS - duration_ticks(error.duration())?,
+        };
+        u64::try_from(ticks).ok().map(Self)
+    }
+}
+
+#[cfg(feature = "std")]
+fn duration_ticks(duration: std::time::Duration) -> Option<i128> {
+    i128::try_from(duration.as_nanos() / 100).ok()
+}
+
+impl LayoutAs<u64> for FileTime {
+    type ReadError = Infallible;
+    type WriteError = Infallible;
+
+    fn try_read(v: u64) -> Result<Self, Self::ReadError> {
+        Ok(Self(v))
+    }
+
+    fn try_write(v: Self) -> Result<u64, Self::WriteError> {
+        Ok(v.0)
+    }
+}
+
+/// This error is thrown when reading or writing a [DosDate], [DosTime] or [DosDateTime] whose components are out of range,
+/// e.g. a month of `0`. Note that FAT file systems often store `0` for timestamps that weren't set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDosDateTimeError(pub(crate) ());
+
+impl core::fmt::Display for InvalidDosDateTimeError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "InvalidDosDateTimeError")
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl crate::utils::error::Error for InvalidDosDateTimeError {}
+
+/// A date in the 16-bit MS-DOS format used by FAT file systems and ZIP archives, stored using the `DosDate as u16` notation.
+///
+/// The date is encoded as `(year - 1980) << 9 | month << 5 | day`, so years from 1980 to 2107 can be stored.
+/// Reading and writing fails with an [InvalidDosDateTimeError] if the month isn't in `1..=12` or the day isn't in `1..=31`.
+/// The day isn't checked against the length of the month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DosDate {
+    /// The year, from 1980 to 2107
+    pub year: u16,
+    /// The month, from 1 to 12
+    pub month: u8,
+    /// The day of the month, from 1 to 31
+    pub day: u8,
+}
+
+impl LayoutAs<u16> for DosDate {
+    type ReadError = InvalidDosDateTimeError;
+    type WriteError = InvalidDosDateTimeError;
+
+    fn try_read(v: u16) -> Result<Self, Self::ReadError> {
+        let date = Self {
+            year: 1980 + (v >> 9),
+            month: ((v >> 5) & 0xf) as u8,
+            day: (v & 0x1f) as u8,
+        };
+        date.check()?;
+        Ok(date)
+    }
+
+    fn try_write(v: Self) -> Result<u16, Self::WriteError> {
+        v.check()?;
+        Ok((v.year - 1980) << 9 | u16::from(v.month) << 5 | u16::from(v.day))
+    }
+}
+
+impl DosDate {
+    fn check(&self) -> Result<(), InvalidDosDateTimeError> {
+        if (1980..=2107).contains(&self.year)
+            && (1..=12).contains(&self.month)
+            && (1..=31).contains(&self.day)
+        {
+            Ok(())
+        } else {
+            Err(InvalidDosDateTimeError(()))
+        }
+    }
+}
+
+/// A time of day in the 16-bit MS-DOS format used by FAT file systems and ZIP archives, stored using the `DosTime as u16` notation.
+///
+/// The time is encoded as `hour << 11 | minute << 5 | second / 2`, so it only has a precision of two seconds
+/// and odd seconds are rounded down when writing. Reading and writing fails with an [InvalidDosDateTimeError]
+/// if the hour isn't in `0..24`, the minute isn't in `0..60` or the second isn't in `0..60`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct DosTime {
+    /// The hour, from 0 to 23
+    pub hour: u8,
+    /// The minute, from 0 to 59
+    pub minute: u8,
+    /// The second, from 0 to 58 when read from the storage
+    pub second: u8,
+}
+
+impl LayoutAs<u16> for DosTime {
+    type ReadError = InvalidDosDateTimeError;
+    type WriteError = InvalidDosDateTimeError;
+
+    fn try_read(v: u16) -> Result<Self, Self::ReadError> {
+        let time = Self {
+            hour: (v >> 11) as u8,
+            minute: ((v >> 5) & 0x3f) as u8,
+            second: 2 * (v & 0x1f) as u8,
+        };
+        time.check()?;
+        Ok(time)
+    }
+
+    fn try_write(v: Self) -> Result<u16, Self::WriteError> {
+        v.check()?;
+        Ok(u16::from(v.hour) << 11 | u16::from(v.minute) << 5 | u16::from(v.second / 2))
+    }
+}
+
+impl DosTime {
+    fn check(&self) -> Result<(), InvalidDosDateTimeError> {
+        if self.hour < 24 && self.minute < 60 && self.second < 60 {
+            Ok(())
+        } else {
+            Err(InvalidDosDateTimeError(()))
+        }
+    }
+}
+
+/// A [DosTime] followed by a [DosDate] in a 32-bit integer, stored using the `DosDateTime as u32` notation.
+///
+/// The time is stored in the lower 16 bits and the date in the upper 16 bits, which matches the
+/// "last modified time" and "last modified date" fields of ZIP archives and FAT directory entries in a `LittleEndian` layout.
+/// DOS timestamps don't have a time zone, they usually store the local time of the system that wrote them.
+///
+/// # Example
+/// ```
+/// use binary_layout::{prelude::*, DosDate, DosDateTime, DosTime};
+///
+/// binary_layout!(zip_local_header, LittleEndian, {
+///   signature: u32,
+///   version: u16,
+///   flags: u16,
+///   compression: u16,
+///   modified: DosDateTime as u32,
+/// });
+///
+/// fn main() {
+///   let mut view = zip_local_header::View::new([0; 14]);
+///   let modified = DosDateTime {
+///     date: DosDate { year: 2024, month: 2, day: 29 },
+///     time: DosTime { hour: 13, minute: 37, second: 42 },
+///   };
+///   view.modified_mut().try_write(modified).unwrap();
+///   assert_eq!(modified, view.modified().try_read().unwrap());
+///   assert!(view.modified_mut().try_write(DosDateTime { date: DosDate { year: 1970, month: 1, day: 1 }, ..modified }).is_err());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DosDateTime {
+    /// The date
+    pub date: DosDate,
+    /// The time of day
+    pub time: DosTime,
+}
+
+impl LayoutAs<u32> for DosDateTime {
+    type ReadError = InvalidDosDateTimeError;
+    type WriteError = InvalidDosDateTimeError;
+
+    fn try_read(v: u32) -> Result<Self, Self::ReadError> {
+        Ok(Self {
+            date: DosDate::try_read((v >> 16) as u16)?,
+            time: DosTime::try_read(v as u16)?,
+        })
+    }
+
+    fn try_write(v: Self) -> Result<u32, Self::WriteError> {
+        Ok(u32::from(DosDate::try_write(v.date)?) << 16 | u32::from(DosTime::try_write(v.time)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn file_time_unix() {
+        assert_eq!(0, FileTime::UNIX_EPOCH.unix_seconds());
+        assert_eq!(0, FileTime::UNIX_EPOCH.subsec_nanos());
+        assert_eq!(Some(FileTime::UNIX_EPOCH), FileTime::from_unix(0, 0));
+
+        let time = FileTime::from_unix(-1, 500_000_099).unwrap();
+        assert_eq!(UNIX_EPOCH_TICKS as u64 - 5_000_000, time.ticks());
+        assert_eq!(-1, time.unix_seconds());
+        assert_eq!(500_000_000, time.subsec_nanos());
+
+        assert_eq!(-11_644_473_600, FileTime::from_ticks(0).unix_seconds());
+        assert_eq!(None, FileTime::from_unix(-11_644_473_601, 0));
+        assert_eq!(None, FileTime::from_unix(0, 1_000_000_000));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_time_system_time() {
+        use std::time::{Duration, SystemTime};
+
+        let time = SystemTime::UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789);
+        let file_time = FileTime::from_system_time(time).unwrap();
+        assert_eq!(1_700_000_000, file_time.unix_seconds());
+        assert_eq!(123_456_700, file_time.subsec_nanos());
+        assert_eq!(
+            SystemTime::UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_700),
+            file_time.to_system_time().unwrap()
+        );
+
+        let before_epoch = SystemTime::UNIX_EPOCH - Duration::from_secs(86400);
+        let file_time = FileTime::from_system_time(before_epoch).unwrap();
+        assert_eq!(-86400, file_time.unix_seconds());
+        assert_eq!(Some(before_epoch), file_time.to_system_time());
+    }
+
+    #[test]
+    fn dos_date_time() {
+        binary_layout!(layout, LittleEndian, {
+            date: DosDate as u16,
+            time: DosTime as u16,
+            modified: DosDateTime as u32,
+        });
+
+        // 2021-09-15 and 12:30:20, as stored in a ZIP archive
+        let mut storage = [0x2f, 0x53, 0xca, 0x63, 0xca, 0x63, 0x2f, 0x53];
+        let view = layout::View::new(&mut storage);
+        let date = DosDate {
+            year: 2021,
+            month: 9,
+            day: 15,
+        };
+        let time = DosTime {
+            hour: 12,
+            minute: 30,
+            second: 20,
+        };
+        assert_eq!(date, view.date().try_read().unwrap());
+        assert_eq!(time, view.time().try_read().unwrap());
+        assert_eq!(
+            DosDateTime { date, time },
+            view.modified().try_read().unwrap()
+        );
+
+        let mut storage = [0; 8];
+        let mut view = layout::View::new(&mut storage);
+        view.date_mut().try_write(date).unwrap();
+        view.time_mut()
+            .try_write(DosTime { second: 21, ..time })
+            .unwrap();
+        view.modified_mut()
+            .try_write(DosDateTime { date, time })
+            .unwrap();
+        assert_eq!([0x2f, 0x53, 0xca, 0x63, 0xca, 0x63, 0x2f, 0x53], storage);
+    }
+
+    #[test]
+    fn invalid_dos_date_time() {
+        assert_eq!(Err(InvalidDosDateTimeError(())), DosDate::try_read(0));
+        assert_eq!(
+            Err(InvalidDosDateTimeError(())),
+            DosTime::try_read(24 << 11)
+        );
+        assert_eq!(
+            Err(InvalidDosDateTimeError(())),
+            DosDate::try_write(DosDate {
+                year: 2108,
+                month: 1,
+                day: 1
+            })
+        );
+        assert_eq!(
+            Err(InvalidDosDateTimeError(())),
+            DosTime::try_write(DosTime {
+                hour: 0,
+                minute: 60,
+                second: 0
+            })
+        );
+    }
+}
@@ -1,14 +1,14 @@
 use core::convert::Infallible;
 use core::fmt::Debug;
 use core::marker::PhantomData;
-#[cfg(feature = "std")]
-use thiserror::Error;
 
+use crate::reflection::{FieldKind, FieldKindInfo};
+use crate::storage::Storage;
 use crate::utils::infallible::IsInfallible;
 
 use super::{
     primitive::{FieldCopyAccess, FieldView},
-    Field, StorageIntoFieldView, StorageToFieldView,
+    Field, FieldAtOffset, StorageIntoFieldView, StorageToFieldView,
 };
 
 /// Implementing the [LayoutAs] trait for a custom type allows that custom type to be used
@@ -16,6 +16,9 @@ use super::{
 /// is accessed, so this is only recommended for primitive wrappers of primitive types,
 /// not for types that are expensive to copy.
 ///
+/// The underlying type `U` can be a primitive type with copy access or a fixed size byte array `[u8; N]`,
+/// which is passed to and returned from the conversion functions by value.
+///
 /// # Example
 /// ```
 /// use binary_layout::{prelude::*, LayoutAs};
@@ -42,6 +45,47 @@ use super::{
 ///
 /// # fn main() {}
 /// ```
+///
+/// With the `derive` feature, `#[derive(LayoutAs)]` generates this implementation for newtypes, i.e. structs with exactly one field.
+/// The conversions of the generated implementation can't fail.
+///
+/// ```ignore
+/// use binary_layout::{prelude::*, LayoutAs};
+///
+/// #[derive(LayoutAs)]
+/// struct BlockId(u64);
+///
+/// binary_layout!(my_layout, BigEndian, {
+///   block: BlockId as u64,
+/// });
+/// ```
+///
+/// # Example (byte array)
+/// ```
+/// use binary_layout::{prelude::*, LayoutAs};
+/// use core::convert::Infallible;
+///
+/// struct Uuid([u8; 16]);
+/// impl LayoutAs<[u8; 16]> for Uuid {
+///   type ReadError = Infallible;
+///   type WriteError = Infallible;
+///   fn try_read(v: [u8; 16]) -> Result<Uuid, Infallible> {
+///     Ok(Uuid(v))
+///   }
+///
+///   fn try_write(v: Uuid) -> Result<[u8; 16], Infallible> {
+///     Ok(v.0)
+///   }
+/// }
+///
+/// binary_layout!(my_layout, BigEndian, {
+///   // ... other fields ...
+///   id: Uuid as [u8; 16],
+///   // ... other fields ...
+/// });
+///
+/// # fn main() {}
+/// ```
 pub trait LayoutAs<U>: Sized {
     /// See [FieldCopyAccess::ReadError].
     ///
@@ -67,22 +111,40 @@ pub trait LayoutAs<U>: Sized {
 
 /// The error being thrown when reading or writing fields that use custom data types implemented via [LayoutAs].
 #[derive(Debug)]
-#[cfg_attr(feature = "std", derive(Error))]
 pub enum WrappedFieldError<PrimitiveAccessError, LayoutAsError> {
     /// An error happened when reading or writing the primitive data type the [LayoutAs] stores values at.
-    #[cfg_attr(
-        feature = "std",
-        error("Error accessing (reading or writing) the primitive data type: {0}")
-    )]
     PrimitiveAccessError(PrimitiveAccessError),
     /// An error happened in the call to [LayoutAs::try_read] or [LayoutAs::try_write]
-    #[cfg_attr(
-        feature = "std",
-        error("Error mapping the primitive data type in `LayoutAs`: {0}")
-    )]
     LayoutAsError(LayoutAsError),
 }
 
+impl<PrimitiveAccessError: core::fmt::Display, LayoutAsError: core::fmt::Display> core::fmt::Display
+    for WrappedFieldError<PrimitiveAccessError, LayoutAsError>
+{
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::PrimitiveAccessError(error) => write!(
+                fmt,
+                "Error accessing (reading or writing) the primitive data type: {}",
+                error
+            ),
+            Self::LayoutAsError(error) => write!(
+                fmt,
+                "Error mapping the primitive data type in `LayoutAs`: {}",
+                error
+            ),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl<
+        PrimitiveAccessError: Debug + core::fmt::Display,
+        LayoutAsError: Debug + core::fmt::Display,
+    > crate::utils::error::Error for WrappedFieldError<PrimitiveAccessError, LayoutAsError>
+{
+}
+
 impl IsInfallible for WrappedFieldError<Infallible, Infallible> {}
 
 /// A [WrappedField] is a [Field] that, unlike [PrimitiveField](crate::PrimitiveField), does not directly represent a primitive type.
@@ -172,6 +234,46 @@ impl IsInfallible for WrappedFieldError<Infallible, Infallible> {}
 /// #   func(&mut storage);
 /// # }
 /// ```
+///
+/// # Example (reading can throw errors, writing can't):
+/// The error types of both directions are independent. If only one of them is [Infallible],
+/// the non-try accessor is available for that direction.
+/// ```
+/// use binary_layout::{prelude::*, WrappedFieldError, LayoutAs};
+/// use core::convert::Infallible;
+///
+/// struct Version(u8);
+/// impl LayoutAs<u8> for Version {
+///   type ReadError = &'static str;
+///   type WriteError = Infallible;
+///
+///   fn try_read(v: u8) -> Result<Version, &'static str> {
+///     if v <= 3 { Ok(Version(v)) } else { Err("Unknown version") }
+///   }
+///
+///   fn try_write(v: Version) -> Result<u8, Infallible> {
+///     Ok(v.0)
+///   }
+/// }
+///
+/// binary_layout!(my_layout, BigEndian, {
+///   version: Version as u8,
+/// });
+///
+/// fn func(storage_data: &mut [u8]) -> Result<(), WrappedFieldError<Infallible, &'static str>> {
+///   let mut view = my_layout::View::new(storage_data);
+///   // writing can't fail
+///   view.version_mut().write(Version(2));
+///   // reading can fail
+///   let version: Version = view.version().try_read()?;
+///   Ok(())
+/// }
+///
+/// # fn main() {
+/// #   let mut storage = [0; 1];
+/// #   func(&mut storage).unwrap();
+/// # }
+/// ```
 pub struct WrappedField<U, T: LayoutAs<U>, F: Field> {
     _p1: PhantomData<U>,
     _p2: PhantomData<T>,
@@ -187,6 +289,16 @@ impl<U, T: LayoutAs<U>, F: Field> Field for WrappedField<U, T, F> {
     const SIZE: Option<usize> = F::SIZE;
 }
 
+impl<U, T: LayoutAs<U>, F: Field + FieldKindInfo> FieldKindInfo for WrappedField<U, T, F> {
+    const KIND: FieldKind = F::KIND;
+}
+
+impl<U, T: LayoutAs<U>, F: FieldAtOffset<NEW_OFFSET>, const NEW_OFFSET: usize>
+    FieldAtOffset<NEW_OFFSET> for WrappedField<U, T, F>
+{
+    type Moved = WrappedField<U, T, F::Moved>;
+}
+
 impl<
         'a,
         U,
@@ -219,7 +331,7 @@ impl<
 
 impl<
         U,
-        S: AsRef<[u8]>,
+        S: Storage,
         T: LayoutAs<U>,
         F: FieldCopyAccess<HighLevelType = U> + StorageIntoFieldView<S>,
     > StorageIntoFieldView<S> for WrappedField<U, T, F>
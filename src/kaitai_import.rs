@@ -0,0 +1,573 @@
+//! Importing layouts from [Kaitai Struct](https://kaitai.io) format descriptions. This module is only available with the `kaitai-import` feature.
+//!
+//! [import] converts the fixed-size subset of a `.ksy` file into [binary_layout!](crate::binary_layout!) definitions,
+//! so that existing format descriptions, e.g. from the [Kaitai format gallery](https://formats.kaitai.io), can be reused.
+//! Each user type used by the top level type becomes its own layout, which is nested into the layouts using it, and the top level type
+//! becomes a layout named after `meta/id`.
+//!
+//! The supported subset consists of `seq` attributes with
+//! - the integer and float types `u1`, `u2`, `u4`, `u8`, `s1`, `s2`, `s4`, `s8`, `f4` and `f8`, with or without an `le` or `be` suffix,
+//! - byte arrays with a fixed `size`, or with `size-eos: true` as the last attribute of the top level type, optionally with a `str` or `strz` type,
+//! - magic signatures given as `contents`,
+//! - user types that have a fixed size themselves,
+//! - and arrays of the integer and float types with `repeat: expr` and an integer `repeat-expr`.
+//!
+//! All attributes of a type need to have the same endianness. Documentation keys, `enum` and `encoding` are ignored,
+//! `enums` are allowed but not imported. Everything else, e.g. `instances`, `params`, `if`, bit-sized integers or sizes given as expressions,
+//! is rejected with an error, because it depends on the parsed data and can't be expressed by a layout with fixed field offsets.
+//!
+//! # Example
+//! ```
+//! use binary_layout::kaitai_import;
+//!
+//! let ksy = r"
+//! meta:
+//!   id: packet
+//!   endian: be
+//! seq:
+//!   - id: magic
+//!     contents: [0xca, 0xfe]
+//!   - id: header
+//!     type: header
+//!   - id: payload
+//!     size-eos: true
+//! types:
+//!   header:
+//!     seq:
+//!       - id: kind
+//!         type: u1
+//!       - id: length
+//!         type: u2
+//! ";
+//! let layouts = kaitai_import::import(ksy).unwrap();
+//! assert_eq!(2, layouts.len());
+//! assert_eq!("header", layouts[0].name());
+//! assert_eq!(Some(3), layouts[0].size());
+//! assert_eq!("packet", layouts[1].name());
+//! assert_eq!(None, layouts[1].size());
+//! assert!(layouts[1].definition().contains("    header: super::header::NestedView,\n"));
+//! ```
+//! The generated definitions can be written to a file in a build script and included like the output of the [codegen](crate::codegen) module.
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+
+use serde_yaml::{Mapping, Value};
+
+use crate::utils::definition::{field_name, write_definition, DefinitionField};
+use crate::EndianKind;
+
+/// A layout imported from a Kaitai Struct definition by [import]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KaitaiLayout {
+    name: String,
+    size: Option<usize>,
+    definition: String,
+}
+
+impl KaitaiLayout {
+    /// The name of the layout, i.e. the name of the Kaitai type or `meta/id` for the top level type
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The size of the layout in number of bytes, or `None` if it ends with a `size-eos` byte array
+    pub fn size(&self) -> Option<usize> {
+        self.size
+    }
+
+    /// The [binary_layout!](crate::binary_layout!) invocation defining the layout
+    pub fn definition(&self) -> &str {
+        &self.definition
+    }
+}
+
+/// This error is returned from [import] if a Kaitai Struct definition can't be imported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KaitaiImportError {
+    path: String,
+    message: String,
+}
+
+impl KaitaiImportError {
+    /// The location of the problem in the definition, e.g. `/types/header/seq/1` for the second attribute of the `header` type.
+    /// This is empty if the definition isn't valid YAML.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// A description of the problem
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for KaitaiImportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl std::error::Error for KaitaiImportError {}
+
+/// Import the Kaitai Struct definition `ksy`, i.e. the contents of a `.ksy` file, see the [module documentation](self)
+/// for the supported subset. The layouts are returned in an order in which each layout comes after the layouts nested into it,
+/// with the layout of the top level type last.
+pub fn import(ksy: &str) -> Result<Vec<KaitaiLayout>, KaitaiImportError> {
+    let root: Value = serde_yaml::from_str(ksy).map_err(|error| KaitaiImportError {
+        path: String::new(),
+        message: format!("Invalid YAML: {}", error),
+    })?;
+    let root = root
+        .as_mapping()
+        .ok_or_else(|| error("/", "Expected a mapping with `meta` and `seq`"))?;
+    let name = get(root, "meta")
+        .and_then(Value::as_mapping)
+        .and_then(|meta| get(meta, "id"))
+        .and_then(Value::as_str)
+        .ok_or_else(|| error("/meta/id", "Expected the name of the format"))?;
+
+    let mut importer = Importer {
+        types: BTreeMap::new(),
+        imported: BTreeMap::new(),
+        in_progress: Vec::new(),
+        layouts: Vec::new(),
+    };
+    let root = importer.collect_types(name, root, None, String::new())?;
+    importer.import_type(name, &root)?;
+    Ok(importer.layouts)
+}
+
+fn error(path: impl Into<String>, message: impl Into<String>) -> KaitaiImportError {
+    KaitaiImportError {
+        path: path.into(),
+        message: message.into(),
+    }
+}
+
+fn get<'v>(mapping: &'v Mapping, key: &str) -> Option<&'v Value> {
+    mapping.get(&Value::String(key.to_string()))
+}
+
+fn endianness(value: &Value, path: &str) -> Result<EndianKind, KaitaiImportError> {
+    match value.as_str() {
+        Some("le") => Ok(EndianKind::Little),
+        Some("be") => Ok(EndianKind::Big),
+        _ => Err(error(
+            path,
+            "Expected `le` or `be`, endianness switches aren't supported",
+        )),
+    }
+}
+
+/// A user type or the top level type of the definition
+#[derive(Clone)]
+struct TypeSpec<'v> {
+    spec: &'v Mapping,
+    /// The default endianness of the type, either from its own `meta` or inherited from the type it is defined in
+    endianness: Option<EndianKind>,
+    /// The path of the type in the definition, e.g. `/types/header`, or empty for the top level type
+    path: String,
+}
+
+struct Importer<'v> {
+    types: BTreeMap<String, TypeSpec<'v>>,
+    /// The sizes of the layouts imported so far
+    imported: BTreeMap<String, Option<usize>>,
+    /// The types that are currently being imported, used to detect recursive types
+    in_progress: Vec<String>,
+    layouts: Vec<KaitaiLayout>,
+}
+
+impl<'v> Importer<'v> {
+    /// Check the keys of a type and register the user types defined in it, including nested ones.
+    /// Kaitai scopes type names, but layouts all live in the same module, so the names have to be unique.
+    fn collect_types(
+        &mut self,
+        name: &str,
+        spec: &'v Mapping,
+        parent_endianness: Option<EndianKind>,
+        path: String,
+    ) -> Result<TypeSpec<'v>, KaitaiImportError> {
+        let mut endianness = parent_endianness;
+        for (key, value) in spec {
+            let key_path = format!("{}/{}", path, key.as_str().unwrap_or("?"));
+            match key.as_str() {
+                Some("meta") => {
+                    if let Some(endian) = value.as_mapping().and_then(|meta| get(meta, "endian")) {
+                        endianness = Some(self::endianness(endian, &format!("{}/endian", key_path))?);
+                    }
+                }
+                Some("seq" | "types" | "doc" | "doc-ref" | "enums") => {}
+                Some(other) => {
+                    return Err(error(
+                        key_path,
+                        format!(
+                            "`{}` isn't supported, only `seq` attributes with a fixed size can be imported",
+                            other
+                        ),
+                    ))
+                }
+                None => return Err(error(key_path, "Expected a string key")),
+            }
+        }
+        if let Some(types) = get(spec, "types") {
+            let types = types
+                .as_mapping()
+                .ok_or_else(|| error(format!("{}/types", path), "Expected a mapping"))?;
+            for (type_name, type_spec) in types {
+                let type_name = type_name
+                    .as_str()
+                    .ok_or_else(|| error(format!("{}/types", path), "Expected a type name"))?;
+                let type_path = format!("{}/types/{}", path, type_name);
+                let type_spec = type_spec
+                    .as_mapping()
+                    .ok_or_else(|| error(type_path.clone(), "Expected a mapping"))?;
+                if type_name == name || self.types.contains_key(type_name) {
+                    return Err(error(
+                        type_path,
+                        format!("There is more than one type named `{}`", type_name),
+                    ));
+                }
+                let collected = self.collect_types(type_name, type_spec, endianness, type_path)?;
+                self.types.insert(type_name.to_string(), collected);
+            }
+        }
+        Ok(TypeSpec {
+            spec,
+            endianness,
+            path,
+        })
+    }
+
+    /// Import the layout for a type and the layouts of the user types it uses, and return its size
+    fn import_type(
+        &mut self,
+        name: &str,
+        spec: &TypeSpec<'v>,
+    ) -> Result<Option<usize>, KaitaiImportError> {
+        if let Some(size) = self.imported.get(name) {
+            return Ok(*size);
+        }
+        if self.in_progress.iter().any(|other| other == name) {
+            return Err(error(
+                spec.path.clone(),
+                format!(
+                    "The type `{}` contains itself, recursive types don't have a fixed size",
+                    name
+                ),
+            ));
+        }
+        if field_name(name).map_or(true, |escaped| escaped != name) {
+            return Err(error(
+                spec.path.clone(),
+                format!("`{}` can't be used as the name of a layout", name),
+            ));
+        }
+        self.in_progress.push(name.to_string());
+
+        let seq_path = format!("{}/seq", spec.path);
+        let seq = match get(spec.spec, "seq") {
+            Some(seq) => seq
+                .as_sequence()
+                .ok_or_else(|| error(seq_path.clone(), "Expected a list of attributes"))?
+                .as_slice(),
+            None => &[],
+        };
+        if seq.is_empty() {
+            return Err(error(seq_path, "Types without attributes aren't supported"));
+        }
+        let mut layout_endianness = spec.endianness;
+        let mut fields = Vec::new();
+        let mut size = Some(0);
+        for (index, attribute) in seq.iter().enumerate() {
+            let path = format!("{}/{}", seq_path, index);
+            if size.is_none() {
+                return Err(error(
+                    path,
+                    "Attributes after a `size-eos` attribute aren't supported",
+                ));
+            }
+            let attribute = attribute
+                .as_mapping()
+                .ok_or_else(|| error(path.clone(), "Expected a mapping"))?;
+            let field = self.attribute(attribute, spec.endianness, &path)?;
+            if let Some(endianness) = field.endianness {
+                match layout_endianness {
+                    Some(layout_endianness) if layout_endianness != endianness => {
+                        return Err(error(
+                            path,
+                            "All attributes of a type need to have the same endianness",
+                        ))
+                    }
+                    _ => layout_endianness = Some(endianness),
+                }
+            }
+            size = size.and_then(|size: usize| field.size.map(|field_size| size + field_size));
+            fields.push(field.definition);
+        }
+
+        // Types that only consist of bytes don't have an endianness, but layouts need one
+        let definition = write_definition(
+            name,
+            layout_endianness.unwrap_or(EndianKind::Little),
+            fields,
+        );
+        self.in_progress.pop();
+        self.imported.insert(name.to_string(), size);
+        self.layouts.push(KaitaiLayout {
+            name: name.to_string(),
+            size,
+            definition,
+        });
+        Ok(size)
+    }
+
+    /// Convert a `seq` attribute into a field
+    fn attribute(
+        &mut self,
+        attribute: &Mapping,
+        default_endianness: Option<EndianKind>,
+        path: &str,
+    ) -> Result<ImportedField, KaitaiImportError> {
+        let mut size = None;
+        let mut size_eos = false;
+        let mut contents_size = None;
+        let mut repeat = None;
+        let mut field_type = None;
+        for (key, value) in attribute {
+            let key_path = format!("{}/{}", path, key.as_str().unwrap_or("?"));
+            match key.as_str() {
+                Some("id" | "doc" | "doc-ref" | "-orig-id" | "enum" | "encoding") => {}
+                Some("type") => {
+                    field_type = Some(value.as_str().ok_or_else(|| {
+                        error(
+                            key_path,
+                            "Expected a type name, type switches aren't supported",
+                        )
+                    })?)
+                }
+                Some("size") => {
+                    size = Some(value.as_u64().ok_or_else(|| {
+                        error(
+                            key_path,
+                            "Expected an integer, sizes given as expressions aren't supported",
+                        )
+                    })? as usize)
+                }
+                Some("size-eos") => size_eos = value.as_bool() == Some(true),
+                Some("contents") => contents_size = Some(contents_len(value, &key_path)?),
+                Some("repeat") => {
+                    if value.as_str() != Some("expr") {
+                        return Err(error(
+                            key_path,
+                            "Only `repeat: expr` with a fixed number of repetitions is supported",
+                        ));
+                    }
+                }
+                Some("repeat-expr") => {
+                    repeat = Some(value.as_u64().ok_or_else(|| {
+                        error(
+                        key_path,
+                        "Expected an integer, repetitions given as expressions aren't supported",
+                    )
+                    })? as usize)
+                }
+                Some(other) => {
+                    return Err(error(
+                        key_path,
+                        format!("`{}` isn't supported by layouts", other),
+                    ))
+                }
+                None => return Err(error(key_path, "Expected a string key")),
+            }
+        }
+        let id = get(attribute, "id")
+            .and_then(Value::as_str)
+            .ok_or_else(|| error(format!("{}/id", path), "Expected the name of the attribute"))?;
+        let name = field_name(id).map_err(|message| error(format!("{}/id", path), message))?;
+        if get(attribute, "repeat").is_some() != repeat.is_some() {
+            return Err(error(
+                path,
+                "`repeat: expr` and `repeat-expr` have to be used together",
+            ));
+        }
+        let byte_array = |size: Option<usize>| ImportedField {
+            definition: DefinitionField {
+                name: name.clone(),
+                field_type: size
+                    .map_or_else(|| "[u8]".to_string(), |size| format!("[u8; {}]", size)),
+                comment: None,
+            },
+            size,
+            endianness: None,
+        };
+
+        match field_type {
+            None | Some("str" | "strz") => {
+                if repeat.is_some() {
+                    return Err(error(path, "Repeated byte arrays aren't supported"));
+                }
+                match (size.or(contents_size), size_eos) {
+                    (Some(size), false) => Ok(byte_array(Some(size))),
+                    (None, true) => Ok(byte_array(None)),
+                    _ => Err(error(
+                        path,
+                        "Byte arrays need either a fixed `size` or `size-eos: true`",
+                    )),
+                }
+            }
+            Some(type_name) => {
+                if size.is_some() || size_eos {
+                    return Err(error(
+                        path,
+                        "Sizes are only supported for byte arrays, not for attributes with a type",
+                    ));
+                }
+                if let Some(PrimitiveType {
+                    rust_type,
+                    size: type_size,
+                    endianness,
+                }) = primitive_type(type_name, default_endianness, path)?
+                {
+                    let field_type = match repeat {
+                        Some(count) => format!("[{}; {}]", rust_type, count),
+                        None => rust_type.to_string(),
+                    };
+                    return Ok(ImportedField {
+                        definition: DefinitionField {
+                            name,
+                            field_type,
+                            comment: None,
+                        },
+                        size: Some(type_size * repeat.unwrap_or(1)),
+                        endianness,
+                    });
+                }
+
+                let spec = self.types.get(type_name).cloned().ok_or_else(|| {
+                    error(
+                        format!("{}/type", path),
+                        format!("Unknown type `{}`", type_name),
+                    )
+                })?;
+                let size = self.import_type(type_name, &spec)?;
+                if repeat.is_some() {
+                    return Err(error(path, "Repeated user types aren't supported"));
+                }
+                let size = size.ok_or_else(|| {
+                    error(
+                        format!("{}/type", path),
+                        format!(
+                            "The type `{}` ends with a `size-eos` attribute and can't be used as the type of an attribute",
+                            type_name
+                        ),
+                    )
+                })?;
+                Ok(ImportedField {
+                    definition: DefinitionField {
+                        name,
+                        field_type: format!("super::{}::NestedView", type_name),
+                        comment: None,
+                    },
+                    size: Some(size),
+                    endianness: None,
+                })
+            }
+        }
+    }
+}
+
+struct ImportedField {
+    definition: DefinitionField,
+    size: Option<usize>,
+    /// The endianness the field needs, if it has more than one byte
+    endianness: Option<EndianKind>,
+}
+
+/// The length of a `contents` value, which is a string or a list of bytes and strings
+fn contents_len(value: &Value, path: &str) -> Result<usize, KaitaiImportError> {
+    match value {
+        Value::String(string) => Ok(string.len()),
+        Value::Sequence(items) => items.iter().try_fold(0, |len, item| match item {
+            Value::Number(_) => Ok(len + 1),
+            Value::String(string) => Ok(len + string.len()),
+            _ => Err(error(path, "Expected bytes or strings")),
+        }),
+        _ => Err(error(path, "Expected a string or a list of bytes")),
+    }
+}
+
+/// An integer or float type
+struct PrimitiveType {
+    rust_type: &'static str,
+    size: usize,
+    /// The endianness of the type, if it has more than one byte
+    endianness: Option<EndianKind>,
+}
+
+/// Map a Kaitai integer or float type like `u4be` to the Rust type, its size and its endianness.
+/// Returns `None` for user types.
+fn primitive_type(
+    name: &str,
+    default_endianness: Option<EndianKind>,
+    path: &str,
+) -> Result<Option<PrimitiveType>, KaitaiImportError> {
+    let (base, endianness) = if let Some(base) = name.strip_suffix("le") {
+        (base, Some(EndianKind::Little))
+    } else if let Some(base) = name.strip_suffix("be") {
+        (base, Some(EndianKind::Big))
+    } else {
+        (name, default_endianness)
+    };
+    let (rust_type, size) = match base {
+        "u1" if base == name => ("u8", 1),
+        "s1" if base == name => ("i8", 1),
+        "u2" => ("u16", 2),
+        "u4" => ("u32", 4),
+        "u8" => ("u64", 8),
+        "s2" => ("i16", 2),
+        "s4" => ("i32", 4),
+        "s8" => ("i64", 8),
+        "f4" => ("f32", 4),
+        "f8" => ("f64", 8),
+        _ if name.len() > 1
+            && name.starts_with('b')
+            && name[1..]
+                .trim_end_matches(['l', 'e', 'b'])
+                .parse::<u32>()
+                .is_ok() =>
+        {
+            return Err(error(
+                format!("{}/type", path),
+                "Bit-sized integers aren't supported",
+            ))
+        }
+        _ => return Ok(None),
+    };
+    if size == 1 {
+        return Ok(Some(PrimitiveType {
+            rust_type,
+            size,
+            endianness: None,
+        }));
+    }
+    let endianness = endianness.ok_or_else(|| {
+        error(
+            format!("{}/type", path),
+            format!(
+                "`{}` needs an endianness, either as `meta/endian` or as a suffix like `{}le`",
+                name, name
+            ),
+        )
+    })?;
+    Ok(Some(PrimitiveType {
+        rust_type,
+        size,
+        endianness: Some(endianness),
+    }))
+}
@@ -0,0 +1,32 @@
+use core::convert::TryFrom;
+
+use crate::{Error, ErrorKind, Field, FieldCopyAccess};
+
+/// Check that the length field `F` holds the given length. Used by the generated `View::validate()` method.
+pub fn length_matches<F: FieldCopyAccess>(storage: &[u8], len: usize) -> bool
+where
+    F::HighLevelType: TryFrom<usize> + PartialEq,
+{
+    match (F::try_read(storage), F::HighLevelType::try_from(len)) {
+        (Ok(value), Ok(len)) => value == len,
+        _ => false,
+    }
+}
+
+/// Check that `storage` is long enough to contain the length field `F` and the field `T` it is bound to,
+/// so that the generated `View::validate()` method can compare them without panicking on short storage.
+pub fn length_in_bounds<F: Field, T: Field>(storage: &[u8]) -> bool {
+    storage.len() >= F::OFFSET + F::SIZE.unwrap_or(0)
+        && storage.len() >= T::OFFSET + T::SIZE.unwrap_or(0)
+}
+
+/// Write the given length to the length field `F`. Used by the generated `View::finalize()` method.
+pub fn write_length<F: FieldCopyAccess>(storage: &mut [u8], len: usize) -> Result<(), Error>
+where
+    F::HighLevelType: TryFrom<usize>,
+    F::WriteError: Into<Error>,
+{
+    let value =
+        F::HighLevelType::try_from(len).map_err(|_| Error::new(ErrorKind::ValueTooLong { len }))?;
+    F::try_write(storage, value).map_err(Into::into)
+}
@@ -10,13 +10,13 @@
 //!
 //! # Example
 //! ```
-//! use binary_layout::prelude::*;
+//! use binary_layout::{prelude::*, InternetChecksum};
 //!
 //! // See https://en.wikipedia.org/wiki/Internet_Control_Message_Protocol for ICMP packet layout
 //! binary_layout!(icmp_packet, BigEndian, {
 //!   packet_type: u8,
 //!   code: u8,
-//!   checksum: u16,
+//!   checksum: u16 = checksum(InternetChecksum, ..), // checksum over the whole packet
 //!   rest_of_header: [u8; 4],
 //!   data_section: [u8], // open ended byte array, matches until the end of the packet
 //! });
@@ -29,8 +29,8 @@
 //!   // equivalent: let code: u8 = packet_data[1];
 //!
 //!   // write some data
-//!   view.checksum_mut().write(10);
-//!   // equivalent: packet_data[2..4].copy_from_slice(&10u16.to_be_bytes());
+//!   view.packet_type_mut().write(8);
+//!   // equivalent: packet_data[0] = 8;
 //!
 //!   // access an open ended byte array
 //!   let data_section: &[u8] = view.data_section();
@@ -39,7 +39,12 @@
 //!   // and modify it
 //!   view.data_section_mut()[..5].copy_from_slice(&[1, 2, 3, 4, 5]);
 //!   // equivalent: packet_data[8..13].copy_from_slice(&[1, 2, 3, 4, 5]);
+//!
+//!   // compute the checksum before sending the packet, and check it after receiving one
+//!   view.finalize().unwrap();
+//!   assert!(view.verify().is_ok());
 //! }
+//! # fn main() {}
 //! ```
 //!
 //! See the [icmp_packet](crate::example::icmp_packet) module for what this [binary_layout!] macro generates for you.
@@ -73,6 +78,10 @@
 //! - You need dynamic data structures, e.g. a list that can change size. This library only supports static data layouts (with the exception of open ended byte arrays at the end of a layout).
 //! - Not all of your layout fits into the memory and you need to process streams of data.
 //!   Note that this crate can still be helpful if you have smaller layouted packets as part of a larger stream, as long as any one layouted packet fits into memory.
+//! - You need to build packets in uninitialized memory, e.g. `&mut [MaybeUninit<u8>]`, to avoid zeroing large buffers first.
+//!   Handing out the initialized bytes as `&mut [u8]` after all fields were written requires `unsafe` code, which this library doesn't use.
+//!   Views need initialized storage, e.g. a zeroed buffer. To make sure that no field of such a buffer was accidentally left at zero,
+//!   build the packet through a [struct@WriteTracked] view (with the `std` feature) in your tests.
 //!
 //! ## Alternatives
 //! To the best of my knowledge, there is no other library offering inplace, zero-copy and type-safe access to structured binary data.
@@ -84,8 +93,12 @@
 //! Layouts are defined using the [binary_layout!] macro. Based on such a layout, this library offers two alternative APIs for data access:
 //! 1. The [trait@Field] API that offers free functions to read/write the data based on an underlying slice of storage (`packet_data` in the example above) holding the packet data. This API does not wrap the underlying slice of storage data, which means you have to pass it in to each accessor.
 //!    This is not the API used in the example above, see [trait@Field] for an API example.
+//!    Every field implements [trait@Field] with its `OFFSET` and `SIZE`, so generic code can bound on it to work with any field, see [Field](https://docs.rs/binary-layout/latest/binary_layout/trait.Field.html#generic-code).
 //! 2. The [struct@FieldView] API that wraps a slice of storage data and remembers it in a `View` object, allowing access to the fields without having to pass in the packed data slice each time. This is the API used in the example above. See [struct@FieldView] for another example.
 //!
+//! A buffer holding consecutive frames of a layout with a fixed size, e.g. a capture file or a ring buffer of records,
+//! can be iterated with `my_layout::iter_frames(buffer)`, which returns a `View` for each frame, see [struct@Frames].
+//!
 //! ## Supported field types
 //!
 //! ### Primitive integer types
@@ -97,6 +110,8 @@
 //! ### Primitive float types
 //! - [f32](https://doc.rust-lang.org/core/primitive.f32.html), [f64](https://doc.rust-lang.org/core/primitive.f64.html)
 //!
+//! With the `f128` feature, 16-byte quad precision floats are supported as [F128](#cargo-features) fields.
+//!
 //! ### Non-zero primitive integer types
 //! - [NonZeroU8](https://doc.rust-lang.org/core/num/struct.NonZeroU8.html), [NonZeroU16](https://doc.rust-lang.org/core/num/struct.NonZeroU16.html), [NonZeroU32](https://doc.rust-lang.org/core/num/struct.NonZeroU32.html), [NonZeroU64](https://doc.rust-lang.org/core/num/struct.NonZeroU64.html), [NonZeroU128](https://doc.rust-lang.org/core/num/struct.NonZeroU128.html)
 //! - [NonZeroI8](https://doc.rust-lang.org/core/num/struct.NonZeroI8.html), [NonZeroI16](https://doc.rust-lang.org/core/num/struct.NonZeroI16.html), [NonZeroI32](https://doc.rust-lang.org/core/num/struct.NonZeroI32.html), [NonZeroI64](https://doc.rust-lang.org/core/num/struct.NonZeroI64.html), [NonZeroI128](https://doc.rust-lang.org/core/num/struct.NonZeroI128.html)
@@ -117,14 +132,87 @@
 //!
 //! ### Fixed size byte arrays: `[u8; N]`.
 //! For these fields, the [trait@Field] API offers [FieldSliceAccess::data], [FieldSliceAccess::data_mut], and the [struct@FieldView] API returns a slice.
+//! The [trait@Field] API additionally offers [FieldReadExt::read] and [FieldWriteExt::write] to copy the whole array.
+//!
+//! The size `N` can be any constant expression, e.g. `[u8; 16]`, `[u8; BTRFS_CSUM_SIZE]`, `[u8; 4 * SECTOR_SIZE]` or `[u8; core::mem::size_of::<u64>()]`,
+//! and the offsets of the following fields are still computed at compile time. Since each layout is defined as a module,
+//! constants used in the layout need to be accessible from that module, i.e. they need to be defined at module level
+//! (or imported there) and not inside of a function. Expressions that aren't constant are rejected by the compiler.
+//!
+//! ### Fixed size signed byte arrays: `[i8; N]`.
+//! These fields have the same size and offset metadata as `[u8; N]`, e.g. for blocks of 8-bit audio samples.
+//! Since signed bytes can't be borrowed from a byte storage without `unsafe`, the [trait@Field] API offers [FieldReadExt::read] and
+//! [FieldWriteExt::write] to copy the whole array and the [struct@FieldView] API returns a [struct@FieldView] to read and write it.
+//! With the `bytemuck` feature, [FieldPodSliceAccess](https://docs.rs/binary-layout/latest/binary_layout/trait.FieldPodSliceAccess.html) additionally borrows the data as `&[i8]` or `&mut [i8]` without copying it.
+//!
+//! ### Number arrays: `[T; N]` and `[T]`.
+//! Arrays of the primitive integer types with more than one byte or of the primitive float types, e.g. `[u32; 16]` or `[f64; 3]`,
+//! store their elements with the endianness of the layout. They can't be borrowed as `&[u32]` because that would use the byte order
+//! of the system, so the whole array is copied with [FieldReadExt::read] and [FieldWriteExt::write], and single elements are
+//! accessed by an index only known at runtime with [FieldArrayAccess::read_at](https://docs.rs/binary-layout/latest/binary_layout/trait.FieldArrayAccess.html#method.read_at) and [FieldArrayAccess::write_at](https://docs.rs/binary-layout/latest/binary_layout/trait.FieldArrayAccess.html#method.write_at), which convert only
+//! that element. The [struct@FieldView] API offers the same as `view.field().read_at(index)` and `view.field_mut().write_at(index, value)`.
+//! Open ended arrays like `samples: [i16]` can only occur as the last field of a layout and contain as many complete elements as fit
+//! into the remaining storage. See [trait@ArrayElement] for the supported element types.
 //!
 //! ### Open ended byte arrays: `[u8]`.
 //! This field type can only occur as the last field of a layout and will mach the remaining data until the end of the storage.
 //! This field has a dynamic size, depending on how large the packet data is.
 //! For these fields, the [trait@Field] API offers [FieldSliceAccess::data], [FieldSliceAccess::data_mut] and the [struct@FieldView] API returns a slice.
+//! If the tail data contains type-length-value encoded entries, e.g. options in network protocol headers, [Tlv](https://docs.rs/binary-layout/latest/binary_layout/enum.Tlv.html) can iterate over them and write them.
+//!
+//! ### Count-prefixed records: `Records<C, N>`.
+//! This field type stores a count of integer type `C` followed by that many records of a nested layout with a fixed size, e.g. `entries: Records<u16, entry::NestedView>`.
+//! Like open ended byte arrays, it can only occur as the last field of a layout and extends until the end of the storage.
+//! The [struct@FieldView] API returns a [struct@RecordsView] that allows indexing and iterating over the records, and appending or removing records
+//! while keeping the count in sync. See [Records](https://docs.rs/binary-layout/latest/binary_layout/enum.Records.html) for an example. Over resizable storage like `&mut Vec<u8>`, [RecordsView::push_record](https://docs.rs/binary-layout/latest/binary_layout/struct.RecordsView.html#method.push_record)
+//! grows the storage by one record instead of failing when it is full. [RecordsView::find](https://docs.rs/binary-layout/latest/binary_layout/struct.RecordsView.html#method.find) looks up a record, e.g. a directory entry by its name.
+//! [RecordsView::iter_range](https://docs.rs/binary-layout/latest/binary_layout/struct.RecordsView.html#method.iter_range) and [RecordsView::iter_step_by](https://docs.rs/binary-layout/latest/binary_layout/struct.RecordsView.html#method.iter_step_by)
+//! iterate over a range of the records or every Nth record, e.g. for downsampling, without creating views to the records in between.
+//! [RecordsView::sort_by_key](https://docs.rs/binary-layout/latest/binary_layout/struct.RecordsView.html#method.sort_by_key) and [RecordsView::sort_unstable_by_key](https://docs.rs/binary-layout/latest/binary_layout/struct.RecordsView.html#method.sort_unstable_by_key) reorder the records within the storage, e.g. to sort a table by its key column. Sorted records can be looked up
+//! in `O(log(n))` with [RecordsView::binary_search_by_key](https://docs.rs/binary-layout/latest/binary_layout/struct.RecordsView.html#method.binary_search_by_key), e.g. the cells of a B-tree page.
+//!
+//! ### Variable length byte arrays: `VarBytes<N>`.
+//! These fields store up to `N` bytes and always reserve `N` bytes of storage, so fields after them still have a constant offset.
+//! Unused bytes are filled with padding (`0` by default, or a custom byte like in `VarBytes<N, b' '>`) and the data of the field
+//! is its content without that trailing padding. This is useful for bounded variable content like file names or identification strings.
+//! For these fields, the [trait@Field] API offers [FieldSliceAccess::data] and [FieldSliceAccess::data_mut], and the [struct@FieldView] API
+//! returns a slice with the data for read access and a [struct@VarBytesMut] for write access. See [VarBytes](https://docs.rs/binary-layout/latest/binary_layout/enum.VarBytes.html) for an example.
+//!
+//! ### UTF-16 strings: `Utf16<N>`.
+//! These fields store a string of up to `N` UTF-16 code units in a buffer of `2 * N` bytes, like the names in many Windows-originated formats.
+//! The code units use the endianness of the layout. The string ends at the first NUL code unit and unused code units are filled with NUL.
+//! `Utf16<N, true>` additionally requires the string to be NUL terminated, so it can store at most `N - 1` code units.
+//! For these fields, the [trait@Field] API offers [FieldSliceAccess::data] and [FieldSliceAccess::data_mut], and the [struct@FieldView] API
+//! returns a [Utf16Str](https://docs.rs/binary-layout/latest/binary_layout/struct.Utf16Str.html) for read access, which decodes the string into `char`s or a `String`, and a [Utf16StrMut](https://docs.rs/binary-layout/latest/binary_layout/struct.Utf16StrMut.html) for write access. See [Utf16](https://docs.rs/binary-layout/latest/binary_layout/enum.Utf16.html) for an example.
 //!
 //! ### Custom field types
 //! You can define your own custom types as long as they implement the [trait@LayoutAs] trait to define how to convert them from/to a primitive type.
+//! The underlying type can be any type with copy access or a fixed size byte array, e.g. `id: Uuid as [u8; 16]`.
+//! If the [LayoutAs::ReadError](https://docs.rs/binary-layout/latest/binary_layout/trait.LayoutAs.html#associatedtype.ReadError) of the custom type and the error of reading the underlying type are both [Infallible](https://doc.rust-lang.org/core/convert/enum.Infallible.html),
+//! the field offers [FieldReadExt::read] and [FieldView::read]. Likewise, if both write errors are [Infallible](https://doc.rust-lang.org/core/convert/enum.Infallible.html),
+//! the field offers [FieldWriteExt::write] and [FieldView::write]. Otherwise, use [FieldCopyAccess::try_read] and [FieldCopyAccess::try_write].
+//!
+//! Enums mapping their variants to integer values can be defined with the [layout_enum!] macro, which implements [trait@LayoutAs] for them.
+//! It optionally supports a fallback variant like `Unknown(u8)` for values that don't belong to any other variant, which keeps reading the field infallible.
+//!
+//! ### Timestamps: `FileTime`, `DosDate`, `DosTime`, `DosDateTime`
+//! The timestamp encodings used by Windows and DOS based formats are available as ready-made custom field types.
+//! [struct@FileTime] is a 64-bit Windows `FILETIME` (`created: FileTime as u64`), which can be converted from and to
+//! [SystemTime](https://doc.rust-lang.org/std/time/struct.SystemTime.html) with the `std` feature. [struct@DosDate] and [struct@DosTime] are the 16-bit MS-DOS date and time
+//! used by FAT file systems and ZIP archives (`date: DosDate as u16`), and [struct@DosDateTime] combines both into a 32-bit field
+//! (`modified: DosDateTime as u32`). The DOS types are plain structs, and reading or writing them fails with an
+//! [struct@InvalidDosDateTimeError] if their components are out of range.
+//!
+//! ### Range constrained fields
+//! Fields with copy access can be restricted to a range of values by adding the range after their type, e.g. `priority: u8 where 0..=7`
+//! or `length: u16 where 20..`. Such fields are [struct@RangedField]s. Reading a value outside of the range or trying to write one returns
+//! a [RangedFieldError::OutOfRange](https://docs.rs/binary-layout/latest/binary_layout/enum.RangedFieldError.html#variant.OutOfRange) error, so [FieldReadExt::read] and [FieldView::read] are not available for them
+//! and you need to use [FieldCopyAccess::try_read] and [FieldView::try_read].
+//!
+//! ## Errors
+//! Each fallible operation returns its own error type, e.g. [struct@NonZeroIsZeroError] or [RangedFieldError](https://docs.rs/binary-layout/latest/binary_layout/enum.RangedFieldError.html). All of them can be converted
+//! into the [struct@Error] type, which can additionally carry the layout, field name and offset the error happened at.
+//! Use [ErrorContextExt::field_context](https://docs.rs/binary-layout/latest/binary_layout/trait.ErrorContextExt.html#tymethod.field_context) to convert the error of a field access and annotate it with that information.
 //!
 //! # Data types maybe supported in the future
 //! These data types aren't supported yet, but they could be added in theory and might be added in future versions.
@@ -135,18 +223,13 @@
 //! In theory, types with dynamic length could be supported if they either
 //! - are the last field of a layout, an already implemented example of this are open ended byte arrays.
 //! - or they may be in the middle of the packet but have a maximal size defined and will always reserve storage for their maximal size, even if smaller.
-//!   This way, the fields after it would still have a constant offset.
+//!   This way, the fields after it would still have a constant offset. Variable length byte arrays (`VarBytes<N>`) are implemented this way.
 //!
-//! Both of these, however, would be some effort to implement and it is unclear if that will ever happen (unless somebody opens a PR for it).
+//! Other data types with dynamic length, however, would be some effort to implement and it is unclear if that will ever happen (unless somebody opens a PR for it).
 //!
 //! ### Strings
 //! For strings, note that even fixed-size UTF-8 strings take a variable number of bytes because of the UTF-8 encoding and that brings all the issues of data types with dynamic length with it.
-//! This is why strings aren't supported yet.
-//!
-//! ### Fixed-size arrays other than `[u8; N]`
-//! Say we wanted to have a `[u32; N]` field. The API couldn't just return a zero-copy `&[u32; N]` to the caller because that would use the system byte order (i.e. endianness) which might be different from the byte order defined in the packet layout.
-//! To make this cross-platform compatible, we'd have to wrap these slices into our own slice type that enforces the correct byte order and return that from the API.
-//! This complexity is why it wasn't implemented yet, but feel free to open a PR if you need this.
+//! This is why strings are only supported as UTF-16 strings in fixed size buffers (`Utf16<N>`) or as raw bytes in `VarBytes<N>` fields.
 //!
 //! # Nesting
 //! Layouts can be nested within each other by using the `NestedView` type created by the [binary_layout!] macro for one layout as a field type in another layout.
@@ -200,29 +283,191 @@
 //! });
 //! # fn main() {}
 //! ```
+//!
+//! Deeply nested fields can be accessed through a dotted path with the [field!] and [field_mut!] macros,
+//! e.g. `field_mut!(view.mid.deep.field1).write(10)` instead of `view.mid_mut().deep_mut().field1_mut().write(10)`.
+//!
+//! The `NestedView` type of each layout implements [trait@NestedViewInfo], [trait@BorrowingNestedView] and [trait@OwningNestedView],
+//! so generic code can take any layout as a type parameter, e.g. a helper that computes a checksum over any nested header.
+//!
+//! # Runtime-defined layouts
+//! Layouts that are only known at runtime, e.g. because they are read from a format description in a config file, can be defined
+//! as a [struct@DynLayout] from a list of field names, [types](https://docs.rs/binary-layout/latest/binary_layout/enum.DynFieldType.html) and endiannesses. It computes the field offsets like the [binary_layout!] macro
+//! and gives access to the fields by name through a [struct@DynView], checking the type of each access at runtime. This needs the `alloc` feature.
+//! [DynLayout::parse](https://docs.rs/binary-layout/latest/binary_layout/struct.DynLayout.html#method.parse) creates them from a definition with the syntax of the macro, so that schemas can be shipped in data files
+//! and still have the same field offsets as layouts defined at compile time.
+//! With the `std` feature, the [codegen](https://docs.rs/binary-layout/latest/binary_layout/codegen/index.html) module generates layouts from such schema files in build scripts.
+//!
+//! # Cargo features
+//! - `std` (enabled by default): Implements [std::error::Error](https://doc.rust-lang.org/std/error/trait.Error.html) for the error types of this crate, generates the `std::io` helpers of layouts
+//!   and implements [trait@SegmentedStorage] for [Mutex](https://doc.rust-lang.org/std/sync/struct.Mutex.html). It also adds the [codegen](https://docs.rs/binary-layout/latest/binary_layout/codegen/index.html) module and the [struct@WriteTracked] view,
+//!   which panics in debug builds when a field is read before it was written, e.g. because a length field was forgotten.
+//!   Implies `alloc`. Disable it for `#[no_std]` builds.
+//! - `alloc`: For `#[no_std]` builds with an allocator. Adds the helpers that need to allocate, i.e. [LayoutInfo::hexdump](https://docs.rs/binary-layout/latest/binary_layout/struct.LayoutInfo.html#method.hexdump) and [LayoutInfo::diff](https://docs.rs/binary-layout/latest/binary_layout/struct.LayoutInfo.html#method.diff)
+//!   and their equivalents in each layout module, [runtime-defined layouts](#runtime-defined-layouts), and implements [trait@SegmentedStorage] for `Vec` and `VecDeque`.
+//!   Views over `Vec<u8>` and [struct@Data] work without this feature as well, because they only need [trait@Storage], which is implemented for any `AsRef<[u8]>`.
+//!   Without `std` and `alloc`, the crate only works with borrowed or statically sized storage like `&[u8]` or `[u8; N]`, unless you bring your own storage types.
+//! - `core-error`: For `#[no_std]` builds. Implements `core::error::Error` for the error types of this crate, so that they work with
+//!   `?` and error handling crates based on the error trait without the `std` feature. This feature requires Rust 1.81 or later.
+//!   With the `std` feature, the error types implement [std::error::Error](https://doc.rust-lang.org/std/error/trait.Error.html) regardless of this feature.
+//! - `binrw`: Requires the `std` feature as well. Implements [binrw](https://crates.io/crates/binrw)'s `BinRead` for `View<Vec<u8>>`
+//!   and `BinWrite` for `View<S>` of each layout, so that layouts can be used as fields of structs parsed with binrw
+//!   and projects mixing copy-based binrw parsing with zero-copy access don't have to define their formats twice.
+//!   Reading takes the length of the open ended field as argument (`0` by default) and reads [FIXED_SIZE](https://docs.rs/binary-layout/latest/binary_layout/macro.binary_layout.html#generated-code)
+//!   plus that many bytes, like `read_with_tail_from(reader, tail_len)`. Writing writes the same bytes as `ViewIoExt::write_to(writer)`.
+//!   The endianness passed by binrw is ignored, fields always use the endianness of their layout.
+//! - `bytemuck`: Adds the [FieldPodSliceAccess](https://docs.rs/binary-layout/latest/binary_layout/trait.FieldPodSliceAccess.html) trait to borrow fixed size byte array fields holding native-endian plain old data types
+//!   as typed slices like `&[u32]`, using the [bytemuck](https://crates.io/crates/bytemuck) crate. This fails with a [PodSliceError](https://docs.rs/binary-layout/latest/binary_layout/enum.PodSliceError.html)
+//!   if the layout doesn't have native endianness or the field isn't aligned correctly.
+//! - `bytes`: Integration with the [bytes](https://crates.io/crates/bytes) crate. Views can be created over `Bytes` and `BytesMut`
+//!   storage already without this feature, but this feature adds `Data::into_bytes()` and `Data::into_bytes_mut()`
+//!   to cheaply extract a subregion, e.g. an open ended byte array field, as a `Bytes` or `BytesMut` instance without copying it.
+//!   It also implements [trait@SegmentedStorage] for `bytes::buf::Chain`, so that fields with copy access can be read from and written to
+//!   chained buffers without flattening them first, see [trait@FieldSegmentedExt]. Fields can be read from other `bytes::Buf` types
+//!   that can be cloned through the [BufSegments](https://docs.rs/binary-layout/latest/binary_layout/struct.BufSegments.html) adapter.
+//! - `async`: Generates `read_from_async(reader)`, `read_with_tail_from_async(reader, tail_len)` and `View::write_to_async(writer)`
+//!   for each layout, the async equivalents of the `std::io` helpers, based on the [tokio](https://crates.io/crates/tokio) `AsyncRead` and `AsyncWrite` traits. This feature requires Rust 1.71 or later.
+//! - `c-header`: Adds [LayoutInfo::to_c_header](https://docs.rs/binary-layout/latest/binary_layout/struct.LayoutInfo.html#method.to_c_header) to generate a C header with a packed struct and offset macros for a layout,
+//!   so that C code can share the same field offsets.
+//! - `c-import`: Adds the [c_import](https://docs.rs/binary-layout/latest/binary_layout/c_import/index.html) module, which converts simple C struct declarations, e.g. from a vendor header, into [binary_layout!] definitions
+//!   with the field offsets a C compiler would use, inserting and reporting padding fields where the compiler would align members.
+//! - `derive`: Adds the `#[binary_layout]` attribute macro in the `attribute` module, which defines layouts from structs with named fields
+//!   as an alternative to the [binary_layout!] macro, and `#[derive(LayoutAs)]`, which implements [trait@LayoutAs] for newtypes like `struct BlockId(u64)`.
+//! - `f128`: Adds the [F128](https://docs.rs/binary-layout/latest/binary_layout/struct.F128.html) field type for 16-byte IEEE 754 binary128 ("quad precision") floating point numbers,
+//!   which are used in some scientific file formats. Since Rust doesn't have a stable `f128` type yet, it stores the bits of the number
+//!   and converts from and to `f64`.
+//! - `disk-formats`: Adds ready-made layouts for MBR partition tables, GPT headers and FAT boot sectors in the `example::disk_formats` module.
+//! - `protocols`: Adds ready-made layouts for Ethernet, IPv4, IPv6, UDP, TCP and ICMP headers in the `example::protocols` module.
+//! - `json`: Adds [LayoutInfo::to_json](https://docs.rs/binary-layout/latest/binary_layout/struct.LayoutInfo.html#method.to_json) to export the field table of a layout (names, offsets, sizes, types and endianness) as JSON,
+//!   e.g. for analysis tools written in other languages.
+//! - `kaitai-import`: Requires the `std` feature as well. Adds the [kaitai_import](https://docs.rs/binary-layout/latest/binary_layout/kaitai_import/index.html) module, which converts the fixed-size subset of
+//!   [Kaitai Struct](https://kaitai.io) `.ksy` definitions into [binary_layout!] definitions, so that existing format descriptions can be reused.
+//! - `arbitrary`: Implements [arbitrary::Arbitrary](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html) for `View<Vec<u8>>`
+//!   of each layout, for use in fuzzers. The generated data is valid for the layout, i.e. `bool` fields are `0` or `1`, `NonZero` fields aren't zero
+//!   and fields of custom types are valid if the custom type implements `Arbitrary` itself. Other fields are filled with arbitrary bytes.
+//!   It also adds the [binary_layout_tests!](https://docs.rs/binary-layout/latest/binary_layout/macro.binary_layout_tests.html) macro, which generates round trip tests for layouts.
+//! - `proptest`: Requires the `arbitrary` feature as well. Generates a `storage_strategy()` function for each layout, returning a
+//!   [proptest](https://crates.io/crates/proptest) strategy that produces valid buffers for the layout,
+//!   which makes property based round trip tests of layouts easy.
+//! - `rayon`: Requires the `std` feature as well. Generates `par_validate_all(buffer)` for each layout with a fixed size, which checks
+//!   the validation checks and checksums of all consecutive frames in a buffer (see [struct@Frames]) in parallel using [rayon](https://crates.io/crates/rayon),
+//!   and `par_decode_all(buffer)` for layouts with an [owned struct](https://docs.rs/binary-layout/latest/binary_layout/macro.binary_layout.html#owned-structs), the parallel equivalent of `decode_all(buffer)`.
+//!   The buffer is split into chunks at frame boundaries, so that large capture files can be processed on all cores.
+//! - `tracing`: Emits [tracing](https://crates.io/crates/tracing) events with the target `binary_layout` at the `TRACE` level
+//!   for each read and write of a field through the [struct@FieldView]s returned by the accessors of a layout's `View`, e.g. `view.field_mut().write(value)`.
+//!   The events contain the name of the layout and the field, its offset and the value read, or the old and the new value written.
+//!   This shows which fields were touched in what order, e.g. when debugging a protocol state machine. Accesses through the [trait@Field] API
+//!   and to fields without copy access, e.g. byte slices, aren't traced. The old value is only copied if the event is enabled.
+//! - `ux`: Implements [trait@LayoutAs] for the narrow integer types of the [ux](https://crates.io/crates/ux) crate, e.g. `ux::u12` or `ux::i20`,
+//!   so that fields can be declared as `field: ux::u12 as u16` and code using them keeps the guarantees of the narrow type.
+//!   Reading such a field fails with an [IntOutOfRangeError](https://docs.rs/binary-layout/latest/binary_layout/struct.IntOutOfRangeError.html) if the stored value is out of range for the narrow type.
+//! - `zerocopy`: Generates conversions between views and types implementing the traits of the [zerocopy](https://crates.io/crates/zerocopy) crate,
+//!   i.e. `View::as_zerocopy::<T>()` and `View::as_zerocopy_mut::<T>()` to reinterpret the data of a view as a zerocopy struct, and
+//!   `View::from_zerocopy(&value)` and `View::from_zerocopy_mut(&mut value)` to create a view over the bytes of a zerocopy value.
+//!   This allows code that already uses zerocopy types to adopt layouts incrementally. The conversions are only available for layouts
+//!   with a static size and native endianness (or the endianness of the target platform), and checked at compile time to match the
+//!   size of the zerocopy type. Reinterpreting a view returns `None` if its storage isn't aligned correctly for the zerocopy type.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![deny(missing_docs)]
+#![allow(clippy::doc_overindented_list_items)]
+
+#[cfg(any(feature = "alloc", feature = "arbitrary", feature = "tracing"))]
+extern crate alloc;
 
-mod endianness;
+#[cfg(all(feature = "proptest", not(feature = "arbitrary")))]
+compile_error!("The `proptest` feature requires the `arbitrary` feature to be enabled as well");
+
+#[cfg(all(feature = "binrw", not(feature = "std")))]
+compile_error!("The `binrw` feature requires the `std` feature to be enabled as well");
+
+#[cfg(all(feature = "rayon", not(feature = "std")))]
+compile_error!("The `rayon` feature requires the `std` feature to be enabled as well");
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_support;
+#[cfg(feature = "derive")]
+pub mod attribute;
+#[cfg(feature = "c-import")]
+pub mod c_import;
+mod checksum;
+#[cfg(feature = "std")]
+pub mod codegen;
+#[cfg(feature = "alloc")]
+mod dynamic;
+mod error;
 mod fields;
+#[cfg(feature = "kaitai-import")]
+pub mod kaitai_import;
+mod length;
 mod macro_binary_layout;
+#[cfg(feature = "arbitrary")]
+mod macro_binary_layout_tests;
+mod macro_field;
+mod macro_layout_enum;
+mod reflection;
+mod storage;
+mod tlv;
+#[cfg(feature = "tracing")]
+mod tracing_support;
 mod utils;
+#[cfg(feature = "ux")]
+mod ux_support;
+mod validation;
+#[cfg(feature = "std")]
+mod write_tracked;
+#[cfg(feature = "zerocopy")]
+mod zerocopy_support;
 
 pub mod example;
 
-pub use endianness::{BigEndian, Endianness, LittleEndian, NativeEndian};
+pub use checksum::{Checksum, InternetChecksum};
+#[cfg(feature = "alloc")]
+pub use dynamic::{
+    DynField, DynFieldType, DynLayout, DynLayoutError, DynLayoutParseError, DynValue, DynView,
+};
+pub use error::{Error, ErrorContextExt, ErrorKind};
+#[cfg(feature = "f128")]
+pub use fields::primitive::F128;
+#[cfg(feature = "bytemuck")]
+pub use fields::primitive::{FieldPodSliceAccess, PodSliceError};
 pub use fields::{
+    enums::InvalidEnumValueError,
     primitive::{
-        FieldCopyAccess, FieldReadExt, FieldSliceAccess, FieldView, FieldWriteExt,
-        NonZeroIsZeroError, PrimitiveField,
+        ArrayElement, BorrowingNestedView, FieldArrayAccess, FieldCopyAccess, FieldReadExt,
+        FieldSegmentedExt, FieldSliceAccess, FieldView, FieldWriteExt, Frames, NestedViewInfo,
+        NonZeroIsZeroError, OwningNestedView, PrimitiveField, Records, RecordsFullError,
+        RecordsIter, RecordsView, UpdateError, Utf16, Utf16Str, Utf16StrMut, Utf16TooLongError,
+        VarBytes, VarBytesMut, VarBytesTooLongError,
     },
+    ranged::{RangedField, RangedFieldError, ValueRange},
+    time::{DosDate, DosDateTime, DosTime, FileTime, InvalidDosDateTimeError},
     wrapped::{LayoutAs, WrappedField, WrappedFieldError},
     Field,
 };
+pub use layout_macros_support::{BigEndian, EndianKind, Endianness, LittleEndian, NativeEndian};
+#[cfg(feature = "alloc")]
+pub use reflection::FieldDiff;
+pub use reflection::{FieldInfo, FieldKind, FieldValue, LayoutInfo, LayoutValue};
+#[cfg(feature = "bytes")]
+pub use storage::BufSegments;
+pub use storage::{
+    FixedSizeStorage, ResizableStorage, SegmentedStorage, SegmentedStorageMut, Storage, StorageMut,
+};
+pub use tlv::{Tlv, TlvBuilder, TlvFormat, TlvIter, TlvWriteError};
+#[cfg(feature = "std")]
+pub use utils::io::ViewIoExt;
 pub use utils::{data::Data, infallible::InfallibleResultExt};
 
+#[cfg(feature = "derive")]
+pub use binary_layout_derive::LayoutAs;
+#[cfg(feature = "ux")]
+pub use ux_support::IntOutOfRangeError;
+pub use validation::ValidationError;
+#[cfg(feature = "std")]
+pub use write_tracked::WriteTracked;
+
 /// Import this to get everything into scope that you need for defining and using layouts.
 ///
 /// # Example
@@ -230,6 +475,8 @@ pub use utils::{data::Data, infallible::InfallibleResultExt};
 /// use binary_layout::prelude::*;
 /// ```
 pub mod prelude {
+    #[cfg(feature = "std")]
+    pub use super::ViewIoExt;
     pub use super::{
         BigEndian, Field, FieldCopyAccess, FieldReadExt, FieldSliceAccess, FieldWriteExt,
         InfallibleResultExt, LittleEndian, NativeEndian, NonZeroIsZeroError,
@@ -237,16 +484,53 @@ pub mod prelude {
     pub use crate::binary_layout;
     #[allow(deprecated)]
     pub use crate::define_layout;
+    pub use crate::{field, field_mut, layout_enum};
 }
 
 /// Internal things that need to be exported so our macros can use them. Don't use directly!
 #[doc(hidden)]
 pub mod internal {
+    pub use crate::checksum::{verify_checksum, write_checksum};
     pub use crate::fields::{
-        primitive::{BorrowingNestedView, NestedViewInfo, OwningNestedView},
-        StorageIntoFieldView, StorageToFieldView,
+        base_out_of_bounds, check_field_bounds, check_generic_field_bounds, field_in_bounds,
+        primitive::RecordCount, split_field_mut, FieldAtOffset, StorageIntoFieldView,
+        StorageToFieldView, ZeroSizedField,
+    };
+    pub use crate::length::{length_in_bounds, length_matches, write_length};
+    pub use crate::reflection::{
+        FieldFormatter, FieldKindInfo, FormatFieldByteArray, FormatFieldBytes, FormatFieldValue,
     };
-    pub use crate::macro_binary_layout::{option_usize_add, unwrap_field_size};
+    #[cfg(feature = "tracing")]
+    pub use crate::tracing_support::{FieldTrace, TraceFieldView, TraceOtherView};
+    #[cfg(feature = "alloc")]
+    pub use alloc::string::String;
+    #[cfg(any(feature = "alloc", feature = "arbitrary"))]
+    pub use alloc::vec::Vec;
+    #[cfg(feature = "binrw")]
+    pub use binrw;
+    pub use core::ops::RangeBounds;
     pub use doc_comment::doc_comment;
+    pub use layout_macros_support::const_math::{
+        fixed_size_add, option_usize_add, unwrap_field_size,
+    };
     pub use paste::paste;
+    #[cfg(feature = "rayon")]
+    pub use rayon;
+    #[cfg(feature = "async")]
+    pub use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+    #[cfg(feature = "proptest")]
+    pub use {crate::arbitrary_support::storage_strategy, proptest};
+    #[cfg(feature = "arbitrary")]
+    pub use {
+        crate::arbitrary_support::{
+            arbitrary_storage, round_trip_test, ArbitraryLayout, FieldFiller, FieldRoundTrip,
+            FillArbitraryBytes, FillArbitraryNested, FillArbitraryValue, RoundTripBytes,
+            RoundTripNested, RoundTripValue,
+        },
+        arbitrary,
+    };
+    #[cfg(feature = "std")]
+    pub use {crate::utils::io::write_all_vectored, std::io};
+    #[cfg(feature = "zerocopy")]
+    pub use {crate::zerocopy_support::ZerocopyCompat, zerocopy};
 }
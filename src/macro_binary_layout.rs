@@ -11,15 +11,526 @@
 /// ```text
 /// binary_layout!(<<Name>>, <<Endianness>>, {
 ///   <<FieldName>>: <<FieldType>>,
+///   <<FieldName>>: <<FieldType>> where <<Range>>,   // range constrained field, e.g. `priority: u8 where 0..=7`
+///   <<FieldName>> @ <<Offset>>: <<FieldType>>,   // field at a pinned offset, e.g. `status @ 0x40: u32`
+///   <<FieldName>>: <<FieldType>> = len(<<TailField>>),   // length field, e.g. `payload_len: u16 = len(payload)`
+///   <<FieldName>>: <<FieldType>> = checksum(<<Algorithm>>, <<Range>>),   // checksum field, e.g. `crc: u32 = checksum(Crc32, ..)`
+///   include <<Layout>>,   // splices the fields of another layout into this one
+///   ...
+/// }, assert_size(<<Size>>),   // optional
+/// assert_impl(<<Trait>>, ...),   // optional
+/// optional {   // optional
+///   <<FieldName>>: <<FieldType>>,
+///   #[since = <<Revision>>] <<FieldName>>: <<FieldType>>,   // documents the revision that added the field
+///   ...
+/// }, conditional {   // optional
+///   <<FieldName>> if <<FlagField>> & <<Mask>>: <<FieldType>>,
 ///   <<FieldName>>: <<FieldType>>,
 ///   ...
+/// }, validate |<<ViewName>>| {   // optional
+///   <<Check>>,
+///   ...
+/// }, variants <<BodyField>> by <<DiscriminatorField>> {   // optional
+///   <<Value>> => <<Variant>>(<<Layout>>),
+///   ...
+/// }, overlay <<BodyField>> {   // optional
+///   <<Name>>(<<Layout>>),
+///   ...
+/// }, owned #[<<Attribute>>] <<StructName>>);   // optional
+///
+/// binary_layout!(<<Visibility>> <<Name>>, <<Endianness>>, { ... });   // e.g. `pub(crate) my_layout`, the default is `pub`
+/// binary_layout!(<<Name>><const <<Param>>: <<ParamType>>, ...>, <<Endianness>>, { ... });   // layout template
+/// binary_layout!(<<Name>> = <<Template>><<<Value>>, ...>);   // layout instantiated from a template
+/// binary_layout!(<<Name>><E: Endianness>, { ... });   // layout generic over the endianness
+/// ```
+///
+/// ## Visibility
+/// The module generated for a layout is `pub` by default. A visibility can be given before the name of the layout,
+/// e.g. `binary_layout!(pub(crate) my_layout, ...)`, or `pub(self)` for a module that is private to the module defining it.
+/// The items inside of the module, e.g. the `View` and the field types, are `pub`, so the visibility of the module determines where they're accessible.
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(pub(crate) my_layout, LittleEndian, {
+///   field: u16,
+/// });
+/// ```
+///
+/// ## Including layouts
+/// Instead of nesting a layout as a field, `include other_layout` splices the fields of another layout directly into the current one.
+/// Its fields get accessors at the same level as the other fields of the layout, with their offsets shifted to where the include is.
+/// This is useful for common header prefixes shared by many message types.
+/// Only the fields of the included layout are spliced in, not its `optional`, `conditional`, `validate` or `variants` sections.
+/// The included layout must be defined in the same crate and use the same endianness, and the types of its fields must also be
+/// accessible from the place the including layout is defined at.
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(common_header, BigEndian, {
+///   version: u8,
+///   message_type: u8,
+/// });
+///
+/// binary_layout!(ping, BigEndian, {
+///   include common_header,
+///   sequence: u32,
+/// });
+///
+/// # fn main() {
+/// let view = ping::View::new([1, 2, 0, 0, 0, 3]);
+/// assert_eq!(2, view.message_type().read());
+/// assert_eq!(3, view.sequence().read());
+/// assert_eq!(2, ping::sequence::OFFSET);
+/// # }
+/// ```
+///
+/// ## Layout templates
+/// Layouts can take const parameters, e.g. `binary_layout!(block<const BLOCK_SIZE: usize>, ...)`, and use them in their field types.
+/// Such a definition is a template, and layouts are instantiated from it with concrete values as in `binary_layout!(block_4k = block<4096>)`.
+/// Each instantiation is a regular layout, which additionally defines the parameters as constants, e.g. `block_4k::BLOCK_SIZE`,
+/// so parameter names must not collide with other items generated for a layout like `SIZE` or `FIXED_SIZE`.
+/// Values that aren't a single literal or identifier need to be wrapped in braces, like const generic arguments, e.g. `block<{4 * SECTOR}>`.
+///
+/// Templates are instantiated instead of generating a `View` that is generic over the parameters because stable Rust can't compute
+/// the offsets of fields from const generic parameters at compile time. Like included layouts, templates can only be instantiated
+/// in the crate defining them.
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(block<const BLOCK_SIZE: usize>, LittleEndian, {
+///   checksum: u32,
+///   data: [u8; BLOCK_SIZE],
+///   next_block: u64,
+/// });
+///
+/// binary_layout!(block_1k = block<1024>);
+/// binary_layout!(block_4k = block<4096>);
+///
+/// # fn main() {
+/// assert_eq!(1028, block_1k::next_block::OFFSET);
+/// assert_eq!(Some(4108), block_4k::SIZE);
+/// assert_eq!(4096, block_4k::BLOCK_SIZE);
+/// # }
+/// ```
+///
+/// ## Endianness-generic layouts
+/// Some formats exist in a little endian and a big endian flavor with the same fields, e.g. because the byte order of the writing machine is used.
+/// Declaring a layout as `binary_layout!(<<Name>><E: Endianness>, { ... })` instead of giving an endianness defines it for all endiannesses at once.
+/// The layout is instantiated as a regular layout for each endianness in the `big_endian`, `little_endian` and `native_endian` submodules,
+/// and `<<Name>>::View<E, S>` is an alias for the `View` of the instantiation for endianness `E`, e.g. `my_layout::View<BigEndian, S>`
+/// is the same type as `my_layout::big_endian::View<S>`. All sections of a regular layout, e.g. `optional` or `owned`, can be used as well.
+///
+/// The instantiations don't share a generic `View` because the types of the fields depend on the endianness.
+/// Code that is generic over the endianness can't access the fields through `View<E, S>`, so it needs to be written for the instantiations,
+/// e.g. with a macro. Nesting the layout in another layout needs to name the instantiation, e.g. `my_layout::big_endian::NestedView`.
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(sample<E: Endianness>, {
+///   timestamp: u32,
+///   value: i16,
+/// });
+///
+/// # fn main() {
+/// let big = sample::View::<BigEndian, _>::new([0, 0, 0, 1, 0xff, 0xfe]);
+/// assert_eq!(1, big.timestamp().read());
+/// assert_eq!(-2, big.value().read());
+///
+/// let little: sample::little_endian::View<_> = sample::View::<LittleEndian, _>::new([1, 0, 0, 0, 0xfe, 0xff]);
+/// assert_eq!(1, little.timestamp().read());
+/// assert_eq!(-2, little.value().read());
+/// # }
+/// ```
+///
+/// ## Pinned offsets
+/// Register maps and some file formats specify the offset of each field instead of a dense sequence of fields.
+/// Declaring a field as `<<FieldName>> @ <<Offset>>: <<FieldType>>` places it at the given offset from the start of the layout,
+/// and the fields after it continue right after it. The bytes between the end of the previous field and a pinned field are reserved,
+/// i.e. they aren't accessible through any field. A pinned offset that overlaps with the fields before it causes a compile error.
+/// Pinned offsets of an [included layout](#including-layouts) are offsets from the start of the layout including it.
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(device_registers, LittleEndian, {
+///   id: u32,
+///   control @ 0x10: u32,
+///   status: u32,
+///   data @ 0x40: [u8; 16],
+/// });
+///
+/// assert_eq!(0x14, device_registers::status::OFFSET);
+/// assert_eq!(Some(0x50), device_registers::SIZE);
+/// ```
+///
+/// ```compile_fail
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(device_registers, LittleEndian, {
+///   id: u32,
+///   control @ 0x02: u32,
+/// });
+/// # fn main() { let _ = device_registers::SIZE; }
+/// ```
+///
+/// ## Size assertion
+/// File formats and protocols often mandate the size of a structure. Adding `assert_size(<<Size>>)` after the fields makes the compiler
+/// check that the fixed size of the layout (see `FIXED_SIZE`) matches that size, so that a mis-sized field causes a compile error.
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(inode, LittleEndian, {
+///   mode: u16,
+///   uid: u16,
+///   size: u64,
+///   reserved: [u8; 116],
+/// }, assert_size(128));
+/// ```
+///
+/// ```compile_fail
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(inode, LittleEndian, {
+///   mode: u16,
+///   uid: u16,
+///   size: u64,
+///   reserved: [u8; 112],
+/// }, assert_size(128));
+/// ```
+///
+/// ## Trait assertions
+/// Views implement auto traits like [Send] and [Sync] whenever their storage does, so that e.g. a `View<Vec<u8>>` can be sent to another thread.
+/// Adding `assert_impl(<<Trait>>, ...)` after the fields (and after `assert_size` if present) makes the compiler check that `View<S>`
+/// implements each of the given traits for every storage `S` that implements them. This locks the guarantee in for the layout,
+/// so that a change that accidentally breaks it, e.g. a field type holding a `!Send` marker, causes a compile error.
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(packet, BigEndian, {
+///   sequence_number: u32,
+///   payload: [u8],
+/// }, assert_impl(Send, Sync));
+///
+/// # fn main() {
+/// let view = packet::View::new(vec![0, 0, 0, 1]);
+/// let handle = std::thread::spawn(move || view.sequence_number().read());
+/// assert_eq!(1, handle.join().unwrap());
+/// # }
+/// ```
+///
+/// ## Optional trailing fields
+/// Protocols often append fields to their headers in newer revisions. Such fields can be declared in an `optional` section after the fields.
+/// They are located right after the other fields and are only accessible if the storage is long enough to contain them,
+/// i.e. their accessors in the `View` return `None` otherwise. An open ended byte array can be the last optional field
+/// if the fields before it don't end with one.
+/// Layouts with optional fields don't have a static `SIZE`, `FIXED_SIZE` is the size of the fields before the `optional` section,
+/// and the `LAYOUT` reflection data only describes those fields. Optional fields can't be combined with conditional fields.
+///
+/// Optional fields can be annotated with `#[since = <<Revision>>]` to document the revision of the format that added them.
+/// `View::try_new` only requires the storage to contain the fields before the `optional` section, so a persistent format can evolve
+/// with a single layout definition that reads the data of all of its revisions.
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(header, LittleEndian, {
+///   version: u8,
+/// }, optional {
+///   #[since = 2] extension: u16,
+/// });
+///
+/// assert!(header::View::try_new([1]).unwrap().extension().is_none());
+/// assert_eq!(5, header::View::try_new([2, 5, 0]).unwrap().extension().unwrap().read());
+/// assert!(header::View::try_new([]).is_err());
+/// ```
+///
+/// ## Conditional fields
+/// Some headers have fields that are only present if a bit in a flags field is set, e.g. TCP or IPv4 options.
+/// Such fields can be declared in a `conditional` section after the fields. Fields in this section either have a condition
+/// `<<FlagField>> & <<Mask>>`, which makes them present if the flag field (which has to be one of the fields before the
+/// `conditional` section) has any of the bits of the mask set, or are always present.
+/// Each field in the section starts right after the fields before it that are present, so their offsets are only known at runtime.
+///
+/// The `View` offers accessors for conditional fields returning `None` if the field isn't present, and `View::dynamic_size()`
+/// returning the size of the layout including the conditional fields that are present.
+/// Conditional fields must have a static size and can't be combined with an open ended field. Layouts with conditional fields
+/// don't have a static `SIZE`, `FIXED_SIZE` is the size of the fields before the `conditional` section, and the `LAYOUT` reflection data
+/// only describes those fields.
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(options, BigEndian, {
+///   flags: u8,
+/// }, conditional {
+///   mss if flags & 0x01: u16,
+///   window_scale if flags & 0x02: u8,
+///   checksum: u16,
+/// });
+///
+/// let view = options::View::new([0x02, 7, 0xab, 0xcd]);
+/// assert!(view.mss().is_none());
+/// assert_eq!(7, view.window_scale().unwrap().read());
+/// assert_eq!(0xabcd, view.checksum().read());
+/// assert_eq!(4, view.dynamic_size());
+/// ```
+///
+/// ## Validation
+/// Layouts can declare invariants of their data, e.g. a version number or a minimal header length, after the fields.
+/// They come after the conditional fields, if there are any.
+/// The checks are boolean expressions over a [View](#struct-view) of the layout, which is bound to the name given between the pipes.
+/// The generated `View::validate()` method evaluates them in order and returns a [ValidationError](crate::ValidationError)
+/// describing the first check that failed. Before evaluating them, it checks that the storage contains at least `FIXED_SIZE` bytes,
+/// so that the checks can access all fields of the fixed-size part of the layout. Layouts without validation checks or
+/// [length fields](#length-fields) don't have a `View::validate()` method.
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(my_header, BigEndian, {
+///   version: u8,
+///   header_len: u16,
+/// }, validate |view| {
+///   view.version().read() == 2,
+///   view.header_len().read() >= 20,
+/// });
+///
+/// let view = my_header::View::new([2, 0, 10]);
+/// let error = view.validate().unwrap_err();
+/// assert_eq!("view.header_len().read() >= 20", error.check());
+/// ```
+///
+/// ## Length fields
+/// Many protocols store the length of an open ended field in a header field. Declaring such a field as `<<FieldName>>: <<FieldType>> = len(<<TailField>>)`
+/// binds it to the open ended byte array field it describes. The generated `View::finalize()` method writes the length of the bound field
+/// into the length field, so that it doesn't have to be kept in sync by hand, and `View::validate()` checks that they match (before any other
+/// [validation checks](#validation)). Length fields can have any integer type that the length can be converted into. If it doesn't fit,
+/// `View::finalize()` returns an [Error](crate::Error) with [ErrorKind::ValueTooLong](crate::ErrorKind::ValueTooLong).
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(packet, BigEndian, {
+///   packet_type: u8,
+///   payload_len: u16 = len(payload),
+///   payload: [u8],
+/// });
+///
+/// # fn main() {
+/// let mut view = packet::View::new(vec![0; 7]);
+/// view.payload_mut().copy_from_slice(b"ping");
+/// assert!(view.validate().is_err());
+///
+/// view.finalize().unwrap();
+/// assert_eq!(4, view.payload_len().read());
+/// assert!(view.validate().is_ok());
+/// # }
+/// ```
+///
+/// ## Checksum fields
+/// A field declared as `<<FieldName>>: <<FieldType>> = checksum(<<Algorithm>>, <<Range>>)` holds a checksum over the given range of bytes,
+/// computed with an algorithm implementing [Checksum](crate::Checksum). The range is a range of byte offsets from the start of the layout,
+/// e.g. `..` for the whole storage including an open ended field, `..FIXED_SIZE` for the fixed-size part, or `field_offsets::payload..`.
+/// It can cover the checksum field itself, whose bytes are treated as zeroes when computing the checksum.
+///
+/// The generated `View::finalize()` method recomputes all checksum fields in the order they were declared (after updating the
+/// [length fields](#length-fields)), so it should be called after all other fields were written, e.g. right before sending the data.
+/// A checksum covering another checksum field must be declared after it.
+/// `View::verify()` checks all checksum fields, e.g. after receiving the data, and returns an [Error](crate::Error)
+/// with [ErrorKind::ChecksumMismatch](crate::ErrorKind::ChecksumMismatch) for the first one that doesn't match.
+/// Checksums that cover additional data outside of the layout, like the pseudo header of TCP and UDP, can be computed and checked
+/// with `View::finalize_with_pseudo_header(pseudo_header)` and `View::verify_with_pseudo_header(pseudo_header)`.
+/// The [InternetChecksum](crate::InternetChecksum) algorithm used by IP, ICMP, TCP and UDP is available out of the box.
+/// ```
+/// use binary_layout::{prelude::*, Checksum, ErrorKind};
+///
+/// #[derive(Default)]
+/// struct Xor(u8);
+///
+/// impl Checksum for Xor {
+///     type Output = u8;
+///
+///     fn update(&mut self, data: &[u8]) {
+///         self.0 = data.iter().fold(self.0, |acc, byte| acc ^ byte);
+///     }
+///
+///     fn finish(self) -> u8 {
+///         self.0
+///     }
+/// }
+///
+/// binary_layout!(frame, BigEndian, {
+///   payload_len: u8 = len(payload),
+///   payload_checksum: u8 = checksum(Xor, field_offsets::payload..),
+///   header_checksum: u8 = checksum(Xor, ..FIXED_SIZE),
+///   payload: [u8],
+/// });
+///
+/// # fn main() {
+/// let mut view = frame::View::new(vec![0; 5]);
+/// view.payload_mut().copy_from_slice(&[0x0f, 0xf0]);
+/// view.finalize().unwrap();
+/// assert_eq!(0xff, view.payload_checksum().read());
+/// assert!(view.verify().is_ok());
+///
+/// view.payload_mut()[0] = 0;
+/// assert_eq!(ErrorKind::ChecksumMismatch, view.verify().unwrap_err().kind());
+/// # }
+/// ```
+///
+/// ## Variants
+/// Protocols often have a body whose layout depends on the value of a header field, e.g. the ICMP message type.
+/// Such alternative body layouts can be declared after the fields (and after the conditional fields and validation checks, if there are any) with
+/// `variants <<BodyField>> by <<DiscriminatorField>> { <<Value>> => <<Variant>>(<<Layout>>), ... }`.
+/// The generated `View::variant()` and `View::variant_mut()` methods read the discriminator field and return a `Variant` enum
+/// holding a view of the body field with the matching layout, or `Variant::Unknown` with the raw body data if no variant matches
+/// or the storage is too short to contain the discriminator and body fields.
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(echo, BigEndian, {
+///   identifier: u16,
+///   sequence_number: u16,
+/// });
+/// binary_layout!(destination_unreachable, BigEndian, {
+///   unused: u16,
+///   next_hop_mtu: u16,
+/// });
+/// binary_layout!(icmp_packet, BigEndian, {
+///   packet_type: u8,
+///   code: u8,
+///   checksum: u16,
+///   body: [u8],
+/// }, variants body by packet_type {
+///   0 => EchoReply(echo),
+///   3 => DestinationUnreachable(destination_unreachable),
+///   8 => EchoRequest(echo),
+/// });
+///
+/// # fn main() {
+/// let packet = icmp_packet::View::new([8, 0, 0, 0, 0, 1, 0, 2]);
+/// match packet.variant() {
+///   icmp_packet::Variant::EchoRequest(echo) => assert_eq!(2, echo.sequence_number().read()),
+///   _ => panic!("Expected an echo request"),
+/// }
+/// # }
+/// ```
+///
+/// ## Overlays
+/// Some message formats reinterpret a body by its type without a discriminator field in the layout itself, like a C union.
+/// Several layouts can be declared to overlay the same field with `overlay <<BodyField>> { <<Name>>(<<Layout>>), ... }` after the variants (if there are any).
+/// For each of them, the generated `View::as_${name}()` and `View::as_${name}_mut()` methods return a view of the body field with that layout.
+/// If the body field has a static size, it is checked at compile time that the fixed-size part of each overlay layout fits into it.
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(position, LittleEndian, {
+///   x: i32,
+///   y: i32,
+/// });
+/// binary_layout!(color, LittleEndian, {
+///   red: u8,
+///   green: u8,
+///   blue: u8,
+/// });
+/// binary_layout!(message, LittleEndian, {
+///   message_type: u8,
+///   body: [u8; 8],
+/// }, overlay body {
+///   position(position),
+///   color(color),
+/// });
+///
+/// # fn main() {
+/// let mut message = message::View::new([0; 9]);
+/// message.as_color_mut().green_mut().write(255);
+/// assert_eq!(255, message.as_color().green().read());
+/// assert_eq!(0xff00, message.as_position().x().read());
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(position, LittleEndian, {
+///   x: i32,
+///   y: i32,
 /// });
+/// binary_layout!(message, LittleEndian, {
+///   message_type: u8,
+///   body: [u8; 4],
+/// }, overlay body {
+///   position(position),   // error: `position` needs 8 bytes
+/// });
+/// # fn main() {}
+/// ```
+///
+/// ## Owned structs
+/// Values that are read from a layout are often needed as a whole, e.g. as a key in a map or to sort records by their values.
+/// `owned <<StructName>>` at the end of the layout definition generates a struct with a public member for each field of the layout,
+/// with the type returned by the `read()` accessor of that field. Attributes given before the name are applied to the struct,
+/// e.g. `#[derive(...)]` to derive `Clone`, `PartialEq`, `Ord`, `Hash` or serde's `Serialize` and `Deserialize` for it.
+/// The generated `View::read_owned()` method reads all fields of a view into the struct and `View::write_owned(&owned)` writes them back.
+/// `View::write_fields(&owned, &[FieldId::...])` only writes back the given fields and leaves the others untouched,
+/// which is useful to patch a few fields in place, e.g. in a memory mapped file.
+/// With the `alloc` feature, `decode_all(buffer)` reads a buffer of consecutive frames of the layout into a `Vec` of the struct,
+/// and `encode_all(items, &mut buffer)` appends a frame for each struct to a `Vec<u8>`.
+///
+/// All fields of the layout need to support copy access, i.e. open ended fields, nested layouts or string fields can't be used in an owned struct,
+/// and their types need to implement [Clone]. Errors of reading or writing a field need to be convertible into an [Error](crate::Error).
+/// ```
+/// use binary_layout::prelude::*;
+/// use std::collections::BTreeMap;
+///
+/// binary_layout!(inode, LittleEndian, {
+///   number: u32,
+///   mode: u16,
+///   size: u64,
+/// }, owned #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)] Inode);
+///
+/// # fn main() {
+/// let view = inode::View::new([7, 0, 0, 0, 0xa4, 0x81, 0, 2, 0, 0, 0, 0, 0, 0]);
+/// let inode = view.read_owned().unwrap();
+/// assert_eq!(inode::Inode { number: 7, mode: 0o100644, size: 512 }, inode);
+///
+/// let mut by_inode = BTreeMap::new();
+/// by_inode.insert(inode.clone(), "file.txt");
+///
+/// let mut copy = inode::View::new([0; 14]);
+/// copy.write_owned(&inode).unwrap();
+/// assert_eq!(inode, copy.read_owned().unwrap());
+///
+/// let patched = inode::Inode { mode: 0o100600, size: 0, ..inode };
+/// copy.write_fields(&patched, &[inode::FieldId::mode]).unwrap();
+/// assert_eq!(0o100600, copy.mode().read());
+/// assert_eq!(512, copy.size().read());
+/// # }
 /// ```
 ///
 /// ## Field names
-/// Field names can be any valid Rust identifiers, but it is recommended to avoid names that contain `storage`, `into_` or `_mut`.
+/// Field names can be any valid Rust identifiers, but it is recommended to avoid names that contain `storage`, `into_` or `_mut`,
+/// or are equal to `validate`, `finalize`, `verify`, `variant`, `get`, `set`, `try_get`, `try_set`, `extract`, `split`, `field_offsets` or `field_sizes`.
 /// This is because the [binary_layout!](crate::binary_layout!) macro creates a [View class with several accessors](#struct-view) for each field that contain those identifier parts.
 ///
+/// Fields can be named with raw identifiers like `r#type` if their name is a Rust keyword. The accessors of such fields
+/// are `r#type()`, `type_mut()` and `into_type()`.
+///
+/// If a specification uses field names that can't be used, e.g. because they collide with the generated `View::new` or `View::into_storage`
+/// methods, the field can be given a different identifier and its original name with a `#[name = "..."]` attribute.
+/// The identifier is used for the generated field module and accessors, while the name is used in the [LAYOUT](crate::LayoutInfo)
+/// reflection data, e.g. for [hexdumps](crate::LayoutInfo::hexdump) or [diffs](crate::LayoutInfo::diff).
+///
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(packet, BigEndian, {
+///   r#type: u8,
+///   #[name = "new"]
+///   is_new: bool as u8,
+/// });
+///
+/// let mut view = packet::View::new([0; 2]);
+/// view.type_mut().write(5);
+/// view.is_new_mut().try_write(true).unwrap();
+/// assert_eq!(5, view.r#type().read());
+/// assert_eq!("new", packet::LAYOUT.fields()[1].name());
+/// ```
+///
 /// ## Example
 /// ```
 /// use binary_layout::prelude::*;
@@ -41,6 +552,33 @@
 ///   - metadata like [OFFSET](crate::Field::OFFSET) and [SIZE](crate::Field::SIZE) as rust `const`s
 ///   - data accessors for the [Field](crate::Field) API
 /// - The module will also contain a `View` struct that offers the [FieldView](crate::FieldView) API.
+/// - For layouts with an [owned struct](#owned-structs), the struct with the given name and a member for each field,
+///   and a `FieldId` enum with a variant for each field.
+/// - A `LAYOUT` constant with reflection data describing the layout and its fields, see [LayoutInfo](crate::LayoutInfo).
+/// - `SIZE` and `FIXED_SIZE` constants with the total size of the layout and the size of its fixed-size part, i.e. without an open ended tail field.
+/// - `field_offsets` and `field_sizes` modules with a constant for each field, whose values rustdoc shows. The documentation of the module
+///   contains a table of all fields with their types and links to their offsets and sizes, so that the wire format can be read from the generated docs.
+/// - A `SCHEMA_HASH` constant with a hash of the structure of the layout, see [LayoutInfo::schema_hash](crate::LayoutInfo::schema_hash).
+/// - `FIELD_NAMES` and `FIELD_OFFSETS` constants listing the name and offset of each field at the same index,
+///   for tooling that needs to enumerate fields without the full reflection data.
+/// - A `convert_to::<L>(src, dst)` function re-encoding data into another layout `L` that only differs in endianness, see [LayoutInfo::convert_to](crate::LayoutInfo::convert_to).
+/// - A `copy_fields_to::<L>(src, dst)` function copying the fields with the same name and type into another layout `L`, see [LayoutInfo::copy_fields_to](crate::LayoutInfo::copy_fields_to).
+/// - With the `std` feature, `read_from(reader)` and `read_with_tail_from(reader, tail_len)` functions that read a layout from
+///   a [std::io::Read] into a `View<Vec<u8>>`, a `View::read_tail_from(reader, tail_len)` method appending the tail to a view
+///   that was read with `read_from`, and an implementation of the [ViewIoExt](crate::ViewIoExt) trait for `View` with a `write_to(writer)` method
+///   writing it to a [std::io::Write] and `as_io_slices()`, `as_io_slices_with_tail(tail)` and `write_with_tail_to(writer, tail)` methods
+///   for vectored writes of the fixed-size part and a tail that can live in a different buffer.
+/// - With the `async` feature, their async equivalents `read_from_async(reader)`, `read_with_tail_from_async(reader, tail_len)`,
+///   `View::read_tail_from_async(reader, tail_len)` and `View::write_to_async(writer)`.
+/// - With the `alloc` feature, a `hexdump(storage)` function formatting a buffer as hex with an annotation for each field,
+///   see [LayoutInfo::hexdump](crate::LayoutInfo::hexdump), and a `diff(a, b)` function listing the fields that differ between two buffers,
+///   see [LayoutInfo::diff](crate::LayoutInfo::diff).
+/// - With the `arbitrary` feature, an implementation of `arbitrary::Arbitrary` for `View<Vec<u8>>` generating valid data for the layout,
+///   and with the `proptest` feature, a `storage_strategy()` function returning a proptest strategy for buffers with valid data.
+/// - With the `zerocopy` feature, conversions between views and zerocopy types, see [Cargo features](crate#cargo-features).
+/// - With the `binrw` feature, implementations of binrw's `BinRead` for `View<Vec<u8>>` and `BinWrite` for `View<S>`, see [Cargo features](crate#cargo-features).
+/// - With the `rayon` feature, `par_validate_all(buffer)` and, for layouts with an [owned struct](#owned-structs), `par_decode_all(buffer)`
+///   to check or decode the consecutive frames of a buffer in parallel, see [Cargo features](crate#cargo-features).
 ///
 /// This macro will also generate rustdoc documentation for everything it generates. One of the best ways to figure out
 /// how to use the generated layouts is to read the rustdoc documentation that was generated for them.
@@ -55,6 +593,8 @@
 /// });
 /// assert_eq!(2, my_layout::field2::OFFSET);
 /// assert_eq!(Some(4), my_layout::field2::SIZE);
+/// assert_eq!(Some(6), my_layout::SIZE);
+/// assert_eq!(6, my_layout::FIXED_SIZE);
 /// ```
 ///
 /// ## struct View
@@ -64,33 +604,252 @@
 /// - Immutable borrowed storage: `&[u8]`
 /// - Mutable borrowed storage: `&mut [u8]`
 /// - Owning storage: impl `AsRef<u8>` (for example: `Vec<u8>`)
+/// - Shared storage: `Arc<[u8]>`, see [Storage](crate::Storage#shared-ownership)
+/// - Custom storage types implementing [Storage](crate::Storage) (and [StorageMut](crate::StorageMut) for write access)
+///
+/// Views implement [Clone] if their storage does.
+/// Accessing a field whose bytes aren't fully contained in the storage panics with a message naming the layout, the field and its offset.
 ///
 /// The generated `View` struct will offer
 /// - `View::new(storage)` to create a `View`
+/// - `View::try_new(storage)` to create a `View`, returning an error if the storage is shorter than `FIXED_SIZE`
+/// - `View::new_at(storage, base)` and `View::try_new_at(storage, base)` to create a `View` over a layout embedded at offset `base` of a larger storage,
+///   returning a `View<Data<S>>` that still owns the whole storage
+/// - `View::new_exact(storage)` to create a `View` over a storage of static size like `[u8; N]`, checking at compile time that it matches the size of the layout
 /// - `View::into_storage(self)` to destroy a `View` and return the storage held
+/// - `View::from_parts(head, tail)` to create a `PartsView` over a layout whose fixed-size part and open ended tail are stored in separate buffers.
+///   Its `head()` and `head_mut()` return a `View` over the fixed-size part and `tail()` and `tail_mut()` the data of the open ended field.
+/// - `View::validate(&self)` to check the [validation checks](#validation) and [length fields](#length-fields) declared for the layout,
+///   only for layouts declaring any of them
+/// - `View::finalize(&mut self)` to update the [length fields](#length-fields) and [checksum fields](#checksum-fields) of the layout,
+///   and `View::finalize_with_pseudo_header(&mut self, pseudo_header)` for checksums that cover data outside of the layout,
+///   only for layouts declaring any of them
+/// - `View::resize_tail(&mut self, new_len)` and `View::set_tail(&mut self, data)` to resize the open ended field at the end of the layout,
+///   for [resizable storage](crate::ResizableStorage) like `Vec<u8>`
+/// - `View::verify(&self)` and `View::verify_with_pseudo_header(&self, pseudo_header)` to check the [checksum fields](#checksum-fields) of the layout,
+///   only for layouts declaring any of them
+/// - `View::variant(&self)` and `View::variant_mut(&mut self)` to access the body of a layout with [variants](#variants)
+/// - `View::as_${name}(&self)` and `View::as_${name}_mut(&mut self)` to access the body of a layout with [overlays](#overlays)
+/// - `View::read_owned(&self)` and `View::write_owned(&mut self, owned)` to read all fields into an [owned struct](#owned-structs) and write them back,
+///   and `View::write_fields(&mut self, owned, fields)` to write back only some of them
 ///
 /// and it will offer the following accessors for each field
 /// - `${field_name}()`: Read access. This returns a [FieldView](crate::FieldView) instance with read access.
 /// - `${field_name}_mut()`: Read access. This returns a [FieldView](crate::FieldView) instance with write access.
 /// - `into_${field_name}`: Extract access. This destroys the `View` and returns a [FieldView](crate::FieldView) instance owning the storage. Mostly useful for slice fields when you want to return an owning slice.
+///
+/// - `get::<F>(&self)` and `set::<F>(&mut self, value)`: Generic read and write access to field `F` of the layout, e.g. `view.get::<my_layout::field1>()`,
+///   so that macros and generic helper functions can pass fields as type parameters. `try_get::<F>` and `try_set::<F>` do the same for fields whose accesses can fail.
+/// - `split_at::<F>(&self)` and `split_at_mut::<F>(&mut self)`: Split the storage into a view over the bytes before field `F` and the bytes starting at `F`.
+/// - `split_mut(&mut self)`: Split the view into a `SplitMut` struct with disjoint views (with write access) for each field.
+///
+/// The generic accessors only accept fields of the layout (see the `LayoutField` trait generated in the layout's module),
+/// passing a field of a different layout fails to compile:
+/// ```compile_fail
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(packet, BigEndian, {
+///   len: u16,
+///   payload: [u8],
+/// });
+/// binary_layout!(other, BigEndian, {
+///   flags: u8,
+///   value: u16,
+///   data: [u8],
+/// });
+///
+/// let data = [0, 2, 10, 11];
+/// let view = packet::View::new(&data[..]);
+/// let value = view.get::<other::value>();
+/// ```
+/// ```compile_fail
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(packet, BigEndian, {
+///   len: u16,
+///   payload: [u8],
+/// });
+/// binary_layout!(other, BigEndian, {
+///   flags: u8,
+///   value: u16,
+///   data: [u8],
+/// });
+///
+/// let mut data = [0, 2, 10, 11];
+/// let mut view = packet::View::new(&mut data[..]);
+/// view.set::<other::value>(5);
+/// ```
+/// ```compile_fail
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(packet, BigEndian, {
+///   len: u16,
+///   payload: [u8],
+/// });
+/// binary_layout!(other, BigEndian, {
+///   flags: u8,
+///   value: u16,
+///   data: [u8],
+/// });
+///
+/// let data = [0, 2, 10, 11];
+/// let view = packet::View::new(&data[..]);
+/// let (header, payload) = view.split_at::<other::data>();
+/// ```
+///
+/// Views over a subregion of borrowed storage, e.g. nested views returned from `into_${field_name}`, additionally offer
+/// - `View::extract(self)` (for `&[u8]` storage) and `View::extract_mut(self)` (for `&mut [u8]` storage) to convert them
+///   into a view borrowing the original storage, so that they (or slices inside them) can be returned from functions consuming the outer view.
 #[macro_export]
 macro_rules! binary_layout {
-    ($name: ident, $endianness: ident, {$($field_name: ident : $field_type: ty $(as $underlying_type: ty)?),* $(,)?}) => {
+    (pub ($($vis_restriction: tt)*) $name: ident $($definition: tt)*) => {
+        $crate::binary_layout!(@vis {($($vis_restriction)*)} $name $($definition)*);
+    };
+    (pub $name: ident $($definition: tt)*) => {
+        $crate::binary_layout!(@vis {} $name $($definition)*);
+    };
+    ($(@const_params {$($const_name: ident : $const_type: ty = $const_value: tt),*})? $(@vis {$($vis_restriction: tt)*})? $name: ident, $endianness: ident, {$($(#[name = $spec_name: literal])? $field_name: ident $(@ $pinned_offset: literal)? : $field_type: ty $(as $underlying_type: ty)? $(= $binding: ident $binding_args: tt)? $(where $range: expr)?),* $(,)?} $(, assert_size($expected_size: expr))? $(, assert_impl($($assert_trait: path),+ $(,)?))? $(, optional $optional_fields: tt)? $(, conditional $conditional_fields: tt)? $(, validate |$view: ident| {$($check: expr),* $(,)?})? $(, variants $body_field: ident by $tag_field: ident {$($tag_value: expr => $variant: ident ($variant_layout: ident)),* $(,)?})? $(, overlay $overlay_field: ident {$($overlay_name: ident ($overlay_layout: ident)),* $(,)?})? $(, owned $(#[$owned_attr: meta])* $owned_name: ident)? $(,)?) => {
         $crate::internal::doc_comment!{
             concat!{"
             This module is autogenerated. It defines a layout using the [binary_layout] crate based on the following definition:
             ```ignore
             binary_layout!(", stringify!($name), ", ", stringify!($endianness), ", {", $("
-                ", stringify!($field_name), ": ", stringify!($field_type), $(" as ", stringify!($underlying_type), )? ",", )* "
+                ", stringify!($field_name), $(" @ ", stringify!($pinned_offset), )? ": ", stringify!($field_type), $(" as ", stringify!($underlying_type), )? $(" = ", stringify!($binding $binding_args), )? $(" where ", stringify!($range), )? ",", )* "
             });
             ```
+
+            # Fields
+            The offsets and sizes are computed by the compiler, follow the links to see their values.
+
+            | Field | Type | Offset | Size |
+            |-------|------|--------|------|", $("
+            | [`", stringify!($field_name), "`](", stringify!($field_name), ") | `", stringify!($field_type), $(" as ", stringify!($underlying_type), )? "` | [offset](field_offsets::", stringify!($field_name), ") | [size](field_sizes::", stringify!($field_name), ") |", )* "
             "},
-            #[allow(dead_code)]
-            pub mod $name {
+            #[allow(dead_code, unused_braces)]
+            pub $($($vis_restriction)*)? mod $name {
                 #[allow(unused_imports)]
                 use super::*;
 
-                $crate::binary_layout!(@impl_fields $crate::$endianness, Some(0), {$($field_name : $field_type $(as $underlying_type)?),*});
+                $($(
+                    $crate::internal::doc_comment!{
+                        concat!("Value of the `", stringify!($const_name), "` parameter of the layout template this layout was instantiated from"),
+                        pub const $const_name: $const_type = $const_value;
+                    }
+                )*)?
+
+                $crate::binary_layout!(@impl_fields $crate::$endianness, Some(0), 0, {$($field_name $(@ $pinned_offset)? : $field_type $(as $underlying_type)? $(where $range)?),*});
+                $crate::binary_layout!(@impl_include_macro ($) $endianness, {$($(#[name = $spec_name])? $field_name $(@ $pinned_offset)? : $field_type $(as $underlying_type)? $(= $binding $binding_args)? $(where $range)?,)*});
+
+                $(
+                    const _: () = assert!(
+                        FIXED_SIZE == $expected_size,
+                        concat!("The fixed size of layout `", stringify!($name), "` doesn't match the size of ", stringify!($expected_size), " bytes given in `assert_size`"),
+                    );
+                )?
+
+                $(
+                    // Checks that `View<S>` implements the given traits for every storage `S` implementing them.
+                    #[allow(dead_code)]
+                    fn __assert_impl<S>() where S: $crate::Storage, $(S: $assert_trait,)+ {
+                        fn assert_impl<T>() where $(T: $assert_trait,)+ {}
+                        assert_impl::<View<S>>();
+                    }
+                )?
+
+                const __HAS_DYNAMIC_FIELDS: bool = $crate::binary_layout!(@is_some $($optional_fields)? $($conditional_fields)?);
+                $(
+                    $crate::binary_layout!(@impl_optional_fields $crate::$endianness, {$crate::internal::unwrap_field_size(__STATIC_SIZE)}, $optional_fields);
+                )?
+                const _: () = assert!(
+                    !($crate::binary_layout!(@is_some $($optional_fields)?) && $crate::binary_layout!(@is_some $($conditional_fields)?)),
+                    "Optional fields can't be combined with conditional fields",
+                );
+                $(
+                    $crate::binary_layout!(@impl_conditional_fields $crate::$endianness, $conditional_fields);
+                )?
+
+                /// Offsets of the fields of this layout in number of bytes, as computed by the compiler.
+                /// The documentation shows the value of each constant.
+                #[allow(non_upper_case_globals)]
+                pub mod field_offsets {
+                    $(
+                        $crate::internal::doc_comment!{
+                            concat!("Offset of the [`", stringify!($field_name), "`](super::", stringify!($field_name), ") field"),
+                            pub const $field_name: usize = <super::$field_name as $crate::Field>::OFFSET;
+                        }
+                    )*
+                }
+
+                /// Sizes of the fields of this layout in number of bytes, as computed by the compiler.
+                /// The documentation shows the value of each constant. Open ended fields don't have a size and are listed with size 0.
+                #[allow(non_upper_case_globals)]
+                pub mod field_sizes {
+                    $(
+                        $crate::internal::doc_comment!{
+                            concat!("Size of the [`", stringify!($field_name), "`](super::", stringify!($field_name), ") field"),
+                            pub const $field_name: usize = $crate::internal::fixed_size_add(0, <super::$field_name as $crate::Field>::SIZE);
+                        }
+                    )*
+                }
+
+                /// A hash of the names, types, offsets, sizes and endianness of the fields of this layout, see [LayoutInfo::schema_hash](crate::LayoutInfo::schema_hash).
+                /// Storing it with persisted data allows detecting data that was written with a different revision of the layout.
+                pub const SCHEMA_HASH: u64 = LAYOUT.schema_hash();
+
+                /// Names of the fields of this layout in the order they were declared, without the full [LAYOUT] reflection data.
+                /// [FIELD_OFFSETS] contains the offset of each of them at the same index.
+                pub const FIELD_NAMES: &[&str] = &[$(
+                    $crate::binary_layout!(@field_name $field_name $($spec_name)?),
+                )*];
+
+                /// Offsets of the fields of this layout in number of bytes, in the order they were declared.
+                /// [FIELD_NAMES] contains the name of each of them at the same index.
+                pub const FIELD_OFFSETS: &[usize] = &[$(
+                    <$field_name as $crate::Field>::OFFSET,
+                )*];
+
+                /// Re-encode the data in `src`, which is stored with this layout, into `dst` with the layout `L`, e.g. `convert_to::<other_layout::NestedView>(src, dst)`.
+                /// The layouts must have the same fields and may only differ in their endianness, see [LayoutInfo::convert_to](crate::LayoutInfo::convert_to).
+                pub fn convert_to<L: $crate::NestedViewInfo>(src: &[u8], dst: &mut [u8]) -> Result<(), $crate::Error> {
+                    LAYOUT.convert_to(L::LAYOUT, src, dst)
+                }
+
+                /// Copy the fields of this layout from `src` into the fields of the layout `L` in `dst` that have the same name and type,
+                /// e.g. `copy_fields_to::<record_v2::NestedView>(src, dst)` to upgrade data to a new revision of a layout, see [LayoutInfo::copy_fields_to](crate::LayoutInfo::copy_fields_to).
+                pub fn copy_fields_to<L: $crate::NestedViewInfo>(src: &[u8], dst: &mut [u8]) -> Result<(), $crate::Error> {
+                    LAYOUT.copy_fields_to(L::LAYOUT, src, dst)
+                }
+
+                /// Iterate over views to the consecutive frames of this layout in `buffer`, e.g. the records of a capture file.
+                /// Bytes at the end that don't make up a whole frame are available through [Frames::remainder]($crate::Frames::remainder).
+                /// This layout must have a fixed size, otherwise calling this panics.
+                #[track_caller]
+                pub fn iter_frames<B: AsRef<[u8]> + ?Sized>(buffer: &B) -> $crate::Frames<'_, NestedView> {
+                    $crate::Frames::new(buffer.as_ref())
+                }
+
+                /// Reflection data describing this layout and its fields, see [LayoutInfo](crate::LayoutInfo).
+                pub const LAYOUT: $crate::LayoutInfo = $crate::LayoutInfo::__new(
+                    stringify!($name),
+                    <$crate::$endianness as $crate::Endianness>::KIND,
+                    SIZE,
+                    FIXED_SIZE,
+                    &[$(
+                        $crate::FieldInfo::__new(
+                            $crate::binary_layout!(@field_name $field_name $($spec_name)?),
+                            concat!(stringify!($field_type) $(, " as ", stringify!($underlying_type))? $(, " where ", stringify!($range))?),
+                            <$field_name as $crate::Field>::OFFSET,
+                            <$field_name as $crate::Field>::SIZE,
+                            <<$field_name as $crate::Field>::Endian as $crate::Endianness>::KIND,
+                            <$field_name as $crate::internal::FieldKindInfo>::KIND,
+                            |storage, f| {
+                                #[allow(unused_imports)]
+                                use $crate::internal::{FormatFieldByteArray as _, FormatFieldBytes as _, FormatFieldValue as _};
+                                (&&&$crate::internal::FieldFormatter::<$field_name>::new()).format_field(storage, f)
+                            },
+                        ),
+                    )*],
+                );
 
                 $crate::internal::doc_comment!{
                     concat!{"
@@ -101,26 +860,108 @@ macro_rules! binary_layout {
                     This view is based on the following layout definition:
                     ```ignore
                     binary_layout!(", stringify!($name), ", ", stringify!($endianness), ", {", $("
-                        ", stringify!($field_name), ": ", stringify!($field_type), $(" as ", stringify!($underlying_type), )? ",",)* "
+                        ", stringify!($field_name), $(" @ ", stringify!($pinned_offset), )? ": ", stringify!($field_type), $(" as ", stringify!($underlying_type), )? $(" = ", stringify!($binding $binding_args), )? $(" where ", stringify!($range), )? ",",)* "
                     });
                     ```
                     "},
-                    pub struct View<S: AsRef<[u8]>> {
+                    #[derive(Clone)]
+                    pub struct View<S: $crate::Storage> {
                         storage: S,
                     }
                 }
-                impl <S: AsRef<[u8]>> View<S> {
+                impl <S: $crate::Storage> View<S> {
                     /// You can create views over a storage by calling [View::new].
                     ///
                     /// `S` is the type of underlying storage. It can be
                     /// - Immutable borrowed storage: `&[u8]`
                     /// - Mutable borrowed storage: `&mut [u8]`
                     /// - Owning storage: impl `AsRef<u8>` (for example: `Vec<u8>`)
+                    /// - Shared storage: `Arc<[u8]>`, which makes cloning the view cheap
+                    /// - Custom storage types implementing [Storage](crate::Storage)
                     #[inline]
                     pub fn new(storage: S) -> Self {
                         Self {storage}
                     }
 
+                    /// Create a view over a storage, checking that the storage is long enough to contain the fields of the layout.
+                    ///
+                    /// Only [FIXED_SIZE] bytes are required, i.e. the storage doesn't need to contain an open ended field or
+                    /// [optional fields](crate::binary_layout!#optional-trailing-fields), which allows reading data written by older revisions of the layout.
+                    /// If the storage is shorter, this returns an error with [ErrorKind::OutOfBounds](crate::ErrorKind::OutOfBounds).
+                    #[inline]
+                    pub fn try_new(storage: S) -> Result<Self, $crate::Error> {
+                        let available = $crate::Storage::as_bytes(&storage).len();
+                        if available < FIXED_SIZE {
+                            return Err($crate::Error::new($crate::ErrorKind::OutOfBounds {
+                                needed: FIXED_SIZE,
+                                available,
+                            }).in_layout(&LAYOUT));
+                        }
+                        Ok(Self {storage})
+                    }
+
+                    /// Create a view over a layout embedded at offset `base` of a larger storage, without slicing the storage first.
+                    ///
+                    /// The returned view is based on a [Data](crate::Data) instance whose [region](crate::Data::region) starts at `base`,
+                    /// so the original storage including the bytes before `base` can be recovered with `view.into_storage().into_inner()`.
+                    ///
+                    /// # Panics
+                    /// Panics if `base` is larger than the storage.
+                    #[inline]
+                    #[track_caller]
+                    pub fn new_at(storage: S, base: usize) -> View<$crate::Data<S>> {
+                        let available = $crate::Storage::as_bytes(&storage).len();
+                        if base > available {
+                            $crate::internal::base_out_of_bounds(LAYOUT.name(), base, available);
+                        }
+                        View::new($crate::Data::from(storage).into_subregion(base..))
+                    }
+
+                    /// Like [View::new_at], but returns an error with [ErrorKind::OutOfBounds](crate::ErrorKind::OutOfBounds) instead
+                    /// if the storage doesn't contain [FIXED_SIZE] bytes starting at `base`.
+                    /// The number of bytes needed and available in the error are counted from the start of the original storage.
+                    #[inline]
+                    pub fn try_new_at(storage: S, base: usize) -> Result<View<$crate::Data<S>>, $crate::Error> {
+                        let available = $crate::Storage::as_bytes(&storage).len();
+                        match base.checked_add(FIXED_SIZE) {
+                            Some(needed) if needed <= available => Ok(View::new($crate::Data::from(storage).into_subregion(base..))),
+                            needed => Err($crate::Error::new($crate::ErrorKind::OutOfBounds {
+                                needed: needed.unwrap_or(usize::MAX),
+                                available,
+                            }).in_layout(&LAYOUT)),
+                        }
+                    }
+
+                    /// Create a view over a storage with a size known at compile time, e.g. `[u8; N]` or `&mut [u8; N]`.
+                    ///
+                    /// This is only available for layouts with a static [SIZE] and fails compilation
+                    /// if the size of the storage differs from [SIZE].
+                    /// Note that this check only happens when the code is compiled, not in `cargo check`.
+                    #[inline]
+                    pub fn new_exact(storage: S) -> Self
+                    where
+                        S: $crate::FixedSizeStorage,
+                    {
+                        #[allow(clippy::let_unit_value)]
+                        let () = ExactSizeCheck::<S>::CHECK;
+                        Self {storage}
+                    }
+
+                    /// Create a view over a layout whose fixed-size part and open ended tail live in two separate storages,
+                    /// e.g. a header in a small stack buffer and a payload in a pooled buffer, without copying them together.
+                    ///
+                    /// `head` should contain the [FIXED_SIZE] bytes of the fixed-size part and `tail` the data of the open ended field at the end of the layout.
+                    /// This is only available for layouts without a static [SIZE] and fails compilation otherwise.
+                    #[inline]
+                    pub fn from_parts<T: $crate::Storage>(head: S, tail: T) -> PartsView<S, T> {
+                        #[allow(clippy::let_unit_value)]
+                        let () = TailCheck::<S>::CHECK;
+                        PartsView {
+                            head: Self {storage: head},
+                            tail,
+                        }
+                    }
+
                     /// This destroys the view and returns the underlying storage back to you.
                     /// This is useful if you created an owning view (e.g. based on `Vec<u8>`)
                     /// and now need the underlying `Vec<u8>` back.
@@ -130,20 +971,343 @@ macro_rules! binary_layout {
                     }
 
                     $crate::binary_layout!(@impl_view_into {$($field_name),*});
+
+                    $crate::binary_layout!(@if_validated [$($view)?] [$($($binding)?)*] {
+                        /// Check the validation checks declared in the layout definition, in the order they were declared,
+                        /// and return an error for the first one that failed. Before them, this checks that the storage contains
+                        /// at least [FIXED_SIZE] bytes and that the [length fields](crate::binary_layout!#length-fields)
+                        /// of the layout match the length of the fields they are bound to.
+                        #[inline]
+                        pub fn validate(&self) -> Result<(), $crate::ValidationError> {
+                            __validate(self)
+                        }
+                    });
+
+                    $crate::binary_layout!(@if_binding checksum, [$($($binding)?)*] {
+                        /// Check that the [checksum fields](crate::binary_layout!#checksum-fields) of this view match its data,
+                        /// e.g. after it was received, and return an error with [ErrorKind::ChecksumMismatch](crate::ErrorKind::ChecksumMismatch)
+                        /// for the first one that doesn't.
+                        #[inline]
+                        pub fn verify(&self) -> Result<(), $crate::Error> {
+                            __verify(self, &[])
+                        }
+
+                        /// Like [View::verify], but the checksums additionally cover the given `pseudo_header`, which is fed into each
+                        /// checksum algorithm before the data of this view. This is needed e.g. for TCP and UDP, whose checksums cover
+                        /// a pseudo header with data from the IP header, see [InternetChecksum](crate::InternetChecksum).
+                        #[inline]
+                        pub fn verify_with_pseudo_header(&self, pseudo_header: &[u8]) -> Result<(), $crate::Error> {
+                            __verify(self, pseudo_header)
+                        }
+                    });
                 }
-                impl <S: AsRef<[u8]>> View<S> {
+                impl <'a> View<$crate::Data<&'a [u8]>> {
+                    /// Convert a view over a subregion of borrowed storage, e.g. a nested view returned from
+                    /// an `into_${field_name}()` accessor, into a view that directly borrows the subregion.
+                    /// This extracts the lifetime: The returned view isn't bound to the outer view or any
+                    /// local object anymore, but to the original storage of the outer view.
+                    #[inline]
+                    pub fn extract(self) -> View<&'a [u8]> {
+                        View::new(self.storage.into_slice())
+                    }
+                }
+                impl <'a> View<$crate::Data<&'a mut [u8]>> {
+                    /// Convert a view over a subregion of mutably borrowed storage, e.g. a nested view returned from
+                    /// an `into_${field_name}()` accessor, into a view that directly borrows the subregion.
+                    /// This extracts the lifetime: The returned view isn't bound to the outer view or any
+                    /// local object anymore, but to the original storage of the outer view.
+                    #[inline]
+                    pub fn extract_mut(self) -> View<&'a mut [u8]> {
+                        View::new(self.storage.into_slice())
+                    }
+                }
+                impl <S: $crate::Storage> View<S> {
                     $crate::binary_layout!(@impl_view_asref {$($field_name),*});
+
+                    /// Split the storage at the start of field `F`, similar to what [slice::split_at] does for slices.
+                    /// This returns a view over the bytes before `F` and the bytes starting at `F`, e.g. the data of an open ended tail field.
+                    ///
+                    /// Only the fields before `F` can be accessed in the returned view. Accessing `F` or any later field will panic.
+                    /// `F` must be a field of this layout, see [LayoutField].
+                    #[inline]
+                    pub fn split_at<F: LayoutField + $crate::Field>(&self) -> (View<&[u8]>, &[u8]) {
+                        let (prefix, rest) = $crate::Storage::as_bytes(&self.storage).split_at(F::OFFSET);
+                        (View::new(prefix), rest)
+                    }
+
+                    /// Read the value of field `F`, e.g. `view.get::<my_layout::field>()`. This is the same as `view.field().read()`,
+                    /// but the field is a type parameter, so that macros and generic helper functions can access fields of a layout.
+                    ///
+                    /// This works for all fields with copy access whose reads can't fail. `F` must be a field of this layout, see [LayoutField].
+                    #[inline]
+                    #[track_caller]
+                    pub fn get<F: LayoutField + $crate::FieldReadExt>(&self) -> F::HighLevelType {
+                        let storage = $crate::Storage::as_bytes(&self.storage);
+                        $crate::internal::check_generic_field_bounds::<F>(storage, &LAYOUT);
+                        F::read(storage)
+                    }
+
+                    /// Like [View::get], but for fields whose reads can fail, e.g. `NonZeroU32` or [range constrained](crate::binary_layout!#range-constrained-fields) fields.
+                    #[inline]
+                    #[track_caller]
+                    pub fn try_get<F: LayoutField + $crate::FieldCopyAccess>(&self) -> Result<F::HighLevelType, F::ReadError> {
+                        let storage = $crate::Storage::as_bytes(&self.storage);
+                        $crate::internal::check_generic_field_bounds::<F>(storage, &LAYOUT);
+                        F::try_read(storage)
+                    }
                 }
-                impl <S: AsRef<[u8]> + AsMut<[u8]>> View<S> {
+                impl <S: $crate::StorageMut> View<S> {
                     $crate::binary_layout!(@impl_view_asmut {$($field_name),*});
-                }
 
-                /// Use this as a marker type for using this layout as a nested field within another layout.
-                ///
-                /// # Example
-                /// ```
-                /// use binary_layout::prelude::*;
-                ///
+                    $crate::binary_layout!(@if_finalized [$($($binding)?)*] {
+                        /// Update the fields of this view that are derived from other data before it is sent or stored,
+                        /// i.e. write the length of the bound fields into the [length fields](crate::binary_layout!#length-fields) of the layout
+                        /// and then recompute its [checksum fields](crate::binary_layout!#checksum-fields) in the order they were declared.
+                        pub fn finalize(&mut self) -> Result<(), $crate::Error> {
+                            self.finalize_with_pseudo_header(&[])
+                        }
+
+                        /// Like [View::finalize], but the checksums additionally cover the given `pseudo_header`, which is fed into each
+                        /// checksum algorithm before the data of this view. This is needed e.g. for TCP and UDP, whose checksums cover
+                        /// a pseudo header with data from the IP header, see [InternetChecksum](crate::InternetChecksum).
+                        #[allow(unused_variables)]
+                        pub fn finalize_with_pseudo_header(&mut self, pseudo_header: &[u8]) -> Result<(), $crate::Error> {
+                            $(
+                                $crate::binary_layout!(@finalize_length self, $field_name, [$($spec_name)?], [$($binding $binding_args)?]);
+                            )*
+                            $(
+                                $crate::binary_layout!(@finalize_checksum self, pseudo_header, $field_name, [$($spec_name)?], [$($binding $binding_args)?]);
+                            )*
+                            Ok(())
+                        }
+                    });
+
+                    /// Split the storage at the start of field `F`, similar to what [slice::split_at_mut] does for slices.
+                    /// This returns a view over the bytes before `F` and the bytes starting at `F`, e.g. the data of an open ended tail field,
+                    /// both with write access. This allows passing the payload of a packet to another parser while still having access to its header.
+                    ///
+                    /// Only the fields before `F` can be accessed in the returned view. Accessing `F` or any later field will panic.
+                    /// `F` must be a field of this layout, see [LayoutField].
+                    #[inline]
+                    pub fn split_at_mut<F: LayoutField + $crate::Field>(&mut self) -> (View<&mut [u8]>, &mut [u8]) {
+                        let (prefix, rest) = $crate::StorageMut::as_bytes_mut(&mut self.storage).split_at_mut(F::OFFSET);
+                        (View::new(prefix), rest)
+                    }
+
+                    /// Write `value` to field `F`, e.g. `view.set::<my_layout::field>(value)`. This is the same as `view.field_mut().write(value)`,
+                    /// but the field is a type parameter, see [View::get].
+                    #[inline]
+                    #[track_caller]
+                    pub fn set<F: LayoutField + $crate::FieldWriteExt>(&mut self, value: F::HighLevelType) {
+                        let storage = $crate::StorageMut::as_bytes_mut(&mut self.storage);
+                        $crate::internal::check_generic_field_bounds::<F>(storage, &LAYOUT);
+                        F::write(storage, value)
+                    }
+
+                    /// Like [View::set], but for fields whose writes can fail, e.g. [range constrained](crate::binary_layout!#range-constrained-fields) fields.
+                    #[inline]
+                    #[track_caller]
+                    pub fn try_set<F: LayoutField + $crate::FieldCopyAccess>(&mut self, value: F::HighLevelType) -> Result<(), F::WriteError> {
+                        let storage = $crate::StorageMut::as_bytes_mut(&mut self.storage);
+                        $crate::internal::check_generic_field_bounds::<F>(storage, &LAYOUT);
+                        F::try_write(storage, value)
+                    }
+
+                    /// Split the view into disjoint views for each of its fields, all with write access.
+                    /// This allows handing different fields to different code paths (or threads) at the same time,
+                    /// similar to what [slice::split_at_mut] does for slices.
+                    #[inline]
+                    pub fn split_mut(&mut self) -> SplitMut<'_> {
+                        let _rest: &mut [u8] = $crate::StorageMut::as_bytes_mut(&mut self.storage);
+                        $(
+                            let ($field_name, _rest) = $crate::internal::split_field_mut::<$field_name>(_rest);
+                        )*
+                        SplitMut {
+                            $($field_name,)*
+                            _p: ::core::marker::PhantomData,
+                        }
+                    }
+                }
+
+                $crate::internal::doc_comment!{
+                    concat!{"
+                    Disjoint views with write access to each field of a [View], returned by [View::split_mut].
+                    The fields of this struct are named like the fields of the layout.
+                    "},
+                    #[allow(non_snake_case)]
+                    pub struct SplitMut<'a> {
+                        $(
+                            #[allow(missing_docs)]
+                            pub $field_name: <<$field_name as $crate::internal::FieldAtOffset<0>>::Moved as $crate::internal::StorageToFieldView<&'a mut [u8]>>::View,
+                        )*
+                        _p: ::core::marker::PhantomData<&'a mut [u8]>,
+                    }
+                }
+
+                $crate::internal::doc_comment!{
+                    concat!{"
+                    A view over a layout whose fixed-size part and open ended tail live in two separate storages, returned by [View::from_parts].
+                    "},
+                    pub struct PartsView<H: $crate::Storage, T: $crate::Storage> {
+                        head: View<H>,
+                        tail: T,
+                    }
+                }
+                impl <H: $crate::Storage, T: $crate::Storage> PartsView<H, T> {
+                    /// A view over the fixed-size part, which gives access to all fields except for the open ended field at the end of the layout.
+                    /// Accessing the open ended field through it will panic, use [PartsView::tail] instead.
+                    #[inline]
+                    pub fn head(&self) -> &View<H> {
+                        &self.head
+                    }
+
+                    /// Like [PartsView::head], but with write access.
+                    #[inline]
+                    pub fn head_mut(&mut self) -> &mut View<H> {
+                        &mut self.head
+                    }
+
+                    /// The data of the open ended field at the end of the layout.
+                    #[inline]
+                    pub fn tail(&self) -> &[u8] {
+                        $crate::Storage::as_bytes(&self.tail)
+                    }
+
+                    /// Like [PartsView::tail], but with write access.
+                    #[inline]
+                    pub fn tail_mut(&mut self) -> &mut [u8]
+                    where
+                        T: $crate::StorageMut,
+                    {
+                        $crate::StorageMut::as_bytes_mut(&mut self.tail)
+                    }
+
+                    /// This destroys the view and returns the view over the fixed-size part and the storage of the tail.
+                    #[inline]
+                    pub fn into_parts(self) -> (View<H>, T) {
+                        (self.head, self.tail)
+                    }
+                }
+
+                $(
+                    $crate::binary_layout!(@impl_variants $body_field, $tag_field, {$($tag_value => $variant($variant_layout)),*});
+                )?
+
+                $(
+                    $crate::binary_layout!(@impl_overlay $overlay_field, {$($overlay_name($overlay_layout)),*});
+                )?
+
+                $crate::binary_layout!(@impl_owned {$($(#[$owned_attr])* $owned_name)?}, {$($field_name [$($spec_name)?]),*});
+
+                impl <S: $crate::ResizableStorage> View<S> {
+                    /// Resize the storage so that the open ended field at the end of the layout, i.e. the data after [FIXED_SIZE],
+                    /// has exactly `new_len` bytes. New bytes are filled with zeroes.
+                    ///
+                    /// This is only available for layouts without a static [SIZE] and fails compilation otherwise.
+                    /// Note that [length fields](crate::binary_layout!#length-fields) are only updated by `View::finalize`.
+                    #[inline]
+                    pub fn resize_tail(&mut self, new_len: usize) {
+                        #[allow(clippy::let_unit_value)]
+                        let () = TailCheck::<S>::CHECK;
+                        $crate::ResizableStorage::resize(&mut self.storage, FIXED_SIZE + new_len);
+                    }
+
+                    /// Resize the storage so that the open ended field at the end of the layout, i.e. the data after [FIXED_SIZE],
+                    /// has exactly the size of `data`, and copy `data` into it.
+                    ///
+                    /// This is only available for layouts without a static [SIZE] and fails compilation otherwise.
+                    /// Note that [length fields](crate::binary_layout!#length-fields) are only updated by `View::finalize`.
+                    #[inline]
+                    pub fn set_tail(&mut self, data: &[u8]) {
+                        self.resize_tail(data.len());
+                        $crate::StorageMut::as_bytes_mut(&mut self.storage)[FIXED_SIZE..].copy_from_slice(data);
+                    }
+                }
+
+                // Implementation of [View::validate]. This is a function instead of a method so that other generated
+                // helpers can use it even for layouts without validation checks, which don't get a `validate` method.
+                #[allow(dead_code)]
+                fn __validate<S: $crate::Storage>(view: &View<S>) -> Result<(), $crate::ValidationError> {
+                    if $crate::Storage::as_bytes(&view.storage).len() < FIXED_SIZE {
+                        return Err($crate::ValidationError::__new(stringify!($name), "len(storage) >= FIXED_SIZE"));
+                    }
+                    $(
+                        $crate::binary_layout!(@validate_length view, $name, $field_name, [$($binding $binding_args)?]);
+                    )*
+                    $(
+                        let $view = view;
+                        $(
+                            if !($check) {
+                                return Err($crate::ValidationError::__new(stringify!($name), stringify!($check)));
+                            }
+                        )*
+                    )?
+                    Ok(())
+                }
+
+                // Implementation of [View::verify]. This is a function for the same reason as `__validate`.
+                #[allow(dead_code, unused_variables)]
+                fn __verify<S: $crate::Storage>(view: &View<S>, pseudo_header: &[u8]) -> Result<(), $crate::Error> {
+                    $(
+                        $crate::binary_layout!(@verify_checksum view, pseudo_header, $field_name, [$($spec_name)?], [$($binding $binding_args)?]);
+                    )*
+                    Ok(())
+                }
+
+                struct TailCheck<S>(::core::marker::PhantomData<S>);
+                impl <S> TailCheck<S> {
+                    const CHECK: () = assert!(
+                        SIZE.is_none(),
+                        "View::resize_tail(), View::set_tail() and View::from_parts() require a layout without a static size",
+                    );
+                }
+
+                /// Implemented for the fields of this layout. The generic accessors of [View], e.g. [View::get] and [View::split_at],
+                /// only accept fields implementing it, so that passing a field of a different layout fails to compile.
+                ///
+                /// Fields are type aliases, so a field of another layout with the same type, endianness and offset as a field
+                /// of this layout is the same type and is accepted. Accessing it is the same as accessing the field of this layout.
+                /// Zero sized fields, e.g. `()` and `[T; 0]`, don't implement it, because zero sized fields following each other have the same type.
+                pub trait LayoutField {}
+
+                // Selects the type to implement [LayoutField] for, which is the field `F` itself, or the placeholder `P` for zero sized fields.
+                // This has to be defined in the crate defining the layout, otherwise the trait impls below would be considered overlapping.
+                trait SelectLayoutField {
+                    type Target: ?Sized;
+                }
+                struct LayoutFieldSelector<F: ?Sized, P, const ZERO_SIZED: bool>(::core::marker::PhantomData<P>, ::core::marker::PhantomData<F>);
+                impl <F: ?Sized, P> SelectLayoutField for LayoutFieldSelector<F, P, false> {
+                    type Target = F;
+                }
+                impl <F: ?Sized, P> SelectLayoutField for LayoutFieldSelector<F, P, true> {
+                    type Target = P;
+                }
+                $(
+                    $crate::internal::paste!{
+                        #[allow(non_camel_case_types)]
+                        struct [<__ $field_name _zero_sized>];
+                        impl LayoutField for <LayoutFieldSelector<
+                            $field_name,
+                            [<__ $field_name _zero_sized>],
+                            {$crate::internal::ZeroSizedField::<$field_name>::VALUE},
+                        > as SelectLayoutField>::Target {}
+                    }
+                )*
+
+                struct ExactSizeCheck<S>(::core::marker::PhantomData<S>);
+                impl <S: $crate::FixedSizeStorage> ExactSizeCheck<S> {
+                    const CHECK: () = assert!(
+                        matches!(SIZE, Some(size) if size == <S as $crate::FixedSizeStorage>::SIZE),
+                        "View::new_exact() requires a layout with a static size and a storage of exactly that size",
+                    );
+                }
+
+                /// Use this as a marker type for using this layout as a nested field within another layout.
+                ///
+                /// # Example
+                /// ```
+                /// use binary_layout::prelude::*;
+                ///
                 /// binary_layout!(icmp_header, BigEndian, {
                 ///   packet_type: u8,
                 ///   code: u8,
@@ -157,7 +1321,7 @@ macro_rules! binary_layout {
                 /// # fn main() {}
                 /// ```
                 pub struct NestedView;
-                impl <S: AsRef<[u8]>> $crate::internal::OwningNestedView<$crate::Data<S>> for NestedView where S: AsRef<[u8]> {
+                impl <S: $crate::Storage> $crate::OwningNestedView<$crate::Data<S>> for NestedView {
                     type View = View<$crate::Data<S>>;
 
                     #[inline(always)]
@@ -165,7 +1329,7 @@ macro_rules! binary_layout {
                         Self::View {storage}
                     }
                 }
-                impl <S: AsRef<[u8]>> $crate::internal::BorrowingNestedView<S> for NestedView {
+                impl <S: $crate::Storage> $crate::BorrowingNestedView<S> for NestedView {
                     type View = View<S>;
 
                     #[inline(always)]
@@ -174,33 +1338,706 @@ macro_rules! binary_layout {
                     }
                 }
 
-                impl $crate::internal::NestedViewInfo for NestedView {
+                impl $crate::NestedViewInfo for NestedView {
                     const SIZE: Option<usize> = SIZE;
+                    const LAYOUT: &'static $crate::LayoutInfo = &LAYOUT;
+                }
+
+                $crate::__binary_layout_impl_alloc!();
+                $crate::__binary_layout_impl_io!();
+                $crate::__binary_layout_impl_async_io!();
+                $crate::__binary_layout_impl_arbitrary!($($field_name),*);
+                $crate::__binary_layout_impl_proptest!();
+                $crate::__binary_layout_impl_zerocopy!();
+                $crate::__binary_layout_impl_binrw!();
+                $crate::__binary_layout_impl_rayon!();
+            }
+        }
+    };
+
+    ($(@vis {$($vis_restriction: tt)*})? $name: ident < $(const $param: ident : $param_type: ty),+ $(,)? >, $endianness: ident, {$($fields: tt)*} $($tail: tt)*) => {
+        $crate::binary_layout!(@impl_template ($) {$($($vis_restriction)*)?} $name, [$($param : $param_type),+], $endianness, {$($fields)*} $($tail)*);
+    };
+    ($(@vis {$($vis_restriction: tt)*})? $name: ident = $($template: ident)::+ < $($value: tt),+ $(,)? >) => {
+        $($template)::+::__binary_layout_template!({$(@vis {$($vis_restriction)*})? $name}, [$($value),+]);
+    };
+    ($(@vis {$($vis_restriction: tt)*})? $name: ident < $param: ident : Endianness >, {$($fields: tt)*} $($tail: tt)*) => {
+        $crate::internal::doc_comment!{
+            concat!("
+            This module is autogenerated. It defines a layout using the [binary_layout] crate that is generic over the endianness `", stringify!($param), "`.
+            The layout is instantiated for each endianness in the [big_endian], [little_endian] and [native_endian] modules,
+            and `View<", stringify!($param), ", S>` is the `View` of the instantiation for endianness `", stringify!($param), "`.
+            "),
+            #[allow(dead_code)]
+            pub $($($vis_restriction)*)? mod $name {
+                #[allow(unused_imports)]
+                use super::*;
+
+                $crate::binary_layout!(pub big_endian, BigEndian, {$($fields)*} $($tail)*);
+                $crate::binary_layout!(pub little_endian, LittleEndian, {$($fields)*} $($tail)*);
+                $crate::binary_layout!(pub native_endian, NativeEndian, {$($fields)*} $($tail)*);
+
+                /// Selects the instantiation of this layout for an endianness, see [View].
+                pub trait SelectView<S: $crate::Storage> {
+                    /// The `View` of the instantiation of this layout for the endianness
+                    type View;
                 }
+                impl<S: $crate::Storage> SelectView<S> for $crate::BigEndian {
+                    type View = big_endian::View<S>;
+                }
+                impl<S: $crate::Storage> SelectView<S> for $crate::LittleEndian {
+                    type View = little_endian::View<S>;
+                }
+                impl<S: $crate::Storage> SelectView<S> for $crate::NativeEndian {
+                    type View = native_endian::View<S>;
+                }
+
+                /// A view over storage `S` using this layout with endianness `E`, e.g. `View<BigEndian, &[u8]>`,
+                /// which is the same type as `big_endian::View<&[u8]>`.
+                pub type View<E, S> = <E as SelectView<S>>::View;
+            }
+        }
+    };
+    (@impl_template ($d: tt) {$($vis_restriction: tt)*} $name: ident, [$($param: ident : $param_type: ty),+], $endianness: ident, {$($fields: tt)*} $($tail: tt)*) => {
+        $crate::internal::doc_comment!{
+            concat!("
+            This module is autogenerated. It defines a layout template using the [binary_layout] crate with the parameters `",
+            $(stringify!($param), ": ", stringify!($param_type), " ",)+ "`.
+            Layouts are instantiated from it with `binary_layout!(<<Name>> = ", stringify!($name), "<<<Values>>>);`.
+            "),
+            #[allow(dead_code)]
+            pub $($vis_restriction)* mod $name {
+                /// Instantiates this layout template, see [binary_layout!](crate::binary_layout!).
+                #[allow(unused_macros)]
+                macro_rules! __binary_layout_template {
+                    ({$d($d __head: tt)*}, [$($d $param: tt),+]) => {
+                        $crate::binary_layout!(@const_params {$($param : $param_type = $d $param),+} $d($d __head)*, $endianness, {$($fields)*} $($tail)*);
+                    };
+                }
+                #[doc(hidden)]
+                #[allow(unused_imports)]
+                pub(crate) use __binary_layout_template;
             }
         }
     };
 
-    (@impl_fields $endianness: ty, $offset_accumulator: expr, {}) => {
+    ($(@const_params $const_params: tt)? $(@vis {$($vis_restriction: tt)*})? $name: ident, $endianness: ident, {$($fields: tt)*} $($tail: tt)*) => {
+        $crate::binary_layout!(@flatten {$(@const_params $const_params)? $(@vis {$($vis_restriction)*})? $name}, $endianness, {}, {$($fields)*}, {$($tail)*}, []);
+    };
+
+    (@flatten {$($head: tt)*}, $endianness: ident, {$($done: tt)*}, {$(,)?}, {$($tail: tt)*}, []) => {
+        compile_error!("Invalid binary_layout! definition");
+    };
+    (@flatten {$($head: tt)*}, $endianness: ident, {$($done: tt)*}, {$(,)?}, {$($tail: tt)*}, [included]) => {
+        $crate::binary_layout!($($head)*, $endianness, {$($done)*} $($tail)*);
+    };
+    (@flatten {$($head: tt)*}, $endianness: ident, {$($done: tt)*}, {include $($included_layout: ident)::+ $(, $($rest: tt)*)?}, {$($tail: tt)*}, [$($included: ident)?]) => {
+        $($included_layout)::+::__binary_layout_fields!(@flatten {$($head)*}, $endianness, {$($done)*}, {$($($rest)*)?}, {$($tail)*});
+    };
+    (@flatten {$($head: tt)*}, $endianness: ident, {$($done: tt)*}, {$(#[name = $spec_name: literal])? $field_name: ident $(@ $pinned_offset: literal)? : $field_type: ty $(as $underlying_type: ty)? $(= $binding: ident $binding_args: tt)? $(where $range: expr)? $(, $($rest: tt)*)?}, {$($tail: tt)*}, [$($included: ident)?]) => {
+        $crate::binary_layout!(@flatten {$($head)*}, $endianness, {$($done)* $(#[name = $spec_name])? $field_name $(@ $pinned_offset)? : $field_type $(as $underlying_type)? $(= $binding $binding_args)? $(where $range)?,}, {$($($rest)*)?}, {$($tail)*}, [$($included)?]);
+    };
+
+    (@if_validated [] [$($binding: ident)*] {$($item: tt)*}) => {
+        $crate::binary_layout!(@if_binding len, [$($binding)*] {$($item)*});
+    };
+    (@if_validated [$validate: ident] [$($binding: ident)*] {$($item: tt)*}) => {
+        $($item)*
+    };
+    (@if_finalized [] {$($item: tt)*}) => {};
+    (@if_finalized [$($binding: ident)+] {$($item: tt)*}) => {
+        $($item)*
+    };
+    (@if_binding $wanted: ident, [] {$($item: tt)*}) => {};
+    (@if_binding len, [len $($binding: ident)*] {$($item: tt)*}) => {
+        $($item)*
+    };
+    (@if_binding checksum, [checksum $($binding: ident)*] {$($item: tt)*}) => {
+        $($item)*
+    };
+    (@if_binding $wanted: ident, [$other: ident $($binding: ident)*] {$($item: tt)*}) => {
+        $crate::binary_layout!(@if_binding $wanted, [$($binding)*] {$($item)*});
+    };
+
+    (@validate_length $self: ident, $name: ident, $field_name: ident, [len ($len_target: ident)]) => {
+        let storage = $crate::Storage::as_bytes(&$self.storage);
+        if !$crate::internal::length_in_bounds::<$field_name, $len_target>(storage)
+            || !$crate::internal::length_matches::<$field_name>(storage, <[u8]>::len($self.$len_target())) {
+            return Err($crate::ValidationError::__new(stringify!($name), concat!(stringify!($field_name), " == len(", stringify!($len_target), ")")));
+        }
+    };
+    (@validate_length $self: ident, $name: ident, $field_name: ident, [$(checksum ($algorithm: path, $checksum_range: expr))?]) => {};
+    (@validate_length $self: ident, $name: ident, $field_name: ident, [$($binding: tt)*]) => {
+        compile_error!(concat!("Invalid binding `", stringify!($($binding)*), "` of field `", stringify!($field_name), "`, expected `len(<<Field>>)` or `checksum(<<Algorithm>>, <<Range>>)`"));
+    };
+
+    (@finalize_length $self: ident, $field_name: ident, [$($spec_name: literal)?], [len ($len_target: ident)]) => {
+        let len = <[u8]>::len($self.$len_target());
+        $crate::ErrorContextExt::field_context(
+            $crate::internal::write_length::<$field_name>($crate::StorageMut::as_bytes_mut(&mut $self.storage), len),
+            &LAYOUT,
+            $crate::binary_layout!(@field_name $field_name $($spec_name)?),
+        )?;
+    };
+    (@finalize_length $self: ident, $field_name: ident, [$($spec_name: literal)?], [$($binding: tt)*]) => {};
+
+    (@finalize_checksum $self: ident, $pseudo_header: ident, $field_name: ident, [$($spec_name: literal)?], [checksum ($algorithm: path, $checksum_range: expr)]) => {
+        $crate::ErrorContextExt::field_context(
+            $crate::internal::write_checksum::<$field_name, $algorithm>($crate::StorageMut::as_bytes_mut(&mut $self.storage), $pseudo_header, $checksum_range),
+            &LAYOUT,
+            $crate::binary_layout!(@field_name $field_name $($spec_name)?),
+        )?;
+    };
+    (@finalize_checksum $self: ident, $pseudo_header: ident, $field_name: ident, [$($spec_name: literal)?], [$($binding: tt)*]) => {};
+
+    (@verify_checksum $self: ident, $pseudo_header: ident, $field_name: ident, [$($spec_name: literal)?], [checksum ($algorithm: path, $checksum_range: expr)]) => {
+        $crate::ErrorContextExt::field_context(
+            $crate::internal::verify_checksum::<$field_name, $algorithm>($crate::Storage::as_bytes(&$self.storage), $pseudo_header, $checksum_range),
+            &LAYOUT,
+            $crate::binary_layout!(@field_name $field_name $($spec_name)?),
+        )?;
+    };
+    (@verify_checksum $self: ident, $pseudo_header: ident, $field_name: ident, [$($spec_name: literal)?], [$($binding: tt)*]) => {};
+
+    (@field_name $field_name: ident) => {
+        stringify!($field_name)
+    };
+    (@field_name $field_name: ident $spec_name: literal) => {
+        $spec_name
+    };
+
+    (@impl_include_macro ($d: tt) $endianness: ident, {$($fields: tt)*}) => {
+        /// Splices the fields of this layout into a layout that includes it, see [binary_layout!](crate::binary_layout!).
+        #[allow(unused_macros)]
+        macro_rules! __binary_layout_fields {
+            (@flatten $d head: tt, $d endianness: ident, {$d($d done: tt)*}, {$d($d rest: tt)*}, {$d($d tail: tt)*}) => {
+                const _: () = assert!(
+                    <$crate::$endianness as $crate::Endianness>::KIND as u8 == <$crate::$d endianness as $crate::Endianness>::KIND as u8,
+                    "Included layouts must have the same endianness as the layout including them",
+                );
+                $crate::binary_layout!(@flatten $d head, $d endianness, {$d($d done)* $($fields)*}, {$d($d rest)*}, {$d($d tail)*}, [included]);
+            };
+        }
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use __binary_layout_fields;
+    };
+
+    (@impl_fields $endianness: ty, $offset_accumulator: expr, $fixed_size_accumulator: expr, {}) => {
+        const __STATIC_SIZE: Option<usize> = $offset_accumulator;
+
         /// Total size of the layout in number of bytes.
-        /// This can be None if the layout ends with an open ended field like a byte slice.
-        pub const SIZE: Option<usize> = $offset_accumulator;
+        /// This can be None if the layout ends with an open ended field like a byte slice or has optional or conditional fields.
+        pub const SIZE: Option<usize> = if __HAS_DYNAMIC_FIELDS { None } else { __STATIC_SIZE };
+
+        /// Size of the fixed-size part of the layout in number of bytes, i.e. the size of the layout
+        /// without its open ended field, optional fields or conditional fields (if it has them). For other layouts, this is equal to [SIZE].
+        pub const FIXED_SIZE: usize = $fixed_size_accumulator;
+    };
+    (@impl_fields $endianness: ty, $offset_accumulator: expr, $fixed_size_accumulator: expr, {$name: ident @ $pinned_offset: literal : $($tail:tt)*}) => {
+        const _: () = assert!(
+            matches!($offset_accumulator, Some(offset) if offset <= $pinned_offset),
+            concat!("Field `", stringify!($name), "` is pinned to offset ", stringify!($pinned_offset), " but overlaps with the fields before it"),
+        );
+        $crate::binary_layout!(@impl_fields $endianness, (Some($pinned_offset)), $fixed_size_accumulator, {$name : $($tail)*});
     };
-    (@impl_fields $endianness: ty, $offset_accumulator: expr, {$name: ident : $type: ty as $underlying_type: ty $(, $($tail:tt)*)?}) => {
+    (@impl_fields $endianness: ty, $offset_accumulator: expr, $fixed_size_accumulator: expr, {$name: ident : $type: ty as $underlying_type: ty where $range: expr $(, $($tail:tt)*)?}) => {
+        $crate::binary_layout!(@impl_value_range $name, $type, $range);
+        $crate::internal::paste!{
+            $crate::internal::doc_comment!{
+                concat!("Metadata and [Field](crate::Field) API accessors for the `", stringify!($name), "` field"),
+                #[allow(non_camel_case_types)]
+                pub type $name = $crate::RangedField::<$crate::WrappedField::<$underlying_type, $type, $crate::PrimitiveField::<$underlying_type, $endianness, {$crate::internal::unwrap_field_size($offset_accumulator)}>>, [<__ $name _range>]>;
+            }
+        }
+        $crate::binary_layout!(@impl_fields $endianness, ($crate::internal::option_usize_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE)), ($crate::internal::fixed_size_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE)), {$($($tail)*)?});
+    };
+    (@impl_fields $endianness: ty, $offset_accumulator: expr, $fixed_size_accumulator: expr, {$name: ident : $type: ty where $range: expr $(, $($tail:tt)*)?}) => {
+        $crate::binary_layout!(@impl_value_range $name, $type, $range);
+        $crate::internal::paste!{
+            $crate::internal::doc_comment!{
+                concat!("Metadata and [Field](crate::Field) API accessors for the `", stringify!($name), "` field"),
+                #[allow(non_camel_case_types)]
+                pub type $name = $crate::RangedField::<$crate::PrimitiveField::<$type, $endianness, {$crate::internal::unwrap_field_size($offset_accumulator)}>, [<__ $name _range>]>;
+            }
+        }
+        $crate::binary_layout!(@impl_fields $endianness, ($crate::internal::option_usize_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE)), ($crate::internal::fixed_size_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE)), {$($($tail)*)?});
+    };
+    (@impl_fields $endianness: ty, $offset_accumulator: expr, $fixed_size_accumulator: expr, {$name: ident : $type: ty as $underlying_type: ty $(, $($tail:tt)*)?}) => {
         $crate::internal::doc_comment!{
             concat!("Metadata and [Field](crate::Field) API accessors for the `", stringify!($name), "` field"),
             #[allow(non_camel_case_types)]
             pub type $name = $crate::WrappedField::<$underlying_type, $type, $crate::PrimitiveField::<$underlying_type, $endianness, {$crate::internal::unwrap_field_size($offset_accumulator)}>>;
         }
-        $crate::binary_layout!(@impl_fields $endianness, ($crate::internal::option_usize_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE)), {$($($tail)*)?});
+        $crate::binary_layout!(@impl_fields $endianness, ($crate::internal::option_usize_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE)), ($crate::internal::fixed_size_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE)), {$($($tail)*)?});
     };
-    (@impl_fields $endianness: ty, $offset_accumulator: expr, {$name: ident : $type: ty $(, $($tail:tt)*)?}) => {
+    (@impl_fields $endianness: ty, $offset_accumulator: expr, $fixed_size_accumulator: expr, {$name: ident : $type: ty $(, $($tail:tt)*)?}) => {
         $crate::internal::doc_comment!{
             concat!("Metadata and [Field](crate::Field) API accessors for the `", stringify!($name), "` field"),
             #[allow(non_camel_case_types)]
             pub type $name = $crate::PrimitiveField::<$type, $endianness, {$crate::internal::unwrap_field_size($offset_accumulator)}>;
         }
-        $crate::binary_layout!(@impl_fields $endianness, ($crate::internal::option_usize_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE)), {$($($tail)*)?});
+        $crate::binary_layout!(@impl_fields $endianness, ($crate::internal::option_usize_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE)), ($crate::internal::fixed_size_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE)), {$($($tail)*)?});
+    };
+
+    (@impl_value_range $name: ident, $type: ty, $range: expr) => {
+        $crate::internal::paste!{
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            pub struct [<__ $name _range>];
+            impl $crate::ValueRange<$type> for [<__ $name _range>] {
+                const RANGE: &'static str = stringify!($range);
+
+                #[inline(always)]
+                fn contains(value: &$type) -> bool {
+                    $crate::internal::RangeBounds::contains(&($range), value)
+                }
+            }
+        }
+    };
+
+    (@impl_variants $body_field: ident, $tag_field: ident, {$($tag_value: expr => $variant: ident ($variant_layout: ident)),*}) => {
+        $crate::internal::doc_comment!{
+            concat!("
+            The alternative layouts of the `", stringify!($body_field), "` field, selected by the value of the `", stringify!($tag_field), "` field.
+            This is returned by [View::variant] and [View::variant_mut].
+            "),
+            pub enum Variant<S: $crate::Storage> {
+                $(
+                    #[allow(missing_docs)]
+                    $variant($variant_layout::View<S>),
+                )*
+                /// The value of the discriminator field doesn't match any of the variants (or couldn't be read, e.g. because the storage is too short).
+                /// This gives access to the raw data of the body field, or the part of it that is contained in the storage.
+                Unknown(S),
+            }
+        }
+
+        impl <S: $crate::Storage> View<S> {
+            $crate::internal::doc_comment!{
+                concat!("
+                Return a view of the `", stringify!($body_field), "` field with read access, interpreted with the layout selected by the value of the `", stringify!($tag_field), "` field.
+                "),
+                #[inline]
+                pub fn variant(&self) -> Variant<&[u8]> {
+                    let storage = $crate::Storage::as_bytes(&self.storage);
+                    if !$crate::internal::field_in_bounds::<$tag_field>(storage) || !$crate::internal::field_in_bounds::<$body_field>(storage) {
+                        return Variant::Unknown(storage.get(<$body_field as $crate::Field>::OFFSET..).unwrap_or_default());
+                    }
+                    let tag = <$tag_field as $crate::FieldCopyAccess>::try_read(storage);
+                    let body: &[u8] = self.$body_field();
+                    match tag {
+                        $(Ok(tag) if tag == $tag_value => Variant::$variant($variant_layout::View::new(body)),)*
+                        _ => Variant::Unknown(body),
+                    }
+                }
+            }
+        }
+
+        impl <S: $crate::StorageMut> View<S> {
+            $crate::internal::paste!{
+                $crate::internal::doc_comment!{
+                    concat!("
+                    Return a view of the `", stringify!($body_field), "` field with write access, interpreted with the layout selected by the value of the `", stringify!($tag_field), "` field.
+                    "),
+                    #[inline]
+                    pub fn variant_mut(&mut self) -> Variant<&mut [u8]> {
+                        let storage = $crate::Storage::as_bytes(&self.storage);
+                        if !$crate::internal::field_in_bounds::<$tag_field>(storage) || !$crate::internal::field_in_bounds::<$body_field>(storage) {
+                            let storage = $crate::StorageMut::as_bytes_mut(&mut self.storage);
+                            return Variant::Unknown(storage.get_mut(<$body_field as $crate::Field>::OFFSET..).unwrap_or_default());
+                        }
+                        let tag = <$tag_field as $crate::FieldCopyAccess>::try_read(storage);
+                        let body: &mut [u8] = self.[<$body_field _mut>]();
+                        match tag {
+                            $(Ok(tag) if tag == $tag_value => Variant::$variant($variant_layout::View::new(body)),)*
+                            _ => Variant::Unknown(body),
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    (@impl_overlay $overlay_field: ident, {$($overlay_name: ident ($overlay_layout: ident)),*}) => {
+        $(
+            const _: () = assert!(
+                match <$overlay_field as $crate::Field>::SIZE {
+                    Some(size) => $overlay_layout::FIXED_SIZE <= size,
+                    None => true,
+                },
+                concat!("Overlay `", stringify!($overlay_name), "` with layout `", stringify!($overlay_layout), "` doesn't fit into the `", stringify!($overlay_field), "` field"),
+            );
+        )*
+
+        impl <S: $crate::Storage> View<S> {
+            $crate::internal::paste!{
+                $(
+                    $crate::internal::doc_comment!{
+                        concat!("Return a view of the `", stringify!($overlay_field), "` field with read access, interpreted with the [", stringify!($overlay_layout), "] layout"),
+                        #[inline]
+                        #[track_caller]
+                        pub fn [<as_ $overlay_name>](&self) -> $overlay_layout::View<&[u8]> {
+                            let body: &[u8] = self.$overlay_field();
+                            $overlay_layout::View::new(body)
+                        }
+                    }
+                )*
+            }
+        }
+
+        impl <S: $crate::StorageMut> View<S> {
+            $crate::internal::paste!{
+                $(
+                    $crate::internal::doc_comment!{
+                        concat!("Return a view of the `", stringify!($overlay_field), "` field with write access, interpreted with the [", stringify!($overlay_layout), "] layout"),
+                        #[inline]
+                        #[track_caller]
+                        pub fn [<as_ $overlay_name _mut>](&mut self) -> $overlay_layout::View<&mut [u8]> {
+                            let body: &mut [u8] = self.[<$overlay_field _mut>]();
+                            $overlay_layout::View::new(body)
+                        }
+                    }
+                )*
+            }
+        }
+    };
+
+    (@impl_owned {}, {$($field_name: ident [$($spec_name: literal)?]),*}) => {};
+    (@impl_owned {$(#[$owned_attr: meta])* $owned_name: ident}, {$($field_name: ident [$($spec_name: literal)?]),*}) => {
+        $crate::internal::doc_comment!{
+            concat!{"
+            An owned copy of the values of all fields of a [View], returned by [View::read_owned] and written back by [View::write_owned].
+            The fields of this struct are named like the fields of the layout and have the types returned by their `read()` accessors.
+            "},
+            $(#[$owned_attr])*
+            pub struct $owned_name {
+                $(
+                    #[allow(missing_docs)]
+                    pub $field_name: <$field_name as $crate::FieldCopyAccess>::HighLevelType,
+                )*
+            }
+        }
+
+        impl <S: $crate::Storage> View<S> {
+            $crate::internal::doc_comment!{
+                concat!(
+                    "Read the values of all fields of this view into a [", stringify!($owned_name), "].\n\n",
+                    "This returns an error with [ErrorKind::OutOfBounds](crate::ErrorKind::OutOfBounds) if the storage is shorter than [FIXED_SIZE], ",
+                    "or the error of the first field whose read failed, annotated with the field it happened in.",
+                ),
+                pub fn read_owned(&self) -> Result<$owned_name, $crate::Error> {
+                    let storage = $crate::Storage::as_bytes(&self.storage);
+                    check_owned_bounds(storage.len())?;
+                    Ok($owned_name {
+                        $(
+                            $field_name: $crate::ErrorContextExt::field_context(
+                                <$field_name as $crate::FieldCopyAccess>::try_read(storage),
+                                &LAYOUT,
+                                $crate::binary_layout!(@field_name $field_name $($spec_name)?),
+                            )?,
+                        )*
+                    })
+                }
+            }
+        }
+
+        impl <S: $crate::StorageMut> View<S> {
+            $crate::internal::doc_comment!{
+                concat!(
+                    "Write the values of all fields of a [", stringify!($owned_name), "] into this view.\n\n",
+                    "This returns an error with [ErrorKind::OutOfBounds](crate::ErrorKind::OutOfBounds) if the storage is shorter than [FIXED_SIZE], ",
+                    "or the error of the first field whose write failed, annotated with the field it happened in. The fields before it have already been written then.",
+                ),
+                pub fn write_owned(&mut self, owned: &$owned_name) -> Result<(), $crate::Error> {
+                    let storage = $crate::StorageMut::as_bytes_mut(&mut self.storage);
+                    check_owned_bounds(storage.len())?;
+                    $(
+                        $crate::ErrorContextExt::field_context(
+                            <$field_name as $crate::FieldCopyAccess>::try_write(storage, ::core::clone::Clone::clone(&owned.$field_name)),
+                            &LAYOUT,
+                            $crate::binary_layout!(@field_name $field_name $($spec_name)?),
+                        )?;
+                    )*
+                    Ok(())
+                }
+            }
+
+            $crate::internal::doc_comment!{
+                concat!(
+                    "Write the values of the given fields of a [", stringify!($owned_name), "] into this view, in the given order, ",
+                    "and leave all other fields untouched, e.g. `view.write_fields(&owned, &[FieldId::field1, FieldId::field2])`. ",
+                    "This allows patching a few fields of a buffer after changing them in a [", stringify!($owned_name), "].\n\n",
+                    "This returns an error with [ErrorKind::OutOfBounds](crate::ErrorKind::OutOfBounds) if the storage is shorter than [FIXED_SIZE], ",
+                    "or the error of the first field whose write failed, annotated with the field it happened in. The fields before it have already been written then.",
+                ),
+                pub fn write_fields(&mut self, owned: &$owned_name, fields: &[FieldId]) -> Result<(), $crate::Error> {
+                    let storage = $crate::StorageMut::as_bytes_mut(&mut self.storage);
+                    check_owned_bounds(storage.len())?;
+                    for field in fields {
+                        match *field {
+                            $(
+                                FieldId::$field_name => $crate::ErrorContextExt::field_context(
+                                    <$field_name as $crate::FieldCopyAccess>::try_write(storage, ::core::clone::Clone::clone(&owned.$field_name)),
+                                    &LAYOUT,
+                                    $crate::binary_layout!(@field_name $field_name $($spec_name)?),
+                                )?,
+                            )*
+                        }
+                    }
+                    Ok(())
+                }
+            }
+        }
+
+        $crate::internal::doc_comment!{
+            concat!{"
+            Identifies a field of the layout, e.g. to select the fields [View::write_fields] writes.
+            The variants are named like the fields of the layout.
+            "},
+            #[allow(non_camel_case_types)]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum FieldId {
+                $(
+                    #[allow(missing_docs)]
+                    $field_name,
+                )*
+            }
+        }
+        impl FieldId {
+            /// The name of the field, as listed in [FIELD_NAMES].
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(
+                        Self::$field_name => $crate::binary_layout!(@field_name $field_name $($spec_name)?),
+                    )*
+                }
+            }
+        }
+
+        $crate::__binary_layout_impl_owned_alloc!($owned_name);
+        $crate::__binary_layout_impl_owned_rayon!($owned_name);
+
+        fn check_owned_bounds(available: usize) -> Result<(), $crate::Error> {
+            if available < FIXED_SIZE {
+                return Err($crate::Error::new($crate::ErrorKind::OutOfBounds {
+                    needed: FIXED_SIZE,
+                    available,
+                }).in_layout(&LAYOUT));
+            }
+            Ok(())
+        }
+    };
+
+    (@is_some) => { false };
+    (@is_some $($something: tt)+) => { true };
+
+    (@impl_optional_fields $endianness: ty, $offset: expr, {}) => {};
+    (@impl_optional_fields $endianness: ty, $offset: expr, {$(#[since = $since: literal])? $name: ident : $type: ty as $underlying_type: ty $(, $($tail:tt)*)?}) => {
+        $crate::binary_layout!(@impl_optional_field $name, [$($since)?], $crate::WrappedField::<$underlying_type, $type, $crate::PrimitiveField::<$underlying_type, $endianness, {$offset}>>);
+        $crate::binary_layout!(@impl_optional_fields $endianness, {$crate::internal::unwrap_field_size($crate::internal::option_usize_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE))}, {$($($tail)*)?});
+    };
+    (@impl_optional_fields $endianness: ty, $offset: expr, {$(#[since = $since: literal])? $name: ident : $type: ty $(, $($tail:tt)*)?}) => {
+        $crate::binary_layout!(@impl_optional_field $name, [$($since)?], $crate::PrimitiveField::<$type, $endianness, {$offset}>);
+        $crate::binary_layout!(@impl_optional_fields $endianness, {$crate::internal::unwrap_field_size($crate::internal::option_usize_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE))}, {$($($tail)*)?});
+    };
+    (@impl_optional_field $name: ident, [$($since: literal)?], $field_type: ty) => {
+        $crate::internal::doc_comment!{
+            concat!("Metadata and [Field](crate::Field) API accessors for the optional `", stringify!($name), "` field", $(", which was added in revision ", $since,)? ""),
+            #[allow(non_camel_case_types)]
+            pub type $name = $field_type;
+        }
+
+        impl <S: $crate::Storage> View<S> {
+            $crate::internal::doc_comment!{
+                concat!("Return a [FieldView](crate::FieldView) with read access to the optional `", stringify!($name), "` field,
+                or `None` if the storage is too short to contain it", $(", e.g. because it was written before revision ", $since, " added the field",)? ""),
+                #[inline]
+                pub fn $name(&self) -> Option<<$name as $crate::internal::StorageToFieldView<&[u8]>>::View> {
+                    let storage = $crate::Storage::as_bytes(&self.storage);
+                    if storage.len() >= <$name as $crate::Field>::OFFSET + <$name as $crate::Field>::SIZE.unwrap_or(0) {
+                        Some(<$name as $crate::internal::StorageToFieldView<&[u8]>>::view(storage))
+                    } else {
+                        None
+                    }
+                }
+            }
+        }
+
+        impl <S: $crate::StorageMut> View<S> {
+            $crate::internal::paste!{
+                $crate::internal::doc_comment!{
+                    concat!("Return a [FieldView](crate::FieldView) with write access to the optional `", stringify!($name), "` field,
+                    or `None` if the storage is too short to contain it", $(", e.g. because it was written before revision ", $since, " added the field",)? ""),
+                    #[inline]
+                    pub fn [<$name _mut>](&mut self) -> Option<<$name as $crate::internal::StorageToFieldView<&mut [u8]>>::View> {
+                        let storage = $crate::StorageMut::as_bytes_mut(&mut self.storage);
+                        if storage.len() >= <$name as $crate::Field>::OFFSET + <$name as $crate::Field>::SIZE.unwrap_or(0) {
+                            Some(<$name as $crate::internal::StorageToFieldView<&mut [u8]>>::view(storage))
+                        } else {
+                            None
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    (@impl_conditional_fields $endianness: ty, {$($fields: tt)*}) => {
+        const _: () = assert!(
+            __STATIC_SIZE.is_some(),
+            "Conditional fields can't be combined with open ended fields",
+        );
+
+        #[inline(always)]
+        fn __fixed_fields_end(_storage: &[u8]) -> usize {
+            FIXED_SIZE
+        }
+
+        $crate::binary_layout!(@impl_conditional_fields $endianness, __fixed_fields_end, {$($fields)*});
+    };
+    (@impl_conditional_fields $endianness: ty, $prev_end: ident, {}) => {
+        impl <S: $crate::Storage> View<S> {
+            /// The size of the data of this view in number of bytes, i.e. [FIXED_SIZE] plus the size of the conditional fields that are present.
+            /// This is the offset of any data following the layout in the storage.
+            #[inline]
+            pub fn dynamic_size(&self) -> usize {
+                $prev_end($crate::Storage::as_bytes(&self.storage))
+            }
+        }
+    };
+    (@impl_conditional_fields $endianness: ty, $prev_end: ident, {$name: ident if $flag: ident & $mask: tt : $type: ty as $underlying_type: ty $(, $($tail:tt)*)?}) => {
+        $crate::binary_layout!(@impl_conditional_field_type $name, $crate::WrappedField::<$underlying_type, $type, $crate::PrimitiveField::<$underlying_type, $endianness, 0>>);
+        $crate::binary_layout!(@impl_conditional_field $prev_end, $name, $flag & $mask);
+        $crate::internal::paste!{
+            $crate::binary_layout!(@impl_conditional_fields $endianness, [<__ $name _end>], {$($($tail)*)?});
+        }
+    };
+    (@impl_conditional_fields $endianness: ty, $prev_end: ident, {$name: ident if $flag: ident & $mask: tt : $type: ty $(, $($tail:tt)*)?}) => {
+        $crate::binary_layout!(@impl_conditional_field_type $name, $crate::PrimitiveField::<$type, $endianness, 0>);
+        $crate::binary_layout!(@impl_conditional_field $prev_end, $name, $flag & $mask);
+        $crate::internal::paste!{
+            $crate::binary_layout!(@impl_conditional_fields $endianness, [<__ $name _end>], {$($($tail)*)?});
+        }
+    };
+    (@impl_conditional_fields $endianness: ty, $prev_end: ident, {$name: ident : $type: ty as $underlying_type: ty $(, $($tail:tt)*)?}) => {
+        $crate::binary_layout!(@impl_conditional_field_type $name, $crate::WrappedField::<$underlying_type, $type, $crate::PrimitiveField::<$underlying_type, $endianness, 0>>);
+        $crate::binary_layout!(@impl_conditional_field $prev_end, $name);
+        $crate::internal::paste!{
+            $crate::binary_layout!(@impl_conditional_fields $endianness, [<__ $name _end>], {$($($tail)*)?});
+        }
+    };
+    (@impl_conditional_fields $endianness: ty, $prev_end: ident, {$name: ident : $type: ty $(, $($tail:tt)*)?}) => {
+        $crate::binary_layout!(@impl_conditional_field_type $name, $crate::PrimitiveField::<$type, $endianness, 0>);
+        $crate::binary_layout!(@impl_conditional_field $prev_end, $name);
+        $crate::internal::paste!{
+            $crate::binary_layout!(@impl_conditional_fields $endianness, [<__ $name _end>], {$($($tail)*)?});
+        }
+    };
+    (@impl_conditional_field_type $name: ident, $field_type: ty) => {
+        $crate::internal::doc_comment!{
+            concat!("Metadata and [Field](crate::Field) API accessors for the conditional `", stringify!($name), "` field.
+            Its offset depends on which of the conditional fields before it are present, so [OFFSET](crate::Field::OFFSET) is always zero.
+            Use the accessors of [View] to access it."),
+            #[allow(non_camel_case_types)]
+            pub type $name = $field_type;
+        }
+        const _: () = assert!(
+            <$name as $crate::Field>::SIZE.is_some(),
+            "Conditional fields must have a static size",
+        );
+    };
+    (@impl_conditional_field $prev_end: ident, $name: ident, $flag: ident & $mask: tt) => {
+        $crate::internal::paste!{
+            #[inline(always)]
+            fn [<__ $name _present>](storage: &[u8]) -> bool {
+                match <$flag as $crate::FieldCopyAccess>::try_read(storage) {
+                    Ok(flags) => (flags & $mask) != 0,
+                    Err(_) => false,
+                }
+            }
+
+            $crate::binary_layout!(@impl_conditional_field_end $prev_end, $name);
+
+            impl <S: $crate::Storage> View<S> {
+                $crate::internal::doc_comment!{
+                    concat!("Return a [FieldView](crate::FieldView) with read access to the conditional `", stringify!($name), "` field,
+                    or `None` if the `", stringify!($flag), "` field doesn't have the `", stringify!($mask), "` bit set."),
+                    #[inline]
+                    pub fn $name(&self) -> Option<<$name as $crate::internal::StorageToFieldView<&[u8]>>::View> {
+                        let storage = $crate::Storage::as_bytes(&self.storage);
+                        if [<__ $name _present>](storage) {
+                            Some(<$name as $crate::internal::StorageToFieldView<&[u8]>>::view(&storage[$prev_end(storage)..]))
+                        } else {
+                            None
+                        }
+                    }
+                }
+            }
+
+            impl <S: $crate::StorageMut> View<S> {
+                $crate::internal::doc_comment!{
+                    concat!("Return a [FieldView](crate::FieldView) with write access to the conditional `", stringify!($name), "` field,
+                    or `None` if the `", stringify!($flag), "` field doesn't have the `", stringify!($mask), "` bit set."),
+                    #[inline]
+                    pub fn [<$name _mut>](&mut self) -> Option<<$name as $crate::internal::StorageToFieldView<&mut [u8]>>::View> {
+                        let storage = $crate::StorageMut::as_bytes_mut(&mut self.storage);
+                        if [<__ $name _present>](storage) {
+                            let offset = $prev_end(storage);
+                            Some(<$name as $crate::internal::StorageToFieldView<&mut [u8]>>::view(&mut storage[offset..]))
+                        } else {
+                            None
+                        }
+                    }
+                }
+            }
+        }
+    };
+    (@impl_conditional_field $prev_end: ident, $name: ident) => {
+        $crate::internal::paste!{
+            #[inline(always)]
+            fn [<__ $name _present>](_storage: &[u8]) -> bool {
+                true
+            }
+
+            $crate::binary_layout!(@impl_conditional_field_end $prev_end, $name);
+
+            impl <S: $crate::Storage> View<S> {
+                $crate::internal::doc_comment!{
+                    concat!("Return a [FieldView](crate::FieldView) with read access to the `", stringify!($name), "` field after the conditional fields"),
+                    #[inline]
+                    pub fn $name(&self) -> <$name as $crate::internal::StorageToFieldView<&[u8]>>::View {
+                        let storage = $crate::Storage::as_bytes(&self.storage);
+                        <$name as $crate::internal::StorageToFieldView<&[u8]>>::view(&storage[$prev_end(storage)..])
+                    }
+                }
+            }
+
+            impl <S: $crate::StorageMut> View<S> {
+                $crate::internal::doc_comment!{
+                    concat!("Return a [FieldView](crate::FieldView) with write access to the `", stringify!($name), "` field after the conditional fields"),
+                    #[inline]
+                    pub fn [<$name _mut>](&mut self) -> <$name as $crate::internal::StorageToFieldView<&mut [u8]>>::View {
+                        let storage = $crate::StorageMut::as_bytes_mut(&mut self.storage);
+                        let offset = $prev_end(storage);
+                        <$name as $crate::internal::StorageToFieldView<&mut [u8]>>::view(&mut storage[offset..])
+                    }
+                }
+            }
+        }
+    };
+    (@impl_conditional_field_end $prev_end: ident, $name: ident) => {
+        $crate::internal::paste!{
+            #[inline(always)]
+            fn [<__ $name _end>](storage: &[u8]) -> usize {
+                let offset = $prev_end(storage);
+                if [<__ $name _present>](storage) {
+                    offset + $crate::internal::unwrap_field_size(<$name as $crate::Field>::SIZE)
+                } else {
+                    offset
+                }
+            }
+        }
     };
 
     (@impl_view_asref {}) => {};
@@ -208,8 +2045,11 @@ macro_rules! binary_layout {
         $crate::internal::doc_comment!{
             concat!("Return a [FieldView](crate::FieldView) with read access to the `", stringify!($name), "` field"),
             #[inline]
+            #[track_caller]
             pub fn $name(&self) -> <$name as $crate::internal::StorageToFieldView<&[u8]>>::View {
-                <$name as $crate::internal::StorageToFieldView<&[u8]>>::view(self.storage.as_ref())
+                let storage = $crate::Storage::as_bytes(&self.storage);
+                $crate::internal::check_field_bounds::<$name>(storage, LAYOUT.name(), stringify!($name));
+                $crate::__binary_layout_trace_view!(<$name as $crate::internal::StorageToFieldView<&[u8]>>::view(storage), $name)
             }
         }
         $crate::binary_layout!(@impl_view_asref {$($name_tail),*});
@@ -221,8 +2061,11 @@ macro_rules! binary_layout {
             $crate::internal::doc_comment!{
                 concat!("Return a [FieldView](crate::FieldView) with write access to the `", stringify!($name), "` field"),
                 #[inline]
+                #[track_caller]
                 pub fn [<$name _mut>](&mut self) -> <$name as $crate::internal::StorageToFieldView<&mut [u8]>>::View {
-                    <$name as $crate::internal::StorageToFieldView<&mut [u8]>>::view(self.storage.as_mut())
+                    let storage = $crate::StorageMut::as_bytes_mut(&mut self.storage);
+                    $crate::internal::check_field_bounds::<$name>(storage, LAYOUT.name(), stringify!($name));
+                    $crate::__binary_layout_trace_view!(<$name as $crate::internal::StorageToFieldView<&mut [u8]>>::view(storage), $name)
                 }
             }
         }
@@ -235,7 +2078,9 @@ macro_rules! binary_layout {
             $crate::internal::doc_comment!{
                 concat!("Destroy the [View] and return a field accessor to the `", stringify!($name), "` field owning the storage. This is mostly useful for [FieldView::extract](crate::FieldView::extract)"),
                 #[inline]
+                #[track_caller]
                 pub fn [<into_ $name>](self) -> <$name as $crate::internal::StorageIntoFieldView<S>>::View {
+                    $crate::internal::check_field_bounds::<$name>($crate::Storage::as_bytes(&self.storage), LAYOUT.name(), stringify!($name));
                     <$name as $crate::internal::StorageIntoFieldView<S>>::into_view(self.storage)
                 }
             }
@@ -244,6 +2089,328 @@ macro_rules! binary_layout {
     };
 }
 
+/// Generates the helpers needing an allocator of a layout module, see [binary_layout!](crate::binary_layout!).
+/// This is a separate macro so that it can depend on the `alloc` feature of this crate.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_alloc {
+    () => {
+        /// Format `storage` as a hexdump annotated with the name, type and decoded value of each field,
+        /// see [LayoutInfo::hexdump]($crate::LayoutInfo::hexdump).
+        pub fn hexdump(storage: &[u8]) -> $crate::internal::String {
+            LAYOUT.hexdump(storage)
+        }
+
+        /// Compare two buffers field by field and return the fields that differ between them,
+        /// see [LayoutInfo::diff]($crate::LayoutInfo::diff).
+        pub fn diff<'a>(a: &'a [u8], b: &'a [u8]) -> impl Iterator<Item = $crate::FieldDiff<'a>> {
+            LAYOUT.diff(a, b)
+        }
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_alloc {
+    () => {};
+}
+
+/// Generates the helpers needing an allocator for the [owned struct](crate::binary_layout!#owned-structs) of a layout.
+/// This is a separate macro so that it can depend on the `alloc` feature of this crate.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_owned_alloc {
+    ($owned_name: ident) => {
+        $crate::internal::doc_comment!{
+            concat!(
+                "Read all consecutive frames of this layout in `buffer` into a [`Vec`] of [", stringify!($owned_name), "], ",
+                "e.g. to load a whole file of records into memory, see [iter_frames].\n\n",
+                "This returns an error with [ErrorKind::OutOfBounds](crate::ErrorKind::OutOfBounds) if the buffer ends with a partial frame, ",
+                "or the error of the first field whose read failed. Use [iter_frames] and [View::read_owned] to handle these cases differently.",
+            ),
+            pub fn decode_all<B: AsRef<[u8]> + ?Sized>(buffer: &B) -> Result<$crate::internal::Vec<$owned_name>, $crate::Error> {
+                let frames = iter_frames(buffer);
+                let remainder = frames.remainder().len();
+                if remainder != 0 {
+                    let available = buffer.as_ref().len();
+                    return Err($crate::Error::new($crate::ErrorKind::OutOfBounds {
+                        needed: available - remainder + FIXED_SIZE,
+                        available,
+                    }).in_layout(&LAYOUT));
+                }
+                frames.map(|frame| frame.read_owned()).collect()
+            }
+        }
+
+        $crate::internal::doc_comment!{
+            concat!(
+                "Append one frame of this layout for each [", stringify!($owned_name), "] in `items` to `buffer`, ",
+                "so that [decode_all] reads them back, e.g. to write a file of records. Capacity for the frames is reserved up front.\n\n",
+                "This returns the error of the first field whose write failed. The buffer is left unchanged then.",
+            ),
+            pub fn encode_all<I>(items: I, buffer: &mut $crate::internal::Vec<u8>) -> Result<(), $crate::Error>
+            where
+                I: IntoIterator,
+                I::Item: ::core::borrow::Borrow<$owned_name>,
+            {
+                let frame_size = $crate::Frames::<NestedView>::frame_size();
+                let items = items.into_iter();
+                let original_len = buffer.len();
+                buffer.reserve(items.size_hint().0.saturating_mul(frame_size));
+                for item in items {
+                    let start = buffer.len();
+                    buffer.resize(start + frame_size, 0);
+                    if let Err(error) = View::new(&mut buffer[start..]).write_owned(::core::borrow::Borrow::borrow(&item)) {
+                        buffer.truncate(original_len);
+                        return Err(error);
+                    }
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_owned_alloc {
+    ($owned_name: ident) => {};
+}
+
+/// Attaches the name of the layout and the field to a field view returned by an accessor of the generated `View`,
+/// so that reads and writes through it emit trace events. This is a separate macro so that it can depend on the `tracing` feature of this crate.
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_trace_view {
+    ($view: expr, $field_name: ident) => {{
+        #[allow(unused_imports)]
+        use $crate::internal::{TraceFieldView as _, TraceOtherView as _};
+        const TRACE: $crate::internal::FieldTrace = $crate::internal::FieldTrace {
+            layout: LAYOUT.name(),
+            field: stringify!($field_name),
+            format: |storage, f| {
+                #[allow(unused_imports)]
+                use $crate::internal::{
+                    FormatFieldByteArray as _, FormatFieldBytes as _, FormatFieldValue as _,
+                };
+                (&&&$crate::internal::FieldFormatter::<
+                    <$field_name as $crate::internal::FieldAtOffset<0>>::Moved,
+                >::new())
+                    .format_field(storage, f)
+            },
+        };
+        let mut view = $view;
+        (&mut view).set_trace(&TRACE);
+        view
+    }};
+}
+
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_trace_view {
+    ($view: expr, $field_name: ident) => {
+        $view
+    };
+}
+
+/// Generates the `std::io` helpers of a layout module, see [binary_layout!](crate::binary_layout!).
+/// This is a separate macro so that it can depend on the `std` feature of this crate.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_io {
+    () => {
+        /// Read exactly [FIXED_SIZE] bytes from `reader` and return a [View] owning them.
+        ///
+        /// If the layout ends with an open ended field, that field will be empty in the returned view.
+        /// Use [read_with_tail_from] to read it as well.
+        pub fn read_from<R: $crate::internal::io::Read>(
+            reader: R,
+        ) -> $crate::internal::io::Result<View<$crate::internal::Vec<u8>>> {
+            read_with_tail_from(reader, 0)
+        }
+
+        /// Read exactly [FIXED_SIZE] + `tail_len` bytes from `reader` and return a [View] owning them.
+        /// The `tail_len` bytes after the fixed-size part of the layout become the data of its open ended field.
+        pub fn read_with_tail_from<R: $crate::internal::io::Read>(
+            mut reader: R,
+            tail_len: usize,
+        ) -> $crate::internal::io::Result<View<$crate::internal::Vec<u8>>> {
+            let mut storage = $crate::internal::Vec::new();
+            storage.resize(FIXED_SIZE + tail_len, 0);
+            reader.read_exact(&mut storage)?;
+            Ok(View::new(storage))
+        }
+
+        impl View<$crate::internal::Vec<u8>> {
+            /// Read exactly `tail_len` more bytes from `reader` and append them to the storage of this view.
+            ///
+            /// Together with [read_from], this allows reading a layout whose tail length is stored in one of its fields:
+            /// read the fixed-size part first, look up the length in it, and then read the tail.
+            /// If reading fails, the storage is left unchanged.
+            pub fn read_tail_from<R: $crate::internal::io::Read>(
+                &mut self,
+                mut reader: R,
+                tail_len: usize,
+            ) -> $crate::internal::io::Result<()> {
+                let len = self.storage.len();
+                self.storage.resize(len + tail_len, 0);
+                let result = reader.read_exact(&mut self.storage[len..]);
+                if result.is_err() {
+                    self.storage.truncate(len);
+                }
+                result
+            }
+        }
+
+        impl<S: $crate::Storage> $crate::ViewIoExt for View<S> {
+            fn write_to<W: $crate::internal::io::Write>(
+                &self,
+                mut writer: W,
+            ) -> $crate::internal::io::Result<()> {
+                let storage = $crate::Storage::as_bytes(&self.storage);
+                let len = SIZE.unwrap_or(storage.len());
+                writer.write_all(&storage[..len])
+            }
+
+            fn as_io_slices(&self) -> [$crate::internal::io::IoSlice<'_>; 2] {
+                let storage = $crate::Storage::as_bytes(&self.storage);
+                let len = SIZE.unwrap_or(storage.len());
+                [
+                    $crate::internal::io::IoSlice::new(&storage[..FIXED_SIZE]),
+                    $crate::internal::io::IoSlice::new(&storage[FIXED_SIZE..len]),
+                ]
+            }
+
+            fn as_io_slices_with_tail<'a>(
+                &'a self,
+                tail: &'a [u8],
+            ) -> [$crate::internal::io::IoSlice<'a>; 2] {
+                let storage = $crate::Storage::as_bytes(&self.storage);
+                [
+                    $crate::internal::io::IoSlice::new(&storage[..FIXED_SIZE]),
+                    $crate::internal::io::IoSlice::new(tail),
+                ]
+            }
+
+            fn write_with_tail_to<W: $crate::internal::io::Write>(
+                &self,
+                writer: W,
+                tail: &[u8],
+            ) -> $crate::internal::io::Result<()> {
+                let storage = $crate::Storage::as_bytes(&self.storage);
+                $crate::internal::write_all_vectored(writer, &storage[..FIXED_SIZE], tail)
+            }
+        }
+
+        impl<H: $crate::Storage, T: $crate::Storage> PartsView<H, T> {
+            /// Return the fixed-size part and the tail of this view as two [IoSlice](std::io::IoSlice)s
+            /// for [Write::write_vectored](std::io::Write::write_vectored), see `ViewIoExt::as_io_slices_with_tail`.
+            pub fn as_io_slices(&self) -> [$crate::internal::io::IoSlice<'_>; 2] {
+                $crate::ViewIoExt::as_io_slices_with_tail(&self.head, self.tail())
+            }
+
+            /// Write the fixed-size part of this view followed by its tail to `writer`, using vectored writes
+            /// so that they don't need to be copied into one buffer first.
+            pub fn write_to<W: $crate::internal::io::Write>(
+                &self,
+                writer: W,
+            ) -> $crate::internal::io::Result<()> {
+                $crate::ViewIoExt::write_with_tail_to(&self.head, writer, self.tail())
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_io {
+    () => {};
+}
+
+/// Generates the async I/O helpers of a layout module, see [binary_layout!](crate::binary_layout!).
+/// This is a separate macro so that it can depend on the `async` feature of this crate.
+#[cfg(feature = "async")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_async_io {
+    () => {
+        /// Read exactly [FIXED_SIZE] bytes from the async `reader` and return a [View] owning them.
+        ///
+        /// If the layout ends with an open ended field, that field will be empty in the returned view.
+        /// Use [read_with_tail_from_async] to read it as well.
+        pub async fn read_from_async<R: $crate::internal::AsyncRead + Unpin>(
+            reader: R,
+        ) -> $crate::internal::io::Result<View<$crate::internal::Vec<u8>>> {
+            read_with_tail_from_async(reader, 0).await
+        }
+
+        /// Read exactly [FIXED_SIZE] + `tail_len` bytes from the async `reader` and return a [View] owning them.
+        /// The `tail_len` bytes after the fixed-size part of the layout become the data of its open ended field.
+        pub async fn read_with_tail_from_async<R: $crate::internal::AsyncRead + Unpin>(
+            mut reader: R,
+            tail_len: usize,
+        ) -> $crate::internal::io::Result<View<$crate::internal::Vec<u8>>> {
+            let mut storage = $crate::internal::Vec::new();
+            storage.resize(FIXED_SIZE + tail_len, 0);
+            $crate::internal::AsyncReadExt::read_exact(&mut reader, &mut storage).await?;
+            Ok(View::new(storage))
+        }
+
+        impl View<$crate::internal::Vec<u8>> {
+            /// Read exactly `tail_len` more bytes from the async `reader` and append them to the storage of this view.
+            ///
+            /// This is the async equivalent of `read_tail_from`. If reading fails, the storage is left unchanged.
+            pub async fn read_tail_from_async<R: $crate::internal::AsyncRead + Unpin>(
+                &mut self,
+                mut reader: R,
+                tail_len: usize,
+            ) -> $crate::internal::io::Result<()> {
+                let len = self.storage.len();
+                self.storage.resize(len + tail_len, 0);
+                let result = $crate::internal::AsyncReadExt::read_exact(
+                    &mut reader,
+                    &mut self.storage[len..],
+                )
+                .await;
+                if result.is_err() {
+                    self.storage.truncate(len);
+                }
+                result.map(|_| ())
+            }
+        }
+
+        impl<S: $crate::Storage> View<S> {
+            /// Write the data of this view to the async `writer`.
+            ///
+            /// For layouts with a static [SIZE], this writes exactly [SIZE] bytes, even if the underlying storage is larger.
+            /// For layouts ending with an open ended field, this writes the whole storage.
+            pub async fn write_to_async<W: $crate::internal::AsyncWrite + Unpin>(
+                &self,
+                mut writer: W,
+            ) -> $crate::internal::io::Result<()> {
+                let storage = $crate::Storage::as_bytes(&self.storage);
+                let len = SIZE.unwrap_or(storage.len());
+                $crate::internal::AsyncWriteExt::write_all(&mut writer, &storage[..len]).await
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "async"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_async_io {
+    () => {};
+}
+
 /// Deprecated name for [crate::binary_layout!]. Please switch to [crate::binary_layout!].
 #[deprecated = "The `define_layout!` macro was renamed to `binary_layout!` and the old name will be removed in future versions."]
 #[macro_export]
@@ -253,32 +2420,313 @@ macro_rules! define_layout {
     }
 }
 
-// TODO This only exists because Option<usize>::unwrap() isn't const. Remove this once it is.
-/// Internal function, don't use!
-/// Unwraps an `Option<usize>`
-#[inline(always)]
-pub const fn unwrap_field_size(opt: Option<usize>) -> usize {
-    match opt {
-        Some(x) => x,
-        None => {
-            panic!("Error: Fields without a static size (e.g. open-ended byte arrays) can only be used at the end of a layout");
+/// Generates the [Arbitrary](arbitrary::Arbitrary) implementation of a layout, see [binary_layout!](crate::binary_layout!).
+/// This is a separate macro so that it can depend on the `arbitrary` feature of this crate.
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_arbitrary {
+    ($($field_name: ident),*) => {
+        impl $crate::internal::ArbitraryLayout for NestedView {
+            fn fill_arbitrary(
+                u: &mut $crate::internal::arbitrary::Unstructured<'_>,
+                storage: &mut [u8],
+            ) -> $crate::internal::arbitrary::Result<()> {
+                #[allow(unused_imports)]
+                use $crate::internal::{FillArbitraryBytes as _, FillArbitraryNested as _, FillArbitraryValue as _};
+                $(
+                    (&&&$crate::internal::FieldFiller::<$field_name>::new()).fill_field(u, storage)?;
+                )*
+                Ok(())
+            }
+
+            fn round_trip(original: &[u8], copy: &mut [u8]) {
+                #[allow(unused_imports)]
+                use $crate::internal::{RoundTripBytes as _, RoundTripNested as _, RoundTripValue as _};
+                $(
+                    (&&&$crate::internal::FieldRoundTrip::<$field_name>::new()).round_trip(LAYOUT.name(), stringify!($field_name), original, copy);
+                )*
+            }
         }
-    }
+
+        impl<'a> $crate::internal::arbitrary::Arbitrary<'a> for View<$crate::internal::Vec<u8>> {
+            fn arbitrary(
+                u: &mut $crate::internal::arbitrary::Unstructured<'a>,
+            ) -> $crate::internal::arbitrary::Result<Self> {
+                $crate::internal::arbitrary_storage::<NestedView>(u).map(View::new)
+            }
+        }
+    };
 }
 
-/// Internal function, don't use!
-#[inline(always)]
-pub const fn option_usize_add(lhs: usize, rhs: Option<usize>) -> Option<usize> {
-    match (lhs, rhs) {
-        (lhs, Some(rhs)) => Some(lhs + rhs),
-        (_, None) => None,
-    }
+#[cfg(not(feature = "arbitrary"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_arbitrary {
+    ($($field_name: ident),*) => {};
+}
+
+/// Generates the proptest strategy of a layout, see [binary_layout!](crate::binary_layout!).
+/// This is a separate macro so that it can depend on the `proptest` feature of this crate.
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_proptest {
+    () => {
+        /// A [proptest](https://docs.rs/proptest) strategy producing buffers with valid data for this layout.
+        /// Layouts ending with an open ended field get a tail of arbitrary length.
+        pub fn storage_strategy(
+        ) -> impl $crate::internal::proptest::strategy::Strategy<Value = $crate::internal::Vec<u8>>
+        {
+            $crate::internal::storage_strategy::<NestedView>()
+        }
+    };
+}
+
+#[cfg(not(feature = "proptest"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_proptest {
+    () => {};
+}
+
+/// Generates the conversions between views and zerocopy types of a layout, see [binary_layout!](crate::binary_layout!).
+/// This is a separate macro so that it can depend on the `zerocopy` feature of this crate.
+#[cfg(feature = "zerocopy")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_zerocopy {
+    () => {
+        impl<S: $crate::Storage> View<S> {
+            /// Reinterpret the data of this view as a reference to the [zerocopy](https://docs.rs/zerocopy) type `T`.
+            ///
+            /// This returns `None` if the storage is too small or isn't aligned correctly for `T`.
+            /// It fails to compile if `T` doesn't have the same size as the layout or if the layout doesn't have native endianness
+            /// or the endianness of the target platform.
+            pub fn as_zerocopy<T>(&self) -> Option<&T>
+            where
+                T: $crate::internal::zerocopy::FromBytes
+                    + $crate::internal::zerocopy::KnownLayout
+                    + $crate::internal::zerocopy::Immutable,
+            {
+                #[allow(clippy::let_unit_value)]
+                let () = $crate::internal::ZerocopyCompat::<NestedView, T>::CHECK;
+                let storage =
+                    $crate::Storage::as_bytes(&self.storage).get(..::core::mem::size_of::<T>())?;
+                T::ref_from_bytes(storage).ok()
+            }
+        }
+
+        impl<S: $crate::StorageMut> View<S> {
+            /// Reinterpret the data of this view as a mutable reference to the [zerocopy](https://docs.rs/zerocopy) type `T`.
+            /// See [View::as_zerocopy].
+            pub fn as_zerocopy_mut<T>(&mut self) -> Option<&mut T>
+            where
+                T: $crate::internal::zerocopy::FromBytes
+                    + $crate::internal::zerocopy::IntoBytes
+                    + $crate::internal::zerocopy::KnownLayout,
+            {
+                #[allow(clippy::let_unit_value)]
+                let () = $crate::internal::ZerocopyCompat::<NestedView, T>::CHECK;
+                let storage = $crate::StorageMut::as_bytes_mut(&mut self.storage)
+                    .get_mut(..::core::mem::size_of::<T>())?;
+                T::mut_from_bytes(storage).ok()
+            }
+        }
+
+        impl<'a> View<&'a [u8]> {
+            /// Create a view over the bytes of a [zerocopy](https://docs.rs/zerocopy) value.
+            ///
+            /// This fails to compile if `T` doesn't have the same size as the layout or if the layout doesn't have native endianness
+            /// or the endianness of the target platform.
+            pub fn from_zerocopy<T>(value: &'a T) -> Self
+            where
+                T: $crate::internal::zerocopy::IntoBytes + $crate::internal::zerocopy::Immutable,
+            {
+                #[allow(clippy::let_unit_value)]
+                let () = $crate::internal::ZerocopyCompat::<NestedView, T>::CHECK;
+                View::new($crate::internal::zerocopy::IntoBytes::as_bytes(value))
+            }
+        }
+
+        impl<'a> View<&'a mut [u8]> {
+            /// Create a view with write access to the bytes of a [zerocopy](https://docs.rs/zerocopy) value.
+            /// See [View::from_zerocopy].
+            pub fn from_zerocopy_mut<T>(value: &'a mut T) -> Self
+            where
+                T: $crate::internal::zerocopy::FromBytes + $crate::internal::zerocopy::IntoBytes,
+            {
+                #[allow(clippy::let_unit_value)]
+                let () = $crate::internal::ZerocopyCompat::<NestedView, T>::CHECK;
+                View::new($crate::internal::zerocopy::IntoBytes::as_mut_bytes(value))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "zerocopy"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_zerocopy {
+    () => {};
+}
+
+/// Generates the binrw implementations of a layout, see [binary_layout!](crate::binary_layout!).
+/// This is a separate macro so that it can depend on the `binrw` feature of this crate.
+#[cfg(feature = "binrw")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_binrw {
+    () => {
+        /// Reads [FIXED_SIZE] bytes plus the number of bytes given as argument for the open ended field (if the layout has one),
+        /// see [read_with_tail_from].
+        impl $crate::internal::binrw::BinRead for View<$crate::internal::Vec<u8>> {
+            type Args<'a> = usize;
+
+            fn read_options<
+                R: $crate::internal::binrw::io::Read + $crate::internal::binrw::io::Seek,
+            >(
+                reader: &mut R,
+                _endian: $crate::internal::binrw::Endian,
+                tail_len: usize,
+            ) -> $crate::internal::binrw::BinResult<Self> {
+                Ok(read_with_tail_from(reader, tail_len)?)
+            }
+        }
+
+        /// Layouts don't depend on the endianness passed by binrw, their fields always use the endianness of the layout.
+        impl $crate::internal::binrw::meta::ReadEndian for View<$crate::internal::Vec<u8>> {
+            const ENDIAN: $crate::internal::binrw::meta::EndianKind =
+                $crate::internal::binrw::meta::EndianKind::None;
+        }
+
+        /// Writes the same bytes as `ViewIoExt::write_to`.
+        impl<S: $crate::Storage> $crate::internal::binrw::BinWrite for View<S> {
+            type Args<'a> = ();
+
+            fn write_options<
+                W: $crate::internal::binrw::io::Write + $crate::internal::binrw::io::Seek,
+            >(
+                &self,
+                writer: &mut W,
+                _endian: $crate::internal::binrw::Endian,
+                _args: (),
+            ) -> $crate::internal::binrw::BinResult<()> {
+                Ok($crate::ViewIoExt::write_to(self, writer)?)
+            }
+        }
+
+        /// Layouts don't depend on the endianness passed by binrw, their fields always use the endianness of the layout.
+        impl<S: $crate::Storage> $crate::internal::binrw::meta::WriteEndian for View<S> {
+            const ENDIAN: $crate::internal::binrw::meta::EndianKind =
+                $crate::internal::binrw::meta::EndianKind::None;
+        }
+    };
+}
+
+#[cfg(not(feature = "binrw"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_binrw {
+    () => {};
+}
+
+/// Generates the rayon-parallel helpers of a layout, see [binary_layout!](crate::binary_layout!).
+/// This is a separate macro so that it can depend on the `rayon` feature of this crate.
+#[cfg(feature = "rayon")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_rayon {
+    () => {
+        /// Check the validation checks and checksums of all consecutive frames of this layout in `buffer` in parallel,
+        /// like calling `View::validate` and `View::verify` for each frame returned by [iter_frames].
+        /// Returns the error of the first frame that failed, or an error with [ErrorKind::OutOfBounds](crate::ErrorKind::OutOfBounds)
+        /// if the buffer ends with a partial frame. This layout must have a fixed size, otherwise calling this panics.
+        pub fn par_validate_all<B: AsRef<[u8]> + ?Sized + Sync>(
+            buffer: &B,
+        ) -> Result<(), $crate::Error> {
+            use $crate::internal::rayon::prelude::*;
+            let buffer = buffer.as_ref();
+            let frame_size = $crate::Frames::<NestedView>::frame_size();
+            check_whole_frames(buffer.len(), frame_size)?;
+            let error = buffer.par_chunks_exact(frame_size).find_map_first(|frame| {
+                let view = View::new(frame);
+                __validate(&view)
+                    .map_err($crate::Error::from)
+                    .and_then(|()| __verify(&view, &[]))
+                    .err()
+            });
+            match error {
+                Some(error) => Err(error),
+                None => Ok(()),
+            }
+        }
+
+        fn check_whole_frames(available: usize, frame_size: usize) -> Result<(), $crate::Error> {
+            let remainder = available % frame_size;
+            if remainder != 0 {
+                return Err($crate::Error::new($crate::ErrorKind::OutOfBounds {
+                    needed: available - remainder + frame_size,
+                    available,
+                })
+                .in_layout(&LAYOUT));
+            }
+            Ok(())
+        }
+    };
+}
+
+#[cfg(not(feature = "rayon"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_rayon {
+    () => {};
+}
+
+/// Generates the rayon-parallel helpers for the [owned struct](crate::binary_layout!#owned-structs) of a layout.
+/// This is a separate macro so that it can depend on the `rayon` feature of this crate.
+#[cfg(feature = "rayon")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_owned_rayon {
+    ($owned_name: ident) => {
+        $crate::internal::doc_comment!{
+            concat!(
+                "Read all consecutive frames of this layout in `buffer` into a [`Vec`] of [", stringify!($owned_name), "] in parallel, ",
+                "the parallel equivalent of [decode_all]. The frames are read on the threads of the global rayon thread pool.\n\n",
+                "This returns an error with [ErrorKind::OutOfBounds](crate::ErrorKind::OutOfBounds) if the buffer ends with a partial frame, ",
+                "or the error of the first frame whose read failed.",
+            ),
+            pub fn par_decode_all<B: AsRef<[u8]> + ?Sized + Sync>(buffer: &B) -> Result<$crate::internal::Vec<$owned_name>, $crate::Error> {
+                use $crate::internal::rayon::prelude::*;
+                let buffer = buffer.as_ref();
+                let frame_size = $crate::Frames::<NestedView>::frame_size();
+                check_whole_frames(buffer.len(), frame_size)?;
+                let decoded: Result<$crate::internal::Vec<$owned_name>, $crate::Error> = buffer
+                    .par_chunks_exact(frame_size)
+                    .map(|frame| View::new(frame).read_owned())
+                    .collect();
+                // Rayon returns the error of any of the failed frames, look up the first one so that the error is deterministic
+                decoded.map_err(|error| {
+                    buffer
+                        .par_chunks_exact(frame_size)
+                        .find_map_first(|frame| View::new(frame).read_owned().err())
+                        .unwrap_or(error)
+                })
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "rayon"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __binary_layout_impl_owned_rayon {
+    ($owned_name: ident) => {};
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::prelude::*;
-
     use rand::{rngs::StdRng, RngCore, SeedableRng};
 
     #[cfg(feature = "std")]
@@ -0,0 +1,48 @@
+/// Generate a round trip test for each of the given layouts. This requires the `arbitrary` feature.
+///
+/// For each layout `my_layout`, this generates a `#[test] fn my_layout_round_trip()`, which creates buffers with
+/// random but valid data for the layout (see the `arbitrary` feature in [Cargo features](crate#cargo-features)) and checks for each of them that
+/// - every field with copy access can be read, written to an empty buffer and read back, resulting in the same bytes,
+/// - the other fields, e.g. byte arrays, are copied and nested layouts are checked field by field,
+/// - the copy is identical to the original buffer, i.e. the fields of the layout cover all of its bytes, and
+/// - the values of integer and floating point fields match the values decoded byte by byte from the buffer with the endianness of the layout.
+///
+/// The random data is deterministic, so the tests are reproducible.
+///
+/// # Example
+/// ```
+/// use binary_layout::{prelude::*, binary_layout_tests};
+///
+/// binary_layout!(header, BigEndian, {
+///   version: u8 where 1..=3,
+///   length: u32,
+/// });
+///
+/// binary_layout!(packet, LittleEndian, {
+///   head: header::NestedView,
+///   checksum: i16,
+///   payload: [u8],
+/// });
+///
+/// #[cfg(test)]
+/// mod tests {
+///   use super::*;
+///
+///   // Generates the `header_round_trip` and `packet_round_trip` tests
+///   binary_layout_tests!(header, packet);
+/// }
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! binary_layout_tests {
+    ($($layout: ident),+ $(,)?) => {
+        $(
+            $crate::internal::paste!{
+                #[test]
+                fn [<$layout _round_trip>]() {
+                    $crate::internal::round_trip_test::<$layout::NestedView>(256);
+                }
+            }
+        )+
+    };
+}
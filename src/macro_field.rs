@@ -0,0 +1,70 @@
+/// Access a field of a view through a dotted path of (possibly nested) field names.
+///
+/// `field!(view.mid.deep.field1)` expands to `view.mid().deep().field1()` and returns the same
+/// [FieldView](crate::FieldView) (or slice or nested view) as that accessor chain would.
+/// For write access, see [field_mut!](crate::field_mut!).
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(deep_layout, LittleEndian, {
+///   field1: u16,
+/// });
+/// binary_layout!(mid_layout, LittleEndian, {
+///   deep: deep_layout::NestedView,
+/// });
+/// binary_layout!(outer_layout, LittleEndian, {
+///   head: u8,
+///   mid: mid_layout::NestedView,
+/// });
+///
+/// # fn main() {
+/// let storage = [0, 5, 0];
+/// let view = outer_layout::View::new(&storage);
+/// assert_eq!(5, field!(view.mid.deep.field1).read());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! field {
+    ($view: ident $(. $path: ident)+) => {
+        $view $(.$path())+
+    };
+}
+
+/// Access a field of a view with write access through a dotted path of (possibly nested) field names.
+///
+/// `field_mut!(view.mid.deep.field1)` expands to `view.mid_mut().deep_mut().field1_mut()` and returns the same
+/// [FieldView](crate::FieldView) (or slice or nested view) as that accessor chain would.
+/// For read access, see [field!](crate::field!).
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(deep_layout, LittleEndian, {
+///   field1: u16,
+/// });
+/// binary_layout!(mid_layout, LittleEndian, {
+///   deep: deep_layout::NestedView,
+/// });
+/// binary_layout!(outer_layout, LittleEndian, {
+///   head: u8,
+///   mid: mid_layout::NestedView,
+/// });
+///
+/// # fn main() {
+/// let mut storage = [0; 3];
+/// let mut view = outer_layout::View::new(&mut storage);
+/// field_mut!(view.mid.deep.field1).write(5);
+/// assert_eq!([0, 5, 0], storage);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! field_mut {
+    ($view: ident $(. $path: ident)+) => {
+        $crate::internal::paste! {
+            $view $(.[<$path _mut>]())+
+        }
+    };
+}
@@ -0,0 +1,121 @@
+/// Define an enum that can be used as the type of a layout field, mapping each variant to a value of a primitive integer type.
+///
+/// The macro defines the enum and implements [LayoutAs](crate::LayoutAs) for it, so that it can be used in a layout with the
+/// `field: MyEnum as u8` notation. Attributes (e.g. `#[derive(...)]`) and doc comments are passed through to the enum and its variants.
+///
+/// By default, the mapping is strict: Reading a value that doesn't belong to any variant throws an
+/// [InvalidEnumValueError](crate::InvalidEnumValueError), so fields of the enum need to be read with [FieldCopyAccess::try_read](crate::FieldCopyAccess::try_read).
+///
+/// Alternatively, the enum can declare a fallback variant with `_ => Unknown(u8)` as its last entry. Values that don't belong to
+/// any other variant are then read as the fallback variant and keep their value, so reading can't fail and [FieldReadExt::read](crate::FieldReadExt::read)
+/// is available. Writing the fallback variant writes the value it carries.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// layout_enum! {
+///     #[derive(Debug, PartialEq, Eq)]
+///     pub enum PacketType: u8 {
+///         EchoReply = 0,
+///         EchoRequest = 8,
+///         _ => Unknown(u8),
+///     }
+/// }
+///
+/// layout_enum! {
+///     #[derive(Debug, PartialEq, Eq)]
+///     pub enum Version: u16 {
+///         V1 = 1,
+///         V2 = 2,
+///     }
+/// }
+///
+/// binary_layout!(packet, BigEndian, {
+///   packet_type: PacketType as u8,
+///   version: Version as u16,
+/// });
+///
+/// # fn main() {
+/// let mut storage = [8, 0, 2];
+/// let mut view = packet::View::new(&mut storage);
+/// assert_eq!(PacketType::EchoRequest, view.packet_type().read());
+/// assert_eq!(Version::V2, view.version().try_read().unwrap());
+///
+/// view.packet_type_mut().write(PacketType::Unknown(42));
+/// view.version_mut().write(Version::V1);
+/// assert_eq!(PacketType::Unknown(42), view.packet_type().read());
+/// assert_eq!([42, 0, 1], storage);
+///
+/// assert!(packet::version::try_read(&[0, 0, 3]).is_err());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! layout_enum {
+    (
+        $(#[$attr: meta])*
+        $vis: vis enum $name: ident : $repr: ty {
+            $($(#[$variant_attr: meta])* $variant: ident = $value: expr),+
+            $(, _ => $(#[$fallback_attr: meta])* $fallback: ident ($fallback_type: ty))? $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis enum $name {
+            $(
+                $(#[$variant_attr])*
+                $variant,
+            )*
+            $(
+                $(#[$fallback_attr])*
+                $fallback($fallback_type),
+            )?
+        }
+
+        $crate::layout_enum!(@impl_layout_as $name, $repr, {$($variant = $value),*} $(, $fallback)?);
+    };
+
+    (@impl_layout_as $name: ident, $repr: ty, {$($variant: ident = $value: expr),*}) => {
+        impl $crate::LayoutAs<$repr> for $name {
+            type ReadError = $crate::InvalidEnumValueError<$repr>;
+            type WriteError = ::core::convert::Infallible;
+
+            fn try_read(v: $repr) -> ::core::result::Result<Self, Self::ReadError> {
+                $(
+                    if v == $value {
+                        return Ok(Self::$variant);
+                    }
+                )*
+                Err($crate::InvalidEnumValueError::__new(v))
+            }
+
+            fn try_write(v: Self) -> ::core::result::Result<$repr, Self::WriteError> {
+                match v {
+                    $(Self::$variant => Ok($value),)*
+                }
+            }
+        }
+    };
+
+    (@impl_layout_as $name: ident, $repr: ty, {$($variant: ident = $value: expr),*}, $fallback: ident) => {
+        impl $crate::LayoutAs<$repr> for $name {
+            type ReadError = ::core::convert::Infallible;
+            type WriteError = ::core::convert::Infallible;
+
+            fn try_read(v: $repr) -> ::core::result::Result<Self, Self::ReadError> {
+                $(
+                    if v == $value {
+                        return Ok(Self::$variant);
+                    }
+                )*
+                Ok(Self::$fallback(v))
+            }
+
+            fn try_write(v: Self) -> ::core::result::Result<$repr, Self::WriteError> {
+                match v {
+                    $(Self::$variant => Ok($value),)*
+                    Self::$fallback(v) => Ok(v),
+                }
+            }
+        }
+    };
+}
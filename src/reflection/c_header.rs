@@ -0,0 +1,192 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use super::{FieldInfo, FieldKind, LayoutInfo};
+use crate::EndianKind;
+
+impl LayoutInfo {
+    /// Generate a C header for this layout.
+    ///
+    /// The header defines a packed C struct with the fields of the layout (and structs for any nested layouts),
+    /// `<LAYOUT>_<FIELD>_OFFSET` and `<LAYOUT>_<FIELD>_SIZE` macros for each field, and `_Static_assert`s that check
+    /// that the C compiler lays out the struct with exactly the same field offsets as this layout.
+    ///
+    /// Note that C struct members are accessed with the endianness of the target platform, so big or little endian fields are
+    /// marked with a comment and need to be converted when accessed on a platform with a different endianness.
+    /// Fields that don't have a C equivalent, e.g. 128 bit integers, are represented as byte arrays.
+    ///
+    /// This is only available with the `c-header` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(icmp_packet, BigEndian, {
+    ///   packet_type: u8,
+    ///   code: u8,
+    ///   checksum: u16,
+    ///   rest_of_header: [u8; 4],
+    ///   data_section: [u8],
+    /// });
+    ///
+    /// let header = icmp_packet::LAYOUT.to_c_header();
+    /// assert!(header.contains("#define ICMP_PACKET_CHECKSUM_OFFSET 2"));
+    /// assert!(header.contains("uint16_t checksum; /* big endian */"));
+    /// assert!(header.contains("uint8_t data_section[];"));
+    /// ```
+    pub fn to_c_header(&self) -> String {
+        let guard = format!("BINARY_LAYOUT_{}_H", self.name.to_uppercase());
+        let mut header = String::new();
+        writeln!(
+            header,
+            "/* Generated by binary-layout from the `{}` layout. Do not edit. */",
+            self.name
+        )
+        .unwrap();
+        writeln!(header, "#ifndef {}", guard).unwrap();
+        writeln!(header, "#define {}", guard).unwrap();
+        writeln!(header).unwrap();
+        writeln!(header, "#include <stddef.h>").unwrap();
+        writeln!(header, "#include <stdint.h>").unwrap();
+
+        let mut defined_structs = Vec::new();
+        self.write_c_struct(&mut header, &mut defined_structs);
+
+        writeln!(header).unwrap();
+        writeln!(header, "#endif /* {} */", guard).unwrap();
+        header
+    }
+
+    fn write_c_struct(&self, header: &mut String, defined_structs: &mut Vec<&'static str>) {
+        if defined_structs.contains(&self.name) {
+            return;
+        }
+        for field in self.fields {
+            if let FieldKind::Nested(nested) = field.kind {
+                nested.write_c_struct(header, defined_structs);
+            }
+        }
+        defined_structs.push(self.name);
+
+        let prefix = self.name.to_uppercase();
+        writeln!(header).unwrap();
+        if let Some(size) = self.size {
+            writeln!(header, "#define {}_SIZE {}", prefix, size).unwrap();
+        }
+        writeln!(header, "#define {}_FIXED_SIZE {}", prefix, self.fixed_size).unwrap();
+        for field in self.fields {
+            let field_prefix = format!("{}_{}", prefix, field.name().to_uppercase());
+            writeln!(header, "#define {}_OFFSET {}", field_prefix, field.offset).unwrap();
+            if let Some(size) = field.size {
+                writeln!(header, "#define {}_SIZE {}", field_prefix, size).unwrap();
+            }
+        }
+
+        writeln!(header).unwrap();
+        writeln!(header, "struct {} {{", self.name).unwrap();
+        for field in self.fields {
+            writeln!(header, "    {}", c_member(field)).unwrap();
+        }
+        writeln!(header, "}} __attribute__((packed));").unwrap();
+
+        writeln!(header).unwrap();
+        for field in self.fields {
+            if field.size != Some(0) {
+                writeln!(
+                    header,
+                    "_Static_assert(offsetof(struct {}, {}) == {}, \"Offset of {}.{} doesn't match the layout\");",
+                    self.name, field.name(), field.offset, self.name, field.name()
+                )
+                .unwrap();
+            }
+        }
+        if let Some(size) = self.size {
+            writeln!(
+                header,
+                "_Static_assert(sizeof(struct {}) == {}, \"Size of {} doesn't match the layout\");",
+                self.name, size, self.name
+            )
+            .unwrap();
+        }
+    }
+}
+
+fn c_member(field: &FieldInfo) -> String {
+    let size = field.size.unwrap_or(0);
+    // Arrays of numbers are declared as C arrays of their element type
+    let (kind, scalar_size) = match field.kind {
+        FieldKind::Array {
+            element,
+            element_size,
+        } => (*element, element_size),
+        kind => (kind, size),
+    };
+    let endianness_comment = match (kind, field.endianness, scalar_size) {
+        (
+            FieldKind::UnsignedInt
+            | FieldKind::SignedInt
+            | FieldKind::Float
+            | FieldKind::Utf16String,
+            EndianKind::Big,
+            2..,
+        ) => " /* big endian */",
+        (
+            FieldKind::UnsignedInt
+            | FieldKind::SignedInt
+            | FieldKind::Float
+            | FieldKind::Utf16String,
+            EndianKind::Little,
+            2..,
+        ) => " /* little endian */",
+        _ => "",
+    };
+    let c_type = match (kind, scalar_size) {
+        (FieldKind::UnsignedInt, 1 | 2 | 4 | 8) => Some(format!("uint{}_t", scalar_size * 8)),
+        (FieldKind::SignedInt, 1 | 2 | 4 | 8) => Some(format!("int{}_t", scalar_size * 8)),
+        (FieldKind::Float, 4) => Some("float".to_string()),
+        (FieldKind::Float, 8) => Some("double".to_string()),
+        (FieldKind::Nested(nested), _) => Some(format!("struct {}", nested.name)),
+        _ => None,
+    };
+    match (c_type, field.kind) {
+        (_, FieldKind::Unit) => format!("/* {}: zero-sized `()` */", field.name()),
+        (Some(c_type), FieldKind::Array { element_size, .. }) => match field.size {
+            Some(size) => format!(
+                "{} {}[{}];{}",
+                c_type,
+                field.name(),
+                size / element_size,
+                endianness_comment
+            ),
+            None => format!("{} {}[];{}", c_type, field.name(), endianness_comment),
+        },
+        (Some(c_type), _) => format!("{} {};{}", c_type, field.name(), endianness_comment),
+        (None, FieldKind::OpenEndedByteArray) => format!("uint8_t {}[];", field.name()),
+        (None, FieldKind::SignedByteArray) => format!("int8_t {}[{}];", field.name(), size),
+        (None, FieldKind::Utf16String) => format!(
+            "uint16_t {}[{}]; /* UTF-16{} */",
+            field.name(),
+            size / 2,
+            endianness_comment
+                .trim_start_matches(" /*")
+                .trim_end_matches(" */"),
+        ),
+        (None, FieldKind::Records(_) | FieldKind::Array { .. }) if field.size.is_none() => {
+            format!("uint8_t {}[]; /* {} */", field.name(), field.type_name)
+        }
+        (None, _) => format!(
+            "uint8_t {}[{}];{}",
+            field.name(),
+            size,
+            if field.kind == FieldKind::ByteArray {
+                String::new()
+            } else {
+                format!(" /* {} */", field.type_name)
+            }
+        ),
+    }
+}
@@ -0,0 +1,239 @@
+use super::{FieldInfo, FieldKind, LayoutInfo};
+use crate::{EndianKind, Error, ErrorKind};
+
+impl LayoutInfo {
+    /// Re-encode the data in `src`, which is stored with this layout, into `dst` with the `target` layout.
+    /// The layouts must have the same structure, i.e. the same fields with the same names, offsets, sizes and kinds,
+    /// and may only differ in the endianness of their fields. This is useful to migrate data between formats that only
+    /// differ in their endianness. The layout generates it as `convert_to::<target_layout::NestedView>(src, dst)`.
+    ///
+    /// Integer, floating point and the code units of [Utf16](crate::Utf16) fields are byte swapped if their endianness differs between the layouts,
+    /// nested layouts are converted recursively, and all other fields are copied. The fields and records of a [Records](crate::Records)
+    /// field must have the same endianness in both layouts because the size of its count isn't part of the reflection data.
+    ///
+    /// Returns an error with [ErrorKind::IncompatibleLayouts] if the layouts differ in anything else than endianness,
+    /// and an error with [ErrorKind::OutOfBounds] if `src` doesn't contain the fixed-size part of the layout or `dst` is shorter than `src`.
+    /// For layouts ending with an open ended field, all of `src` is converted. `dst` can be longer than the converted data,
+    /// the remaining bytes are left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(capture_be, BigEndian, {
+    ///   timestamp: u32,
+    ///   flags: u8,
+    ///   payload: [u8],
+    /// });
+    ///
+    /// binary_layout!(capture_le, LittleEndian, {
+    ///   timestamp: u32,
+    ///   flags: u8,
+    ///   payload: [u8],
+    /// });
+    ///
+    /// let src = [0, 0, 1, 2, 3, 4, 5];
+    /// let mut dst = [0; 7];
+    /// capture_be::convert_to::<capture_le::NestedView>(&src, &mut dst).unwrap();
+    /// assert_eq!([2, 1, 0, 0, 3, 4, 5], dst);
+    /// assert_eq!(0x0102, capture_le::View::new(&dst).timestamp().read());
+    /// ```
+    pub fn convert_to(&self, target: &LayoutInfo, src: &[u8], dst: &mut [u8]) -> Result<(), Error> {
+        if self.fields.len() != target.fields.len() || self.size != target.size {
+            return Err(Error::new(ErrorKind::IncompatibleLayouts).in_layout(target));
+        }
+        if let Some((_, field)) = self
+            .fields
+            .iter()
+            .zip(target.fields)
+            .find(|(field, target_field)| !fields_compatible(field, target_field))
+        {
+            return Err(Error::new(ErrorKind::IncompatibleLayouts).in_field(target, field.name()));
+        }
+        if src.len() < self.fixed_size {
+            return Err(Error::new(ErrorKind::OutOfBounds {
+                needed: self.fixed_size,
+                available: src.len(),
+            })
+            .in_layout(self));
+        }
+        let len = self.size.unwrap_or(src.len());
+        if dst.len() < len {
+            return Err(Error::new(ErrorKind::OutOfBounds {
+                needed: len,
+                available: dst.len(),
+            })
+            .in_layout(target));
+        }
+        let dst = &mut dst[..len];
+        dst.copy_from_slice(&src[..len]);
+        swap_bytes(self, target, dst);
+        Ok(())
+    }
+
+    /// Copy the fields of this layout from `src` into the fields of the `target` layout in `dst` that have the same name and type,
+    /// e.g. to upgrade data from an old revision of a layout to a new one. The layout generates it as `copy_fields_to::<target_layout::NestedView>(src, dst)`.
+    ///
+    /// Fields match if they have the same name, type, size and kind, even if they are at different offsets.
+    /// Integer, floating point and the code units of [Utf16](crate::Utf16) fields are byte swapped if the layouts have a different endianness.
+    /// Fields of `target` that don't have a matching field in this layout are left unchanged.
+    /// For an open ended field, all of its data in `src` is copied to the start of the field in `dst`.
+    ///
+    /// Returns an error with [ErrorKind::OutOfBounds] if `src` or `dst` are too short for a matching field,
+    /// and an error with [ErrorKind::IncompatibleLayouts] for [Records](crate::Records) fields whose counts are stored with a different endianness.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(record_v1, LittleEndian, {
+    ///   id: u32,
+    ///   name: [u8; 8],
+    /// });
+    ///
+    /// binary_layout!(record_v2, LittleEndian, {
+    ///   version: u8,
+    ///   id: u32,
+    ///   name: [u8; 8],
+    ///   flags: u16,
+    /// });
+    ///
+    /// let mut v1 = record_v1::View::new([0; 12]);
+    /// v1.id_mut().write(10);
+    /// v1.name_mut().copy_from_slice(b"binary  ");
+    ///
+    /// let mut storage = [0; 15];
+    /// record_v1::copy_fields_to::<record_v2::NestedView>(&v1.into_storage(), &mut storage).unwrap();
+    /// let mut v2 = record_v2::View::new(storage);
+    /// v2.version_mut().write(2);
+    /// assert_eq!(10, v2.id().read());
+    /// assert_eq!(b"binary  ", v2.name());
+    /// ```
+    pub fn copy_fields_to(
+        &self,
+        target: &LayoutInfo,
+        src: &[u8],
+        dst: &mut [u8],
+    ) -> Result<(), Error> {
+        for target_field in target.fields {
+            let field = match self.field(target_field.name()) {
+                Some(field)
+                    if field.type_name == target_field.type_name
+                        && field.size == target_field.size
+                        && field.kind == target_field.kind =>
+                {
+                    field
+                }
+                _ => continue,
+            };
+            if matches!(field.kind, FieldKind::Records(_))
+                && resolve(field.endianness) != resolve(target_field.endianness)
+            {
+                return Err(Error::new(ErrorKind::IncompatibleLayouts)
+                    .in_field(target, target_field.name()));
+            }
+            let bytes = field.bytes(src).ok_or_else(|| {
+                Error::new(ErrorKind::OutOfBounds {
+                    needed: field.offset + field.size.unwrap_or(0),
+                    available: src.len(),
+                })
+                .in_field(self, field.name())
+            })?;
+            let needed = target_field.offset + bytes.len();
+            let available = dst.len();
+            let target_bytes = dst.get_mut(target_field.offset..needed).ok_or_else(|| {
+                Error::new(ErrorKind::OutOfBounds { needed, available })
+                    .in_field(target, target_field.name())
+            })?;
+            target_bytes.copy_from_slice(bytes);
+            swap_field(field, target_field, target_bytes);
+        }
+        Ok(())
+    }
+}
+
+/// Check that a layout can be converted into `target`, which requires that both have the same structure.
+/// If `same_encoding` is set, their fields additionally need to have the same endianness.
+fn layouts_compatible(layout: &LayoutInfo, target: &LayoutInfo, same_encoding: bool) -> bool {
+    layout.fields.len() == target.fields.len()
+        && layout.size == target.size
+        && layout
+            .fields
+            .iter()
+            .zip(target.fields)
+            .all(|(field, target_field)| {
+                fields_compatible(field, target_field)
+                    && !(same_encoding && needs_swap(field, target_field))
+            })
+}
+
+fn fields_compatible(field: &FieldInfo, target: &FieldInfo) -> bool {
+    let kinds_compatible = match (field.kind, target.kind) {
+        (FieldKind::Nested(layout), FieldKind::Nested(target_layout)) => {
+            layouts_compatible(layout, target_layout, false)
+        }
+        (FieldKind::Records(layout), FieldKind::Records(target_layout)) => {
+            resolve(field.endianness) == resolve(target.endianness)
+                && layouts_compatible(layout, target_layout, true)
+        }
+        (FieldKind::Nested(_) | FieldKind::Records(_), _) => false,
+        (kind, target_kind) => kind == target_kind,
+    };
+    kinds_compatible
+        && field.name() == target.name()
+        && field.offset == target.offset
+        && field.size == target.size
+}
+
+fn needs_swap(field: &FieldInfo, target: &FieldInfo) -> bool {
+    match field.kind {
+        FieldKind::UnsignedInt
+        | FieldKind::SignedInt
+        | FieldKind::Float
+        | FieldKind::Utf16String => resolve(field.endianness) != resolve(target.endianness),
+        FieldKind::Array { element_size, .. } => {
+            element_size > 1 && resolve(field.endianness) != resolve(target.endianness)
+        }
+        FieldKind::Nested(layout) => match target.kind {
+            FieldKind::Nested(target_layout) => !layouts_compatible(layout, target_layout, true),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Swap the bytes of the fields in `storage`, which already contains a copy of the data, whose endianness differs between the layouts
+fn swap_bytes(layout: &LayoutInfo, target: &LayoutInfo, storage: &mut [u8]) {
+    for (field, target_field) in layout.fields.iter().zip(target.fields) {
+        let bytes = match field.size {
+            Some(size) => &mut storage[field.offset..field.offset + size],
+            None => &mut storage[field.offset..],
+        };
+        swap_field(field, target_field, bytes);
+    }
+}
+
+/// Swap the bytes of a field in `bytes`, which already contains a copy of its data, if its endianness differs between the layouts
+fn swap_field(field: &FieldInfo, target: &FieldInfo, bytes: &mut [u8]) {
+    if !needs_swap(field, target) {
+        return;
+    }
+    match (field.kind, target.kind) {
+        (FieldKind::Nested(layout), FieldKind::Nested(target_layout)) => {
+            swap_bytes(layout, target_layout, bytes)
+        }
+        (FieldKind::Utf16String, _) => bytes.chunks_exact_mut(2).for_each(<[u8]>::reverse),
+        (FieldKind::Array { element_size, .. }, _) => bytes
+            .chunks_exact_mut(element_size)
+            .for_each(<[u8]>::reverse),
+        _ => bytes.reverse(),
+    }
+}
+
+fn resolve(endianness: EndianKind) -> EndianKind {
+    match endianness {
+        EndianKind::Native if cfg!(target_endian = "little") => EndianKind::Little,
+        EndianKind::Native => EndianKind::Big,
+        endianness => endianness,
+    }
+}
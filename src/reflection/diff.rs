@@ -0,0 +1,101 @@
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{self, Debug, Display, Formatter};
+
+use super::{FieldInfo, FieldKind, FieldValue, LayoutInfo};
+
+/// A field that differs between two buffers, see [LayoutInfo::diff].
+pub struct FieldDiff<'a> {
+    path: String,
+    field: &'static FieldInfo,
+    old: FieldValue<'a>,
+    new: FieldValue<'a>,
+}
+
+impl<'a> FieldDiff<'a> {
+    /// The name of the field. Fields of nested layouts are named by their dotted path, e.g. `header.version`.
+    pub fn name(&self) -> &str {
+        &self.path
+    }
+
+    /// Reflection data of the field
+    pub fn field(&self) -> &'static FieldInfo {
+        self.field
+    }
+
+    /// The value of the field in the first buffer
+    pub fn old_value(&self) -> &FieldValue<'a> {
+        &self.old
+    }
+
+    /// The value of the field in the second buffer
+    pub fn new_value(&self) -> &FieldValue<'a> {
+        &self.new
+    }
+}
+
+impl Display for FieldDiff<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} -> {}", self.path, self.old, self.new)
+    }
+}
+
+impl Debug for FieldDiff<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl LayoutInfo {
+    /// Compare two buffers field by field and return the fields that differ between them, in the order of the layout.
+    ///
+    /// Nested layouts are compared field by field as well. A field is considered different if its bytes differ,
+    /// or if only one of the buffers is long enough to contain it.
+    ///
+    /// This is only available with the `alloc` feature. The layout module generated by [binary_layout!](crate::binary_layout!)
+    /// offers the same function as `diff(a, b)`.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///   field1: u16,
+    ///   field2: u32,
+    ///   field3: [u8; 2],
+    /// });
+    ///
+    /// let a = [1, 0, 2, 0, 0, 0, 3, 4];
+    /// let b = [1, 0, 5, 0, 0, 0, 3, 6];
+    /// let differences: Vec<String> = my_layout::diff(&a, &b).map(|diff| diff.to_string()).collect();
+    /// assert_eq!(vec!["field2: 2 -> 5", "field3: [03 04] -> [03 06]"], differences);
+    /// ```
+    pub fn diff<'a>(&self, a: &'a [u8], b: &'a [u8]) -> impl Iterator<Item = FieldDiff<'a>> {
+        let mut differences = Vec::new();
+        self.collect_differences(&mut differences, a, b, "");
+        differences.into_iter()
+    }
+
+    fn collect_differences<'a>(
+        &self,
+        differences: &mut Vec<FieldDiff<'a>>,
+        a: &'a [u8],
+        b: &'a [u8],
+        prefix: &str,
+    ) {
+        for field in self.fields {
+            let path = format!("{}{}", prefix, field.name());
+            match (field.kind, field.bytes(a), field.bytes(b)) {
+                (_, old, new) if old == new => {}
+                (FieldKind::Nested(layout), Some(old), Some(new)) => {
+                    layout.collect_differences(differences, old, new, &format!("{}.", path))
+                }
+                _ => differences.push(FieldDiff {
+                    path,
+                    field,
+                    old: field.value(a),
+                    new: field.value(b),
+                }),
+            }
+        }
+    }
+}
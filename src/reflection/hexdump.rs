@@ -0,0 +1,119 @@
+use alloc::{format, string::String};
+use core::fmt::Write;
+
+use super::{FieldKind, LayoutInfo};
+
+const BYTES_PER_LINE: usize = 16;
+
+impl LayoutInfo {
+    /// Format the given storage as a hexdump, annotating each field with its name, type and decoded value.
+    ///
+    /// Each line starts with the offset of its first byte, followed by up to 16 bytes in hex. Fields longer than that are
+    /// continued on the following lines. Fields of nested layouts are listed individually with a dotted path, e.g. `header.version`.
+    /// Bytes after the end of a layout with a static size are shown as trailing bytes, and fields the storage is too short for
+    /// are marked as out of bounds.
+    ///
+    /// This is only available with the `alloc` feature. The layout module generated by [binary_layout!](crate::binary_layout!)
+    /// offers the same function as `hexdump(storage)`.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(my_layout, BigEndian, {
+    ///   field1: u16,
+    ///   field2: [u8],
+    /// });
+    ///
+    /// assert_eq!(
+    ///   concat!(
+    ///     "00000000  00 05                                            field1 (u16) = 5\n",
+    ///     "00000002  61 62 63                                         field2 ([u8])\n",
+    ///   ),
+    ///   my_layout::hexdump(b"\x00\x05abc"),
+    /// );
+    /// ```
+    pub fn hexdump(&self, storage: &[u8]) -> String {
+        let mut hexdump = String::new();
+        self.write_hexdump(&mut hexdump, storage, 0, "");
+        if let Some(size) = self.size {
+            if let Some(trailing) = storage.get(size..) {
+                if !trailing.is_empty() {
+                    write_lines(&mut hexdump, size, trailing, "<trailing bytes>");
+                }
+            }
+        }
+        hexdump
+    }
+
+    fn write_hexdump(
+        &self,
+        hexdump: &mut String,
+        storage: &[u8],
+        base_offset: usize,
+        prefix: &str,
+    ) {
+        for field in self.fields {
+            let name = format!("{}{}", prefix, field.name());
+            let offset = base_offset + field.offset;
+            match field.bytes(storage) {
+                None => write_lines(
+                    hexdump,
+                    offset,
+                    &[],
+                    &format!("{} ({}) <out of bounds>", name, field.type_name),
+                ),
+                Some(bytes) => match field.kind {
+                    FieldKind::Nested(layout) => {
+                        layout.write_hexdump(hexdump, bytes, offset, &format!("{}.", name))
+                    }
+                    FieldKind::ByteArray
+                    | FieldKind::SignedByteArray
+                    | FieldKind::Utf16String
+                    | FieldKind::OpenEndedByteArray
+                    | FieldKind::VarBytes
+                    | FieldKind::Records(_) => write_lines(
+                        hexdump,
+                        offset,
+                        bytes,
+                        &format!("{} ({})", name, field.type_name),
+                    ),
+                    _ => write_lines(
+                        hexdump,
+                        offset,
+                        bytes,
+                        &format!("{} ({}) = {}", name, field.type_name, field.value(storage)),
+                    ),
+                },
+            }
+        }
+    }
+}
+
+/// Writes `bytes` in lines of [BYTES_PER_LINE] bytes, with the annotation on the first line.
+/// Writes a single line if `bytes` is empty.
+fn write_lines(hexdump: &mut String, offset: usize, bytes: &[u8], annotation: &str) {
+    let mut chunks = bytes.chunks(BYTES_PER_LINE);
+    let first_chunk = chunks.next().unwrap_or(&[]);
+    write_line(hexdump, offset, first_chunk, annotation);
+    for (index, chunk) in chunks.enumerate() {
+        write_line(hexdump, offset + (index + 1) * BYTES_PER_LINE, chunk, "");
+    }
+}
+
+fn write_line(hexdump: &mut String, offset: usize, bytes: &[u8], annotation: &str) {
+    write!(hexdump, "{:08x} ", offset).unwrap();
+    for index in 0..BYTES_PER_LINE {
+        match bytes.get(index) {
+            Some(byte) => write!(hexdump, " {:02x}", byte).unwrap(),
+            None => hexdump.push_str("   "),
+        }
+    }
+    if annotation.is_empty() {
+        // Don't leave trailing whitespace on continuation lines
+        hexdump.truncate(hexdump.trim_end().len());
+    } else {
+        write!(hexdump, "  {}", annotation).unwrap();
+    }
+    hexdump.push('\n');
+}
@@ -0,0 +1,128 @@
+use alloc::string::String;
+use core::fmt::Write;
+
+use super::{FieldInfo, FieldKind, LayoutInfo};
+use crate::EndianKind;
+
+impl LayoutInfo {
+    /// Serialize the field table of this layout to JSON, so that tools written in other languages can use the same offsets.
+    ///
+    /// The JSON object has the keys `name`, `endianness` (`"big"`, `"little"` or `"native"`), `size` (`null` for layouts
+    /// ending with an open ended field), `fixed_size` and `fields`. Each field has the keys `name`, `type`, `offset`, `size`,
+    /// `endianness` and `kind` (`"unsigned_int"`, `"signed_int"`, `"float"`, `"unit"`, `"byte_array"`, `"open_ended_byte_array"`,
+    /// `"var_bytes"`, `"nested"` or `"records"`). Nested fields and records additionally have a `layout` key containing the JSON object
+    /// of the nested layout.
+    ///
+    /// This is only available with the `json` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///   field1: u16,
+    ///   field2: [u8],
+    /// });
+    ///
+    /// assert_eq!(
+    ///   concat!(
+    ///     r#"{"name":"my_layout","endianness":"little","size":null,"fixed_size":2,"fields":["#,
+    ///     r#"{"name":"field1","type":"u16","offset":0,"size":2,"endianness":"little","kind":"unsigned_int"},"#,
+    ///     r#"{"name":"field2","type":"[u8]","offset":2,"size":null,"endianness":"little","kind":"open_ended_byte_array"}"#,
+    ///     r#"]}"#,
+    ///   ),
+    ///   my_layout::LAYOUT.to_json(),
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json);
+        json
+    }
+
+    fn write_json(&self, json: &mut String) {
+        json.push_str("{\"name\":");
+        write_json_string(json, self.name);
+        write!(
+            json,
+            ",\"endianness\":\"{}\",\"size\":",
+            endianness_name(self.endianness)
+        )
+        .unwrap();
+        write_json_size(json, self.size);
+        write!(json, ",\"fixed_size\":{},\"fields\":[", self.fixed_size).unwrap();
+        for (index, field) in self.fields.iter().enumerate() {
+            if index != 0 {
+                json.push(',');
+            }
+            write_field_json(json, field);
+        }
+        json.push_str("]}");
+    }
+}
+
+fn write_field_json(json: &mut String, field: &FieldInfo) {
+    json.push_str("{\"name\":");
+    write_json_string(json, field.name());
+    json.push_str(",\"type\":");
+    write_json_string(json, field.type_name);
+    write!(json, ",\"offset\":{},\"size\":", field.offset).unwrap();
+    write_json_size(json, field.size);
+    write!(
+        json,
+        ",\"endianness\":\"{}\",\"kind\":\"{}\"",
+        endianness_name(field.endianness),
+        kind_name(field.kind)
+    )
+    .unwrap();
+    if let FieldKind::Nested(layout) | FieldKind::Records(layout) = field.kind {
+        json.push_str(",\"layout\":");
+        layout.write_json(json);
+    }
+    json.push('}');
+}
+
+fn write_json_size(json: &mut String, size: Option<usize>) {
+    match size {
+        Some(size) => write!(json, "{}", size).unwrap(),
+        None => json.push_str("null"),
+    }
+}
+
+fn write_json_string(json: &mut String, value: &str) {
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if (c as u32) < 0x20 => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+fn endianness_name(endianness: EndianKind) -> &'static str {
+    match endianness {
+        EndianKind::Big => "big",
+        EndianKind::Little => "little",
+        EndianKind::Native => "native",
+    }
+}
+
+fn kind_name(kind: FieldKind) -> &'static str {
+    match kind {
+        FieldKind::UnsignedInt => "unsigned_int",
+        FieldKind::SignedInt => "signed_int",
+        FieldKind::Float => "float",
+        FieldKind::Unit => "unit",
+        FieldKind::ByteArray => "byte_array",
+        FieldKind::SignedByteArray => "signed_byte_array",
+        FieldKind::Utf16String => "utf16_string",
+        FieldKind::OpenEndedByteArray => "open_ended_byte_array",
+        FieldKind::VarBytes => "var_bytes",
+        FieldKind::Nested(_) => "nested",
+        FieldKind::Records(_) => "records",
+        FieldKind::Array { .. } => "array",
+    }
+}
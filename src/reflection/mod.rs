@@ -0,0 +1,402 @@
+use core::fmt::{self, Debug, Display, Formatter};
+use core::marker::PhantomData;
+
+use crate::{EndianKind, Endianness, Field, FieldCopyAccess, PrimitiveField};
+
+#[cfg(feature = "c-header")]
+mod c_header;
+mod convert;
+#[cfg(feature = "alloc")]
+mod diff;
+#[cfg(feature = "alloc")]
+mod hexdump;
+
+#[cfg(feature = "alloc")]
+pub use diff::FieldDiff;
+#[cfg(feature = "json")]
+mod json;
+mod schema_hash;
+
+/// Reflection data describing a layout defined with [binary_layout!](crate::binary_layout!).
+///
+/// The macro generates a `LAYOUT` constant with this information for each layout.
+/// It allows tools (e.g. test harnesses, debuggers or code generators for other languages) to enumerate the fields of a layout at runtime.
+///
+/// # Example
+/// ```
+/// use binary_layout::{prelude::*, FieldKind};
+///
+/// binary_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: [u8; 4],
+/// });
+///
+/// assert_eq!("my_layout", my_layout::LAYOUT.name());
+/// assert_eq!(Some(6), my_layout::LAYOUT.size());
+///
+/// let field2 = &my_layout::LAYOUT.fields()[1];
+/// assert_eq!("field2", field2.name());
+/// assert_eq!(2, field2.offset());
+/// assert_eq!(Some(4), field2.size());
+/// assert_eq!("[u8; 4]", field2.type_name());
+/// assert_eq!(FieldKind::ByteArray, field2.kind());
+///
+/// let storage = [1, 0, 2, 3, 4, 5];
+/// assert_eq!("1", my_layout::LAYOUT.fields()[0].value(&storage).to_string());
+/// ```
+#[derive(Debug)]
+pub struct LayoutInfo {
+    name: &'static str,
+    endianness: EndianKind,
+    size: Option<usize>,
+    fixed_size: usize,
+    fields: &'static [FieldInfo],
+}
+
+impl LayoutInfo {
+    #[doc(hidden)]
+    pub const fn __new(
+        name: &'static str,
+        endianness: EndianKind,
+        size: Option<usize>,
+        fixed_size: usize,
+        fields: &'static [FieldInfo],
+    ) -> Self {
+        Self {
+            name,
+            endianness,
+            size,
+            fixed_size,
+            fields,
+        }
+    }
+
+    /// The name of the layout
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The endianness the layout was defined with
+    pub const fn endianness(&self) -> EndianKind {
+        self.endianness
+    }
+
+    /// Total size of the layout in number of bytes.
+    /// This is `None` if the layout ends with an open ended field like a byte slice.
+    pub const fn size(&self) -> Option<usize> {
+        self.size
+    }
+
+    /// Size of the fixed-size part of the layout in number of bytes, i.e. the size without its open ended field (if it has one).
+    pub const fn fixed_size(&self) -> usize {
+        self.fixed_size
+    }
+
+    /// The fields of the layout, in the order they were defined in
+    pub const fn fields(&self) -> &'static [FieldInfo] {
+        self.fields
+    }
+
+    /// Look up a field by its name
+    pub fn field(&self, name: &str) -> Option<&'static FieldInfo> {
+        self.fields.iter().find(|field| field.name() == name)
+    }
+
+    /// Returns an object that formats the values of all fields of the layout in the given storage
+    /// with [Display] and [Debug], e.g. as `my_layout { field1: 1, field2: [02 03 04 05] }`.
+    pub fn value<'a>(&'a self, storage: &'a [u8]) -> LayoutValue<'a> {
+        LayoutValue {
+            layout: self,
+            storage,
+        }
+    }
+}
+
+/// Reflection data describing one field of a layout, see [LayoutInfo].
+pub struct FieldInfo {
+    name: &'static str,
+    type_name: &'static str,
+    offset: usize,
+    size: Option<usize>,
+    endianness: EndianKind,
+    kind: FieldKind,
+    format_value: fn(&[u8], &mut Formatter<'_>) -> fmt::Result,
+}
+
+impl FieldInfo {
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn __new(
+        name: &'static str,
+        type_name: &'static str,
+        offset: usize,
+        size: Option<usize>,
+        endianness: EndianKind,
+        kind: FieldKind,
+        format_value: fn(&[u8], &mut Formatter<'_>) -> fmt::Result,
+    ) -> Self {
+        let endianness = match kind {
+            FieldKind::Nested(layout) => layout.endianness,
+            _ => endianness,
+        };
+        Self {
+            name,
+            type_name,
+            offset,
+            size,
+            endianness,
+            kind,
+            format_value,
+        }
+    }
+
+    /// The name of the field. This is the name given with a `#[name = "..."]` attribute in the layout definition if there is one.
+    /// Otherwise, it is the identifier of the field, without the `r#` prefix of raw identifiers.
+    pub fn name(&self) -> &'static str {
+        self.name.strip_prefix("r#").unwrap_or(self.name)
+    }
+
+    /// The type of the field as written in the layout definition, e.g. `u16`, `[u8; 4]` or `bool as u8`
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Offset of the field in number of bytes from the start of the layout
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Size of the field in number of bytes.
+    /// This is `None` for open ended fields like byte slices.
+    pub const fn size(&self) -> Option<usize> {
+        self.size
+    }
+
+    /// The endianness the field is stored with. For nested layouts, this is the endianness of the nested layout.
+    pub const fn endianness(&self) -> EndianKind {
+        self.endianness
+    }
+
+    /// What kind of data the field stores
+    pub const fn kind(&self) -> FieldKind {
+        self.kind
+    }
+
+    /// Returns an object that formats the value of this field in the given storage with [Display] and [Debug].
+    ///
+    /// Fields whose type implements [Debug] are formatted with it, other fields are formatted as hex bytes.
+    /// The storage is expected to contain the whole layout, not just the field.
+    pub fn value<'a>(&'a self, storage: &'a [u8]) -> FieldValue<'a> {
+        FieldValue {
+            field: self,
+            storage,
+        }
+    }
+
+    /// The bytes of this field in the given storage, or `None` if the storage is too short to contain the field.
+    /// The storage is expected to contain the whole layout, not just the field.
+    pub fn bytes<'a>(&self, storage: &'a [u8]) -> Option<&'a [u8]> {
+        match self.size {
+            Some(size) => storage.get(self.offset..self.offset.checked_add(size)?),
+            None => storage.get(self.offset..),
+        }
+    }
+}
+
+impl Debug for FieldInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldInfo")
+            .field("name", &self.name())
+            .field("type_name", &self.type_name)
+            .field("offset", &self.offset)
+            .field("size", &self.size)
+            .field("endianness", &self.endianness)
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+
+/// The kind of data a field stores, see [FieldInfo::kind].
+///
+/// Fields using custom types via [LayoutAs](crate::LayoutAs) have the kind of the underlying primitive type.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum FieldKind {
+    /// An unsigned integer like [u16] or [NonZeroU16](core::num::NonZeroU16)
+    UnsignedInt,
+    /// A signed integer like [i16] or [NonZeroI16](core::num::NonZeroI16)
+    SignedInt,
+    /// A floating point number like [f32]
+    Float,
+    /// The zero-sized unit type `()`
+    Unit,
+    /// A fixed size byte array `[u8; N]`
+    ByteArray,
+    /// A fixed size signed byte array `[i8; N]`
+    SignedByteArray,
+    /// A UTF-16 string stored in a fixed size buffer, see [Utf16](crate::Utf16)
+    Utf16String,
+    /// An open ended byte array `[u8]`
+    OpenEndedByteArray,
+    /// A byte array with a variable length and a reserved maximal size, see [VarBytes](crate::VarBytes)
+    VarBytes,
+    /// A nested layout
+    Nested(&'static LayoutInfo),
+    /// A count followed by that many records of a nested layout, see [Records](crate::Records)
+    Records(&'static LayoutInfo),
+    /// A fixed size array `[T; N]` or an open ended array `[T]` of numbers, see [FieldArrayAccess](crate::FieldArrayAccess)
+    Array {
+        /// The kind of the elements
+        element: &'static FieldKind,
+        /// The size of one element in number of bytes
+        element_size: usize,
+    },
+}
+
+/// Nested layouts and records are considered equal if their layouts have the same name, arrays if their elements are equal.
+impl PartialEq for FieldKind {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Nested(lhs), Self::Nested(rhs)) | (Self::Records(lhs), Self::Records(rhs)) => {
+                lhs.name == rhs.name
+            }
+            (
+                Self::Array {
+                    element: lhs,
+                    element_size: lhs_size,
+                },
+                Self::Array {
+                    element: rhs,
+                    element_size: rhs_size,
+                },
+            ) => lhs == rhs && lhs_size == rhs_size,
+            _ => core::mem::discriminant(self) == core::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for FieldKind {}
+
+/// Formats the value of a field, see [FieldInfo::value].
+pub struct FieldValue<'a> {
+    field: &'a FieldInfo,
+    storage: &'a [u8],
+}
+
+impl Display for FieldValue<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.field.bytes(self.storage) {
+            None => write!(f, "<out of bounds>"),
+            Some(bytes) => match self.field.kind {
+                FieldKind::Nested(layout) => Display::fmt(&layout.value(bytes), f),
+                _ => (self.field.format_value)(self.storage, f),
+            },
+        }
+    }
+}
+
+impl Debug for FieldValue<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Formats the values of all fields of a layout, see [LayoutInfo::value].
+pub struct LayoutValue<'a> {
+    layout: &'a LayoutInfo,
+    storage: &'a [u8],
+}
+
+impl Display for LayoutValue<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {{", self.layout.name)?;
+        for (index, field) in self.layout.fields.iter().enumerate() {
+            let separator = if index == 0 { " " } else { ", " };
+            write!(
+                f,
+                "{}{}: {}",
+                separator,
+                field.name(),
+                field.value(self.storage)
+            )?;
+        }
+        write!(f, " }}")
+    }
+}
+
+impl Debug for LayoutValue<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Implemented for all field types to get their [FieldKind].
+#[doc(hidden)]
+pub trait FieldKindInfo {
+    const KIND: FieldKind;
+}
+
+/// Formats the value of field `F`. The [binary_layout!](crate::binary_layout!) macro uses autoref specialization
+/// to call [FormatFieldByteArray::format_field] for byte arrays, [FormatFieldValue::format_field] if the field type
+/// implements [Debug] and [FormatFieldBytes::format_field] otherwise.
+#[doc(hidden)]
+pub struct FieldFormatter<F>(PhantomData<F>);
+
+impl<F> FieldFormatter<F> {
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait FormatFieldByteArray {
+    fn format_field(&self, storage: &[u8], f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FormatFieldByteArray
+    for &&FieldFormatter<PrimitiveField<[u8; N], E, OFFSET_>>
+{
+    fn format_field(&self, storage: &[u8], f: &mut Formatter<'_>) -> fmt::Result {
+        FormatFieldBytes::format_field(**self, storage, f)
+    }
+}
+
+#[doc(hidden)]
+pub trait FormatFieldValue {
+    fn format_field(&self, storage: &[u8], f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+impl<F> FormatFieldValue for &FieldFormatter<F>
+where
+    F: FieldCopyAccess,
+    F::HighLevelType: Debug,
+    F::ReadError: Debug,
+{
+    fn format_field(&self, storage: &[u8], f: &mut Formatter<'_>) -> fmt::Result {
+        match F::try_read(storage) {
+            Ok(value) => Debug::fmt(&value, f),
+            Err(error) => write!(f, "<invalid: {:?}>", error),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait FormatFieldBytes {
+    fn format_field(&self, storage: &[u8], f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+impl<F: Field> FormatFieldBytes for FieldFormatter<F> {
+    fn format_field(&self, storage: &[u8], f: &mut Formatter<'_>) -> fmt::Result {
+        let bytes = match F::SIZE {
+            Some(size) => &storage[F::OFFSET..(F::OFFSET + size)],
+            None => &storage[F::OFFSET..],
+        };
+        write!(f, "[")?;
+        for (index, byte) in bytes.iter().enumerate() {
+            let separator = if index == 0 { "" } else { " " };
+            write!(f, "{}{:02x}", separator, byte)?;
+        }
+        write!(f, "]")
+    }
+}
@@ -0,0 +1,146 @@
+use super::{FieldInfo, FieldKind, LayoutInfo};
+use crate::EndianKind;
+
+// 64 bit FNV-1a, see http://www.isthe.com/chongo/tech/comp/fnv/
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+impl LayoutInfo {
+    /// A hash of the structure of the layout, i.e. of the names, types, offsets, sizes and endianness of its fields,
+    /// including the fields of nested layouts. The layout generates it as the `SCHEMA_HASH` constant.
+    ///
+    /// The hash doesn't depend on the name of the layout itself or on the platform, so it can be stored in a file or sent over the network
+    /// to detect that the data was written with a different revision of the layout. Whitespace in the type names is ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// binary_layout!(header_v1, LittleEndian, {
+    ///   version: u16,
+    ///   flags: u8,
+    /// });
+    ///
+    /// binary_layout!(header_v2, LittleEndian, {
+    ///   version: u16,
+    ///   flags: u16,
+    /// });
+    ///
+    /// assert_eq!(header_v1::SCHEMA_HASH, header_v1::LAYOUT.schema_hash());
+    /// assert_ne!(header_v1::SCHEMA_HASH, header_v2::SCHEMA_HASH);
+    /// ```
+    pub const fn schema_hash(&self) -> u64 {
+        hash_layout(FNV_OFFSET_BASIS, self)
+    }
+}
+
+const fn hash_layout(mut hash: u64, layout: &LayoutInfo) -> u64 {
+    hash = hash_endianness(hash, layout.endianness);
+    hash = hash_usize(hash, layout.fields.len());
+    let mut index = 0;
+    while index < layout.fields.len() {
+        hash = hash_field(hash, &layout.fields[index]);
+        index += 1;
+    }
+    hash
+}
+
+const fn hash_field(mut hash: u64, field: &FieldInfo) -> u64 {
+    let name = field.name.as_bytes();
+    let name_start = if name.len() >= 2 && name[0] == b'r' && name[1] == b'#' {
+        2
+    } else {
+        0
+    };
+    hash = hash_str(hash, name, name_start);
+    hash = hash_str(hash, field.type_name.as_bytes(), 0);
+    hash = hash_usize(hash, field.offset);
+    hash = match field.size {
+        Some(size) => hash_usize(hash_byte(hash, 1), size),
+        None => hash_byte(hash, 0),
+    };
+    hash = hash_endianness(hash, field.endianness);
+    hash_kind(hash, &field.kind)
+}
+
+const fn hash_kind(hash: u64, kind: &FieldKind) -> u64 {
+    match *kind {
+        FieldKind::UnsignedInt => hash_byte(hash, 0),
+        FieldKind::SignedInt => hash_byte(hash, 1),
+        FieldKind::Float => hash_byte(hash, 2),
+        FieldKind::Unit => hash_byte(hash, 3),
+        FieldKind::ByteArray => hash_byte(hash, 4),
+        FieldKind::OpenEndedByteArray => hash_byte(hash, 5),
+        FieldKind::VarBytes => hash_byte(hash, 6),
+        FieldKind::Nested(layout) => hash_layout(hash_byte(hash, 7), layout),
+        FieldKind::Records(layout) => hash_layout(hash_byte(hash, 8), layout),
+        FieldKind::SignedByteArray => hash_byte(hash, 9),
+        FieldKind::Utf16String => hash_byte(hash, 10),
+        FieldKind::Array {
+            element,
+            element_size,
+        } => hash_usize(hash_kind(hash_byte(hash, 11), element), element_size),
+    }
+}
+
+const fn hash_endianness(hash: u64, endianness: EndianKind) -> u64 {
+    match endianness {
+        EndianKind::Big => hash_byte(hash, 0),
+        EndianKind::Little => hash_byte(hash, 1),
+        EndianKind::Native => hash_byte(hash, 2),
+    }
+}
+
+/// Hash the string starting at `start`, ignoring whitespace, followed by a terminator
+const fn hash_str(mut hash: u64, bytes: &[u8], start: usize) -> u64 {
+    let mut index = start;
+    while index < bytes.len() {
+        if !bytes[index].is_ascii_whitespace() {
+            hash = hash_byte(hash, bytes[index]);
+        }
+        index += 1;
+    }
+    hash_byte(hash, 0)
+}
+
+/// Hash the value as 8 little endian bytes, so that the hash doesn't depend on the pointer width of the platform
+const fn hash_usize(mut hash: u64, value: usize) -> u64 {
+    let bytes = (value as u64).to_le_bytes();
+    let mut index = 0;
+    while index < bytes.len() {
+        hash = hash_byte(hash, bytes[index]);
+        index += 1;
+    }
+    hash
+}
+
+const fn hash_byte(hash: u64, byte: u8) -> u64 {
+    (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fnv1a() {
+        // Test vectors from the FNV reference implementation
+        assert_eq!(0xaf63_dc4c_8601_ec8c, hash_byte(FNV_OFFSET_BASIS, b'a'));
+        let foobar = b"foobar"
+            .iter()
+            .fold(FNV_OFFSET_BASIS, |hash, byte| hash_byte(hash, *byte));
+        assert_eq!(0x8594_4171_f739_67e8, foobar);
+    }
+
+    #[test]
+    fn str_ignores_whitespace_and_is_terminated() {
+        assert_eq!(
+            hash_str(FNV_OFFSET_BASIS, b"[u8; 4]", 0),
+            hash_str(FNV_OFFSET_BASIS, b"[u8;4]", 0)
+        );
+        assert_ne!(
+            hash_str(hash_str(FNV_OFFSET_BASIS, b"ab", 0), b"c", 0),
+            hash_str(hash_str(FNV_OFFSET_BASIS, b"a", 0), b"bc", 0)
+        );
+    }
+}
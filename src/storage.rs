@@ -0,0 +1,579 @@
+/// The [Storage] trait abstracts over the underlying data a `View` is created for.
+///
+/// It is implemented for every type implementing `AsRef<[u8]>` (e.g. `&[u8]`, `[u8; N]` or `Vec<u8>`),
+/// but you can also implement it for your own container types, for example ring buffer segments,
+/// guarded memory pages or instrumented buffers, and create views over them.
+///
+/// If the storage should also be writable, implement [StorageMut] as well.
+///
+/// # Example
+/// ```
+/// use binary_layout::{prelude::*, Storage, StorageMut};
+/// use core::cell::Cell;
+///
+/// /// A buffer that counts how often it was accessed
+/// struct CountingBuffer {
+///     data: [u8; 16],
+///     num_accesses: Cell<usize>,
+/// }
+/// impl Storage for CountingBuffer {
+///     fn as_bytes(&self) -> &[u8] {
+///         self.num_accesses.set(self.num_accesses.get() + 1);
+///         &self.data
+///     }
+/// }
+/// impl StorageMut for CountingBuffer {
+///     fn as_bytes_mut(&mut self) -> &mut [u8] {
+///         self.num_accesses.set(self.num_accesses.get() + 1);
+///         &mut self.data
+///     }
+/// }
+///
+/// binary_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: u32,
+/// });
+///
+/// let mut view = my_layout::View::new(CountingBuffer {
+///     data: [0; 16],
+///     num_accesses: Cell::new(0),
+/// });
+/// view.field1_mut().write(10);
+/// assert_eq!(10, view.field1().read());
+/// assert_eq!(2, view.into_storage().num_accesses.get());
+/// ```
+///
+/// # Shared ownership
+/// Views over `Arc<[u8]>` share the underlying data, so cloning them doesn't copy it.
+/// This allows handing the same packet to several threads or tasks. Calling `into_${field_name}()`
+/// on such a view returns a [Data](crate::Data) subregion that still shares the data.
+///
+/// Note that `Arc<Vec<u8>>` doesn't implement `AsRef<[u8]>` and therefore isn't a [Storage].
+/// Convert it with `Arc::<[u8]>::from(vec)` instead, which takes over the allocation of the `Vec`.
+///
+/// ```
+/// use binary_layout::{prelude::*, Data};
+/// use std::sync::Arc;
+///
+/// binary_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   tail: [u8],
+/// });
+///
+/// let packet: Arc<[u8]> = Arc::from(vec![1, 0, 2, 3]);
+/// let view = my_layout::View::new(packet);
+///
+/// let handle = {
+///     let view = view.clone();
+///     std::thread::spawn(move || view.field1().read())
+/// };
+/// let tail: Data<Arc<[u8]>> = view.into_tail();
+/// assert_eq!(&[2, 3], &*tail);
+/// assert_eq!(1, handle.join().unwrap());
+/// ```
+pub trait Storage {
+    /// Borrow the underlying bytes with read access.
+    fn as_bytes(&self) -> &[u8];
+}
+
+/// The [StorageMut] trait is implemented by storage types that can be written to.
+/// See [Storage] for more details.
+///
+/// It is implemented for every type implementing both `AsRef<[u8]>` and `AsMut<[u8]>`.
+pub trait StorageMut: Storage {
+    /// Borrow the underlying bytes with write access.
+    fn as_bytes_mut(&mut self) -> &mut [u8];
+}
+
+impl<S: AsRef<[u8]> + ?Sized> Storage for S {
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        self.as_ref()
+    }
+}
+
+impl<S: AsRef<[u8]> + AsMut<[u8]> + ?Sized> StorageMut for S {
+    #[inline(always)]
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        self.as_mut()
+    }
+}
+
+/// The [FixedSizeStorage] trait is implemented by storage types whose size is known at compile time,
+/// e.g. `[u8; N]`, `&[u8; N]` or `&mut [u8; N]`.
+///
+/// It allows creating views with `View::new_exact(storage)`, which checks at compile time
+/// that the storage has exactly the size of the layout.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: u32,
+/// });
+///
+/// let mut storage = [0; 6];
+/// let mut view = my_layout::View::new_exact(&mut storage);
+/// view.field2_mut().write(10);
+/// ```
+///
+/// ```compile_fail
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: u32,
+/// });
+///
+/// let mut storage = [0; 5];
+/// // Fails to compile because the storage is too small for the layout
+/// let mut view = my_layout::View::new_exact(&mut storage);
+/// ```
+pub trait FixedSizeStorage: Storage {
+    /// The size of the storage in bytes
+    const SIZE: usize;
+}
+
+impl<const N: usize> FixedSizeStorage for [u8; N] {
+    const SIZE: usize = N;
+}
+
+impl<const N: usize> FixedSizeStorage for &[u8; N] {
+    const SIZE: usize = N;
+}
+
+impl<const N: usize> FixedSizeStorage for &mut [u8; N] {
+    const SIZE: usize = N;
+}
+
+/// The [ResizableStorage] trait is implemented by storage types that can change their size, e.g. `Vec<u8>` and `&mut Vec<u8>`
+/// (with the `alloc` feature) or `bytes::BytesMut` (with the `bytes` feature).
+///
+/// Views over such storage offer `View::resize_tail(new_len)` and `View::set_tail(data)` for layouts ending with an open ended field,
+/// which resize the storage so that the open ended field has exactly the requested size.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(packet, BigEndian, {
+///   payload_len: u16 = len(payload),
+///   payload: [u8],
+/// });
+///
+/// # #[cfg(feature = "alloc")]
+/// # fn main() {
+/// let mut view = packet::View::new(vec![0; 2]);
+/// view.set_tail(b"hello");
+/// view.finalize().unwrap();
+/// assert_eq!(5, view.payload_len().read());
+///
+/// view.resize_tail(2);
+/// assert_eq!(b"he", view.payload());
+/// # }
+/// # #[cfg(not(feature = "alloc"))]
+/// # fn main() {}
+/// ```
+pub trait ResizableStorage: StorageMut {
+    /// Resize the storage to `new_len` bytes, truncating it or filling new bytes with zeroes.
+    fn resize(&mut self, new_len: usize);
+}
+
+#[cfg(feature = "alloc")]
+impl ResizableStorage for alloc::vec::Vec<u8> {
+    #[inline(always)]
+    fn resize(&mut self, new_len: usize) {
+        alloc::vec::Vec::resize(self, new_len, 0);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ResizableStorage for &mut alloc::vec::Vec<u8> {
+    #[inline(always)]
+    fn resize(&mut self, new_len: usize) {
+        alloc::vec::Vec::resize(self, new_len, 0);
+    }
+}
+
+/// The [SegmentedStorage] trait abstracts over data that isn't stored in one contiguous memory region,
+/// e.g. a packet that arrived as a list of buffers or the two halves of a ring buffer.
+///
+/// Fields with copy access can be read from such storage using [FieldSegmentedExt](crate::FieldSegmentedExt),
+/// which gathers the bytes of the field from the segments they're spread across.
+/// Fields with slice access require contiguous [Storage].
+///
+/// It is implemented for lists of [Storage] segments (e.g. `[&[u8]]`, `[Vec<u8>; N]` or `Vec<Bytes>`), for `VecDeque<u8>`
+/// and `&[u8]`. If the `bytes` feature is enabled, it is also implemented for `Bytes`, `BytesMut` and `bytes::buf::Chain` of them,
+/// and `BufSegments` adapts other `bytes::Buf` types that can be cloned for reading.
+///
+/// It is also implemented for storage behind interior mutability, i.e. for [RefCell](core::cell::RefCell) and,
+/// if the `std` feature is enabled, [Mutex](std::sync::Mutex) around a [Storage], see [below](#interior-mutability).
+///
+/// # Example
+/// ```
+/// use binary_layout::{prelude::*, FieldSegmentedExt};
+///
+/// binary_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: u32,
+/// });
+///
+/// let segments: [&[u8]; 2] = [&[1, 0, 2], &[0, 0, 0]];
+/// assert_eq!(Ok(1), my_layout::field1::try_read_segmented(&segments[..]));
+/// assert_eq!(Ok(2), my_layout::field2::try_read_segmented(&segments[..]));
+/// ```
+///
+/// # Interior mutability
+/// A buffer shared between several callbacks that each need occasional write access can be put into a
+/// [RefCell](core::cell::RefCell) or a [Mutex](std::sync::Mutex). Shared references to them, e.g. `&RefCell<Vec<u8>>` or `&Mutex<[u8]>`,
+/// implement [SegmentedStorageMut], so fields with copy access can be written through them. The borrow or lock is only
+/// acquired for the duration of each access. Reading or writing panics if the [RefCell](core::cell::RefCell) is already
+/// borrowed mutably or if the [Mutex](std::sync::Mutex) is poisoned.
+///
+/// Views and fields with slice access need the storage for longer than one access,
+/// so create them over the borrow guard instead, e.g. `my_layout::View::new(&mut *buffer.borrow_mut())`.
+///
+/// ```
+/// use binary_layout::{prelude::*, FieldSegmentedExt};
+/// use core::cell::RefCell;
+///
+/// binary_layout!(my_layout, LittleEndian, {
+///   sequence_number: u32,
+///   payload: [u8],
+/// });
+///
+/// let buffer = RefCell::new(vec![0; 8]);
+/// let mut storage = &buffer;
+///
+/// let mut on_send = || my_layout::sequence_number::try_write_segmented(&mut storage, 5).unwrap();
+/// on_send();
+///
+/// assert_eq!(Ok(5), my_layout::sequence_number::try_read_segmented(&buffer));
+/// my_layout::View::new(&mut *buffer.borrow_mut()).payload_mut()[0] = 1;
+/// assert_eq!(vec![5, 0, 0, 0, 1, 0, 0, 0], buffer.into_inner());
+/// ```
+pub trait SegmentedStorage {
+    /// Copy `dst.len()` bytes starting at `offset` into `dst`.
+    ///
+    /// # Panics
+    /// Panics if the storage has less than `offset + dst.len()` bytes.
+    fn read_bytes(&self, offset: usize, dst: &mut [u8]);
+}
+
+/// The [SegmentedStorageMut] trait is implemented by segmented storage types that can be written to.
+/// See [SegmentedStorage] for more details.
+pub trait SegmentedStorageMut: SegmentedStorage {
+    /// Copy the bytes of `src` into the storage, starting at `offset`.
+    ///
+    /// # Panics
+    /// Panics if the storage has less than `offset + src.len()` bytes.
+    fn write_bytes(&mut self, offset: usize, src: &[u8]);
+}
+
+fn read_from_segments<'a>(
+    segments: impl IntoIterator<Item = &'a [u8]>,
+    mut offset: usize,
+    mut dst: &mut [u8],
+) {
+    for segment in segments {
+        if dst.is_empty() {
+            return;
+        }
+        if offset >= segment.len() {
+            offset -= segment.len();
+            continue;
+        }
+        let num_bytes = dst.len().min(segment.len() - offset);
+        let (current, rest) = dst.split_at_mut(num_bytes);
+        current.copy_from_slice(&segment[offset..offset + num_bytes]);
+        dst = rest;
+        offset = 0;
+    }
+    assert!(dst.is_empty(), "Segmented storage is too small");
+}
+
+fn write_to_segments<'a>(
+    segments: impl IntoIterator<Item = &'a mut [u8]>,
+    mut offset: usize,
+    mut src: &[u8],
+) {
+    for segment in segments {
+        if src.is_empty() {
+            return;
+        }
+        if offset >= segment.len() {
+            offset -= segment.len();
+            continue;
+        }
+        let num_bytes = src.len().min(segment.len() - offset);
+        segment[offset..offset + num_bytes].copy_from_slice(&src[..num_bytes]);
+        src = &src[num_bytes..];
+        offset = 0;
+    }
+    assert!(src.is_empty(), "Segmented storage is too small");
+}
+
+impl<S: Storage> SegmentedStorage for [S] {
+    fn read_bytes(&self, offset: usize, dst: &mut [u8]) {
+        read_from_segments(self.iter().map(Storage::as_bytes), offset, dst)
+    }
+}
+
+impl<S: StorageMut> SegmentedStorageMut for [S] {
+    fn write_bytes(&mut self, offset: usize, src: &[u8]) {
+        write_to_segments(self.iter_mut().map(StorageMut::as_bytes_mut), offset, src)
+    }
+}
+
+impl SegmentedStorage for &[u8] {
+    #[inline(always)]
+    fn read_bytes(&self, offset: usize, dst: &mut [u8]) {
+        read_from_segments([*self], offset, dst)
+    }
+}
+
+impl<S: Storage, const N: usize> SegmentedStorage for [S; N] {
+    #[inline(always)]
+    fn read_bytes(&self, offset: usize, dst: &mut [u8]) {
+        self[..].read_bytes(offset, dst)
+    }
+}
+
+impl<S: StorageMut, const N: usize> SegmentedStorageMut for [S; N] {
+    #[inline(always)]
+    fn write_bytes(&mut self, offset: usize, src: &[u8]) {
+        self[..].write_bytes(offset, src)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: Storage> SegmentedStorage for alloc::vec::Vec<S> {
+    #[inline(always)]
+    fn read_bytes(&self, offset: usize, dst: &mut [u8]) {
+        self[..].read_bytes(offset, dst)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: StorageMut> SegmentedStorageMut for alloc::vec::Vec<S> {
+    #[inline(always)]
+    fn write_bytes(&mut self, offset: usize, src: &[u8]) {
+        self[..].write_bytes(offset, src)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl SegmentedStorage for alloc::collections::VecDeque<u8> {
+    fn read_bytes(&self, offset: usize, dst: &mut [u8]) {
+        let (first, second) = self.as_slices();
+        read_from_segments([first, second], offset, dst)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl SegmentedStorageMut for alloc::collections::VecDeque<u8> {
+    fn write_bytes(&mut self, offset: usize, src: &[u8]) {
+        let (first, second) = self.as_mut_slices();
+        write_to_segments([first, second], offset, src)
+    }
+}
+
+#[cfg(feature = "bytes")]
+mod bytes_impls {
+    use super::{
+        read_from_segments, write_to_segments, ResizableStorage, SegmentedStorage,
+        SegmentedStorageMut,
+    };
+    use bytes::{buf::Chain, Buf, Bytes, BytesMut};
+
+    /// An adapter that implements [SegmentedStorage] for any [Buf] of the [bytes](https://crates.io/crates/bytes) crate
+    /// that can be cloned, e.g. a `std::io::Cursor` or a custom [Buf] implementation. This requires the `bytes` feature.
+    ///
+    /// [Buf] only supports reading its chunks in order, so each read clones the buffer and advances the clone to the field.
+    /// Offsets are relative to the current position of the buffer, i.e. to the first of its remaining bytes.
+    /// Cloning is cheap for reference counted or borrowed buffers, but copies the data for owned buffers like `VecDeque<u8>`.
+    ///
+    /// Buffers that can't be cloned, e.g. `bytes::buf::Take`, can't be read without consuming them and aren't supported.
+    /// [Bytes], [BytesMut], `&[u8]` and [Chain] of them implement [SegmentedStorage] directly and don't need this adapter.
+    /// Since [Buf] doesn't offer write access, fields can only be read through this adapter.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::{prelude::*, BufSegments, FieldSegmentedExt};
+    /// use bytes::Buf;
+    /// use std::io::Cursor;
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///   field1: u16,
+    ///   field2: u32,
+    /// });
+    ///
+    /// let mut buf = Cursor::new(vec![0xff, 1, 0, 2, 0, 0, 0]);
+    /// // The first byte was already consumed, e.g. a message type
+    /// assert_eq!(0xff, buf.get_u8());
+    ///
+    /// let storage = BufSegments::new(buf);
+    /// assert_eq!(Ok(1), my_layout::field1::try_read_segmented(&storage));
+    /// assert_eq!(Ok(2), my_layout::field2::try_read_segmented(&storage));
+    /// ```
+    #[derive(Debug, Clone)]
+    pub struct BufSegments<B> {
+        buf: B,
+    }
+
+    impl<B: Buf + Clone> BufSegments<B> {
+        /// Create a new [BufSegments] adapter reading from `buf`.
+        pub fn new(buf: B) -> Self {
+            Self { buf }
+        }
+
+        /// Return the wrapped buffer.
+        pub fn into_inner(self) -> B {
+            self.buf
+        }
+    }
+
+    impl<B: Buf + Clone> SegmentedStorage for BufSegments<B> {
+        fn read_bytes(&self, offset: usize, dst: &mut [u8]) {
+            let mut buf = self.buf.clone();
+            assert!(
+                offset + dst.len() <= buf.remaining(),
+                "Segmented storage is too small"
+            );
+            buf.advance(offset);
+            buf.copy_to_slice(dst);
+        }
+    }
+
+    impl ResizableStorage for BytesMut {
+        #[inline(always)]
+        fn resize(&mut self, new_len: usize) {
+            BytesMut::resize(self, new_len, 0);
+        }
+    }
+
+    impl SegmentedStorage for Bytes {
+        #[inline(always)]
+        fn read_bytes(&self, offset: usize, dst: &mut [u8]) {
+            read_from_segments([&self[..]], offset, dst)
+        }
+    }
+
+    impl SegmentedStorage for BytesMut {
+        #[inline(always)]
+        fn read_bytes(&self, offset: usize, dst: &mut [u8]) {
+            read_from_segments([&self[..]], offset, dst)
+        }
+    }
+
+    impl SegmentedStorageMut for BytesMut {
+        #[inline(always)]
+        fn write_bytes(&mut self, offset: usize, src: &[u8]) {
+            write_to_segments([&mut self[..]], offset, src)
+        }
+    }
+
+    impl<A: Buf + SegmentedStorage, B: SegmentedStorage> SegmentedStorage for Chain<A, B> {
+        fn read_bytes(&self, offset: usize, dst: &mut [u8]) {
+            let first_len = self.first_ref().remaining();
+            if offset >= first_len {
+                self.last_ref().read_bytes(offset - first_len, dst);
+            } else {
+                let (in_first, in_last) = dst.split_at_mut(dst.len().min(first_len - offset));
+                self.first_ref().read_bytes(offset, in_first);
+                self.last_ref().read_bytes(0, in_last);
+            }
+        }
+    }
+
+    impl<A: Buf + SegmentedStorageMut, B: SegmentedStorageMut> SegmentedStorageMut for Chain<A, B> {
+        fn write_bytes(&mut self, offset: usize, src: &[u8]) {
+            let first_len = self.first_ref().remaining();
+            if offset >= first_len {
+                self.last_mut().write_bytes(offset - first_len, src);
+            } else {
+                let (in_first, in_last) = src.split_at(src.len().min(first_len - offset));
+                self.first_mut().write_bytes(offset, in_first);
+                self.last_mut().write_bytes(0, in_last);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "bytes")]
+pub use bytes_impls::BufSegments;
+
+mod interior_mutability {
+    use super::{
+        read_from_segments, write_to_segments, SegmentedStorage, SegmentedStorageMut, Storage,
+        StorageMut,
+    };
+    use core::cell::RefCell;
+
+    impl<S: Storage + ?Sized> SegmentedStorage for RefCell<S> {
+        fn read_bytes(&self, offset: usize, dst: &mut [u8]) {
+            read_from_segments([self.borrow().as_bytes()], offset, dst)
+        }
+    }
+
+    impl<S: StorageMut + ?Sized> SegmentedStorageMut for RefCell<S> {
+        fn write_bytes(&mut self, offset: usize, src: &[u8]) {
+            write_to_segments([self.get_mut().as_bytes_mut()], offset, src)
+        }
+    }
+
+    impl<S: Storage + ?Sized> SegmentedStorage for &RefCell<S> {
+        #[inline(always)]
+        fn read_bytes(&self, offset: usize, dst: &mut [u8]) {
+            (**self).read_bytes(offset, dst)
+        }
+    }
+
+    impl<S: StorageMut + ?Sized> SegmentedStorageMut for &RefCell<S> {
+        fn write_bytes(&mut self, offset: usize, src: &[u8]) {
+            write_to_segments([self.borrow_mut().as_bytes_mut()], offset, src)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod mutex {
+        use super::{
+            read_from_segments, write_to_segments, SegmentedStorage, SegmentedStorageMut, Storage,
+            StorageMut,
+        };
+        use std::sync::Mutex;
+
+        const POISONED: &str = "Mutex is poisoned";
+
+        impl<S: Storage + ?Sized> SegmentedStorage for Mutex<S> {
+            fn read_bytes(&self, offset: usize, dst: &mut [u8]) {
+                read_from_segments([self.lock().expect(POISONED).as_bytes()], offset, dst)
+            }
+        }
+
+        impl<S: StorageMut + ?Sized> SegmentedStorageMut for Mutex<S> {
+            fn write_bytes(&mut self, offset: usize, src: &[u8]) {
+                write_to_segments(
+                    [self.get_mut().expect(POISONED).as_bytes_mut()],
+                    offset,
+                    src,
+                )
+            }
+        }
+
+        impl<S: Storage + ?Sized> SegmentedStorage for &Mutex<S> {
+            #[inline(always)]
+            fn read_bytes(&self, offset: usize, dst: &mut [u8]) {
+                (**self).read_bytes(offset, dst)
+            }
+        }
+
+        impl<S: StorageMut + ?Sized> SegmentedStorageMut for &Mutex<S> {
+            fn write_bytes(&mut self, offset: usize, src: &[u8]) {
+                write_to_segments([self.lock().expect(POISONED).as_bytes_mut()], offset, src)
+            }
+        }
+    }
+}
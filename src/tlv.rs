@@ -0,0 +1,250 @@
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+
+use crate::{Endianness, Field, FieldReadExt, FieldWriteExt, PrimitiveField};
+
+/// Type-length-value (TLV) encoding for the open ended tail of a layout, e.g. for options in network protocol headers.
+///
+/// Each entry is encoded as a tag of type `T`, followed by the length of the value as type `L`, followed by the value itself.
+/// Tag and length are stored with endianness `E`. Any primitive integer type can be used for tags and lengths.
+///
+/// This type is only used as a marker to select the encoding, it can't be instantiated.
+/// Use [Tlv::iter] to iterate over the entries in a byte slice and [Tlv::builder] to write entries to one.
+///
+/// # Example
+/// ```
+/// use binary_layout::{prelude::*, Tlv};
+///
+/// binary_layout!(packet, BigEndian, {
+///   packet_type: u8,
+///   options: [u8],
+/// });
+///
+/// type Options = Tlv<u8, u8, BigEndian>;
+///
+/// let mut storage = [0; 9];
+/// let mut view = packet::View::new(&mut storage[..]);
+/// let mut builder = Options::builder(view.options_mut());
+/// builder.append(1, &[10, 20]).unwrap();
+/// builder.append(2, &[30]).unwrap();
+/// assert_eq!(7, builder.len());
+///
+/// let entries: Vec<(u8, &[u8])> = Options::iter(&view.options()[..7]).collect();
+/// assert_eq!(vec![(1, &[10, 20][..]), (2, &[30][..])], entries);
+/// ```
+pub enum Tlv<T, L, E: Endianness> {
+    #[doc(hidden)]
+    _Phantom(PhantomData<(T, L, E)>, core::convert::Infallible),
+}
+
+/// Describes how the tag and length of TLV entries are encoded. This is implemented by [Tlv].
+pub trait TlvFormat {
+    /// The type of the tags
+    type Tag;
+
+    /// Size of the encoded tag in number of bytes
+    const TAG_SIZE: usize;
+
+    /// Size of the encoded length in number of bytes
+    const LENGTH_SIZE: usize;
+
+    /// Read a tag from the beginning of `storage`
+    fn read_tag(storage: &[u8]) -> Self::Tag;
+
+    /// Read a length from the beginning of `storage`. Returns `None` if the length doesn't fit into a [usize].
+    fn read_length(storage: &[u8]) -> Option<usize>;
+
+    /// Write a tag to the beginning of `storage`
+    fn write_tag(storage: &mut [u8], tag: Self::Tag);
+
+    /// Write a length to the beginning of `storage`. Returns `None` and doesn't write anything if the length can't be encoded.
+    fn write_length(storage: &mut [u8], length: usize) -> Option<()>;
+}
+
+impl<T, L, E: Endianness> TlvFormat for Tlv<T, L, E>
+where
+    PrimitiveField<T, E, 0>: FieldReadExt<HighLevelType = T> + FieldWriteExt<HighLevelType = T>,
+    PrimitiveField<L, E, 0>: FieldReadExt<HighLevelType = L> + FieldWriteExt<HighLevelType = L>,
+    L: TryFrom<usize>,
+    usize: TryFrom<L>,
+{
+    type Tag = T;
+
+    const TAG_SIZE: usize = unwrap_size(<PrimitiveField<T, E, 0> as Field>::SIZE);
+    const LENGTH_SIZE: usize = unwrap_size(<PrimitiveField<L, E, 0> as Field>::SIZE);
+
+    #[inline]
+    fn read_tag(storage: &[u8]) -> T {
+        PrimitiveField::<T, E, 0>::read(storage)
+    }
+
+    #[inline]
+    fn read_length(storage: &[u8]) -> Option<usize> {
+        usize::try_from(PrimitiveField::<L, E, 0>::read(storage)).ok()
+    }
+
+    #[inline]
+    fn write_tag(storage: &mut [u8], tag: T) {
+        PrimitiveField::<T, E, 0>::write(storage, tag)
+    }
+
+    #[inline]
+    fn write_length(storage: &mut [u8], length: usize) -> Option<()> {
+        PrimitiveField::<L, E, 0>::write(storage, L::try_from(length).ok()?);
+        Some(())
+    }
+}
+
+const fn unwrap_size(size: Option<usize>) -> usize {
+    match size {
+        Some(size) => size,
+        None => panic!("TLV tags and lengths must have a fixed size"),
+    }
+}
+
+impl<T, L, E: Endianness> Tlv<T, L, E>
+where
+    Self: TlvFormat,
+{
+    /// Iterate over the TLV entries in `storage`, yielding the tag and value of each entry.
+    ///
+    /// Iteration stops at the end of `storage` or when the remaining bytes don't contain a complete entry.
+    /// In the latter case, [TlvIter::remainder] returns the bytes that couldn't be parsed.
+    #[inline]
+    pub fn iter(storage: &[u8]) -> TlvIter<'_, Self> {
+        TlvIter {
+            remaining: storage,
+            _p: PhantomData,
+        }
+    }
+
+    /// Create a [TlvBuilder] that writes TLV entries to `storage`, starting at its beginning.
+    #[inline]
+    pub fn builder(storage: &mut [u8]) -> TlvBuilder<'_, Self> {
+        TlvBuilder {
+            storage,
+            len: 0,
+            _p: PhantomData,
+        }
+    }
+}
+
+/// Iterator over the entries of TLV encoded data, see [Tlv::iter].
+pub struct TlvIter<'a, F: TlvFormat> {
+    remaining: &'a [u8],
+    _p: PhantomData<F>,
+}
+
+impl<'a, F: TlvFormat> TlvIter<'a, F> {
+    /// The bytes that weren't parsed yet.
+    ///
+    /// Once the iterator returned `None`, this is empty if all of the data was well formed,
+    /// and otherwise it contains the truncated entry at the end of the data.
+    #[inline]
+    pub fn remainder(&self) -> &'a [u8] {
+        self.remaining
+    }
+}
+
+impl<'a, F: TlvFormat> Iterator for TlvIter<'a, F> {
+    type Item = (F::Tag, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header_size = F::TAG_SIZE + F::LENGTH_SIZE;
+        if self.remaining.len() < header_size {
+            return None;
+        }
+        let length = F::read_length(&self.remaining[F::TAG_SIZE..])?;
+        let end = header_size.checked_add(length)?;
+        if self.remaining.len() < end {
+            return None;
+        }
+        let tag = F::read_tag(self.remaining);
+        let value = &self.remaining[header_size..end];
+        self.remaining = &self.remaining[end..];
+        Some((tag, value))
+    }
+}
+
+/// This error is returned when appending an entry with a [TlvBuilder] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TlvWriteError {
+    /// The length of the value can't be encoded with the length type of the TLV encoding
+    ValueTooLong {
+        /// Length of the value in number of bytes
+        len: usize,
+    },
+    /// The storage doesn't have enough space left for the entry
+    StorageTooSmall {
+        /// Size of the encoded entry in number of bytes
+        needed: usize,
+        /// Number of bytes left in the storage
+        available: usize,
+    },
+}
+
+impl core::fmt::Display for TlvWriteError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ValueTooLong { len } => write!(
+                fmt,
+                "A value of {} bytes is too long for the TLV length type",
+                len
+            ),
+            Self::StorageTooSmall { needed, available } => write!(
+                fmt,
+                "The entry needs {} bytes but only {} bytes are left in the storage",
+                needed, available
+            ),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl crate::utils::error::Error for TlvWriteError {}
+
+/// Writes TLV entries to a byte slice, see [Tlv::builder].
+pub struct TlvBuilder<'a, F: TlvFormat> {
+    storage: &'a mut [u8],
+    len: usize,
+    _p: PhantomData<F>,
+}
+
+impl<'a, F: TlvFormat> TlvBuilder<'a, F> {
+    /// Append an entry after the entries written so far.
+    ///
+    /// If the entry doesn't fit, an error is returned and the storage isn't modified.
+    pub fn append(&mut self, tag: F::Tag, value: &[u8]) -> Result<(), TlvWriteError> {
+        let header_size = F::TAG_SIZE + F::LENGTH_SIZE;
+        let available = self.storage.len() - self.len;
+        let needed = header_size.saturating_add(value.len());
+        if needed > available {
+            return Err(TlvWriteError::StorageTooSmall { needed, available });
+        }
+        let entry = &mut self.storage[self.len..(self.len + needed)];
+        F::write_length(&mut entry[F::TAG_SIZE..], value.len())
+            .ok_or(TlvWriteError::ValueTooLong { len: value.len() })?;
+        F::write_tag(entry, tag);
+        entry[header_size..].copy_from_slice(value);
+        self.len += needed;
+        Ok(())
+    }
+
+    /// Number of bytes written so far
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no entries were written yet
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of bytes left in the storage for further entries
+    #[inline]
+    pub fn remaining_capacity(&self) -> usize {
+        self.storage.len() - self.len
+    }
+}
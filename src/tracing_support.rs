@@ -0,0 +1,119 @@
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+use crate::fields::primitive::FieldView;
+use crate::Field;
+
+/// Identifies a field of a layout in the trace events emitted by its [FieldView]. The [binary_layout!](crate::binary_layout!)
+/// macro creates one for each field and attaches it to the field views returned by the accessors of the generated `View`.
+#[doc(hidden)]
+pub struct FieldTrace {
+    pub layout: &'static str,
+    pub field: &'static str,
+    /// Formats the value of the field from a slice starting at the field
+    pub format: fn(&[u8], &mut Formatter<'_>) -> fmt::Result,
+}
+
+/// Formats the value of a field for a trace event, only if the event is actually recorded
+struct TracedValue<'a> {
+    trace: &'static FieldTrace,
+    data: &'a [u8],
+}
+
+impl Display for TracedValue<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (self.trace.format)(self.data, f)
+    }
+}
+
+fn field_data<F: Field>(storage: &[u8]) -> &[u8] {
+    let end = F::SIZE.map_or(storage.len(), |size| F::OFFSET + size);
+    &storage[F::OFFSET..end]
+}
+
+pub(crate) fn trace_read<F: Field>(trace: Option<&'static FieldTrace>, storage: &[u8]) {
+    if let Some(trace) = trace {
+        let value = TracedValue {
+            trace,
+            data: field_data::<F>(storage),
+        };
+        tracing::trace!(
+            target: "binary_layout",
+            layout = trace.layout,
+            field = trace.field,
+            offset = F::OFFSET,
+            value = %value,
+            "read",
+        );
+    }
+}
+
+/// The bytes a field had before it was written, so that the write event can show the old value
+pub(crate) struct OldValue {
+    trace: &'static FieldTrace,
+    data: Vec<u8>,
+}
+
+/// Remember the old value of a field before writing it, if writes of the field are traced
+pub(crate) fn before_write<F: Field>(
+    trace: Option<&'static FieldTrace>,
+    storage: &[u8],
+) -> Option<OldValue> {
+    match trace {
+        Some(trace) if tracing::enabled!(target: "binary_layout", tracing::Level::TRACE) => {
+            Some(OldValue {
+                trace,
+                data: field_data::<F>(storage).to_vec(),
+            })
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn trace_write<F: Field>(old: Option<OldValue>, storage: &[u8]) {
+    if let Some(old) = old {
+        let trace = old.trace;
+        let old = TracedValue {
+            trace,
+            data: &old.data,
+        };
+        let new = TracedValue {
+            trace,
+            data: field_data::<F>(storage),
+        };
+        tracing::trace!(
+            target: "binary_layout",
+            layout = trace.layout,
+            field = trace.field,
+            offset = F::OFFSET,
+            old = %old,
+            new = %new,
+            "write",
+        );
+    }
+}
+
+/// Attaches a [FieldTrace] to the views returned by the accessors of a layout. The [binary_layout!](crate::binary_layout!)
+/// macro uses autoref specialization to call [TraceFieldView::set_trace] for [FieldView]s and [TraceOtherView::set_trace]
+/// for other views, e.g. slices or nested views, which aren't traced.
+#[doc(hidden)]
+pub trait TraceFieldView {
+    fn set_trace(&mut self, trace: &'static FieldTrace);
+}
+
+impl<S, F: Field> TraceFieldView for FieldView<S, F> {
+    #[inline(always)]
+    fn set_trace(&mut self, trace: &'static FieldTrace) {
+        self.trace = Some(trace);
+    }
+}
+
+#[doc(hidden)]
+pub trait TraceOtherView {
+    fn set_trace(&mut self, trace: &'static FieldTrace);
+}
+
+impl<T> TraceOtherView for &mut T {
+    #[inline(always)]
+    fn set_trace(&mut self, _trace: &'static FieldTrace) {}
+}
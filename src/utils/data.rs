@@ -1,5 +1,8 @@
-use core::fmt::Debug;
-use core::ops::{Bound, Deref, DerefMut, Range, RangeBounds};
+use core::fmt::{self, Debug, Formatter};
+use core::ops::{Bound, Deref, DerefMut, Index, IndexMut, Range, RangeBounds};
+use core::slice::SliceIndex;
+
+use crate::storage::{ResizableStorage, Storage, StorageMut};
 
 /// An instance of data owns a block of data. It implements `AsRef<[u8]>` and `AsMut<[u8]>` to allow
 /// borrowing that data, and it has a [Data::into_subregion] function that cuts away bytes at either
@@ -11,6 +14,10 @@ use core::ops::{Bound, Deref, DerefMut, Range, RangeBounds};
 /// of the remaining data on to something else without having to copy it. The downside is that the
 /// header data isn't freed up - as long as any subregion of the original data exists somewhere,
 /// the whole data has to be kept in memory.
+///
+/// [Data] only depends on `core`, so it is available in `#[no_std]` builds, even without an allocator.
+/// It works with any [Storage], e.g. `&[u8]`, `&mut [u8]` or `[u8; N]` on embedded targets,
+/// and `Vec<u8>` or `Box<[u8]>` when an allocator is available.
 #[derive(Clone)]
 pub struct Data<S> {
     storage: S,
@@ -34,6 +41,35 @@ impl<S> Data<S> {
         self.region.is_empty()
     }
 
+    /// Return the region of the underlying storage that this [Data] instance represents.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::Data;
+    ///
+    /// let data = Data::from(vec![1, 2, 3, 4, 5]).into_subregion(1..).into_subregion(..2);
+    /// assert_eq!(1..3, data.region());
+    /// assert_eq!(&[2, 3], &*data);
+    /// ```
+    #[inline(always)]
+    pub fn region(&self) -> Range<usize> {
+        self.region.clone()
+    }
+
+    /// Destroy the [Data] instance and return the underlying storage, including any bytes outside of its [region](Data::region).
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::Data;
+    ///
+    /// let data = Data::from(vec![1, 2, 3, 4, 5]).into_subregion(1..3);
+    /// assert_eq!(vec![1, 2, 3, 4, 5], data.into_inner());
+    /// ```
+    #[inline(always)]
+    pub fn into_inner(self) -> S {
+        self.storage
+    }
+
     /// Return a [Data] instance that semantically only represents a subregion of the original instance.
     /// Using any data accessors like `AsRef<[u8]>` or `AsMut<[u8]>` on the new instance will behave
     /// as if the instance only owned the subregion.
@@ -81,12 +117,12 @@ impl<S> Data<S> {
 
 impl<S> From<S> for Data<S>
 where
-    S: AsRef<[u8]>,
+    S: Storage,
 {
-    /// Create a new [Data] object from a given `Vec<[u8]>` allocation.
+    /// Create a new [Data] object from a given storage, e.g. a `Vec<u8>` allocation or a `[u8; N]` array.
     #[inline(always)]
     fn from(data: S) -> Data<S> {
-        let len = data.as_ref().len();
+        let len = data.as_bytes().len();
         Self {
             storage: data,
             region: 0..len,
@@ -96,28 +132,43 @@ where
 
 impl<S> AsRef<[u8]> for Data<S>
 where
-    S: AsRef<[u8]>,
+    S: Storage,
 {
     #[inline(always)]
     fn as_ref(&self) -> &[u8] {
-        &self.storage.as_ref()[self.region.clone()]
+        &self.storage.as_bytes()[self.region.clone()]
     }
 }
 
 impl<S> AsMut<[u8]> for Data<S>
 where
-    S: AsMut<[u8]>,
+    S: StorageMut,
 {
     #[inline(always)]
     fn as_mut(&mut self) -> &mut [u8] {
-        &mut self.storage.as_mut()[self.region.clone()]
+        &mut self.storage.as_bytes_mut()[self.region.clone()]
+    }
+}
+
+/// Resizing a [Data] instance resizes its region, and the underlying storage with it.
+/// Any bytes of the underlying storage after the end of the region are discarded.
+impl<S> ResizableStorage for Data<S>
+where
+    S: ResizableStorage,
+{
+    #[inline]
+    fn resize(&mut self, new_len: usize) {
+        let new_end = self.region.start + new_len;
+        self.storage.resize(self.region.end);
+        self.storage.resize(new_end);
+        self.region.end = new_end;
     }
 }
 
 // TODO Test
 impl<S> Deref for Data<S>
 where
-    S: AsRef<[u8]>,
+    S: Storage,
 {
     type Target = [u8];
 
@@ -130,7 +181,7 @@ where
 // TODO Test
 impl<S> DerefMut for Data<S>
 where
-    S: AsRef<[u8]> + AsMut<[u8]>,
+    S: StorageMut,
 {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut [u8] {
@@ -138,6 +189,71 @@ where
     }
 }
 
+impl<S, I> Index<I> for Data<S>
+where
+    S: Storage,
+    I: SliceIndex<[u8]>,
+{
+    type Output = I::Output;
+
+    #[inline(always)]
+    fn index(&self, index: I) -> &I::Output {
+        &self.as_ref()[index]
+    }
+}
+
+impl<S, I> IndexMut<I> for Data<S>
+where
+    S: StorageMut,
+    I: SliceIndex<[u8]>,
+{
+    #[inline(always)]
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        &mut self.as_mut()[index]
+    }
+}
+
+/// Formats the region of the data and the bytes in it.
+impl<S> Debug for Data<S>
+where
+    S: Storage,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Data")
+            .field("region", &self.region)
+            .field("data", &self.as_ref())
+            .finish()
+    }
+}
+
+/// [Data] instances are equal if the bytes in their regions are equal, independent of the rest of their storage.
+impl<S1, S2> PartialEq<Data<S2>> for Data<S1>
+where
+    S1: Storage,
+    S2: Storage,
+{
+    #[inline]
+    fn eq(&self, other: &Data<S2>) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<S: Storage> Eq for Data<S> {}
+
+impl<S: Storage> PartialEq<[u8]> for Data<S> {
+    #[inline]
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_ref() == other
+    }
+}
+
+impl<S: Storage, const N: usize> PartialEq<[u8; N]> for Data<S> {
+    #[inline]
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.as_ref() == other
+    }
+}
+
 impl<'a> Data<&'a [u8]> {
     /// Transform the [Data] object into a slice for the data pointed to.
     /// This also extracts the lifetime and can be useful to get an object
@@ -200,10 +316,143 @@ impl<'a> Data<&'a mut [u8]> {
     }
 }
 
+#[cfg(feature = "bytes")]
+impl Data<bytes::Bytes> {
+    /// Transform the [Data] object into a [bytes::Bytes] instance for the data pointed to.
+    /// This doesn't copy the data, the returned [bytes::Bytes] instance shares the underlying
+    /// buffer with the original storage.
+    ///
+    /// Example:
+    /// ---------------
+    /// ```
+    /// use binary_layout::binary_layout;
+    /// use bytes::Bytes;
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///   field: u16,
+    ///   data: [u8],
+    /// });
+    ///
+    /// fn payload(packet: Bytes) -> Bytes {
+    ///   let view = my_layout::View::new(packet);
+    ///   view.into_data().into_bytes()
+    /// }
+    ///
+    /// let packet = Bytes::from(vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(Bytes::from_static(&[3, 4, 5]), payload(packet));
+    /// ```
+    #[inline]
+    pub fn into_bytes(self) -> bytes::Bytes {
+        self.storage.slice(self.region)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl From<Data<bytes::Bytes>> for bytes::Bytes {
+    #[inline]
+    fn from(data: Data<bytes::Bytes>) -> bytes::Bytes {
+        data.into_bytes()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Data<bytes::BytesMut> {
+    /// Transform the [Data] object into a [bytes::BytesMut] instance for the data pointed to.
+    /// This doesn't copy the data, the returned [bytes::BytesMut] instance owns the part of
+    /// the underlying buffer that belongs to the subregion.
+    ///
+    /// Example:
+    /// ---------------
+    /// ```
+    /// use binary_layout::binary_layout;
+    /// use bytes::BytesMut;
+    ///
+    /// binary_layout!(my_layout, LittleEndian, {
+    ///   field: u16,
+    ///   data: [u8],
+    /// });
+    ///
+    /// fn payload(packet: BytesMut) -> BytesMut {
+    ///   let view = my_layout::View::new(packet);
+    ///   view.into_data().into_bytes_mut()
+    /// }
+    ///
+    /// let packet = BytesMut::from(&[1, 2, 3, 4, 5][..]);
+    /// assert_eq!(&[3, 4, 5], &payload(packet)[..]);
+    /// ```
+    #[inline]
+    pub fn into_bytes_mut(self) -> bytes::BytesMut {
+        let mut storage = self.storage;
+        storage.truncate(self.region.end);
+        storage.split_off(self.region.start.min(self.region.end))
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl From<Data<bytes::BytesMut>> for bytes::BytesMut {
+    #[inline]
+    fn from(data: Data<bytes::BytesMut>) -> bytes::BytesMut {
+        data.into_bytes_mut()
+    }
+}
+
 #[cfg(test)]
-#[cfg(feature = "std")] // TODO add no-std tests?
+mod no_alloc_tests {
+    use super::*;
+
+    #[test]
+    fn region_and_into_inner() {
+        let data = Data::from([1, 2, 3, 4, 5, 6]).into_subregion(1..5);
+        assert_eq!(1..5, data.region());
+        let data = data.into_subregion(1..);
+        assert_eq!(2..5, data.region());
+        assert_eq!([1, 2, 3, 4, 5, 6], data.into_inner());
+    }
+
+    #[test]
+    fn index() {
+        let mut data = Data::from([1, 2, 3, 4, 5, 6]).into_subregion(2..);
+        assert_eq!(3, data[0]);
+        assert_eq!([4, 5], data[1..3]);
+        data[3] = 10;
+        data[..2].copy_from_slice(&[7, 8]);
+        assert_eq!([1, 2, 7, 8, 5, 10], data.into_inner());
+    }
+
+    #[test]
+    fn eq() {
+        let data = Data::from([1, 2, 3, 4]).into_subregion(1..3);
+        assert_eq!(data, [2, 3]);
+        assert_eq!(data, [2, 3][..]);
+        assert_eq!(data, Data::from(&[2, 3][..]));
+        assert_eq!(data, Data::from([0, 0, 2, 3]).into_subregion(2..));
+        assert_ne!(data, [2, 3, 4]);
+        assert_ne!(data, Data::from([1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn array_storage() {
+        let data = Data::from([1, 2, 3, 4, 5, 6]).into_subregion(1..5);
+        assert_eq!(&[2, 3, 4, 5], data.as_ref());
+        let data = data.into_subregion(2..);
+        assert_eq!(&[4, 5], &*data);
+    }
+
+    #[test]
+    fn mut_slice_storage() {
+        let mut storage = [1, 2, 3, 4, 5, 6];
+        let mut data = Data::from(&mut storage[..]).into_subregion(..=2);
+        data.as_mut().copy_from_slice(&[7, 8, 9]);
+        assert_eq!(&mut [7, 8, 9], data.into_slice());
+        assert_eq!([7, 8, 9, 4, 5, 6], storage);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
 mod tests {
     use super::*;
+    use alloc::{vec, vec::Vec};
     use rand::{rngs::StdRng, RngCore, SeedableRng};
 
     fn data_region(size: usize, seed: u64) -> Vec<u8> {
@@ -378,4 +627,23 @@ mod tests {
         let data = data.into_subregion(5000..400);
         assert_eq!(0, data.len());
     }
+
+    #[test]
+    fn debug() {
+        let data = Data::from(vec![1, 2, 3, 4]).into_subregion(1..3);
+        assert_eq!(
+            "Data { region: 1..3, data: [2, 3] }",
+            alloc::format!("{:?}", data)
+        );
+    }
+
+    #[test]
+    fn resize() {
+        let mut data = Data::from(vec![1, 2, 3, 4]).into_subregion(1..3);
+        data.resize(4);
+        assert_eq!(&[2, 3, 0, 0], &*data);
+        data.resize(1);
+        assert_eq!(&[2], &*data);
+        assert_eq!(vec![1, 2], data.into_inner());
+    }
 }
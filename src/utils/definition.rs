@@ -0,0 +1,64 @@
+//! Helpers for writing [binary_layout!](crate::binary_layout!) definitions from layouts imported from other formats
+
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use crate::EndianKind;
+
+/// Rust keywords that field names have to be escaped for
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
+
+/// Turn `name` into a field name, escaping it as a raw identifier if it is a Rust keyword.
+/// Fails for the keywords that can't be raw identifiers.
+pub fn field_name(name: &str) -> Result<String, String> {
+    if RUST_KEYWORDS.contains(&name) {
+        Ok(format!("r#{}", name))
+    } else if matches!(name, "self" | "Self" | "super" | "crate") {
+        Err(format!(
+            "The name `{}` can't be used as a field name in Rust",
+            name
+        ))
+    } else {
+        Ok(name.to_string())
+    }
+}
+
+/// A field of a definition written by [write_definition]
+pub struct DefinitionField {
+    pub name: String,
+    pub field_type: String,
+    /// Written as a `//` comment after the field
+    pub comment: Option<&'static str>,
+}
+
+/// Write the [binary_layout!](crate::binary_layout!) invocation for a public layout with the given fields
+pub fn write_definition(
+    name: &str,
+    endianness: EndianKind,
+    fields: impl IntoIterator<Item = DefinitionField>,
+) -> String {
+    let endianness = match endianness {
+        EndianKind::Big => "BigEndian",
+        EndianKind::Little => "LittleEndian",
+        EndianKind::Native => "NativeEndian",
+    };
+    let mut definition = format!(
+        "::binary_layout::binary_layout!(pub {}, {}, {{\n",
+        name, endianness
+    );
+    for field in fields {
+        definition += &format!("    {}: {},", field.name, field.field_type);
+        if let Some(comment) = field.comment {
+            definition += &format!(" // {}", comment);
+        }
+        definition += "\n";
+    }
+    definition += "});\n";
+    definition
+}
@@ -0,0 +1,6 @@
+// The error trait implemented by the error types of this crate. With `std`, this is `std::error::Error`.
+// Without `std`, the `core-error` feature uses `core::error::Error`, which needs Rust 1.81 or later.
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+pub use core::error::Error;
+#[cfg(feature = "std")]
+pub use std::error::Error;
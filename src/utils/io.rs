@@ -0,0 +1,111 @@
+use std::io::{ErrorKind, IoSlice, Result, Write};
+
+/// This extension trait adds methods to write the data of a view to a [Write], e.g. a file or a socket, optionally with vectored writes.
+/// It is implemented for the `View` of each layout defined with [binary_layout!](crate::binary_layout!) if the `std` feature is enabled,
+/// and it is part of the [prelude](crate::prelude).
+///
+/// These are trait methods so that they don't collide with the accessors of fields that have the same name.
+///
+/// # Example:
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// binary_layout!(my_layout, BigEndian, {
+///   field1: u16,
+///   tail: [u8],
+/// });
+///
+/// let view = my_layout::View::new(vec![1, 2, 3, 4]);
+/// let mut written = Vec::new();
+/// view.write_to(&mut written).unwrap();
+/// assert_eq!(vec![1, 2, 3, 4], written);
+/// ```
+pub trait ViewIoExt {
+    /// Write the data of this view to `writer`.
+    ///
+    /// For layouts with a static `SIZE`, this writes exactly `SIZE` bytes, even if the underlying storage is larger.
+    /// For layouts ending with an open ended field, this writes the whole storage.
+    fn write_to<W: Write>(&self, writer: W) -> Result<()>;
+
+    /// Return the data of this view as two [IoSlice]s for [Write::write_vectored],
+    /// the first one with the `FIXED_SIZE` bytes of the fixed-size part of the layout and the second one with its open ended tail.
+    ///
+    /// For layouts with a static `SIZE`, the second slice is empty.
+    fn as_io_slices(&self) -> [IoSlice<'_>; 2];
+
+    /// Return the fixed-size part of this view and the given `tail` as two [IoSlice]s for [Write::write_vectored].
+    ///
+    /// This allows sending a layout whose tail lives in a different buffer than its fixed-size part without copying them together.
+    /// If the storage of this view already contains data for the open ended tail, that data is ignored.
+    fn as_io_slices_with_tail<'a>(&'a self, tail: &'a [u8]) -> [IoSlice<'a>; 2];
+
+    /// Write the fixed-size part of this view followed by `tail` to `writer`, using vectored writes so that
+    /// they don't need to be copied into one buffer first. See [ViewIoExt::as_io_slices_with_tail].
+    fn write_with_tail_to<W: Write>(&self, writer: W, tail: &[u8]) -> Result<()>;
+}
+
+/// Write two buffers to `writer` with [Write::write_vectored], retrying until both of them are written completely.
+/// This is like the unstable `Write::write_all_vectored`.
+pub fn write_all_vectored<W: Write>(
+    mut writer: W,
+    mut first: &[u8],
+    mut second: &[u8],
+) -> Result<()> {
+    while !first.is_empty() || !second.is_empty() {
+        match writer.write_vectored(&[IoSlice::new(first), IoSlice::new(second)]) {
+            Ok(0) => {
+                return Err(std::io::Error::new(
+                    ErrorKind::WriteZero,
+                    "failed to write whole buffer",
+                ));
+            }
+            Ok(written) => {
+                let written_from_first = written.min(first.len());
+                first = &first[written_from_first..];
+                second = &second[(written - written_from_first)..];
+            }
+            Err(error) if error.kind() == ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A writer that only accepts a few bytes per call, to test that partial writes are continued
+    struct SlowWriter {
+        written: Vec<u8>,
+    }
+
+    impl Write for SlowWriter {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            let len = buf.len().min(3);
+            self.written.extend_from_slice(&buf[..len]);
+            Ok(len)
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn partial_writes() {
+        let mut writer = SlowWriter {
+            written: Vec::new(),
+        };
+        write_all_vectored(&mut writer, &[1, 2, 3, 4], &[5, 6, 7, 8, 9]).unwrap();
+        assert_eq!(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], writer.written);
+    }
+
+    #[test]
+    fn write_zero() {
+        let mut storage = [0; 3];
+        let error = write_all_vectored(&mut storage[..], &[1, 2], &[3, 4]).unwrap_err();
+        assert_eq!(ErrorKind::WriteZero, error.kind());
+        assert_eq!([1, 2, 3], storage);
+    }
+}
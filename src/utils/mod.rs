@@ -1,2 +1,10 @@
 pub mod data;
+#[cfg(any(feature = "c-import", feature = "kaitai-import"))]
+pub mod definition;
+#[cfg(any(feature = "std", feature = "core-error"))]
+pub mod error;
 pub mod infallible;
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(any(feature = "zerocopy", feature = "bytemuck"))]
+pub mod target_endian;
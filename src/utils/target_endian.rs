@@ -0,0 +1,10 @@
+use crate::EndianKind;
+
+/// Returns true if data stored with the given endianness can be reinterpreted as native types on the target platform
+pub const fn matches_target_endianness(endianness: EndianKind) -> bool {
+    match endianness {
+        EndianKind::Native => true,
+        EndianKind::Little => cfg!(target_endian = "little"),
+        EndianKind::Big => cfg!(target_endian = "big"),
+    }
+}
@@ -0,0 +1,114 @@
+use core::convert::TryFrom;
+
+use crate::{Error, ErrorKind, LayoutAs};
+
+/// This error is thrown when reading a field of one of the narrow integer types of the [ux](https://crates.io/crates/ux) crate,
+/// e.g. `ux::u12 as u16`, whose stored value is out of range for the narrow type. This requires the `ux` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntOutOfRangeError(());
+
+impl core::fmt::Display for IntOutOfRangeError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "IntOutOfRangeError")
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl crate::utils::error::Error for IntOutOfRangeError {}
+
+impl From<IntOutOfRangeError> for Error {
+    fn from(_: IntOutOfRangeError) -> Self {
+        Self::new(ErrorKind::LayoutAs)
+    }
+}
+
+// Implements [LayoutAs] for the integer types of the `ux` crate, so that e.g. `field: ux::u12 as u16`
+// reads a `ux::u12` from a `u16` in the storage.
+macro_rules! impl_layout_as {
+    ($($type: ident),* $(,)?) => {
+        $(
+            impl<U> LayoutAs<U> for ux::$type
+            where
+                ux::$type: TryFrom<U>,
+                U: From<ux::$type>,
+            {
+                type ReadError = IntOutOfRangeError;
+                type WriteError = core::convert::Infallible;
+
+                #[inline(always)]
+                fn try_read(v: U) -> Result<Self, Self::ReadError> {
+                    ux::$type::try_from(v).map_err(|_| IntOutOfRangeError(()))
+                }
+
+                #[inline(always)]
+                fn try_write(v: Self) -> Result<U, Self::WriteError> {
+                    Ok(U::from(v))
+                }
+            }
+        )*
+    };
+}
+
+impl_layout_as!(
+    u1, u2, u3, u4, u5, u6, u7, u9, u10, u11, u12, u13, u14, u15, u17, u18, u19, u20, u21, u22,
+    u23, u24, u25, u26, u27, u28, u29, u30, u31, u33, u34, u35, u36, u37, u38, u39, u40, u41, u42,
+    u43, u44, u45, u46, u47, u48, u49, u50, u51, u52, u53, u54, u55, u56, u57, u58, u59, u60, u61,
+    u62, u63, u65, u66, u67, u68, u69, u70, u71, u72, u73, u74, u75, u76, u77, u78, u79, u80, u81,
+    u82, u83, u84, u85, u86, u87, u88, u89, u90, u91, u92, u93, u94, u95, u96, u97, u98, u99, u100,
+    u101, u102, u103, u104, u105, u106, u107, u108, u109, u110, u111, u112, u113, u114, u115, u116,
+    u117, u118, u119, u120, u121, u122, u123, u124, u125, u126, u127,
+);
+
+impl_layout_as!(
+    i1, i2, i3, i4, i5, i6, i7, i9, i10, i11, i12, i13, i14, i15, i17, i18, i19, i20, i21, i22,
+    i23, i24, i25, i26, i27, i28, i29, i30, i31, i33, i34, i35, i36, i37, i38, i39, i40, i41, i42,
+    i43, i44, i45, i46, i47, i48, i49, i50, i51, i52, i53, i54, i55, i56, i57, i58, i59, i60, i61,
+    i62, i63, i65, i66, i67, i68, i69, i70, i71, i72, i73, i74, i75, i76, i77, i78, i79, i80, i81,
+    i82, i83, i84, i85, i86, i87, i88, i89, i90, i91, i92, i93, i94, i95, i96, i97, i98, i99, i100,
+    i101, i102, i103, i104, i105, i106, i107, i108, i109, i110, i111, i112, i113, i114, i115, i116,
+    i117, i118, i119, i120, i121, i122, i123, i124, i125, i126, i127,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::IntOutOfRangeError;
+    use crate::prelude::*;
+    use crate::{Error, ErrorKind, WrappedFieldError};
+
+    binary_layout!(layout, BigEndian, {
+        small: ux::u4 as u8,
+        medium: ux::u12 as u16,
+        signed: ux::i20 as i32,
+        wide: ux::u12 as u32,
+    });
+
+    #[test]
+    fn read_and_write() {
+        let mut view = layout::View::new([0; 11]);
+        view.small_mut().write(ux::u4::new(9));
+        view.medium_mut().write(ux::u12::MAX);
+        view.signed_mut().write(ux::i20::MIN);
+        view.wide_mut().write(ux::u12::new(0x123));
+        assert_eq!(ux::u4::new(9), view.small().try_read().unwrap());
+        assert_eq!(ux::u12::MAX, view.medium().try_read().unwrap());
+        assert_eq!(ux::i20::MIN, view.signed().try_read().unwrap());
+        assert_eq!(ux::u12::new(0x123), view.wide().try_read().unwrap());
+        assert_eq!(
+            [9, 0x0f, 0xff, 0xff, 0xf8, 0, 0, 0, 0, 0x01, 0x23],
+            view.into_storage()
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        let view = layout::View::new([0x10, 0x10, 0, 0, 0x08, 0, 0, 0, 0, 0, 0]);
+        assert!(matches!(
+            view.small().try_read(),
+            Err(WrappedFieldError::LayoutAsError(IntOutOfRangeError(())))
+        ));
+        assert!(view.medium().try_read().is_err());
+        assert!(view.signed().try_read().is_err());
+        let error = Error::from(view.medium().try_read().unwrap_err());
+        assert_eq!(ErrorKind::LayoutAs, error.kind());
+    }
+}
@@ -0,0 +1,37 @@
+/// This error is returned by the generated `View::validate()` method if one of the validation checks
+/// declared in the [binary_layout!](crate::binary_layout!) definition failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationError {
+    layout: &'static str,
+    check: &'static str,
+}
+
+impl ValidationError {
+    #[doc(hidden)]
+    pub const fn __new(layout: &'static str, check: &'static str) -> Self {
+        Self { layout, check }
+    }
+
+    /// The name of the layout whose validation failed
+    pub const fn layout(&self) -> &'static str {
+        self.layout
+    }
+
+    /// The check that failed, as written in the layout definition, e.g. `view.version().read() == 2`
+    pub const fn check(&self) -> &'static str {
+        self.check
+    }
+}
+
+impl core::fmt::Display for ValidationError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            fmt,
+            "Validation of layout `{}` failed: `{}`",
+            self.layout, self.check
+        )
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl crate::utils::error::Error for ValidationError {}
@@ -0,0 +1,161 @@
+use core::ops::Range;
+
+use crate::{Field, FieldCopyAccess, FieldReadExt, FieldWriteExt, Storage, StorageMut};
+
+/// An instrumented view for debug builds that remembers which bytes were written and panics when a field is read before it was written.
+/// This requires the `std` feature.
+///
+/// When building a packet in a freshly zeroed buffer, it's easy to forget setting a field, e.g. a length or checksum field,
+/// and since zero is often a valid value, nothing points out the mistake. Building the packet through a [WriteTracked] view
+/// catches this: [WriteTracked::read] panics if any byte of the field wasn't written through [WriteTracked::write] or
+/// [WriteTracked::try_write] or declared as written with [WriteTracked::mark_written] before.
+///
+/// Fields are accessed by their type, like with `View::get` and `View::set`. This works for all fields with copy access.
+/// Other fields, e.g. byte slices or nested layouts, and methods like `View::finalize` can be accessed through a view over
+/// [WriteTracked::inner_mut]. Those writes aren't tracked, so the fields have to be declared as written afterwards
+/// with [WriteTracked::mark_field_written].
+///
+/// The checks only happen in builds with debug assertions, like [debug_assert!]. In release builds, this is a plain wrapper around the storage.
+/// Views created by the [binary_layout!](crate::binary_layout!) macro aren't instrumented, so their accesses are never checked.
+///
+/// # Example
+/// ```should_panic
+/// use binary_layout::{prelude::*, WriteTracked};
+///
+/// binary_layout!(packet, BigEndian, {
+///   kind: u8,
+///   len: u16,
+/// });
+///
+/// let mut packet = WriteTracked::new([0; 3]);
+/// packet.write::<packet::kind>(1);
+/// assert_eq!(1, packet.read::<packet::kind>());
+///
+/// // Panics in debug builds because `len` was never written
+/// let len = packet.read::<packet::len>();
+/// # panic!("Reads are only checked if the library was built with debug assertions");
+/// ```
+pub struct WriteTracked<S> {
+    storage: S,
+    #[cfg(debug_assertions)]
+    written: Vec<bool>,
+}
+
+impl<S: Storage> WriteTracked<S> {
+    /// Wrap a storage, e.g. a freshly zeroed buffer. All of its bytes are considered not written yet.
+    pub fn new(storage: S) -> Self {
+        Self {
+            #[cfg(debug_assertions)]
+            written: vec![false; storage.as_bytes().len()],
+            storage,
+        }
+    }
+
+    /// Declare the bytes in `range` as written, e.g. because they were written through a view over [WriteTracked::inner_mut]
+    /// or the storage already contained a valid header.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds of the storage.
+    #[track_caller]
+    pub fn mark_written(&mut self, range: Range<usize>) {
+        let len = self.storage.as_bytes().len();
+        assert!(
+            range.start <= range.end && range.end <= len,
+            "Range {:?} is out of bounds of a storage with {} bytes",
+            range,
+            len,
+        );
+        #[cfg(debug_assertions)]
+        self.written[range].fill(true);
+    }
+
+    /// Declare field `F` as written, e.g. after `View::finalize` computed it. For open ended fields, this covers the rest of the storage.
+    ///
+    /// # Panics
+    /// Panics if the storage is too short for the field.
+    #[track_caller]
+    pub fn mark_field_written<F: Field>(&mut self) {
+        let range = self.field_range::<F>();
+        self.mark_written(range);
+    }
+
+    /// Read field `F`, e.g. `tracked.read::<my_layout::field>()`, like `View::get` does.
+    ///
+    /// # Panics
+    /// Panics in debug builds if any byte of the field wasn't written yet, and like `View::get` if the storage is too short for the field.
+    #[track_caller]
+    pub fn read<F: FieldReadExt>(&self) -> F::HighLevelType {
+        self.check_written::<F>();
+        F::read(self.storage.as_bytes())
+    }
+
+    /// Like [WriteTracked::read], but for fields whose reads can fail, e.g. `NonZeroU32` or range constrained fields.
+    #[track_caller]
+    pub fn try_read<F: FieldCopyAccess>(&self) -> Result<F::HighLevelType, F::ReadError> {
+        self.check_written::<F>();
+        F::try_read(self.storage.as_bytes())
+    }
+
+    /// Borrow the wrapped storage
+    pub fn inner(&self) -> &S {
+        &self.storage
+    }
+
+    /// Destroy the wrapper and return the wrapped storage
+    pub fn into_inner(self) -> S {
+        self.storage
+    }
+
+    #[track_caller]
+    fn field_range<F: Field>(&self) -> Range<usize> {
+        let len = self.storage.as_bytes().len();
+        let end = F::SIZE.map_or(len, |size| F::OFFSET + size);
+        F::OFFSET..end
+    }
+
+    #[inline(always)]
+    #[track_caller]
+    fn check_written<F: Field>(&self) {
+        #[cfg(debug_assertions)]
+        {
+            let range = self.field_range::<F>();
+            // Out of bounds fields are left to the read, which panics with the usual message
+            if let Some(written) = self.written.get(range.clone()) {
+                if !written.iter().all(|&written| written) {
+                    panic!(
+                        "Read of the field at offset {} with {} bytes before it was written",
+                        range.start,
+                        range.len()
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl<S: StorageMut> WriteTracked<S> {
+    /// Write `value` to field `F`, e.g. `tracked.write::<my_layout::field>(value)`, like `View::set` does, and record that the field was written.
+    #[track_caller]
+    pub fn write<F: FieldWriteExt>(&mut self, value: F::HighLevelType) {
+        F::write(self.storage.as_bytes_mut(), value);
+        self.mark_field_written::<F>();
+    }
+
+    /// Like [WriteTracked::write], but for fields whose writes can fail, e.g. range constrained fields.
+    /// A failed write isn't recorded.
+    #[track_caller]
+    pub fn try_write<F: FieldCopyAccess>(
+        &mut self,
+        value: F::HighLevelType,
+    ) -> Result<(), F::WriteError> {
+        F::try_write(self.storage.as_bytes_mut(), value)?;
+        self.mark_field_written::<F>();
+        Ok(())
+    }
+
+    /// Borrow the wrapped storage with write access, e.g. to create a view over it and call `View::finalize`.
+    /// Writes through it aren't tracked, see [WriteTracked::mark_field_written].
+    pub fn inner_mut(&mut self) -> &mut S {
+        &mut self.storage
+    }
+}
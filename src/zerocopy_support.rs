@@ -0,0 +1,26 @@
+use core::marker::PhantomData;
+
+use crate::fields::primitive::NestedViewInfo;
+use crate::utils::target_endian::matches_target_endianness;
+
+/// Compile time checks for the conversions between views of the layout `N` and the zerocopy type `T`.
+/// Accessing [ZerocopyCompat::CHECK] fails to compile if the conversion isn't possible.
+#[doc(hidden)]
+pub struct ZerocopyCompat<N, T>(PhantomData<(N, T)>);
+
+impl<N: NestedViewInfo, T> ZerocopyCompat<N, T> {
+    /// Evaluating this fails if views of `N` can't be converted to and from `T`
+    pub const CHECK: () = {
+        match N::SIZE {
+            Some(size) => assert!(
+                size == core::mem::size_of::<T>(),
+                "The zerocopy type must have the same size as the layout"
+            ),
+            None => panic!("Only layouts with a static size can be converted to zerocopy types"),
+        }
+        assert!(
+            matches_target_endianness(N::LAYOUT.endianness()),
+            "Only layouts with native endianness or the endianness of the target can be converted to zerocopy types"
+        );
+    };
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use binary_layout::{binary_layout_tests, prelude::*, LayoutAs};
+use core::num::NonZeroU32;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use std::convert::Infallible;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Even(u16);
+impl LayoutAs<u16> for Even {
+    type ReadError = ();
+    type WriteError = ();
+
+    fn try_read(v: u16) -> Result<Self, ()> {
+        if v % 2 == 0 {
+            Ok(Even(v))
+        } else {
+            Err(())
+        }
+    }
+
+    fn try_write(v: Self) -> Result<u16, ()> {
+        Self::try_read(v.0).map(|v| v.0)
+    }
+}
+impl<'a> Arbitrary<'a> for Even {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Even(u16::arbitrary(u)? & !1))
+    }
+}
+
+/// A custom type without an [Arbitrary] implementation
+struct Opaque(u16);
+impl LayoutAs<u16> for Opaque {
+    type ReadError = Infallible;
+    type WriteError = Infallible;
+
+    fn try_read(v: u16) -> Result<Self, Infallible> {
+        Ok(Opaque(v))
+    }
+
+    fn try_write(v: Self) -> Result<u16, Infallible> {
+        Ok(v.0)
+    }
+}
+
+binary_layout!(inner, LittleEndian, {
+    flag: bool as u8,
+    id: NonZeroU32,
+});
+
+binary_layout!(outer, BigEndian, {
+    head: inner::NestedView,
+    even: Even as u16,
+    opaque: Opaque as u16,
+    bytes: [u8; 5],
+    tail: [u8],
+});
+
+binary_layout!(numbers, LittleEndian, {
+    small: i8,
+    medium: u16 where ..1000,
+    large: i64,
+    huge: u128,
+    single: f32,
+    double: f64,
+    big_endian: outer::NestedView,
+});
+
+binary_layout_tests!(inner, outer, numbers);
+
+fn random_data(seed: u64) -> Vec<u8> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut data = vec![0; 1024];
+    rng.fill_bytes(&mut data);
+    data
+}
+
+#[test]
+fn generates_valid_data() {
+    let mut seen_true = false;
+    let mut seen_false = false;
+    let mut seen_tail_lengths = std::collections::HashSet::new();
+    for seed in 0..100 {
+        let data = random_data(seed);
+        let view = outer::View::<Vec<u8>>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        match view.head().flag().try_read().unwrap() {
+            true => seen_true = true,
+            false => seen_false = true,
+        }
+        assert!(view.head().id().try_read().is_ok());
+        assert!(view.even().try_read().is_ok());
+        seen_tail_lengths.insert(view.tail().len());
+        assert_eq!(
+            outer::FIXED_SIZE + view.tail().len(),
+            view.into_storage().len()
+        );
+    }
+    assert!(seen_true && seen_false);
+    assert!(seen_tail_lengths.len() > 1);
+}
+
+#[test]
+fn fixed_size_layout_has_exact_size() {
+    let data = random_data(0);
+    let view = inner::View::<Vec<u8>>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+    assert_eq!(inner::SIZE, Some(view.into_storage().len()));
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_strategy {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn storage_strategy_produces_valid_data(storage in outer::storage_strategy()) {
+            let view = outer::View::new(&storage);
+            prop_assert!(view.head().flag().try_read().is_ok());
+            prop_assert!(view.head().id().try_read().is_ok());
+            prop_assert!(view.even().try_read().is_ok());
+            prop_assert!(storage.len() >= outer::FIXED_SIZE);
+        }
+
+        #[test]
+        fn round_trip(storage in inner::storage_strategy()) {
+            let view = inner::View::new(&storage);
+            let mut copy = vec![0; inner::SIZE.unwrap()];
+            let mut copy_view = inner::View::new(&mut copy);
+            copy_view.flag_mut().write(view.flag().try_read().unwrap());
+            copy_view.id_mut().write(view.id().try_read().unwrap());
+            prop_assert_eq!(storage, copy);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use binary_layout::{prelude::*, FieldArrayAccess, FieldKind};
+
+binary_layout!(histogram, BigEndian, {
+    kind: u8,
+    buckets: [u32; 4],
+    scale: [f32; 2],
+    samples: [i16],
+});
+
+binary_layout!(coefficients, LittleEndian, {
+    values: [i64; 2],
+}, owned #[derive(Debug, PartialEq)] Coefficients);
+
+#[test]
+fn metadata() {
+    assert_eq!(1, histogram::buckets::OFFSET);
+    assert_eq!(Some(16), histogram::buckets::SIZE);
+    assert_eq!(17, histogram::scale::OFFSET);
+    assert_eq!(25, histogram::samples::OFFSET);
+    assert_eq!(None, histogram::samples::SIZE);
+    assert_eq!(25, histogram::FIXED_SIZE);
+    assert_eq!(
+        FieldKind::Array {
+            element: &FieldKind::UnsignedInt,
+            element_size: 4
+        },
+        histogram::LAYOUT.field("buckets").unwrap().kind()
+    );
+    assert_ne!(
+        histogram::LAYOUT.field("buckets").unwrap().kind(),
+        histogram::LAYOUT.field("samples").unwrap().kind()
+    );
+}
+
+#[test]
+fn element_access() {
+    let mut storage = vec![0; 29];
+    let mut view = histogram::View::new(&mut storage);
+    assert_eq!(4, view.buckets().len());
+    view.buckets_mut().write_at(2, 0x01020304);
+    view.samples_mut().write_at(1, -2);
+    assert_eq!(0x01020304, view.buckets().read_at(2));
+    assert_eq!(0, view.buckets().read_at(3));
+    assert_eq!(2, view.samples().len());
+    assert_eq!(-2, view.samples().read_at(1));
+    assert_eq!([0, 0, 0x01020304, 0], view.buckets().read());
+    assert_eq!(&[1, 2, 3, 4], &storage[9..13]);
+    assert_eq!(&[0xff, 0xfe], &storage[27..29]);
+}
+
+#[test]
+fn field_api() {
+    let mut storage = [0; 30];
+    histogram::scale::write(&mut storage, [1.5, -0.5]);
+    histogram::samples::write_at(&mut storage, 0, 7);
+    assert_eq!(-0.5, histogram::scale::read_at(&storage, 1));
+    assert_eq!(7, histogram::samples::read_at(&storage, 0));
+    assert_eq!(&[0, 7], &storage[25..27]);
+    // The last byte isn't a complete element
+    assert_eq!(2, histogram::samples::len(&storage));
+    assert!(histogram::samples::is_empty(&storage[..26]));
+}
+
+#[test]
+#[should_panic(expected = "Index 2 is out of bounds of an array with 2 elements")]
+fn out_of_bounds() {
+    let view = histogram::View::new([0; 29]);
+    view.samples().read_at(2);
+}
+
+#[test]
+fn owned() {
+    let mut view = coefficients::View::new([0; 16]);
+    view.write_owned(&coefficients::Coefficients { values: [-1, 2] })
+        .unwrap();
+    assert_eq!(-1, view.values().read_at(0));
+    assert_eq!(
+        coefficients::Coefficients { values: [-1, 2] },
+        view.read_owned().unwrap()
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn value() {
+    let storage = [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0];
+    assert_eq!(
+        "coefficients { values: [1, 2] }",
+        coefficients::LAYOUT.value(&storage).to_string()
+    );
+}
+
+#[cfg(feature = "c-header")]
+#[test]
+fn c_header() {
+    let c_header = histogram::LAYOUT.to_c_header();
+    for line in [
+        "    uint32_t buckets[4]; /* big endian */",
+        "    float scale[2]; /* big endian */",
+        "    int16_t samples[]; /* big endian */",
+    ] {
+        assert!(
+            c_header.lines().any(|l| l == line),
+            "Missing line {:?} in:\n{}",
+            line,
+            c_header
+        );
+    }
+}
+
+binary_layout!(capture<E: Endianness>, {
+    channels: [u16; 2],
+    samples: [i32],
+});
+
+#[test]
+fn convert_endianness() {
+    let src = [0, 1, 0, 2, 0, 0, 0, 3];
+    let mut dst = [0; 8];
+    capture::big_endian::convert_to::<capture::little_endian::NestedView>(&src, &mut dst).unwrap();
+    assert_eq!([1, 0, 2, 0, 3, 0, 0, 0], dst);
+    let view = capture::View::<LittleEndian, _>::new(&dst);
+    assert_eq!([1, 2], view.channels().read());
+    assert_eq!(3, view.samples().read_at(0));
+}
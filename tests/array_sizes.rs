@@ -0,0 +1,47 @@
+#![deny(warnings)]
+
+use binary_layout::prelude::*;
+
+const SECTOR_SIZE: usize = 512;
+
+mod btrfs {
+    pub const CSUM_SIZE: usize = 32;
+}
+
+const fn block_count(size: usize) -> usize {
+    size / 256
+}
+
+binary_layout!(superblock, LittleEndian, {
+    csum: [u8; btrfs::CSUM_SIZE],
+    sectors: [u8; 4 * SECTOR_SIZE],
+    braced: [u8; { SECTOR_SIZE / 2 }],
+    size_of: [u8; core::mem::size_of::<u64>()],
+    blocks: [u8; block_count(SECTOR_SIZE)],
+    generation: u64,
+});
+
+// Offsets and sizes are available at compile time
+const _: () = assert!(superblock::generation::OFFSET == 32 + 2048 + 256 + 8 + 2);
+const _: [u8; 2354] = [0; superblock::FIXED_SIZE];
+
+#[test]
+fn metadata() {
+    assert_eq!(32, superblock::sectors::OFFSET);
+    assert_eq!(Some(2048), superblock::sectors::SIZE);
+    assert_eq!(2080, superblock::braced::OFFSET);
+    assert_eq!(Some(8), superblock::size_of::SIZE);
+    assert_eq!(Some(2), superblock::blocks::SIZE);
+    assert_eq!(Some(2354), superblock::SIZE);
+}
+
+#[test]
+fn view() {
+    let mut storage = vec![0; 2354];
+    let mut view = superblock::View::new(&mut storage);
+    view.csum_mut()[31] = 1;
+    view.blocks_mut().copy_from_slice(&[2, 3]);
+    view.generation_mut().write(4);
+    assert_eq!(1, storage[31]);
+    assert_eq!(&[2, 3, 4], &storage[2344..2347]);
+}
@@ -0,0 +1,41 @@
+use binary_layout::prelude::*;
+use std::sync::Arc;
+
+binary_layout!(packet, BigEndian, {
+    sequence_number: u32,
+    payload: [u8],
+}, assert_impl(Send, Sync));
+
+binary_layout!(header, LittleEndian, {
+    version: u8,
+    flags: u16,
+}, assert_size(3), assert_impl(Send, Sync, Unpin, Clone,), optional {
+    checksum: u32,
+});
+
+binary_layout!(block<const BLOCK_SIZE: usize>, LittleEndian, {
+    include header,
+    data: [u8; BLOCK_SIZE],
+}, assert_impl(Send, Sync));
+
+binary_layout!(block_16 = block<16>);
+
+#[test]
+fn send_to_thread() {
+    let view = packet::View::new(Arc::<[u8]>::from(vec![0, 0, 0, 5, 1, 2]));
+    let handle = {
+        let view = view.clone();
+        std::thread::spawn(move || view.sequence_number().read())
+    };
+    assert_eq!(5, handle.join().unwrap());
+    assert_eq!(&[1, 2], view.payload());
+}
+
+#[test]
+fn with_other_sections() {
+    assert_eq!(3, header::FIXED_SIZE);
+    let view = header::View::new(vec![1, 2, 0]);
+    assert_eq!(2, view.clone().flags().read());
+    assert!(view.checksum().is_none());
+    assert_eq!(Some(19), block_16::SIZE);
+}
@@ -0,0 +1,27 @@
+use binary_layout::prelude::*;
+
+binary_layout!(superblock, LittleEndian, {
+    magic: u32,
+    block_size: u32,
+    reserved: [u8; 1016],
+}, assert_size(1024));
+
+binary_layout!(packet, BigEndian, {
+    header: [u8; 4],
+}, assert_size(4), optional {
+    extension: u16,
+});
+
+binary_layout!(block<const BLOCK_SIZE: usize>, LittleEndian, {
+    checksum: u32,
+    data: [u8; BLOCK_SIZE],
+}, assert_size(BLOCK_SIZE + 4));
+
+binary_layout!(block_4k = block<4096>);
+
+#[test]
+fn sizes() {
+    assert_eq!(Some(1024), superblock::SIZE);
+    assert_eq!(4, packet::FIXED_SIZE);
+    assert_eq!(Some(4100), block_4k::SIZE);
+}
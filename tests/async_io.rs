@@ -0,0 +1,134 @@
+#![cfg(feature = "async")]
+
+use binary_layout::prelude::*;
+use std::convert::TryInto;
+use std::future::Future;
+use std::io::ErrorKind;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake};
+
+mod common;
+use common::data_region;
+
+// The readers and writers in these tests are in-memory buffers that never return `Poll::Pending`,
+// so polling the future once is enough and the tests don't need an async runtime.
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Arc::new(NoopWaker).into();
+    let mut context = Context::from_waker(&waker);
+    match Box::pin(future).as_mut().poll(&mut context) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("In-memory I/O should complete immediately"),
+    }
+}
+
+binary_layout!(fixed_size, BigEndian, {
+    first: u32,
+    second: [u8; 3],
+});
+
+binary_layout!(open_ended, LittleEndian, {
+    first: u16,
+    tail: [u8],
+});
+
+#[test]
+fn read_fixed_size() {
+    block_on(async {
+        let data = data_region(1024, 0);
+        let mut reader = &data[..];
+        let view = fixed_size::read_from_async(&mut reader).await.unwrap();
+        assert_eq!(1024 - 7, reader.len());
+        assert_eq!(
+            u32::from_be_bytes(data[0..4].try_into().unwrap()),
+            view.first().read()
+        );
+        assert_eq!(&data[4..7], view.second());
+    });
+}
+
+#[test]
+fn read_open_ended() {
+    block_on(async {
+        let data = data_region(1024, 1);
+        let mut reader = &data[..];
+
+        let view = open_ended::read_from_async(&mut reader).await.unwrap();
+        assert_eq!(
+            u16::from_le_bytes(data[0..2].try_into().unwrap()),
+            view.first().read()
+        );
+        assert!(view.tail().is_empty());
+
+        let view = open_ended::read_with_tail_from_async(&mut reader, 10)
+            .await
+            .unwrap();
+        assert_eq!(1024 - 14, reader.len());
+        assert_eq!(&data[4..14], view.tail());
+    });
+}
+
+binary_layout!(framed, BigEndian, {
+    payload_len: u16,
+    payload: [u8],
+});
+
+#[test]
+fn read_tail_after_fixed_size() {
+    block_on(async {
+        let data = [0, 3, 10, 11, 12, 13];
+        let mut reader = &data[..];
+
+        let mut view = framed::read_from_async(&mut reader).await.unwrap();
+        let payload_len = view.payload_len().read();
+        view.read_tail_from_async(&mut reader, payload_len.into())
+            .await
+            .unwrap();
+        assert_eq!(1, reader.len());
+        assert_eq!(&[10, 11, 12], view.payload());
+
+        let error = view
+            .read_tail_from_async(&mut reader, 2)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(ErrorKind::UnexpectedEof, error.kind());
+        assert_eq!(&[10, 11, 12], view.payload());
+    });
+}
+
+#[test]
+fn read_too_short() {
+    block_on(async {
+        let data = data_region(6, 2);
+        let error = fixed_size::read_from_async(&data[..]).await.err().unwrap();
+        assert_eq!(ErrorKind::UnexpectedEof, error.kind());
+    });
+}
+
+#[test]
+fn write() {
+    block_on(async {
+        let mut storage = data_region(1024, 3);
+        let mut view = fixed_size::View::new(&mut storage[..]);
+        view.first_mut().write(0x01020304);
+        view.second_mut().copy_from_slice(&[5, 6, 7]);
+
+        let mut written = Vec::new();
+        view.write_to_async(&mut written).await.unwrap();
+        assert_eq!(vec![1, 2, 3, 4, 5, 6, 7], written);
+
+        let storage = data_region(20, 4);
+        let mut written = Vec::new();
+        open_ended::View::new(&storage)
+            .write_to_async(&mut written)
+            .await
+            .unwrap();
+        assert_eq!(storage, written);
+    });
+}
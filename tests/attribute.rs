@@ -0,0 +1,120 @@
+#![cfg(feature = "derive")]
+
+use binary_layout::attribute::binary_layout;
+use binary_layout::prelude::*;
+
+#[binary_layout(BigEndian)]
+pub struct packet {
+    /// Doc comments are allowed on fields
+    packet_type: u8,
+    checksum: u16,
+    payload: [u8],
+}
+
+#[derive(Default)]
+struct Xor(u8);
+
+impl binary_layout::Checksum for Xor {
+    type Output = u8;
+
+    fn update(&mut self, data: &[u8]) {
+        self.0 = data.iter().fold(self.0, |acc, byte| acc ^ byte);
+    }
+
+    fn finish(self) -> u8 {
+        self.0
+    }
+}
+
+#[binary_layout(BigEndian)]
+struct framed {
+    #[binary_layout(len(payload))]
+    payload_len: u16,
+    #[binary_layout(checksum(Xor, ..))]
+    checksum: u8,
+    payload: [u8],
+}
+
+#[binary_layout(LittleEndian, assert_size(8), assert_impl(Send, Sync))]
+pub(crate) struct header {
+    #[binary_layout(as u8)]
+    is_compressed: bool,
+    #[binary_layout(where 1..=3)]
+    version: u16,
+    #[binary_layout(@ 3, where 1..)]
+    flags: u8,
+    #[name = "type"]
+    header_type: u32,
+}
+
+#[binary_layout(LittleEndian)]
+struct block<const BLOCK_SIZE: usize> {
+    id: u8,
+    data: [u8; BLOCK_SIZE],
+}
+
+binary_layout::binary_layout!(block_4 = block<4>);
+
+binary_layout::binary_layout!(outer, LittleEndian, {
+    include header,
+    nested: packet::NestedView,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(0, packet::packet_type::OFFSET);
+    assert_eq!(1, packet::checksum::OFFSET);
+    assert_eq!(3, packet::payload::OFFSET);
+    assert_eq!(None, packet::SIZE);
+    assert_eq!(Some(8), header::SIZE);
+    assert_eq!(Some(5), block_4::SIZE);
+
+    let names: Vec<&str> = header::LAYOUT.fields().iter().map(|f| f.name()).collect();
+    assert_eq!(vec!["is_compressed", "version", "flags", "type"], names);
+    assert_eq!("bool as u8", header::LAYOUT.fields()[0].type_name());
+}
+
+#[test]
+fn view() {
+    let mut view = packet::View::new(vec![0; 5]);
+    view.packet_type_mut().write(4);
+    view.checksum_mut().write(0x0102);
+    view.payload_mut().copy_from_slice(&[5, 6]);
+    assert_eq!(vec![4, 1, 2, 5, 6], view.into_storage());
+}
+
+#[test]
+fn custom_types_and_ranges() {
+    let mut view = header::View::new([0; 8]);
+    view.is_compressed_mut().try_write(true).unwrap();
+    view.version_mut().try_write(2).unwrap();
+    assert!(view.version_mut().try_write(4).is_err());
+    view.flags_mut().try_write(7).unwrap();
+    view.header_type_mut().write(0x04030201);
+    assert_eq!([1, 2, 0, 7, 1, 2, 3, 4], view.into_storage());
+}
+
+#[test]
+fn nested_and_included() {
+    assert_eq!(8, outer::nested::OFFSET);
+    assert_eq!(4, outer::header_type::OFFSET);
+
+    let mut view = outer::View::new(vec![0; 12]);
+    view.version_mut().try_write(3).unwrap();
+    view.nested_mut().packet_type_mut().write(9);
+    assert_eq!(3, view.version().try_read().unwrap());
+    assert_eq!(9, view.nested().packet_type().read());
+}
+
+#[test]
+fn length_and_checksum_fields() {
+    let mut view = framed::View::new(vec![0, 0, 0, 1, 2, 4]);
+    assert!(view.validate().is_err());
+    assert!(view.verify().is_err());
+    view.finalize().unwrap();
+    assert_eq!(3, view.payload_len().read());
+    // 3 ^ 1 ^ 2 ^ 4
+    assert_eq!(4, view.checksum().read());
+    assert_eq!(Ok(()), view.validate());
+    assert!(view.verify().is_ok());
+}
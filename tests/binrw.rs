@@ -0,0 +1,69 @@
+#![cfg(feature = "binrw")]
+
+use binary_layout::prelude::*;
+use binrw::{binrw, io::Cursor, BinRead, BinWrite};
+
+binary_layout!(header, LittleEndian, {
+    version: u16,
+    flags: u8,
+});
+
+binary_layout!(packet, BigEndian, {
+    id: u32,
+    payload: [u8],
+});
+
+#[binrw]
+#[brw(big)]
+struct Message {
+    kind: u8,
+    header: header::View<Vec<u8>>,
+    payload_len: u16,
+    #[br(args_raw = usize::from(payload_len))]
+    packet: packet::View<Vec<u8>>,
+}
+
+#[test]
+fn read_view() {
+    let mut reader = Cursor::new(vec![1, 0, 2, 3]);
+    let view = header::View::read(&mut reader).unwrap();
+    assert_eq!(1, view.version().read());
+    assert_eq!(2, view.flags().read());
+    assert_eq!(3, reader.position());
+}
+
+#[test]
+fn read_view_with_tail() {
+    let mut reader = Cursor::new(vec![0, 0, 0, 5, 1, 2, 3]);
+    let view = packet::View::read_args(&mut reader, 2).unwrap();
+    assert_eq!(5, view.id().read());
+    assert_eq!(&[1, 2], view.payload());
+}
+
+#[test]
+fn read_view_too_short() {
+    let mut reader = Cursor::new(vec![1, 0]);
+    assert!(header::View::read(&mut reader).is_err());
+}
+
+#[test]
+fn write_view() {
+    let view = header::View::new(vec![1, 0, 2, 0xff]);
+    let mut writer = Cursor::new(Vec::new());
+    view.write(&mut writer).unwrap();
+    assert_eq!(vec![1, 0, 2], writer.into_inner());
+}
+
+#[test]
+fn nested_in_binrw_struct() {
+    let data = vec![7, 1, 0, 2, 0, 2, 0, 0, 0, 5, 10, 11];
+    let message = Message::read(&mut Cursor::new(&data)).unwrap();
+    assert_eq!(7, message.kind);
+    assert_eq!(1, message.header.version().read());
+    assert_eq!(5, message.packet.id().read());
+    assert_eq!(&[10, 11], message.packet.payload());
+
+    let mut writer = Cursor::new(Vec::new());
+    message.write(&mut writer).unwrap();
+    assert_eq!(data, writer.into_inner());
+}
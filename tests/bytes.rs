@@ -0,0 +1,142 @@
+#![cfg(feature = "bytes")]
+
+use binary_layout::{prelude::*, BufSegments, Data, FieldSegmentedExt};
+use bytes::{Buf, Bytes, BytesMut};
+use std::convert::TryInto;
+
+mod common;
+use common::data_region;
+
+binary_layout!(header, BigEndian, {
+    field1: u16,
+    data: [u8; 4],
+});
+
+binary_layout!(layout, LittleEndian, {
+    first: u32,
+    nested: header::NestedView,
+    tail: [u8],
+});
+
+#[test]
+fn view_over_bytes() {
+    let storage = Bytes::from(data_region(1024, 0));
+    let view = layout::View::new(storage);
+    assert_eq!(
+        u32::from_le_bytes(data_region(1024, 0)[0..4].try_into().unwrap()),
+        view.first().read()
+    );
+    assert_eq!(
+        u16::from_be_bytes(data_region(1024, 0)[4..6].try_into().unwrap()),
+        view.nested().field1().read()
+    );
+    assert_eq!(&data_region(1024, 0)[10..], view.tail());
+}
+
+#[test]
+fn extract_tail_from_bytes() {
+    let storage = Bytes::from(data_region(1024, 0));
+    let view = layout::View::new(storage.clone());
+    let tail: Bytes = view.into_tail().into_bytes();
+    assert_eq!(&data_region(1024, 0)[10..], &tail[..]);
+    // The extracted tail shares the buffer with the original storage
+    assert_eq!(storage[10..].as_ptr(), tail.as_ptr());
+}
+
+#[test]
+fn extract_nested_from_bytes() {
+    let storage = Bytes::from(data_region(1024, 0));
+    let view = layout::View::new(storage.clone());
+    let data: Data<Data<Bytes>> = view.into_nested().into_data();
+    assert_eq!(&data_region(1024, 0)[6..10], &*data);
+
+    let view = layout::View::new(storage);
+    let nested: Bytes = Bytes::from(view.into_nested().into_storage());
+    assert_eq!(&data_region(1024, 0)[4..10], &nested[..]);
+}
+
+#[test]
+fn view_over_bytesmut() {
+    let storage = BytesMut::from(&data_region(1024, 0)[..]);
+    let mut view = layout::View::new(storage);
+    view.first_mut().write(1000);
+    view.nested_mut().field1_mut().write(2000);
+    view.tail_mut()[..2].copy_from_slice(&[1, 2]);
+
+    assert_eq!(1000, view.first().read());
+    assert_eq!(2000, view.nested().field1().read());
+    assert_eq!(&[1, 2], &view.tail()[..2]);
+}
+
+#[test]
+fn extract_tail_from_bytesmut() {
+    let storage = BytesMut::from(&data_region(1024, 0)[..]);
+    let view = layout::View::new(storage);
+    let mut tail: BytesMut = view.into_tail().into_bytes_mut();
+    assert_eq!(&data_region(1024, 0)[10..], &tail[..]);
+    tail[0] = 5;
+    assert_eq!(5, tail[0]);
+}
+
+#[test]
+fn extract_empty_subregion_from_bytesmut() {
+    let storage = BytesMut::from(&data_region(10, 0)[..]);
+    let view = layout::View::new(storage);
+    let tail: BytesMut = view.into_tail().into();
+    assert!(tail.is_empty());
+}
+
+#[test]
+fn read_from_chain() {
+    let data = data_region(1024, 6);
+    let chain = Bytes::copy_from_slice(&data[..2])
+        .chain(Bytes::copy_from_slice(&data[2..5]))
+        .chain(Bytes::copy_from_slice(&data[5..]));
+
+    assert_eq!(
+        u32::from_le_bytes(data[0..4].try_into().unwrap()),
+        layout::first::try_read_segmented(&chain).unwrap()
+    );
+    assert_eq!(
+        u16::from_be_bytes(data[4..6].try_into().unwrap()),
+        header::field1::try_read_segmented(&(&data[4..5]).chain(&data[5..])).unwrap()
+    );
+}
+
+#[test]
+fn write_to_chain() {
+    let mut chain = BytesMut::from(&[0u8; 3][..]).chain(BytesMut::from(&[0u8; 3][..]));
+    layout::first::try_write_segmented(&mut chain, 0x01020304).unwrap();
+    assert_eq!(&[4, 3, 2], &chain.first_ref()[..]);
+    assert_eq!(&[1, 0, 0], &chain.last_ref()[..]);
+    assert_eq!(
+        0x01020304,
+        layout::first::try_read_segmented(&chain).unwrap()
+    );
+}
+
+#[test]
+fn read_from_buf_segments() {
+    let data = data_region(1024, 7);
+    let mut buf = Bytes::from(data.clone());
+    buf.advance(2);
+    let storage = BufSegments::new(buf);
+
+    assert_eq!(
+        u32::from_le_bytes(data[2..6].try_into().unwrap()),
+        layout::first::try_read_segmented(&storage).unwrap()
+    );
+    assert_eq!(
+        u16::from_be_bytes(data[2..4].try_into().unwrap()),
+        header::field1::try_read_segmented(&storage).unwrap()
+    );
+    // Reading doesn't consume the wrapped buffer
+    assert_eq!(1022, storage.into_inner().remaining());
+}
+
+#[test]
+#[should_panic(expected = "Segmented storage is too small")]
+fn buf_segments_too_small() {
+    let storage = BufSegments::new(Bytes::from_static(&[0; 3]));
+    let _ = layout::first::try_read_segmented(&storage);
+}
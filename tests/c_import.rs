@@ -0,0 +1,141 @@
+#![cfg(feature = "c-import")]
+
+use binary_layout::c_import::{self, CStruct};
+use binary_layout::{EndianKind, Field};
+
+include!("layouts/device_h.rs");
+
+fn import(header: &str) -> Vec<CStruct> {
+    c_import::import(header, EndianKind::Little).unwrap()
+}
+
+fn import_error(header: &str) -> String {
+    c_import::import(header, EndianKind::Little)
+        .unwrap_err()
+        .to_string()
+}
+
+#[test]
+fn import_header() {
+    let structs = import(include_str!("layouts/device.h"));
+    let definitions: Vec<&str> = structs.iter().map(CStruct::definition).collect();
+    assert_eq!(include_str!("layouts/device_h.rs"), definitions.join("\n"));
+
+    let names: Vec<&str> = structs.iter().map(CStruct::name).collect();
+    assert_eq!(
+        vec!["version", "device_info_t", "sample_t", "message"],
+        names
+    );
+    let sizes: Vec<usize> = structs.iter().map(CStruct::size).collect();
+    assert_eq!(vec![4, 48, 11, 3], sizes);
+    let alignments: Vec<usize> = structs.iter().map(CStruct::alignment).collect();
+    assert_eq!(vec![2, 8, 1, 1], alignments);
+}
+
+#[test]
+fn imported_layouts_match_c_sizes() {
+    assert_eq!(Some(4), version::SIZE);
+    assert_eq!(Some(48), device_info_t::SIZE);
+    assert_eq!(Some(11), sample_t::SIZE);
+    assert_eq!(None, message::SIZE);
+    assert_eq!(3, <message::payload as Field>::OFFSET);
+}
+
+#[test]
+fn padding() {
+    let structs = import(include_str!("layouts/device.h"));
+    assert!(structs[0].padding().is_empty());
+    let padding: Vec<(&str, usize, usize)> = structs[1]
+        .padding()
+        .iter()
+        .map(|padding| (padding.field(), padding.offset(), padding.size()))
+        .collect();
+    assert_eq!(
+        vec![
+            ("_padding0", 14, 2),
+            ("_padding1", 21, 3),
+            ("_padding2", 42, 6),
+        ],
+        padding
+    );
+    assert_eq!(16, <device_info_t::uptime as Field>::OFFSET);
+    assert_eq!(24, <device_info_t::calibration as Field>::OFFSET);
+    assert!(structs[2].padding().is_empty());
+    assert!(structs[3].padding().is_empty());
+}
+
+#[test]
+fn pragma_pack() {
+    let structs = import(
+        "#pragma pack(2)\n\
+         struct a { uint8_t x; uint64_t y; };\n\
+         #pragma pack()\n\
+         struct b { uint8_t x; uint64_t y; };",
+    );
+    assert_eq!(10, structs[0].size());
+    assert_eq!(2, structs[0].alignment());
+    assert_eq!(16, structs[1].size());
+    assert_eq!(8, structs[1].alignment());
+}
+
+#[test]
+fn flexible_array_member() {
+    let structs = import("struct packet { uint32_t length; uint8_t kind; uint8_t data[]; };");
+    assert_eq!(8, structs[0].size());
+    assert!(structs[0].padding().is_empty());
+    assert!(structs[0].definition().contains("    data: [u8],\n"));
+}
+
+#[test]
+fn member_named_like_its_struct() {
+    let structs =
+        import("struct version { uint8_t major; };\nstruct a { struct version version; };");
+    assert!(structs[1]
+        .definition()
+        .contains("    version: super::version::NestedView,\n"));
+}
+
+#[test]
+fn endianness() {
+    let structs = c_import::import("struct a { uint16_t x; };", EndianKind::Big).unwrap();
+    assert_eq!(
+        "::binary_layout::binary_layout!(pub a, BigEndian, {\n    x: u16,\n});\n",
+        structs[0].definition()
+    );
+}
+
+#[test]
+fn unsupported_declarations() {
+    assert_eq!(
+        "1: Pointers aren't supported",
+        import_error("struct a { uint8_t *x; };")
+    );
+    assert_eq!(
+        "1: Bit fields aren't supported",
+        import_error("struct a { uint8_t x : 3; };")
+    );
+    assert_eq!(
+        "2: The type `unsigned long` has a different size on different platforms, use a fixed width integer type",
+        import_error("struct a {\n  unsigned long x;\n};")
+    );
+    assert_eq!(
+        "1: Unknown type `struct b`",
+        import_error("struct a { struct b x; };")
+    );
+    assert_eq!(
+        "1: Unions aren't supported",
+        import_error("struct a { union { uint8_t x; } y; };")
+    );
+    assert_eq!(
+        "1: Array lengths must be integer literals or `#define`d integer constants, found `LEN`",
+        import_error("struct a { uint8_t x[LEN]; };")
+    );
+    assert_eq!(
+        "1: Flexible array members must be the last member",
+        import_error("struct a { uint8_t x[]; uint8_t y; };")
+    );
+    assert_eq!(
+        "1: Unsupported attribute `aligned`, only `packed` is supported",
+        import_error("struct __attribute__((aligned(8))) a { uint8_t x; };")
+    );
+}
@@ -0,0 +1,196 @@
+use binary_layout::{prelude::*, Checksum, ErrorKind, InternetChecksum};
+
+/// Sum of all bytes, modulo 256
+#[derive(Default)]
+struct ByteSum(u8);
+
+impl Checksum for ByteSum {
+    type Output = u8;
+
+    fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            self.0 = self.0.wrapping_add(*byte);
+        }
+    }
+
+    fn finish(self) -> u8 {
+        self.0
+    }
+}
+
+/// Records the data it was fed, to check which bytes are covered by a checksum
+#[derive(Default)]
+struct Covered(Vec<u8>);
+
+impl Checksum for Covered {
+    type Output = u32;
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.extend_from_slice(data);
+    }
+
+    fn finish(self) -> u32 {
+        self.0
+            .iter()
+            .fold(0, |acc, byte| acc * 31 + u32::from(*byte))
+    }
+}
+
+binary_layout!(frame, LittleEndian, {
+    version: u8,
+    sum: u8 = checksum(ByteSum, ..),
+    payload: [u8],
+});
+
+binary_layout!(nested, BigEndian, {
+    payload_len: u16 = len(payload),
+    #[name = "payload-checksum"]
+    payload_sum: u32 = checksum(Covered, field_offsets::payload..),
+    // declared after the payload checksum because it covers it
+    header_sum: u8 = checksum(ByteSum, ..FIXED_SIZE),
+    payload: [u8],
+});
+
+#[test]
+fn finalize_and_verify() {
+    let mut view = frame::View::new(vec![3, 0xff, 1, 2]);
+    assert_eq!(
+        ErrorKind::ChecksumMismatch,
+        view.verify().unwrap_err().kind()
+    );
+    view.finalize().unwrap();
+    assert_eq!(6, view.sum().read());
+    assert!(view.verify().is_ok());
+}
+
+#[test]
+fn mismatch_context() {
+    let mut view = nested::View::new(vec![0; 10]);
+    view.payload_mut().copy_from_slice(&[1, 2, 3]);
+    view.finalize().unwrap();
+    assert!(view.verify().is_ok());
+
+    view.payload_mut()[1] = 5;
+    let error = view.verify().unwrap_err();
+    assert_eq!(ErrorKind::ChecksumMismatch, error.kind());
+    assert_eq!(Some("nested"), error.layout());
+    assert_eq!(Some("payload-checksum"), error.field());
+    assert_eq!(Some(2), error.offset());
+}
+
+#[test]
+fn checksums_computed_after_lengths() {
+    let mut view = nested::View::new(vec![0; 9]);
+    view.payload_mut().copy_from_slice(&[7, 8]);
+    view.finalize().unwrap();
+    assert_eq!(2, view.payload_len().read());
+    let payload_sum = 7 * 31 + 8;
+    assert_eq!(payload_sum, view.payload_sum().read());
+    // the header checksum covers the length field and the payload checksum, which were already updated
+    let header_sum = payload_sum
+        .to_be_bytes()
+        .iter()
+        .fold(2u8, |acc, byte| acc.wrapping_add(*byte));
+    assert_eq!(header_sum, view.header_sum().read());
+}
+
+#[test]
+fn checksum_field_is_zero_while_computing() {
+    binary_layout!(covered, BigEndian, {
+        before: u8,
+        checksum: u32 = checksum(Covered, ..),
+        after: u8,
+    });
+
+    let mut view = covered::View::new([1, 0xff, 0xff, 0xff, 0xff, 2]);
+    view.finalize().unwrap();
+    assert_eq!(
+        Covered(vec![1, 0, 0, 0, 0, 2]).finish(),
+        view.checksum().read()
+    );
+    assert!(view.verify().is_ok());
+}
+
+#[test]
+fn without_checksums() {
+    // Layouts without checksum or length fields don't have `finalize()` and `verify()` methods,
+    // so fields can be named like them
+    binary_layout!(plain, BigEndian, {
+        finalize: u8,
+        verify: u8,
+    });
+
+    let mut view = plain::View::new([0, 0]);
+    view.finalize_mut().write(1);
+    view.verify_mut().write(2);
+    assert_eq!(1, view.finalize().read());
+    assert_eq!(2, view.verify().read());
+    assert_eq!([1, 2], view.into_storage());
+}
+
+binary_layout!(icmp_packet, BigEndian, {
+    packet_type: u8,
+    code: u8,
+    checksum: u16 = checksum(InternetChecksum, ..),
+    rest_of_header: [u8; 4],
+    data_section: [u8],
+});
+
+binary_layout!(udp_header, BigEndian, {
+    source_port: u16,
+    destination_port: u16,
+    length: u16,
+    checksum: u16 = checksum(InternetChecksum, ..),
+    payload: [u8],
+});
+
+#[test]
+fn internet_checksum() {
+    let mut view = icmp_packet::View::new([8, 0, 0, 0, 0, 0, 0, 0]);
+    view.finalize().unwrap();
+    assert_eq!(0xf7ff, view.checksum().read());
+    assert_eq!([8, 0, 0xf7, 0xff, 0, 0, 0, 0], view.into_storage());
+
+    let mut view = icmp_packet::View::new(vec![8, 0, 0, 0, 0, 1, 0, 2, 0xab]);
+    view.finalize().unwrap();
+    assert!(view.verify().is_ok());
+    view.data_section_mut()[0] = 0xac;
+    assert!(view.verify().is_err());
+}
+
+#[test]
+fn internet_checksum_with_pseudo_header() {
+    let mut view = udp_header::View::new(vec![0; 11]);
+    view.source_port_mut().write(1234);
+    view.destination_port_mut().write(53);
+    view.length_mut().write(11);
+    view.payload_mut().copy_from_slice(b"abc");
+
+    let pseudo_header =
+        InternetChecksum::ipv4_pseudo_header([192, 168, 0, 1], [192, 168, 0, 2], 17, 11);
+    assert_eq!(
+        [192, 168, 0, 1, 192, 168, 0, 2, 0, 17, 0, 11],
+        pseudo_header
+    );
+    view.finalize_with_pseudo_header(&pseudo_header).unwrap();
+    let mut covered = pseudo_header.to_vec();
+    covered.extend_from_slice(&view.clone().into_storage());
+    covered[12 + 6..12 + 8].copy_from_slice(&[0, 0]);
+    assert_eq!(InternetChecksum::checksum(&covered), view.checksum().read());
+
+    assert!(view.verify_with_pseudo_header(&pseudo_header).is_ok());
+    assert!(view.verify().is_err());
+    let other_destination =
+        InternetChecksum::ipv4_pseudo_header([192, 168, 0, 1], [192, 168, 0, 3], 17, 11);
+    assert!(view.verify_with_pseudo_header(&other_destination).is_err());
+}
+
+#[test]
+fn ipv6_pseudo_header() {
+    let source = [1; 16];
+    let destination = [2; 16];
+    let pseudo_header = InternetChecksum::ipv6_pseudo_header(source, destination, 6, 0x01020304);
+    assert_eq!(&source, &pseudo_header[..16]);
+    assert_eq!(&destination, &pseudo_header[16..32]);
+    assert_eq!(&[1, 2, 3, 4, 0, 0, 0, 6], &pseudo_header[32..]);
+}
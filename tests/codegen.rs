@@ -0,0 +1,64 @@
+#![cfg(feature = "std")]
+
+use binary_layout::{codegen, DynLayout};
+use std::path::PathBuf;
+
+include!("layouts/sensor_record.rs");
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "binary_layout_codegen_{}_{}",
+        std::process::id(),
+        name
+    ))
+}
+
+#[test]
+fn generate() {
+    let output = temp_path("generate.rs");
+    codegen::generate(["tests/layouts/sensor_record.layout"], &output).unwrap();
+    let generated = std::fs::read_to_string(&output).unwrap();
+    std::fs::remove_file(&output).unwrap();
+    assert_eq!(include_str!("layouts/sensor_record.rs"), generated);
+}
+
+#[test]
+fn generated_layout_matches_definition_file() {
+    let definition = std::fs::read_to_string("tests/layouts/sensor_record.layout").unwrap();
+    let layout = DynLayout::parse(&definition).unwrap();
+    assert_eq!(sensor_record::SIZE, layout.size());
+    for (field, info) in layout.fields().iter().zip(sensor_record::LAYOUT.fields()) {
+        assert_eq!(info.name(), field.name());
+        assert_eq!(info.offset(), field.offset());
+    }
+
+    let mut view = sensor_record::View::new([0; 20]);
+    view.value_mut().write(1.5);
+    assert_eq!(
+        1.5,
+        layout
+            .view(view.into_storage())
+            .read::<f32>("value")
+            .unwrap()
+    );
+}
+
+#[test]
+fn invalid_definition_file() {
+    let input = temp_path("invalid.layout");
+    std::fs::write(&input, "broken, BigEndian, {\n  value: u24,\n}").unwrap();
+    let error = codegen::generate([&input], temp_path("invalid.rs")).unwrap_err();
+    std::fs::remove_file(&input).unwrap();
+    assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+    assert_eq!(
+        format!("{}:2:10: Unsupported field type `u24`", input.display()),
+        error.to_string()
+    );
+}
+
+#[test]
+fn missing_definition_file() {
+    let error =
+        codegen::generate(["tests/layouts/missing.layout"], temp_path("missing.rs")).unwrap_err();
+    assert_eq!(std::io::ErrorKind::NotFound, error.kind());
+}
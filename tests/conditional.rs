@@ -0,0 +1,73 @@
+use binary_layout::prelude::*;
+
+const HAS_TIMESTAMP: u8 = 0x04;
+
+binary_layout!(options, BigEndian, {
+    flags: u8,
+    length: u16,
+}, conditional {
+    mss if flags & 0x01: u16,
+    window_scale if flags & 0x02: u8,
+    timestamp if flags & HAS_TIMESTAMP: u64,
+    valid if flags & 0x08: bool as u8,
+    checksum: u16,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(None, options::SIZE);
+    assert_eq!(3, options::FIXED_SIZE);
+    assert_eq!(Some(2), <options::mss as Field>::SIZE);
+    assert_eq!(Some(8), <options::timestamp as Field>::SIZE);
+}
+
+#[test]
+fn no_conditional_fields_present() {
+    let storage = [0, 0, 5, 0xab, 0xcd];
+    let view = options::View::new(&storage);
+    assert_eq!(5, view.length().read());
+    assert!(view.mss().is_none());
+    assert!(view.window_scale().is_none());
+    assert!(view.timestamp().is_none());
+    assert!(view.valid().is_none());
+    assert_eq!(0xabcd, view.checksum().read());
+    assert_eq!(5, view.dynamic_size());
+}
+
+#[test]
+fn some_conditional_fields_present() {
+    let storage = [0x05, 0, 5, 0x01, 0x02, 0, 0, 0, 0, 0, 0, 0, 9, 0xab, 0xcd];
+    let view = options::View::new(&storage);
+    assert_eq!(0x0102, view.mss().unwrap().read());
+    assert!(view.window_scale().is_none());
+    assert_eq!(9, view.timestamp().unwrap().read());
+    assert!(view.valid().is_none());
+    assert_eq!(0xabcd, view.checksum().read());
+    assert_eq!(15, view.dynamic_size());
+}
+
+#[test]
+fn all_conditional_fields_present() {
+    let mut storage = vec![0; 17];
+    let mut view = options::View::new(&mut storage);
+    view.flags_mut().write(0x0f);
+    view.mss_mut().unwrap().write(1);
+    view.window_scale_mut().unwrap().write(2);
+    view.timestamp_mut().unwrap().write(3);
+    view.valid_mut().unwrap().write(true);
+    view.checksum_mut().write(4);
+    assert_eq!(17, view.dynamic_size());
+    assert_eq!(
+        vec![0x0f, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 4],
+        storage
+    );
+}
+
+#[test]
+fn write_absent_field() {
+    let mut storage = [0x02, 0, 0, 7, 0, 0];
+    let mut view = options::View::new(&mut storage);
+    assert!(view.mss_mut().is_none());
+    view.checksum_mut().write(0x0102);
+    assert_eq!([0x02, 0, 0, 7, 1, 2], storage);
+}
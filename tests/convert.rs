@@ -0,0 +1,216 @@
+use binary_layout::{prelude::*, ErrorKind, Records};
+
+binary_layout!(inner_be, BigEndian, {
+    id: u16,
+    value: f32,
+});
+
+binary_layout!(inner_le, LittleEndian, {
+    id: u16,
+    value: f32,
+});
+
+binary_layout!(capture_be, BigEndian, {
+    version: u8,
+    timestamp: i64,
+    is_valid: bool as u8,
+    magic: [u8; 2],
+    inner: inner_be::NestedView,
+    payload: [u8],
+});
+
+binary_layout!(capture_le, LittleEndian, {
+    version: u8,
+    timestamp: i64,
+    is_valid: bool as u8,
+    magic: [u8; 2],
+    inner: inner_le::NestedView,
+    payload: [u8],
+});
+
+binary_layout!(capture_native, NativeEndian, {
+    version: u8,
+    timestamp: i64,
+    is_valid: bool as u8,
+    magic: [u8; 2],
+    inner: inner_le::NestedView,
+    payload: [u8],
+});
+
+binary_layout!(renamed_le, LittleEndian, {
+    version: u8,
+    time: i64,
+    is_valid: bool as u8,
+    magic: [u8; 2],
+    inner: inner_le::NestedView,
+    payload: [u8],
+});
+
+binary_layout!(table_be, BigEndian, {
+    entries: Records<u16, inner_le::NestedView>,
+});
+
+binary_layout!(table_le, LittleEndian, {
+    entries: Records<u16, inner_le::NestedView>,
+});
+
+binary_layout!(other_table_be, BigEndian, {
+    entries: Records<u16, inner_le::NestedView>,
+});
+
+fn capture() -> Vec<u8> {
+    let mut view = capture_be::View::new(vec![0; 20]);
+    view.version_mut().write(2);
+    view.timestamp_mut().write(-0x0102_0304_0506);
+    view.is_valid_mut().try_write(true).unwrap();
+    view.magic_mut().copy_from_slice(b"BL");
+    view.inner_mut().id_mut().write(0x0a0b);
+    view.inner_mut().value_mut().write(1.5);
+    view.payload_mut().copy_from_slice(&[7, 8]);
+    view.into_storage()
+}
+
+#[test]
+fn convert_between_endianness() {
+    let src = capture();
+    let mut dst = vec![0; 20];
+    capture_be::convert_to::<capture_le::NestedView>(&src, &mut dst).unwrap();
+
+    let view = capture_le::View::new(&dst);
+    assert_eq!(2, view.version().read());
+    assert_eq!(-0x0102_0304_0506, view.timestamp().read());
+    assert!(view.is_valid().try_read().unwrap());
+    assert_eq!(b"BL", view.magic());
+    assert_eq!(0x0a0b, view.inner().id().read());
+    assert_eq!(1.5, view.inner().value().read());
+    assert_eq!(&[7, 8], view.payload());
+
+    let mut back = vec![0; 20];
+    capture_le::convert_to::<capture_be::NestedView>(&dst, &mut back).unwrap();
+    assert_eq!(src, back);
+}
+
+#[test]
+fn convert_to_native_endian() {
+    let src = capture();
+    let mut dst = vec![0; 20];
+    capture_be::convert_to::<capture_native::NestedView>(&src, &mut dst).unwrap();
+    let view = capture_native::View::new(&dst);
+    assert_eq!(-0x0102_0304_0506, view.timestamp().read());
+    assert_eq!(0x0a0b, view.inner().id().read());
+}
+
+#[test]
+fn longer_destination() {
+    let src = capture();
+    let mut dst = vec![9; 24];
+    capture_be::convert_to::<capture_le::NestedView>(&src, &mut dst).unwrap();
+    assert_eq!(&[7, 8, 9, 9, 9, 9], &dst[18..]);
+}
+
+#[test]
+fn incompatible_layouts() {
+    let src = capture();
+    let mut dst = vec![0; 20];
+    let error = capture_be::convert_to::<renamed_le::NestedView>(&src, &mut dst).unwrap_err();
+    assert_eq!(ErrorKind::IncompatibleLayouts, error.kind());
+    assert_eq!(Some("time"), error.field());
+    assert_eq!(Some("renamed_le"), error.layout());
+
+    let error = inner_be::convert_to::<capture_le::NestedView>(&src, &mut dst).unwrap_err();
+    assert_eq!(ErrorKind::IncompatibleLayouts, error.kind());
+    assert_eq!(vec![0; 20], dst);
+}
+
+#[test]
+fn records() {
+    let src = vec![0, 1, 0, 5, 0, 0, 0, 0];
+    let mut dst = vec![0; 8];
+    table_be::convert_to::<other_table_be::NestedView>(&src, &mut dst).unwrap();
+    assert_eq!(src, dst);
+
+    let error = table_be::convert_to::<table_le::NestedView>(&src, &mut dst).unwrap_err();
+    assert_eq!(ErrorKind::IncompatibleLayouts, error.kind());
+    assert_eq!(Some("entries"), error.field());
+}
+
+#[test]
+fn out_of_bounds() {
+    let src = capture();
+    let mut dst = vec![0; 19];
+    let error = capture_be::convert_to::<capture_le::NestedView>(&src, &mut dst).unwrap_err();
+    assert_eq!(
+        ErrorKind::OutOfBounds {
+            needed: 20,
+            available: 19
+        },
+        error.kind()
+    );
+
+    let error = capture_be::convert_to::<capture_le::NestedView>(&src[..17], &mut dst).unwrap_err();
+    assert_eq!(
+        ErrorKind::OutOfBounds {
+            needed: 18,
+            available: 17
+        },
+        error.kind()
+    );
+    assert_eq!(Some("capture_be"), error.layout());
+}
+
+binary_layout!(record_v1, BigEndian, {
+    id: u32,
+    kind: u8,
+    checksum: u16,
+    inner: inner_le::NestedView,
+    name: [u8],
+});
+
+binary_layout!(record_v2, LittleEndian, {
+    version: u8,
+    kind: u16,
+    checksum: u16,
+    id: u32,
+    inner: inner_le::NestedView,
+    name: [u8],
+});
+
+#[test]
+fn copy_fields() {
+    let mut v1 = record_v1::View::new(vec![0; 16]);
+    v1.id_mut().write(0x0102_0304);
+    v1.kind_mut().write(5);
+    v1.checksum_mut().write(0x0a0b);
+    v1.inner_mut().id_mut().write(7);
+    v1.name_mut().copy_from_slice(b"abc");
+
+    let mut storage = vec![0xff; 18];
+    record_v1::copy_fields_to::<record_v2::NestedView>(&v1.into_storage(), &mut storage).unwrap();
+    let v2 = record_v2::View::new(storage);
+    assert_eq!(0xff, v2.version().read());
+    assert_eq!(0xffff, v2.kind().read());
+    assert_eq!(0x0a0b, v2.checksum().read());
+    assert_eq!(0x0102_0304, v2.id().read());
+    assert_eq!(7, v2.inner().id().read());
+    assert_eq!(b"abc", v2.name());
+}
+
+#[test]
+fn copy_fields_out_of_bounds() {
+    let v1 = vec![0; 16];
+    let mut v2 = vec![0; 17];
+    let error = record_v1::copy_fields_to::<record_v2::NestedView>(&v1, &mut v2).unwrap_err();
+    assert_eq!(
+        ErrorKind::OutOfBounds {
+            needed: 18,
+            available: 17
+        },
+        error.kind()
+    );
+    assert_eq!(Some("name"), error.field());
+    assert_eq!(Some("record_v2"), error.layout());
+
+    let error = record_v1::copy_fields_to::<record_v2::NestedView>(&v1[..12], &mut v2).unwrap_err();
+    assert_eq!(Some("inner"), error.field());
+    assert_eq!(Some("record_v1"), error.layout());
+}
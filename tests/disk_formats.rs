@@ -0,0 +1,103 @@
+#![cfg(feature = "disk-formats")]
+
+use binary_layout::example::disk_formats::{
+    fat_boot_sector, gpt_header, gpt_partition_entry, mbr, BOOT_SIGNATURE,
+};
+use binary_layout::prelude::*;
+use binary_layout::ErrorKind;
+
+// GPT header of a small disk, its CRC was computed with zlib
+const GPT_HEADER: [u8; 92] = [
+    0x45, 0x46, 0x49, 0x20, 0x50, 0x41, 0x52, 0x54, 0x00, 0x00, 0x01, 0x00, 0x5c, 0x00, 0x00, 0x00,
+    0xac, 0x46, 0x1a, 0xd4, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xff, 0xff, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xde, 0xff, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+    0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x80, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x78, 0x56, 0x34, 0x12,
+];
+
+#[test]
+fn offsets() {
+    assert_eq!(440, mbr::disk_signature::OFFSET);
+    assert_eq!(446, mbr::partition1::OFFSET);
+    assert_eq!(494, mbr::partition4::OFFSET);
+    assert_eq!(510, mbr::boot_signature::OFFSET);
+    assert_eq!(16, gpt_header::header_crc32::OFFSET);
+    assert_eq!(72, gpt_header::partition_entries_lba::OFFSET);
+    assert_eq!(56, gpt_partition_entry::name::OFFSET);
+    assert_eq!(11, fat_boot_sector::bytes_per_sector::OFFSET);
+    assert_eq!(54, fat_boot_sector::file_system_type::OFFSET);
+}
+
+#[test]
+fn mbr_partitions() {
+    let mut sector = vec![0; 512];
+    let mut view = mbr::View::new(&mut sector);
+    assert!(view.validate().is_err());
+    view.boot_signature_mut().write(BOOT_SIGNATURE);
+    view.partition2_mut().status_mut().write(0x80);
+    view.partition2_mut().partition_type_mut().write(0x0c);
+    view.partition2_mut().first_lba_mut().write(2048);
+    view.partition2_mut().sector_count_mut().write(1 << 20);
+    assert!(view.validate().is_ok());
+
+    assert_eq!(&[0x55, 0xaa], &sector[510..]);
+    assert_eq!(
+        &[0x80, 0, 0, 0, 0x0c, 0, 0, 0, 0x00, 0x08, 0, 0, 0, 0, 0x10, 0],
+        &sector[462..478]
+    );
+}
+
+#[test]
+fn gpt() {
+    let header = gpt_header::View::new(GPT_HEADER);
+    assert_eq!(Ok(()), header.validate());
+    assert!(header.verify().is_ok());
+    assert_eq!(0x10000, header.revision().read());
+    assert_eq!(1, header.current_lba().read());
+    assert_eq!(0x3ffff, header.backup_lba().read());
+    assert_eq!(128, header.number_of_partition_entries().read());
+
+    let mut header = gpt_header::View::new(GPT_HEADER);
+    header.header_crc32_mut().write(0);
+    assert_eq!(
+        ErrorKind::ChecksumMismatch,
+        header.verify().unwrap_err().kind()
+    );
+    header.finalize().unwrap();
+    assert_eq!(0xd41a46ac, header.header_crc32().read());
+    assert_eq!(GPT_HEADER, header.into_storage());
+}
+
+#[test]
+fn gpt_partition_name() {
+    let mut entry = gpt_partition_entry::View::new([0; 128]);
+    for (i, unit) in "EFI".encode_utf16().enumerate() {
+        entry.name_mut()[2 * i..2 * i + 2].copy_from_slice(&unit.to_le_bytes());
+    }
+    let name: Vec<u16> = entry
+        .name()
+        .chunks_exact(2)
+        .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+        .take_while(|unit| *unit != 0)
+        .collect();
+    assert_eq!("EFI", String::from_utf16(&name).unwrap());
+}
+
+#[test]
+fn fat() {
+    let mut sector = [0; 512];
+    let mut view = fat_boot_sector::View::new(&mut sector);
+    view.oem_name_mut().copy_from_slice(b"MSWIN4.1");
+    view.bytes_per_sector_mut().write(512);
+    view.sectors_per_cluster_mut().write(4);
+    view.number_of_fats_mut().write(2);
+    view.file_system_type_mut().copy_from_slice(b"FAT16   ");
+    view.boot_signature_mut().write(BOOT_SIGNATURE);
+    assert!(view.validate().is_ok());
+
+    assert_eq!(b"MSWIN4.1", &sector[3..11]);
+    assert_eq!(&[0x00, 0x02, 4], &sector[11..14]);
+    assert_eq!(2, sector[16]);
+    assert_eq!(b"FAT16   ", &sector[54..62]);
+}
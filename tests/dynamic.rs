@@ -0,0 +1,253 @@
+#![cfg(feature = "alloc")]
+
+use binary_layout::{prelude::*, DynFieldType, DynLayout, DynLayoutError, EndianKind};
+
+binary_layout!(header, BigEndian, {
+    magic: u32,
+    version: i16,
+    flags: [u8; 3],
+    ratio: f64,
+    payload: [u8],
+});
+
+fn dyn_header() -> DynLayout {
+    DynLayout::new(
+        "header",
+        [
+            ("magic", DynFieldType::U32, EndianKind::Big),
+            ("version", DynFieldType::I16, EndianKind::Big),
+            ("flags", DynFieldType::Bytes(3), EndianKind::Big),
+            ("ratio", DynFieldType::F64, EndianKind::Big),
+            ("payload", DynFieldType::OpenEndedBytes, EndianKind::Big),
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn metadata() {
+    let layout = dyn_header();
+    assert_eq!("header", layout.name());
+    assert_eq!(header::SIZE, layout.size());
+    assert_eq!(header::FIXED_SIZE, layout.fixed_size());
+    assert_eq!(5, layout.fields().len());
+    for (field, info) in layout.fields().iter().zip(header::LAYOUT.fields()) {
+        assert_eq!(info.name(), field.name());
+        assert_eq!(info.offset(), field.offset());
+        assert_eq!(info.size(), field.size());
+        assert_eq!(info.kind(), field.field_type().kind());
+        assert_eq!(info.type_name(), field.field_type().to_string());
+    }
+    assert!(layout.field("missing").is_none());
+
+    let sized = DynLayout::new("sized", [("a", DynFieldType::U8, EndianKind::Little)]).unwrap();
+    assert_eq!(Some(1), sized.size());
+    let empty = DynLayout::new("empty", Vec::<(String, _, _)>::new()).unwrap();
+    assert_eq!(Some(0), empty.size());
+}
+
+#[test]
+fn matches_macro_layout() {
+    let layout = dyn_header();
+    let mut storage = vec![0; 19];
+    let mut view = layout.view(&mut storage);
+    view.write("magic", 0xDEAD_BEEFu32).unwrap();
+    view.write("version", -2i16).unwrap();
+    view.bytes_mut("flags").unwrap().copy_from_slice(&[1, 2, 3]);
+    view.write("ratio", 0.5f64).unwrap();
+    view.bytes_mut("payload").unwrap().copy_from_slice(&[4, 5]);
+    assert_eq!(-2, view.read::<i16>("version").unwrap());
+    assert_eq!(&[4, 5], view.bytes("payload").unwrap());
+
+    let view = header::View::new(&storage);
+    assert_eq!(0xDEAD_BEEF, view.magic().read());
+    assert_eq!(-2, view.version().read());
+    assert_eq!(&[1, 2, 3], view.flags());
+    assert_eq!(0.5, view.ratio().read());
+    assert_eq!(&[4, 5], view.payload());
+}
+
+#[test]
+fn mixed_endianness() {
+    let layout = DynLayout::new(
+        "mixed",
+        [
+            ("big", DynFieldType::U16, EndianKind::Big),
+            ("little", DynFieldType::U16, EndianKind::Little),
+        ],
+    )
+    .unwrap();
+    let mut view = layout.view([0; 4]);
+    view.write("big", 0x0102u16).unwrap();
+    view.write("little", 0x0102u16).unwrap();
+    assert_eq!([1, 2, 2, 1], view.into_storage());
+}
+
+#[test]
+fn invalid_layouts() {
+    assert_eq!(
+        Err(DynLayoutError::DuplicateField { name: "a".into() }),
+        DynLayout::new(
+            "duplicate",
+            [
+                ("a", DynFieldType::U8, EndianKind::Little),
+                ("a", DynFieldType::U16, EndianKind::Little),
+            ],
+        )
+    );
+    assert_eq!(
+        Err(DynLayoutError::OpenEndedFieldNotLast { name: "a".into() }),
+        DynLayout::new(
+            "open_ended",
+            [
+                ("a", DynFieldType::OpenEndedBytes, EndianKind::Little),
+                ("b", DynFieldType::U8, EndianKind::Little),
+            ],
+        )
+    );
+}
+
+#[test]
+fn access_errors() {
+    let layout = dyn_header();
+    let mut view = layout.view(vec![0; 10]);
+    assert_eq!(
+        Err(DynLayoutError::UnknownField {
+            name: "missing".into()
+        }),
+        view.read::<u8>("missing")
+    );
+    assert_eq!(
+        Err(DynLayoutError::TypeMismatch {
+            field: "magic".into(),
+            field_type: DynFieldType::U32,
+            requested: DynFieldType::I32,
+        }),
+        view.write("magic", 1i32)
+    );
+    assert_eq!(
+        Err(DynLayoutError::OutOfBounds {
+            field: "ratio".into(),
+            needed: 17,
+            available: 10,
+        }),
+        view.read::<f64>("ratio")
+    );
+    assert!(view.bytes("payload").is_err());
+    assert_eq!(
+        "Field `magic` has type `u32` but was accessed as `i32`",
+        view.read::<i32>("magic").unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn parse_matches_macro_layout() {
+    let layout = DynLayout::parse(
+        "binary_layout!(header, BigEndian, {
+            magic: u32,
+            version: i16,
+            flags: [u8; 3],
+            ratio: f64,
+            payload: [u8],
+        });",
+    )
+    .unwrap();
+    assert_eq!(dyn_header(), layout);
+}
+
+#[test]
+fn parse_arguments_only() {
+    let layout: DynLayout = "
+        // A record with a trailing comma missing
+        record, NativeEndian, {
+            id: u64, // the key
+            data: [u8; 1_000]
+        }
+    "
+    .parse()
+    .unwrap();
+    assert_eq!("record", layout.name());
+    assert_eq!(Some(1008), layout.size());
+    assert_eq!(EndianKind::Native, layout.fields()[1].endianness());
+    assert_eq!(DynFieldType::Bytes(1000), layout.fields()[1].field_type());
+
+    let public =
+        DynLayout::parse("binary_layout::binary_layout!(pub(crate) public, BigEndian, {});")
+            .unwrap();
+    assert_eq!("public", public.name());
+
+    let empty = DynLayout::parse("empty, LittleEndian, {}").unwrap();
+    assert_eq!(Some(0), empty.size());
+}
+
+#[test]
+fn parse_errors() {
+    let error = |definition: &str| DynLayout::parse(definition).unwrap_err().to_string();
+    assert_eq!(
+        "1:12: Unknown endianness `MiddleEndian`, expected `BigEndian`, `LittleEndian` or `NativeEndian`",
+        error("my_layout, MiddleEndian, {}")
+    );
+    assert_eq!(
+        "2:15: Unsupported field type `bool`",
+        error("my_layout, BigEndian, {\n    is_valid: bool,\n}")
+    );
+    assert_eq!(
+        "1:31: Only byte arrays `[u8; N]` and `[u8]` are supported",
+        error("my_layout, BigEndian, { data: [u16; 4] }")
+    );
+    assert_eq!(
+        "1:35: Expected `,` or `}` after the field, other features of binary_layout! aren't supported",
+        error("my_layout, BigEndian, { value: u8 where 0..=7 }")
+    );
+    assert_eq!("1:11: Expected `,`", error("my_layout BigEndian, {}"));
+    assert_eq!(
+        "1:25: Expected an identifier",
+        error("my_layout, BigEndian, { : u8 }")
+    );
+    assert_eq!(
+        "1:36: Expected the end of the layout definition",
+        error("my_layout, BigEndian, { value: u8 }, owned Fields")
+    );
+    assert_eq!(
+        "1:47: Expected `)`",
+        error("binary_layout!(my_layout, BigEndian, { a: u8 }")
+    );
+}
+
+#[test]
+fn parse_invalid_layouts() {
+    let error = DynLayout::parse("my_layout, BigEndian, {\n  a: u8,\n  a: u16,\n}").unwrap_err();
+    assert_eq!((3, 3), (error.line(), error.column()));
+    assert_eq!("Duplicate field `a`", error.message());
+
+    let error = DynLayout::parse("my_layout, BigEndian, { data: [u8], a: u8 }").unwrap_err();
+    assert_eq!((1, 25), (error.line(), error.column()));
+}
+
+#[test]
+fn to_definition() {
+    let layout = dyn_header();
+    let definition = layout.to_definition().unwrap();
+    assert_eq!(
+        "::binary_layout::binary_layout!(pub header, BigEndian, {
+    magic: u32,
+    version: i16,
+    flags: [u8; 3],
+    ratio: f64,
+    payload: [u8],
+});
+",
+        definition
+    );
+    assert_eq!(layout, DynLayout::parse(&definition).unwrap());
+
+    let mixed = DynLayout::new(
+        "mixed",
+        [
+            ("big", DynFieldType::U16, EndianKind::Big),
+            ("little", DynFieldType::U16, EndianKind::Little),
+        ],
+    )
+    .unwrap();
+    assert_eq!(Err(DynLayoutError::MixedEndianness), mixed.to_definition());
+}
@@ -0,0 +1,87 @@
+use binary_layout::prelude::*;
+
+binary_layout!(raw_identifiers, LittleEndian, {
+    r#type: u8,
+    r#match: u16 where 1..,
+    r#struct: [u8; 2],
+});
+
+binary_layout!(renamed, BigEndian, {
+    #[name = "type"]
+    packet_type: u8,
+    #[name = "new"]
+    is_new: bool as u8,
+    #[name = "into_storage"]
+    storage_flags: u16 where ..100,
+    checksum: u16,
+});
+
+binary_layout!(including, BigEndian, {
+    version: u8,
+    include renamed,
+});
+
+#[test]
+fn raw_identifiers_accessors() {
+    let mut view = raw_identifiers::View::new([0; 5]);
+    view.type_mut().write(3);
+    view.match_mut().try_write(0x0201).unwrap();
+    view.struct_mut().copy_from_slice(&[4, 5]);
+
+    assert_eq!(3, view.r#type().read());
+    assert_eq!(0x0201, view.r#match().try_read().unwrap());
+    assert_eq!(&[4, 5], view.r#struct());
+    assert_eq!(1, raw_identifiers::r#match::OFFSET);
+    assert_eq!([3, 1, 2, 4, 5], view.into_storage());
+}
+
+#[test]
+fn raw_identifiers_reflection() {
+    let names: Vec<&str> = raw_identifiers::LAYOUT
+        .fields()
+        .iter()
+        .map(|f| f.name())
+        .collect();
+    assert_eq!(vec!["type", "match", "struct"], names);
+    assert_eq!(1, raw_identifiers::LAYOUT.field("match").unwrap().offset());
+}
+
+#[test]
+fn renamed_accessors() {
+    let mut view = renamed::View::new([0; 6]);
+    view.packet_type_mut().write(8);
+    view.is_new_mut().try_write(true).unwrap();
+    view.storage_flags_mut().try_write(99).unwrap();
+    view.checksum_mut().write(0x0102);
+
+    assert_eq!(8, view.packet_type().read());
+    assert!(view.is_new().try_read().unwrap());
+    assert_eq!(99, view.storage_flags().try_read().unwrap());
+    assert_eq!([8, 1, 0, 99, 1, 2], view.into_storage());
+}
+
+#[test]
+fn renamed_reflection() {
+    let names: Vec<&str> = renamed::LAYOUT.fields().iter().map(|f| f.name()).collect();
+    assert_eq!(vec!["type", "new", "into_storage", "checksum"], names);
+    assert_eq!(2, renamed::LAYOUT.field("into_storage").unwrap().offset());
+    assert!(renamed::LAYOUT.field("packet_type").is_none());
+    assert_eq!(
+        "renamed { type: 8, new: true, into_storage: 0, checksum: 0 }",
+        renamed::LAYOUT.value(&[8, 1, 0, 0, 0, 0]).to_string()
+    );
+}
+
+#[test]
+fn renamed_fields_can_be_included() {
+    let names: Vec<&str> = including::LAYOUT
+        .fields()
+        .iter()
+        .map(|f| f.name())
+        .collect();
+    assert_eq!(
+        vec!["version", "type", "new", "into_storage", "checksum"],
+        names
+    );
+    assert_eq!(1, including::packet_type::OFFSET);
+}
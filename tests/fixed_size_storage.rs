@@ -0,0 +1,33 @@
+use binary_layout::prelude::*;
+use std::convert::TryInto;
+
+mod common;
+use common::data_region;
+
+binary_layout!(layout, LittleEndian, {
+    first: u32,
+    second: [u8; 3],
+    third: u16,
+});
+
+#[test]
+fn new_exact_over_array() {
+    let mut view = layout::View::new_exact([0; 9]);
+    view.first_mut().write(1000);
+    view.third_mut().write(20);
+    let storage: [u8; 9] = view.into_storage();
+    assert_eq!(1000, u32::from_le_bytes(storage[0..4].try_into().unwrap()));
+    assert_eq!(20, u16::from_le_bytes(storage[7..9].try_into().unwrap()));
+}
+
+#[test]
+fn new_exact_over_borrowed_array() {
+    let data: [u8; 9] = data_region(9, 0).try_into().unwrap();
+    let view = layout::View::new_exact(&data);
+    assert_eq!(&data[4..7], view.second());
+
+    let mut storage = data;
+    let mut view = layout::View::new_exact(&mut storage);
+    view.second_mut().copy_from_slice(&[1, 2, 3]);
+    assert_eq!([1, 2, 3], storage[4..7]);
+}
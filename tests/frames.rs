@@ -0,0 +1,61 @@
+use binary_layout::prelude::*;
+
+binary_layout!(sample, BigEndian, {
+    channel: u8,
+    value: u16,
+});
+
+binary_layout!(packet, BigEndian, {
+    len: u16,
+    payload: [u8],
+});
+
+#[test]
+fn iter_frames() {
+    let buffer = [1, 0, 10, 2, 0, 20, 3, 0, 30];
+    let frames: Vec<(u8, u16)> = sample::iter_frames(&buffer)
+        .map(|frame| (frame.channel().read(), frame.value().read()))
+        .collect();
+    assert_eq!(vec![(1, 10), (2, 20), (3, 30)], frames);
+    assert!(sample::iter_frames(&buffer).remainder().is_empty());
+}
+
+#[test]
+fn remainder() {
+    let buffer = vec![1, 0, 10, 2, 0];
+    let mut frames = sample::iter_frames(&buffer);
+    assert_eq!(1, frames.len());
+    assert_eq!(&[2, 0], frames.remainder());
+    assert_eq!(10, frames.next().unwrap().value().read());
+    assert!(frames.next().is_none());
+    assert_eq!(&[2, 0], frames.remainder());
+}
+
+#[test]
+fn empty_buffer() {
+    let mut frames = sample::iter_frames(&[]);
+    assert_eq!(0, frames.len());
+    assert!(frames.next().is_none());
+    assert!(frames.remainder().is_empty());
+}
+
+#[test]
+fn nth_and_rev() {
+    let buffer = [1, 0, 10, 2, 0, 20, 3, 0, 30, 4, 0, 40];
+    let mut frames = sample::iter_frames(&buffer[..]);
+    assert_eq!(2, frames.nth(1).unwrap().channel().read());
+    assert_eq!(4, frames.next_back().unwrap().channel().read());
+    assert_eq!(3, frames.next().unwrap().channel().read());
+    assert!(frames.next().is_none());
+    let channels: Vec<u8> = sample::iter_frames(&buffer)
+        .rev()
+        .map(|frame| frame.channel().read())
+        .collect();
+    assert_eq!(vec![4, 3, 2, 1], channels);
+}
+
+#[test]
+#[should_panic(expected = "Frames must have a fixed size")]
+fn open_ended_layout() {
+    packet::iter_frames(&[0u8; 16]);
+}
@@ -0,0 +1,147 @@
+use binary_layout::prelude::*;
+use core::num::NonZeroU16;
+
+binary_layout!(header, LittleEndian, {
+    version: u8 where 1..=3,
+    checksum: u16,
+    block_size: NonZeroU16,
+    counter: u16,
+    payload: [u8],
+});
+
+fn increment<F>(view: &mut header::View<Vec<u8>>)
+where
+    F: header::LayoutField + FieldReadExt<HighLevelType = u16> + FieldWriteExt<HighLevelType = u16>,
+{
+    let value = view.get::<F>();
+    view.set::<F>(value + 1);
+}
+
+#[test]
+fn get_and_set() {
+    let mut view = header::View::new(vec![0; 8]);
+    view.set::<header::checksum>(0x0102);
+    assert_eq!(0x0102, view.get::<header::checksum>());
+    assert_eq!(0x0102, view.checksum().read());
+
+    increment::<header::checksum>(&mut view);
+    increment::<header::counter>(&mut view);
+    increment::<header::counter>(&mut view);
+    assert_eq!(0x0103, view.checksum().read());
+    assert_eq!(2, view.counter().read());
+    assert_eq!(vec![0, 3, 1, 0, 0, 2, 0, 0], view.into_storage());
+}
+
+#[test]
+fn try_get_and_try_set() {
+    let mut view = header::View::new(vec![0; 7]);
+    assert!(view.try_get::<header::block_size>().is_err());
+    assert!(view.try_set::<header::version>(4).is_err());
+    view.try_set::<header::version>(2).unwrap();
+    view.try_set::<header::block_size>(NonZeroU16::new(512).unwrap())
+        .unwrap();
+    assert_eq!(2, view.try_get::<header::version>().unwrap());
+    assert_eq!(512, view.try_get::<header::block_size>().unwrap().get());
+}
+
+#[test]
+#[should_panic(
+    expected = "Needed 7 bytes to access field `counter` at offset 5 of layout `header`, but the storage only has 6 bytes"
+)]
+fn storage_too_small() {
+    let view = header::View::new(vec![0; 6]);
+    view.get::<header::counter>();
+}
+
+binary_layout!(with_zero_sized_fields, LittleEndian, {
+    start: (),
+    reserved: [u8; 0],
+    include header,
+});
+
+#[test]
+fn zero_sized_and_included_fields() {
+    let mut view = with_zero_sized_fields::View::new(vec![0; 8]);
+    view.set::<with_zero_sized_fields::checksum>(0x0102);
+    assert_eq!(0x0102, view.get::<with_zero_sized_fields::checksum>());
+    assert_eq!(vec![0, 2, 1, 0, 0, 0, 0, 0], view.into_storage());
+}
+
+const EMPTY: usize = 0;
+
+binary_layout!(with_const_sized_empty_arrays, LittleEndian, {
+    first: [u8; EMPTY],
+    second: [u8; EMPTY],
+    value: u16,
+});
+
+binary_layout!(padded<const PADDING: usize>, LittleEndian, {
+    before: [u8; PADDING],
+    after: [u8; PADDING],
+    value: u16,
+});
+binary_layout!(unpadded = padded<0>);
+
+binary_layout!(empty, LittleEndian, {});
+
+binary_layout!(with_empty_nested_layouts, LittleEndian, {
+    first: empty::NestedView,
+    second: empty::NestedView,
+    value: u16,
+});
+
+#[test]
+fn zero_sized_fields_with_computed_sizes() {
+    let mut view = with_const_sized_empty_arrays::View::new([0; 2]);
+    view.set::<with_const_sized_empty_arrays::value>(0x0102);
+    assert_eq!(0x0102, view.get::<with_const_sized_empty_arrays::value>());
+    assert_eq!([2, 1], view.into_storage());
+
+    let mut view = unpadded::View::new([0; 2]);
+    view.set::<unpadded::value>(0x0102);
+    assert_eq!(0x0102, view.get::<unpadded::value>());
+    assert_eq!([2, 1], view.into_storage());
+
+    let mut view = with_empty_nested_layouts::View::new([0; 2]);
+    view.set::<with_empty_nested_layouts::value>(0x0102);
+    assert_eq!(0x0102, view.get::<with_empty_nested_layouts::value>());
+    assert_eq!([2, 1], view.into_storage());
+}
+
+binary_layout!(packet_header, BigEndian, {
+    version: u8 where 1..=3,
+    block_size: NonZeroU16,
+});
+
+binary_layout!(packet, BigEndian, {
+    header: packet_header::NestedView,
+    flags: [u8; 2],
+    values: [u32; 2],
+    enabled: bool as u8,
+    tail: [u8],
+});
+
+fn field_bytes<F: Field>(storage: &[u8]) -> &[u8] {
+    match F::SIZE {
+        Some(size) => &storage[F::OFFSET..F::OFFSET + size],
+        None => &storage[F::OFFSET..],
+    }
+}
+
+#[test]
+fn bound_on_field() {
+    let storage: Vec<u8> = (0..20).collect();
+    assert_eq!(&[0], field_bytes::<packet_header::version>(&storage));
+    assert_eq!(&[1, 2], field_bytes::<packet_header::block_size>(&storage));
+    assert_eq!(&[0, 1, 2], field_bytes::<packet::header>(&storage));
+    assert_eq!(&[3, 4], field_bytes::<packet::flags>(&storage));
+    assert_eq!(
+        &[5, 6, 7, 8, 9, 10, 11, 12],
+        field_bytes::<packet::values>(&storage)
+    );
+    assert_eq!(&[13], field_bytes::<packet::enabled>(&storage));
+    assert_eq!(
+        &[14, 15, 16, 17, 18, 19],
+        field_bytes::<packet::tail>(&storage)
+    );
+}
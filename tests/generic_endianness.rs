@@ -0,0 +1,97 @@
+use binary_layout::prelude::*;
+
+binary_layout!(sample<E: Endianness>, {
+    timestamp: u32,
+    value: i16 where -100..=100,
+    payload_len: u16 = len(payload),
+    payload: [u8],
+});
+
+binary_layout!(point<E: Endianness>, {
+    x: i16,
+    y: i16,
+}, owned #[derive(Debug, PartialEq)] Point);
+
+binary_layout!(pub(crate) header<E: Endianness>, {
+    magic: [u8; 2],
+    version: u16,
+});
+
+binary_layout!(file, LittleEndian, {
+    file_header: header::big_endian::NestedView,
+    entries: u32,
+});
+
+#[test]
+fn big_endian() {
+    let mut view = sample::View::<BigEndian, _>::new(vec![0; 10]);
+    view.timestamp_mut().write(0x01020304);
+    view.value_mut().try_write(-2).unwrap();
+    view.payload_mut().copy_from_slice(&[5, 6]);
+    view.finalize().unwrap();
+    assert_eq!(
+        vec![1, 2, 3, 4, 0xff, 0xfe, 0, 2, 5, 6],
+        view.into_storage()
+    );
+}
+
+#[test]
+fn little_endian() {
+    let mut view = sample::View::<LittleEndian, _>::new(vec![0; 10]);
+    view.timestamp_mut().write(0x01020304);
+    view.value_mut().try_write(-2).unwrap();
+    view.payload_mut().copy_from_slice(&[5, 6]);
+    view.finalize().unwrap();
+    assert_eq!(
+        vec![4, 3, 2, 1, 0xfe, 0xff, 2, 0, 5, 6],
+        view.into_storage()
+    );
+}
+
+#[test]
+fn native_endian() {
+    let mut storage = [0; 8];
+    let mut view = sample::View::<NativeEndian, _>::new(&mut storage[..]);
+    view.timestamp_mut().write(0x01020304);
+    assert_eq!(0x01020304u32.to_ne_bytes(), storage[..4]);
+}
+
+#[test]
+fn same_types_as_instantiations() {
+    let storage = [0, 0, 0, 1, 0, 2, 0, 0];
+    let view: sample::big_endian::View<&[u8]> = sample::View::<BigEndian, _>::new(&storage[..]);
+    assert_eq!(1, view.timestamp().read());
+    let view: sample::View<LittleEndian, &[u8]> = sample::little_endian::View::new(&storage[..]);
+    assert_eq!(0x01000000, view.timestamp().read());
+}
+
+#[test]
+fn metadata() {
+    assert_eq!(6, sample::big_endian::payload_len::OFFSET);
+    assert_eq!(6, sample::little_endian::payload_len::OFFSET);
+    assert_eq!(8, sample::native_endian::FIXED_SIZE);
+    assert_eq!(None, sample::big_endian::SIZE);
+    assert_eq!(Some(4), header::little_endian::SIZE);
+}
+
+#[test]
+fn owned() {
+    let view = point::View::<BigEndian, _>::new([0, 1, 0, 2]);
+    assert_eq!(
+        point::big_endian::Point { x: 1, y: 2 },
+        view.read_owned().unwrap()
+    );
+    let view = point::View::<LittleEndian, _>::new([0, 1, 0, 2]);
+    assert_eq!(
+        point::little_endian::Point { x: 256, y: 512 },
+        view.read_owned().unwrap()
+    );
+}
+
+#[test]
+fn nested() {
+    let view = file::View::new([b'A', b'B', 0, 3, 4, 0, 0, 0]);
+    assert_eq!(b"AB", view.file_header().magic());
+    assert_eq!(3, view.file_header().version().read());
+    assert_eq!(4, view.entries().read());
+}
@@ -0,0 +1,67 @@
+use binary_layout::prelude::*;
+
+binary_layout!(common_header, BigEndian, {
+    version: u8,
+    message_type: u16,
+});
+
+binary_layout!(ping, BigEndian, {
+    include common_header,
+    sequence: u32,
+    payload: [u8],
+});
+
+binary_layout!(status, BigEndian, {
+    flags: u8,
+    include common_header,
+    code: i16
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(0, ping::version::OFFSET);
+    assert_eq!(1, ping::message_type::OFFSET);
+    assert_eq!(3, ping::sequence::OFFSET);
+    assert_eq!(7, ping::payload::OFFSET);
+    assert_eq!(None, ping::SIZE);
+    assert_eq!(7, ping::FIXED_SIZE);
+
+    assert_eq!(1, status::version::OFFSET);
+    assert_eq!(2, status::message_type::OFFSET);
+    assert_eq!(4, status::code::OFFSET);
+    assert_eq!(Some(6), status::SIZE);
+
+    let names: Vec<&str> = status::LAYOUT.fields().iter().map(|f| f.name()).collect();
+    assert_eq!(vec!["flags", "version", "message_type", "code"], names);
+}
+
+#[test]
+fn view() {
+    let mut storage = [0; 9];
+    let mut view = ping::View::new(&mut storage);
+    view.version_mut().write(1);
+    view.message_type_mut().write(0x0203);
+    view.sequence_mut().write(4);
+    view.payload_mut().copy_from_slice(&[5, 6]);
+    assert_eq!([1, 2, 3, 0, 0, 0, 4, 5, 6], storage);
+
+    let view = status::View::new([7, 1, 0, 2, 0xff, 0xfe]);
+    assert_eq!(7, view.flags().read());
+    assert_eq!(1, view.version().read());
+    assert_eq!(2, view.message_type().read());
+    assert_eq!(-2, view.code().read());
+}
+
+mod nested_include {
+    use binary_layout::prelude::*;
+
+    binary_layout!(extended, BigEndian, {
+        include super::ping,
+    });
+
+    #[test]
+    fn include_from_other_module() {
+        assert_eq!(3, extended::sequence::OFFSET);
+        assert_eq!(None, extended::SIZE);
+    }
+}
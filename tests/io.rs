@@ -0,0 +1,202 @@
+#![cfg(feature = "std")]
+
+use binary_layout::prelude::*;
+use std::convert::TryInto;
+use std::io::{Cursor, ErrorKind};
+
+mod common;
+use common::data_region;
+
+binary_layout!(fixed_size, BigEndian, {
+    first: u32,
+    second: [u8; 3],
+});
+
+binary_layout!(open_ended, LittleEndian, {
+    first: u16,
+    tail: [u8],
+});
+
+#[test]
+fn read_fixed_size() {
+    let data = data_region(1024, 0);
+    let mut reader = Cursor::new(&data);
+    let view = fixed_size::read_from(&mut reader).unwrap();
+    assert_eq!(7, reader.position());
+    assert_eq!(
+        u32::from_be_bytes(data[0..4].try_into().unwrap()),
+        view.first().read()
+    );
+    assert_eq!(&data[4..7], view.second());
+    assert_eq!(&data[..7], &view.into_storage()[..]);
+}
+
+#[test]
+fn read_open_ended() {
+    let data = data_region(1024, 1);
+    let mut reader = Cursor::new(&data);
+
+    let view = open_ended::read_from(&mut reader).unwrap();
+    assert_eq!(2, reader.position());
+    assert_eq!(
+        u16::from_le_bytes(data[0..2].try_into().unwrap()),
+        view.first().read()
+    );
+    assert!(view.tail().is_empty());
+
+    let view = open_ended::read_with_tail_from(&mut reader, 10).unwrap();
+    assert_eq!(14, reader.position());
+    assert_eq!(
+        u16::from_le_bytes(data[2..4].try_into().unwrap()),
+        view.first().read()
+    );
+    assert_eq!(&data[4..14], view.tail());
+}
+
+binary_layout!(framed, BigEndian, {
+    payload_len: u16,
+    payload: [u8],
+});
+
+#[test]
+fn read_tail_after_fixed_size() {
+    let data = vec![0, 3, 10, 11, 12, 13];
+    let mut reader = Cursor::new(&data);
+
+    let mut view = framed::read_from(&mut reader).unwrap();
+    assert_eq!(2, reader.position());
+    let payload_len = view.payload_len().read();
+    view.read_tail_from(&mut reader, payload_len.into())
+        .unwrap();
+    assert_eq!(5, reader.position());
+    assert_eq!(&[10, 11, 12], view.payload());
+    assert_eq!(&data[..5], &view.into_storage()[..]);
+}
+
+#[test]
+fn read_tail_too_short() {
+    let data = vec![0, 5, 10, 11];
+    let mut reader = Cursor::new(&data);
+
+    let mut view = framed::read_from(&mut reader).unwrap();
+    let error = view.read_tail_from(&mut reader, 5).err().unwrap();
+    assert_eq!(ErrorKind::UnexpectedEof, error.kind());
+    assert!(view.payload().is_empty());
+    assert_eq!(5, view.payload_len().read());
+}
+
+#[test]
+fn read_too_short() {
+    let data = data_region(6, 2);
+    let error = fixed_size::read_from(Cursor::new(&data)).err().unwrap();
+    assert_eq!(ErrorKind::UnexpectedEof, error.kind());
+
+    let error = open_ended::read_with_tail_from(Cursor::new(&data), 5)
+        .err()
+        .unwrap();
+    assert_eq!(ErrorKind::UnexpectedEof, error.kind());
+}
+
+#[test]
+fn write_fixed_size() {
+    let mut storage = data_region(1024, 3);
+    let mut view = fixed_size::View::new(&mut storage[..]);
+    view.first_mut().write(0x01020304);
+    view.second_mut().copy_from_slice(&[5, 6, 7]);
+
+    let mut written = Vec::new();
+    view.write_to(&mut written).unwrap();
+    assert_eq!(vec![1, 2, 3, 4, 5, 6, 7], written);
+}
+
+#[test]
+fn write_open_ended() {
+    let storage = data_region(20, 4);
+    let view = open_ended::View::new(&storage);
+
+    let mut written = Vec::new();
+    view.write_to(&mut written).unwrap();
+    assert_eq!(storage, written);
+}
+
+binary_layout!(with_write_to_field, BigEndian, {
+    write_to: u16,
+    as_io_slices: [u8],
+});
+
+#[test]
+fn fields_named_like_io_methods() {
+    let mut view = with_write_to_field::View::new([0; 3]);
+    view.write_to_mut().write(0x0102);
+    view.as_io_slices_mut().copy_from_slice(&[3]);
+    assert_eq!(0x0102, view.write_to().read());
+    assert_eq!(&[3], view.as_io_slices());
+
+    let mut written = Vec::new();
+    ViewIoExt::write_to(&view, &mut written).unwrap();
+    assert_eq!(vec![1, 2, 3], written);
+    let [fixed, tail] = ViewIoExt::as_io_slices(&view);
+    assert_eq!(&[1, 2], &*fixed);
+    assert_eq!(&[3], &*tail);
+}
+
+#[test]
+fn roundtrip() {
+    let mut view = open_ended::View::new(vec![0; 6]);
+    view.first_mut().write(1000);
+    view.tail_mut().copy_from_slice(&[1, 2, 3, 4]);
+
+    let mut written = Vec::new();
+    view.write_to(&mut written).unwrap();
+    let read = open_ended::read_with_tail_from(Cursor::new(&written), 4).unwrap();
+    assert_eq!(1000, read.first().read());
+    assert_eq!(&[1, 2, 3, 4], read.tail());
+}
+
+#[test]
+fn io_slices() {
+    let storage = data_region(20, 5);
+    let view = open_ended::View::new(&storage);
+    let [fixed, tail] = view.as_io_slices();
+    assert_eq!(&storage[..2], &*fixed);
+    assert_eq!(&storage[2..], &*tail);
+
+    let storage = data_region(20, 6);
+    let view = fixed_size::View::new(&storage);
+    let [fixed, tail] = view.as_io_slices();
+    assert_eq!(&storage[..7], &*fixed);
+    assert!(tail.is_empty());
+}
+
+#[test]
+fn write_vectored_with_tail() {
+    let mut header = framed::View::new([0; 2]);
+    let payload = vec![10, 11, 12];
+    header.payload_len_mut().write(payload.len() as u16);
+
+    let [fixed, tail] = header.as_io_slices_with_tail(&payload);
+    assert_eq!(&[0, 3], &*fixed);
+    assert_eq!(&[10, 11, 12], &*tail);
+
+    let mut written = Vec::new();
+    header.write_with_tail_to(&mut written, &payload).unwrap();
+    assert_eq!(vec![0, 3, 10, 11, 12], written);
+
+    let read = framed::read_with_tail_from(Cursor::new(&written), 3).unwrap();
+    assert_eq!(&payload[..], read.payload());
+}
+
+#[test]
+fn write_parts() {
+    let mut header = framed::View::new([0; 2]);
+    header.payload_len_mut().write(3);
+    let view = framed::View::from_parts(header.into_storage(), vec![10, 11, 12]);
+
+    let [fixed, tail] = view.as_io_slices();
+    assert_eq!(&[0, 3], &*fixed);
+    assert_eq!(&[10, 11, 12], &*tail);
+
+    let mut written = Vec::new();
+    view.write_to(&mut written).unwrap();
+    assert_eq!(vec![0, 3, 10, 11, 12], written);
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "kaitai-import")]
+
+use binary_layout::kaitai_import::{self, KaitaiLayout};
+use binary_layout::Field;
+
+include!("layouts/sensor_log_ksy.rs");
+
+fn import_error(ksy: &str) -> String {
+    kaitai_import::import(ksy).unwrap_err().to_string()
+}
+
+#[test]
+fn import_definition() {
+    let layouts = kaitai_import::import(include_str!("layouts/sensor_log.ksy")).unwrap();
+    let definitions: Vec<&str> = layouts.iter().map(KaitaiLayout::definition).collect();
+    assert_eq!(
+        include_str!("layouts/sensor_log_ksy.rs"),
+        definitions.join("\n")
+    );
+    let names: Vec<&str> = layouts.iter().map(KaitaiLayout::name).collect();
+    assert_eq!(vec!["calibration", "log_header", "sensor_log"], names);
+    let sizes: Vec<Option<usize>> = layouts.iter().map(KaitaiLayout::size).collect();
+    assert_eq!(vec![Some(13), Some(25), None], sizes);
+}
+
+#[test]
+fn imported_layouts() {
+    assert_eq!(Some(13), calibration::SIZE);
+    assert_eq!(Some(25), log_header::SIZE);
+    assert_eq!(None, sensor_log::SIZE);
+    assert_eq!(48, <sensor_log::data as Field>::OFFSET);
+
+    let mut storage = [0; 52];
+    let mut view = sensor_log::View::new(&mut storage[..]);
+    view.magic_mut().copy_from_slice(b"SLOG");
+    view.header_mut().calibration_mut().offset_mut().write(-2);
+    assert_eq!(b"SLOG", &storage[..4]);
+    assert_eq!([0xff, 0xff, 0xff, 0xfe], storage[18..22]);
+}
+
+#[test]
+fn fixed_size_format() {
+    let layouts = kaitai_import::import(
+        "meta:\n  id: point\nseq:\n  - id: x\n    type: f4le\n  - id: y\n    type: f4le\n",
+    )
+    .unwrap();
+    assert_eq!(1, layouts.len());
+    assert_eq!(Some(8), layouts[0].size());
+    assert_eq!(
+        "::binary_layout::binary_layout!(pub point, LittleEndian, {\n    x: f32,\n    y: f32,\n});\n",
+        layouts[0].definition()
+    );
+}
+
+#[test]
+fn unused_types_are_not_imported() {
+    let layouts = kaitai_import::import(
+        "meta:\n  id: a\nseq:\n  - id: x\n    type: u1\ntypes:\n  unused:\n    seq:\n      - id: y\n        size-eos: true\n",
+    )
+    .unwrap();
+    assert_eq!(1, layouts.len());
+}
+
+#[test]
+fn unsupported_definitions() {
+    assert_eq!(
+        "/meta/id: Expected the name of the format",
+        import_error("seq: []")
+    );
+    assert_eq!(
+        "/seq/0/type: `u2` needs an endianness, either as `meta/endian` or as a suffix like `u2le`",
+        import_error("meta:\n  id: a\nseq:\n  - id: x\n    type: u2\n")
+    );
+    assert_eq!(
+        "/seq/1: All attributes of a type need to have the same endianness",
+        import_error(
+            "meta:\n  id: a\nseq:\n  - id: x\n    type: u2le\n  - id: y\n    type: u2be\n"
+        )
+    );
+    assert_eq!(
+        "/seq/0/size: Expected an integer, sizes given as expressions aren't supported",
+        import_error("meta:\n  id: a\nseq:\n  - id: x\n    size: len\n")
+    );
+    assert_eq!(
+        "/seq/0/if: `if` isn't supported by layouts",
+        import_error("meta:\n  id: a\nseq:\n  - id: x\n    type: u1\n    if: true\n")
+    );
+    assert_eq!(
+        "/instances: `instances` isn't supported, only `seq` attributes with a fixed size can be imported",
+        import_error("meta:\n  id: a\nseq:\n  - id: x\n    type: u1\ninstances: {}\n")
+    );
+    assert_eq!(
+        "/seq/0/type: Bit-sized integers aren't supported",
+        import_error("meta:\n  id: a\nseq:\n  - id: x\n    type: b3\n")
+    );
+    assert_eq!(
+        "/seq/0/type: Unknown type `missing`",
+        import_error("meta:\n  id: a\nseq:\n  - id: x\n    type: missing\n")
+    );
+    assert_eq!(
+        "/seq/1: Attributes after a `size-eos` attribute aren't supported",
+        import_error(
+            "meta:\n  id: a\nseq:\n  - id: x\n    size-eos: true\n  - id: y\n    type: u1\n"
+        )
+    );
+    assert_eq!(
+        "/types/b/seq/0/type: The type `c` ends with a `size-eos` attribute and can't be used as the type of an attribute",
+        import_error("meta:\n  id: a\nseq:\n  - id: x\n    type: b\ntypes:\n  b:\n    seq:\n      - id: y\n        type: c\n  c:\n    seq:\n      - id: z\n        size-eos: true\n")
+    );
+    assert_eq!(
+        "/types/b: The type `b` contains itself, recursive types don't have a fixed size",
+        import_error("meta:\n  id: a\nseq:\n  - id: x\n    type: b\ntypes:\n  b:\n    seq:\n      - id: y\n        type: b\n")
+    );
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "derive")]
+
+use binary_layout::{prelude::*, LayoutAs};
+
+#[derive(LayoutAs, Debug, PartialEq, Eq, Clone, Copy)]
+struct BlockId(u64);
+
+#[derive(LayoutAs, Debug, PartialEq, Eq)]
+struct Checksum {
+    value: [u8; 4],
+}
+
+#[derive(LayoutAs, Debug, PartialEq, Eq)]
+struct Generic<T>(T);
+
+binary_layout!(block, BigEndian, {
+    id: BlockId as u64,
+    checksum: Checksum as [u8; 4],
+    parent: Generic<u16> as u16,
+});
+
+#[test]
+fn read_write() {
+    let mut view = block::View::new([0; 14]);
+    view.id_mut().write(BlockId(0x0102));
+    view.checksum_mut().write(Checksum {
+        value: [3, 4, 5, 6],
+    });
+    view.parent_mut().write(Generic(7));
+
+    assert_eq!(BlockId(0x0102), view.id().read());
+    assert_eq!(
+        Checksum {
+            value: [3, 4, 5, 6]
+        },
+        view.checksum().read()
+    );
+    assert_eq!(Generic(7), view.parent().read());
+    assert_eq!(
+        [0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 0, 7],
+        view.into_storage()
+    );
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(Ok(BlockId(5)), <BlockId as LayoutAs<u64>>::try_read(5));
+    assert_eq!(Ok(5), <BlockId as LayoutAs<u64>>::try_write(BlockId(5)));
+}
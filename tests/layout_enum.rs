@@ -0,0 +1,75 @@
+use binary_layout::{prelude::*, InvalidEnumValueError, LayoutAs, WrappedFieldError};
+
+const ECHO_REQUEST: u8 = 8;
+
+layout_enum! {
+    /// Enum with a fallback variant
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub enum PacketType: u8 {
+        EchoReply = 0,
+        /// Variant with a doc comment and a constant value
+        EchoRequest = ECHO_REQUEST,
+        _ => Unknown(u8),
+    }
+}
+
+layout_enum! {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Strict: i16 {
+        Negative = -1,
+        Positive = 1,
+    }
+}
+
+binary_layout!(packet, LittleEndian, {
+    packet_type: PacketType as u8,
+    strict: Strict as i16,
+});
+
+#[test]
+fn fallback_read() {
+    assert_eq!(Ok(PacketType::EchoReply), PacketType::try_read(0));
+    assert_eq!(Ok(PacketType::EchoRequest), PacketType::try_read(8));
+    assert_eq!(Ok(PacketType::Unknown(3)), PacketType::try_read(3));
+    assert_eq!(Ok(PacketType::Unknown(255)), PacketType::try_read(255));
+}
+
+#[test]
+fn fallback_write() {
+    assert_eq!(Ok(0), PacketType::try_write(PacketType::EchoReply));
+    assert_eq!(Ok(8), PacketType::try_write(PacketType::EchoRequest));
+    assert_eq!(Ok(3), PacketType::try_write(PacketType::Unknown(3)));
+}
+
+#[test]
+fn strict() {
+    assert_eq!(Strict::Negative, Strict::try_read(-1).unwrap());
+    assert_eq!(Strict::Positive, Strict::try_read(1).unwrap());
+    assert_eq!(0, *Strict::try_read(0).unwrap_err().value());
+    assert_eq!(Ok(-1), Strict::try_write(Strict::Negative));
+}
+
+#[test]
+fn view() {
+    let mut storage = [0; 3];
+    let mut view = packet::View::new(&mut storage);
+    view.packet_type_mut().write(PacketType::EchoRequest);
+    view.strict_mut().write(Strict::Negative);
+    assert_eq!([8, 255, 255], storage);
+
+    let view = packet::View::new([100, 2, 0]);
+    assert_eq!(PacketType::Unknown(100), view.packet_type().read());
+    assert!(matches!(
+        view.strict().try_read(),
+        Err(WrappedFieldError::LayoutAsError(
+            InvalidEnumValueError { .. }
+        ))
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn error_message() {
+    let error = Strict::try_read(5).unwrap_err();
+    assert_eq!("InvalidEnumValueError: 5", error.to_string());
+}
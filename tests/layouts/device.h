@@ -0,0 +1,53 @@
+/* Register map of a fictional sensor device */
+#ifndef DEVICE_H
+#define DEVICE_H
+
+#include <stdbool.h>
+#include <stdint.h>
+
+#define SERIAL_LEN 10
+#define MAX_CHANNELS (4)
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef uint16_t channel_id_t;
+
+struct version {
+    uint8_t major;
+    uint8_t minor;
+    uint16_t patch;
+};
+
+typedef struct device_info {
+    struct version firmware;
+    char serial[SERIAL_LEN];
+    uint32_t uptime; // seconds
+    bool enabled;
+    double calibration;
+    int16_t offsets[MAX_CHANNELS], gain;
+} device_info_t;
+
+#pragma pack(push, 1)
+typedef struct {
+    uint8_t type;
+    uint32_t timestamp;
+    channel_id_t channel;
+    float value;
+} sample_t;
+#pragma pack(pop)
+
+struct __attribute__((packed)) message {
+    uint8_t kind;
+    uint16_t length;
+    uint8_t payload[];
+};
+
+void device_reset(struct device_info *info);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* DEVICE_H */
@@ -0,0 +1,31 @@
+::binary_layout::binary_layout!(pub version, LittleEndian, {
+    major: u8,
+    minor: u8,
+    patch: u16,
+});
+
+::binary_layout::binary_layout!(pub device_info_t, LittleEndian, {
+    firmware: super::version::NestedView,
+    serial: [u8; 10],
+    _padding0: [u8; 2], // inserted for alignment
+    uptime: u32,
+    enabled: bool as u8,
+    _padding1: [u8; 3], // inserted for alignment
+    calibration: f64,
+    offsets: [i16; 4],
+    gain: i16,
+    _padding2: [u8; 6], // inserted for alignment
+});
+
+::binary_layout::binary_layout!(pub sample_t, LittleEndian, {
+    r#type: u8,
+    timestamp: u32,
+    channel: u16,
+    value: f32,
+});
+
+::binary_layout::binary_layout!(pub message, LittleEndian, {
+    kind: u8,
+    length: u16,
+    payload: [u8],
+});
@@ -0,0 +1,21 @@
+::binary_layout::binary_layout!(pub calibration, BigEndian, {
+    offset: i32,
+    scale: f64,
+    flags: u8,
+});
+
+::binary_layout::binary_layout!(pub log_header, LittleEndian, {
+    timestamp: u64,
+    rate: f32,
+    calibration: super::calibration::NestedView,
+});
+
+::binary_layout::binary_layout!(pub sensor_log, LittleEndian, {
+    magic: [u8; 4],
+    version: u16,
+    header: super::log_header::NestedView,
+    channels: [i16; 4],
+    device_name: [u8; 8],
+    r#type: u8,
+    data: [u8],
+});
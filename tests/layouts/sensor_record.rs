@@ -0,0 +1,9 @@
+// This file is generated by binary_layout::codegen, don't edit it.
+
+// Generated from tests/layouts/sensor_record.layout
+::binary_layout::binary_layout!(pub sensor_record, LittleEndian, {
+    timestamp: u64,
+    sensor_id: u16,
+    value: f32,
+    raw: [u8; 6],
+});
@@ -0,0 +1,103 @@
+use binary_layout::{prelude::*, ErrorKind, ValidationError};
+
+binary_layout!(packet, BigEndian, {
+    packet_type: u8,
+    payload_len: u16 = len(payload),
+    payload: [u8],
+});
+
+binary_layout!(short_packet, LittleEndian, {
+    #[name = "length"]
+    payload_len: u8 = len(payload),
+    payload: [u8],
+}, validate |view| {
+    view.payload().first() == Some(&1),
+});
+
+binary_layout!(outer, BigEndian, {
+    version: u8,
+    include packet,
+});
+
+#[test]
+fn finalize() {
+    let mut view = packet::View::new(vec![0; 8]);
+    view.packet_type_mut().write(1);
+    view.payload_mut().copy_from_slice(&[1, 2, 3, 4, 5]);
+    view.finalize().unwrap();
+    assert_eq!(5, view.payload_len().read());
+    assert_eq!(vec![1, 0, 5, 1, 2, 3, 4, 5], view.into_storage());
+}
+
+#[test]
+fn finalize_empty_tail() {
+    let mut view = packet::View::new([0, 0xff, 0xff]);
+    view.finalize().unwrap();
+    assert_eq!([0, 0, 0], view.into_storage());
+}
+
+#[test]
+fn finalize_too_long() {
+    let mut view = short_packet::View::new(vec![0; 300]);
+    let error = view.finalize().unwrap_err();
+    assert_eq!(ErrorKind::ValueTooLong { len: 299 }, error.kind());
+    assert_eq!(Some("short_packet"), error.layout());
+    assert_eq!(Some("length"), error.field());
+    assert_eq!(Some(0), error.offset());
+}
+
+#[test]
+fn validate() {
+    assert_eq!(Ok(()), packet::View::new([0, 0, 2, 7, 8]).validate());
+    assert_eq!(
+        Err(ValidationError::__new(
+            "packet",
+            "payload_len == len(payload)"
+        )),
+        packet::View::new([0, 0, 3, 7, 8]).validate()
+    );
+
+    assert_eq!(Ok(()), short_packet::View::new([1, 1]).validate());
+    assert_eq!(
+        "payload_len == len(payload)",
+        short_packet::View::new([2, 1])
+            .validate()
+            .unwrap_err()
+            .check()
+    );
+    assert_eq!(
+        "view.payload().first() == Some(&1)",
+        short_packet::View::new([1, 2])
+            .validate()
+            .unwrap_err()
+            .check()
+    );
+}
+
+#[test]
+fn validate_truncated() {
+    let truncated = Err(ValidationError::__new(
+        "packet",
+        "len(storage) >= FIXED_SIZE",
+    ));
+    assert_eq!(truncated, packet::View::new(&[][..]).validate());
+    assert_eq!(truncated, packet::View::new(&[0u8][..]).validate());
+    assert_eq!(truncated, packet::View::new(&[0u8, 0][..]).validate());
+    assert_eq!(Ok(()), packet::View::new(&[0u8, 0, 0][..]).validate());
+}
+
+#[test]
+fn finalize_then_validate() {
+    let mut view = short_packet::View::new(vec![0, 1, 0, 0]);
+    assert!(view.validate().is_err());
+    view.finalize().unwrap();
+    assert_eq!(Ok(()), view.validate());
+}
+
+#[test]
+fn included() {
+    let mut view = outer::View::new(vec![0; 6]);
+    view.finalize().unwrap();
+    assert_eq!(2, view.payload_len().read());
+    assert_eq!(Ok(()), view.validate());
+}
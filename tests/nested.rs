@@ -1,4 +1,5 @@
 use binary_layout::prelude::*;
+use binary_layout::{BorrowingNestedView, NestedViewInfo, OwningNestedView};
 use std::convert::TryInto;
 use std::num::NonZeroU128;
 
@@ -336,3 +337,88 @@ fn view_vec_readwrite() {
     let extracted_storage_2 = view.into_foot().into_storage();
     assert_eq!(&&extracted_storage[30..], &extracted_storage_2.as_ref());
 }
+
+fn layout_name<N: NestedViewInfo>() -> &'static str {
+    N::LAYOUT.name()
+}
+
+fn view_of_any_layout<'a, N: BorrowingNestedView<&'a [u8]>>(storage: &'a [u8]) -> N::View {
+    N::view(storage)
+}
+
+#[test]
+fn generic_nested_views() {
+    assert_eq!("middle", layout_name::<middle::NestedView>());
+    assert_eq!(Some(4), middle::NestedView::SIZE);
+    assert_eq!(None, footer::NestedView::SIZE);
+
+    let storage = [1, 0, 2, 0];
+    let deep: deep_nesting::View<&[u8]> = view_of_any_layout::<deep_nesting::NestedView>(&storage);
+    assert_eq!(1, deep.field1().read());
+    let mid: middle::View<&[u8]> = view_of_any_layout::<middle::NestedView>(&storage);
+    assert_eq!(u16::from_ne_bytes([2, 0]), mid.field1().read());
+
+    let owned =
+        <header::NestedView as OwningNestedView<_>>::into_view(binary_layout::Data::from(vec![
+            0xff, 0xfe,
+        ]));
+    assert_eq!(-2, owned.field1().read());
+}
+
+#[test]
+fn extract_nested_view() {
+    fn get_foot(storage: &[u8]) -> footer::View<&[u8]> {
+        whole::View::new(storage).into_foot().extract()
+    }
+    fn get_tail(storage: &[u8]) -> &[u8] {
+        get_foot(storage).into_tail().into_slice()
+    }
+
+    let storage = data_region(1024, 0);
+    let foot = get_foot(&storage);
+    assert_eq!(
+        u32::from_be_bytes(storage[30..34].try_into().unwrap()),
+        foot.field1().read()
+    );
+    assert_eq!(&storage[36..], foot.tail());
+    assert_eq!(&storage[36..], get_tail(&storage));
+}
+
+#[test]
+fn extract_mut_nested_view() {
+    fn get_foot(storage: &mut [u8]) -> footer::View<&mut [u8]> {
+        whole::View::new(storage).into_foot().extract_mut()
+    }
+    fn get_deep(storage: &mut [u8]) -> deep_nesting::View<&mut [u8]> {
+        get_foot(storage).into_deep().extract_mut()
+    }
+
+    let mut storage = data_region(1024, 1);
+    get_foot(&mut storage).field1_mut().write(1000);
+    get_deep(&mut storage).field1_mut().write(50);
+    assert_eq!(
+        1000,
+        u32::from_be_bytes(storage[30..34].try_into().unwrap())
+    );
+    assert_eq!(50, u16::from_le_bytes(storage[34..36].try_into().unwrap()));
+}
+
+#[test]
+fn dotted_path_access() {
+    let mut storage = data_region(1024, 2);
+    let mut view = whole::View::new(&mut storage);
+
+    field_mut!(view.mid.deep.field1).write(10);
+    field_mut!(view.foot.deep.field1).write(20);
+    field_mut!(view.foot.tail)[..2].copy_from_slice(&[1, 2]);
+    field_mut!(view.head.field1).write(-5);
+
+    assert_eq!(10, field!(view.mid.deep.field1).read());
+    assert_eq!(20, field!(view.foot.deep.field1).read());
+    assert_eq!(&[1, 2], &field!(view.foot.tail)[..2]);
+    assert_eq!(-5, field!(view.head.field1).read());
+    assert_eq!(view.field1().read(), field!(view.field1).read());
+
+    assert_eq!(10, u16::from_le_bytes(storage[10..12].try_into().unwrap()));
+    assert_eq!(20, u16::from_le_bytes(storage[34..36].try_into().unwrap()));
+}
@@ -0,0 +1,75 @@
+use binary_layout::{prelude::*, ErrorKind};
+
+binary_layout!(header, BigEndian, {
+    version: u8,
+    length: u16,
+}, optional {
+    ext_flags: u32,
+    #[since = 3] ext_valid: bool as u8,
+    #[since = "3.1"] ext_data: [u8],
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(None, header::SIZE);
+    assert_eq!(3, header::FIXED_SIZE);
+    assert_eq!(3, header::ext_flags::OFFSET);
+    assert_eq!(7, header::ext_valid::OFFSET);
+    assert_eq!(8, header::ext_data::OFFSET);
+    assert_eq!(None, header::ext_data::SIZE);
+}
+
+#[test]
+fn old_revision() {
+    let storage = [1, 0, 3];
+    let view = header::View::new(&storage);
+    assert_eq!(1, view.version().read());
+    assert!(view.ext_flags().is_none());
+    assert!(view.ext_valid().is_none());
+    assert!(view.ext_data().is_none());
+}
+
+#[test]
+fn partially_present() {
+    let storage = [1, 0, 3, 0, 0, 0, 7, 1];
+    let view = header::View::new(&storage);
+    assert_eq!(7, view.ext_flags().unwrap().read());
+    assert!(view.ext_valid().unwrap().try_read().unwrap());
+    assert_eq!(&[] as &[u8], view.ext_data().unwrap());
+}
+
+#[test]
+fn write() {
+    let mut storage = vec![0; 10];
+    let mut view = header::View::new(&mut storage);
+    view.ext_flags_mut().unwrap().write(0x01020304);
+    view.ext_valid_mut().unwrap().write(true);
+    view.ext_data_mut().unwrap().copy_from_slice(&[5, 6]);
+    assert_eq!(vec![0, 0, 0, 1, 2, 3, 4, 1, 5, 6], storage);
+
+    let mut storage = [0; 6];
+    let mut view = header::View::new(&mut storage);
+    assert!(view.ext_flags_mut().is_none());
+    assert!(view.ext_data_mut().is_none());
+}
+
+#[test]
+fn try_new() {
+    let view = header::View::try_new([1, 0, 3]).unwrap();
+    assert_eq!(3, view.length().read());
+    assert!(view.ext_flags().is_none());
+
+    let view = header::View::try_new([1, 0, 3, 0, 0, 0, 7]).unwrap();
+    assert_eq!(7, view.ext_flags().unwrap().read());
+    assert!(view.ext_valid().is_none());
+
+    let error = header::View::try_new([1, 0]).map(|_| ()).unwrap_err();
+    assert_eq!(
+        ErrorKind::OutOfBounds {
+            needed: 3,
+            available: 2
+        },
+        error.kind()
+    );
+    assert_eq!(Some("header"), error.layout());
+}
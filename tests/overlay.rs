@@ -0,0 +1,54 @@
+use binary_layout::prelude::*;
+
+binary_layout!(request, BigEndian, {
+    id: u16,
+    arg: u32,
+});
+
+binary_layout!(reply, BigEndian, {
+    id: u16,
+    status: u8,
+    data: [u8],
+});
+
+binary_layout!(fixed_body, BigEndian, {
+    msg_type: u8,
+    body: [u8; 6],
+    trailer: u8,
+}, overlay body {
+    request(request),
+    reply(reply),
+});
+
+binary_layout!(open_body, BigEndian, {
+    msg_type: u8,
+    body: [u8],
+}, variants body by msg_type {
+    1 => Request(request),
+}, overlay body {
+    request(request),
+    reply(reply),
+});
+
+#[test]
+fn fixed_size_body() {
+    let mut view = fixed_body::View::new(vec![0; 8]);
+    view.as_request_mut().id_mut().write(0x0102);
+    view.as_request_mut().arg_mut().write(0x0304_0506);
+    view.trailer_mut().write(9);
+    assert_eq!(0x0102, view.as_reply().id().read());
+    assert_eq!(3, view.as_reply().status().read());
+    assert_eq!(&[4, 5, 6], view.as_reply().data());
+    assert_eq!(vec![0, 1, 2, 3, 4, 5, 6, 9], view.into_storage());
+}
+
+#[test]
+fn open_ended_body() {
+    let mut view = open_body::View::new(vec![1, 0, 7, 2, 0, 0, 0]);
+    assert_eq!(7, view.as_reply().id().read());
+    view.as_reply_mut().status_mut().write(4);
+    match view.variant() {
+        open_body::Variant::Request(request) => assert_eq!(0x0400_0000, request.arg().read()),
+        _ => panic!("Expected a request"),
+    }
+}
@@ -0,0 +1,252 @@
+use binary_layout::{prelude::*, ErrorKind};
+use std::collections::{BTreeSet, HashSet};
+use std::num::NonZeroU16;
+
+binary_layout!(record, BigEndian, {
+    id: u32,
+    flags: u8,
+    value: i16,
+    tag: [u8; 2],
+}, owned #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)] Record);
+
+binary_layout!(checked, LittleEndian, {
+    #[name = "new"]
+    is_new: bool as u8,
+    count: NonZeroU16,
+    priority: u8 where 0..=7,
+}, owned #[derive(Debug, PartialEq)] Checked);
+
+binary_layout!(no_derives, LittleEndian, {
+    r#type: u8,
+}, owned NoDerives,);
+
+#[test]
+fn read_owned() {
+    let view = record::View::new([0, 0, 1, 2, 3, 0xff, 0xfe, b'a', b'b']);
+    assert_eq!(
+        record::Record {
+            id: 0x0102,
+            flags: 3,
+            value: -2,
+            tag: *b"ab",
+        },
+        view.read_owned().unwrap()
+    );
+}
+
+#[test]
+fn write_owned() {
+    let owned = record::Record {
+        id: 5,
+        flags: 1,
+        value: -1,
+        tag: *b"xy",
+    };
+    let mut view = record::View::new([0; 9]);
+    view.write_owned(&owned).unwrap();
+    assert_eq!([0, 0, 0, 5, 1, 0xff, 0xff, b'x', b'y'], view.into_storage());
+}
+
+#[test]
+fn derives() {
+    let a = record::View::new([0, 0, 0, 1, 0, 0, 0, 0, 0])
+        .read_owned()
+        .unwrap();
+    let b = record::View::new([0, 0, 0, 2, 0, 0, 0, 0, 0])
+        .read_owned()
+        .unwrap();
+    assert!(a < b);
+
+    let sorted: BTreeSet<_> = [b, a, b].iter().copied().collect();
+    assert_eq!(vec![a, b], sorted.into_iter().collect::<Vec<_>>());
+    let set: HashSet<_> = [a, b, a].iter().copied().collect();
+    assert_eq!(2, set.len());
+}
+
+#[test]
+fn without_derives() {
+    let mut view = no_derives::View::new([0]);
+    view.write_owned(&no_derives::NoDerives { r#type: 3 })
+        .unwrap();
+    assert_eq!(3, view.read_owned().unwrap().r#type);
+}
+
+#[test]
+fn fallible_fields() {
+    let mut view = checked::View::new([1, 2, 0, 7]);
+    assert_eq!(
+        checked::Checked {
+            is_new: true,
+            count: NonZeroU16::new(2).unwrap(),
+            priority: 7,
+        },
+        view.read_owned().unwrap()
+    );
+
+    let error = checked::View::new([1, 0, 0, 7]).read_owned().unwrap_err();
+    assert_eq!(ErrorKind::NonZeroIsZero, error.kind());
+    assert_eq!(Some("count"), error.field());
+
+    let error = view
+        .write_owned(&checked::Checked {
+            is_new: false,
+            count: NonZeroU16::new(1).unwrap(),
+            priority: 8,
+        })
+        .unwrap_err();
+    assert_eq!(Some("priority"), error.field());
+    assert_eq!(0, view.into_storage()[0]);
+}
+
+#[test]
+fn out_of_bounds() {
+    let error = record::View::new([0; 8]).read_owned().unwrap_err();
+    assert_eq!(
+        ErrorKind::OutOfBounds {
+            needed: 9,
+            available: 8
+        },
+        error.kind()
+    );
+    assert_eq!(Some("record"), error.layout());
+
+    let mut view = record::View::new([0; 3]);
+    let owned = record::View::new([0; 9]).read_owned().unwrap();
+    assert!(view.write_owned(&owned).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_all() {
+    let buffer = [
+        0, 0, 0, 1, 2, 0, 3, b'a', b'b', 0, 0, 0, 4, 5, 0xff, 0xfe, b'c', b'd',
+    ];
+    assert_eq!(
+        vec![
+            record::Record {
+                id: 1,
+                flags: 2,
+                value: 3,
+                tag: *b"ab",
+            },
+            record::Record {
+                id: 4,
+                flags: 5,
+                value: -2,
+                tag: *b"cd",
+            },
+        ],
+        record::decode_all(&buffer).unwrap()
+    );
+    assert!(record::decode_all(&[]).unwrap().is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_all_partial_frame() {
+    let error = record::decode_all(&[0; 12]).unwrap_err();
+    assert_eq!(
+        ErrorKind::OutOfBounds {
+            needed: 18,
+            available: 12
+        },
+        error.kind()
+    );
+    assert_eq!(Some("record"), error.layout());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_all_field_error() {
+    let error = checked::decode_all(&[1, 1, 0, 7, 0, 0, 0, 2]).unwrap_err();
+    assert_eq!(ErrorKind::NonZeroIsZero, error.kind());
+    assert_eq!(Some("count"), error.field());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_all() {
+    let records = [
+        record::Record {
+            id: 1,
+            flags: 2,
+            value: 3,
+            tag: *b"ab",
+        },
+        record::Record {
+            id: 4,
+            flags: 5,
+            value: -2,
+            tag: *b"cd",
+        },
+    ];
+    let mut buffer = vec![0xff];
+    record::encode_all(records, &mut buffer).unwrap();
+    assert_eq!(
+        vec![0xff, 0, 0, 0, 1, 2, 0, 3, b'a', b'b', 0, 0, 0, 4, 5, 0xff, 0xfe, b'c', b'd'],
+        buffer
+    );
+    assert_eq!(records.to_vec(), record::decode_all(&buffer[1..]).unwrap());
+
+    let mut buffer = Vec::new();
+    record::encode_all(records.iter().copied().rev(), &mut buffer).unwrap();
+    assert_eq!(records[1], record::View::new(&buffer).read_owned().unwrap());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_all_error() {
+    let valid = checked::Checked {
+        is_new: true,
+        count: NonZeroU16::new(1).unwrap(),
+        priority: 7,
+    };
+    let invalid = checked::Checked {
+        priority: 8,
+        ..valid
+    };
+    let mut buffer = vec![1, 2];
+    let error = checked::encode_all(vec![valid, invalid], &mut buffer).unwrap_err();
+    assert_eq!(Some("priority"), error.field());
+    assert_eq!(vec![1, 2], buffer);
+}
+
+#[test]
+fn write_fields() {
+    let mut view = record::View::new([0, 0, 0, 1, 2, 0, 3, b'a', b'b']);
+    let mut owned = view.read_owned().unwrap();
+    owned.flags = 7;
+    owned.value = -1;
+    owned.tag = *b"xy";
+    view.write_fields(&owned, &[record::FieldId::value, record::FieldId::flags])
+        .unwrap();
+    assert_eq!([0, 0, 0, 1, 7, 0xff, 0xff, b'a', b'b'], view.into_storage());
+}
+
+#[test]
+fn write_fields_error() {
+    let mut view = checked::View::new([0, 1, 0, 0]);
+    let owned = checked::Checked {
+        is_new: true,
+        count: NonZeroU16::new(5).unwrap(),
+        priority: 8,
+    };
+    let error = view
+        .write_fields(
+            &owned,
+            &[checked::FieldId::is_new, checked::FieldId::priority],
+        )
+        .unwrap_err();
+    assert_eq!(Some("priority"), error.field());
+    assert_eq!([1, 1, 0, 0], view.into_storage());
+}
+
+#[test]
+fn field_id_names() {
+    assert_eq!("new", checked::FieldId::is_new.name());
+    assert_eq!("count", checked::FieldId::count.name());
+    assert_eq!(
+        no_derives::FIELD_NAMES[0],
+        no_derives::FieldId::r#type.name()
+    );
+}
@@ -0,0 +1,62 @@
+use binary_layout::prelude::*;
+
+binary_layout!(registers, LittleEndian, {
+    id: u16,
+    control @ 0x08: u32,
+    status: u8 where ..4,
+    enabled @ 16: bool as u8,
+    data @ 0x20: [u8],
+});
+
+binary_layout!(header, LittleEndian, {
+    version: u8,
+    flags @ 4: u8,
+});
+
+binary_layout!(including, LittleEndian, {
+    include header,
+    length: u16,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(0, registers::id::OFFSET);
+    assert_eq!(8, registers::control::OFFSET);
+    assert_eq!(12, registers::status::OFFSET);
+    assert_eq!(16, registers::enabled::OFFSET);
+    assert_eq!(32, registers::data::OFFSET);
+    assert_eq!(None, registers::SIZE);
+    assert_eq!(32, registers::FIXED_SIZE);
+    assert_eq!(&[0, 8, 12, 16, 32], registers::FIELD_OFFSETS);
+    assert_eq!(
+        "u32",
+        registers::LAYOUT.field("control").unwrap().type_name()
+    );
+}
+
+#[test]
+fn gaps_are_reserved() {
+    let mut storage = vec![0xff; 34];
+    let mut view = registers::View::new(&mut storage);
+    view.id_mut().write(0x0201);
+    view.control_mut().write(0x06050403);
+    view.status_mut().try_write(3).unwrap();
+    view.enabled_mut().write(true);
+    view.data_mut().copy_from_slice(&[7, 8]);
+    assert_eq!(
+        vec![
+            1, 2, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 3, 4, 5, 6, 3, 0xff, 0xff, 0xff, 1, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 7,
+            8
+        ],
+        storage
+    );
+}
+
+#[test]
+fn included() {
+    assert_eq!(4, header::flags::OFFSET);
+    assert_eq!(4, including::flags::OFFSET);
+    assert_eq!(5, including::length::OFFSET);
+    assert_eq!(Some(7), including::SIZE);
+}
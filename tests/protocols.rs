@@ -0,0 +1,129 @@
+#![cfg(feature = "protocols")]
+
+use binary_layout::example::protocols::{
+    ether_type, ethernet_frame, icmp_message, ip_protocol, ipv4_header, ipv6_header, tcp_header,
+    udp_header,
+};
+use binary_layout::prelude::*;
+use binary_layout::InternetChecksum;
+
+// IPv4 header from https://en.wikipedia.org/wiki/Internet_checksum#Calculating_the_IPv4_header_checksum
+const IPV4_HEADER: [u8; 20] = [
+    0x45, 0x00, 0x00, 0x73, 0x00, 0x00, 0x40, 0x00, 0x40, 0x11, 0xb8, 0x61, 0xc0, 0xa8, 0x00, 0x01,
+    0xc0, 0xa8, 0x00, 0xc7,
+];
+
+#[test]
+fn sizes() {
+    assert_eq!(14, ethernet_frame::FIXED_SIZE);
+    assert_eq!(20, ipv4_header::FIXED_SIZE);
+    assert_eq!(40, ipv6_header::FIXED_SIZE);
+    assert_eq!(8, udp_header::FIXED_SIZE);
+    assert_eq!(20, tcp_header::FIXED_SIZE);
+    assert_eq!(12, ipv4_header::source::OFFSET);
+    assert_eq!(8, ipv6_header::source::OFFSET);
+    assert_eq!(16, tcp_header::checksum::OFFSET);
+}
+
+#[test]
+fn ethernet() {
+    let mut data = vec![0xff; 6];
+    data.extend_from_slice(&[2, 0, 0, 0, 0, 1, 0x86, 0xdd, 1, 2]);
+    let frame = ethernet_frame::View::new(&data);
+    assert_eq!(&[0xff; 6], frame.destination());
+    assert_eq!(&[2, 0, 0, 0, 0, 1], frame.source());
+    assert_eq!(ether_type::IPV6, frame.ether_type().read());
+    assert_eq!(&[1, 2], frame.payload());
+}
+
+#[test]
+fn ipv4() {
+    let header = ipv4_header::View::new(IPV4_HEADER);
+    assert_eq!(0x45, header.version_ihl().read());
+    assert_eq!(0x73, header.total_length().read());
+    assert_eq!(0x4000, header.flags_fragment_offset().read());
+    assert_eq!(64, header.time_to_live().read());
+    assert_eq!(ip_protocol::UDP, header.protocol().read());
+    assert_eq!(&[192, 168, 0, 1], header.source());
+    assert_eq!(&[192, 168, 0, 199], header.destination());
+    assert!(header.verify().is_ok());
+
+    let mut header = ipv4_header::View::new(IPV4_HEADER);
+    header.header_checksum_mut().write(0);
+    header.finalize().unwrap();
+    assert_eq!(IPV4_HEADER, header.into_storage());
+}
+
+#[test]
+fn ipv6() {
+    let mut packet = ipv6_header::View::new(vec![0; 43]);
+    packet
+        .version_traffic_class_flow_label_mut()
+        .write(0x6000_0000);
+    packet.next_header_mut().write(ip_protocol::ICMPV6);
+    packet.hop_limit_mut().write(255);
+    packet.payload_mut().copy_from_slice(&[1, 2, 3]);
+    packet.finalize().unwrap();
+    assert_eq!(3, packet.payload_length().read());
+    assert_eq!(Ok(()), packet.validate());
+    assert_eq!(&[0x60, 0, 0, 0, 0, 3, 58, 255], &packet.into_storage()[..8]);
+}
+
+#[test]
+fn udp_over_ipv4() {
+    let mut datagram = udp_header::View::new(vec![0; 11]);
+    datagram.source_port_mut().write(1234);
+    datagram.destination_port_mut().write(53);
+    datagram.length_mut().write(11);
+    datagram.payload_mut().copy_from_slice(b"abc");
+
+    let pseudo_header = InternetChecksum::ipv4_pseudo_header(
+        [192, 168, 0, 1],
+        [192, 168, 0, 2],
+        ip_protocol::UDP,
+        11,
+    );
+    datagram
+        .finalize_with_pseudo_header(&pseudo_header)
+        .unwrap();
+    assert!(datagram.verify_with_pseudo_header(&pseudo_header).is_ok());
+
+    let mut covered = pseudo_header.to_vec();
+    covered.extend_from_slice(&[0x04, 0xd2, 0x00, 0x35, 0x00, 0x0b, 0, 0, b'a', b'b', b'c']);
+    assert_eq!(
+        InternetChecksum::checksum(&covered),
+        datagram.checksum().read()
+    );
+}
+
+#[test]
+fn tcp_over_ipv6() {
+    let mut segment = tcp_header::View::new(vec![0; 20]);
+    segment.source_port_mut().write(443);
+    segment.destination_port_mut().write(50000);
+    segment.sequence_number_mut().write(1);
+    // header length of 5 words and the SYN flag
+    segment.data_offset_flags_mut().write(0x5002);
+    segment.window_size_mut().write(65535);
+
+    let pseudo_header =
+        InternetChecksum::ipv6_pseudo_header([1; 16], [2; 16], ip_protocol::TCP, 20);
+    segment.finalize_with_pseudo_header(&pseudo_header).unwrap();
+    assert!(segment.verify_with_pseudo_header(&pseudo_header).is_ok());
+    assert!(segment.verify().is_err());
+}
+
+#[test]
+fn icmp_echo_request() {
+    let mut message = icmp_message::View::new(vec![8, 0, 0, 0, 0, 1, 0, 2, b'h', b'i']);
+    message.finalize().unwrap();
+    assert!(message.verify().is_ok());
+    match message.variant() {
+        icmp_message::Variant::EchoRequest(echo) => {
+            assert_eq!(1, echo.identifier().read());
+            assert_eq!(2, echo.sequence_number().read());
+            assert_eq!(b"hi", echo.data());
+        }
+        _ => panic!("Expected an echo request"),
+    }
+}
@@ -0,0 +1,145 @@
+use binary_layout::{prelude::*, FieldKind, LayoutAs, RangedFieldError, WrappedFieldError};
+use core::convert::Infallible;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct Level(u16);
+impl LayoutAs<u16> for Level {
+    type ReadError = Infallible;
+    type WriteError = Infallible;
+
+    fn try_read(v: u16) -> Result<Self, Infallible> {
+        Ok(Level(v))
+    }
+
+    fn try_write(v: Self) -> Result<u16, Infallible> {
+        Ok(v.0)
+    }
+}
+
+const MAX_LEN: u32 = 1000;
+
+binary_layout!(ranged, BigEndian, {
+    priority: u8 where 0..=7,
+    length: u32 where 20..MAX_LEN,
+    offset: i16 where ..=0,
+    level: Level as u16 where Level(1)..=Level(3),
+    flag: bool as u8,
+    tail: [u8],
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(0, ranged::priority::OFFSET);
+    assert_eq!(Some(1), ranged::priority::SIZE);
+    assert_eq!(1, ranged::length::OFFSET);
+    assert_eq!(Some(4), ranged::length::SIZE);
+    assert_eq!(5, ranged::offset::OFFSET);
+    assert_eq!(7, ranged::level::OFFSET);
+    assert_eq!(9, ranged::flag::OFFSET);
+    assert_eq!(10, ranged::FIXED_SIZE);
+
+    let fields = ranged::LAYOUT.fields();
+    assert_eq!("u8 where 0..=7", fields[0].type_name());
+    assert_eq!(FieldKind::UnsignedInt, fields[0].kind());
+    assert_eq!(
+        "Level as u16 where Level(1)..=Level(3)",
+        fields[3].type_name()
+    );
+}
+
+#[test]
+fn read_write_in_range() {
+    let mut storage = [0; 12];
+    let mut view = ranged::View::new(&mut storage[..]);
+    view.priority_mut().try_write(7).unwrap();
+    view.length_mut().try_write(999).unwrap();
+    view.offset_mut().try_write(-5).unwrap();
+    view.level_mut().try_write(Level(2)).unwrap();
+
+    assert_eq!(7, view.priority().try_read().unwrap());
+    assert_eq!(999, view.length().try_read().unwrap());
+    assert_eq!(-5, view.offset().try_read().unwrap());
+    assert_eq!(Level(2), view.level().try_read().unwrap());
+    assert_eq!(&[7, 0, 0, 3, 231, 255, 251, 0, 2, 0, 0, 0], &storage);
+}
+
+#[test]
+fn write_out_of_range() {
+    let mut storage = [0; 12];
+    let mut view = ranged::View::new(&mut storage[..]);
+    assert!(matches!(
+        view.priority_mut().try_write(8),
+        Err(RangedFieldError::OutOfRange { range: "0..=7" })
+    ));
+    assert!(matches!(
+        view.length_mut().try_write(19),
+        Err(RangedFieldError::OutOfRange {
+            range: "20..MAX_LEN"
+        })
+    ));
+    assert!(matches!(
+        view.length_mut().try_write(1000),
+        Err(RangedFieldError::OutOfRange { .. })
+    ));
+    assert!(matches!(
+        view.offset_mut().try_write(1),
+        Err(RangedFieldError::OutOfRange { .. })
+    ));
+    assert!(matches!(
+        view.level_mut().try_write(Level(4)),
+        Err(RangedFieldError::OutOfRange { .. })
+    ));
+    assert_eq!([0; 12], storage);
+}
+
+#[test]
+fn read_out_of_range() {
+    let storage = [8, 0, 0, 0, 19, 0, 1, 0, 0, 0];
+    let view = ranged::View::new(&storage);
+    assert!(matches!(
+        view.priority().try_read(),
+        Err(RangedFieldError::OutOfRange { range: "0..=7" })
+    ));
+    assert!(matches!(
+        view.length().try_read(),
+        Err(RangedFieldError::OutOfRange { .. })
+    ));
+    assert!(matches!(
+        view.offset().try_read(),
+        Err(RangedFieldError::OutOfRange { .. })
+    ));
+    assert!(matches!(
+        view.level().try_read(),
+        Err(RangedFieldError::OutOfRange { .. })
+    ));
+}
+
+#[test]
+fn read_invalid_underlying_value() {
+    binary_layout!(ranged_bool, LittleEndian, {
+        flag: bool as u8 where true..=true,
+    });
+    assert!(matches!(
+        ranged_bool::flag::try_read(&[2]),
+        Err(RangedFieldError::PrimitiveAccessError(
+            WrappedFieldError::LayoutAsError(_)
+        ))
+    ));
+    assert!(matches!(
+        ranged_bool::flag::try_read(&[0]),
+        Err(RangedFieldError::OutOfRange {
+            range: "true..=true"
+        })
+    ));
+    assert!(ranged_bool::flag::try_read(&[1]).unwrap());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn error_message() {
+    let error = ranged::priority::try_read(&[8]).unwrap_err();
+    assert_eq!(
+        "Value is outside of the allowed range `0..=7`",
+        error.to_string()
+    );
+}
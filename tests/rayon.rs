@@ -0,0 +1,105 @@
+#![cfg(feature = "rayon")]
+
+use binary_layout::{prelude::*, Checksum, ErrorKind};
+
+/// Sum of all bytes, modulo 256
+#[derive(Default)]
+struct ByteSum(u8);
+
+impl Checksum for ByteSum {
+    type Output = u8;
+
+    fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            self.0 = self.0.wrapping_add(*byte);
+        }
+    }
+
+    fn finish(self) -> u8 {
+        self.0
+    }
+}
+
+binary_layout!(sample, LittleEndian, {
+    version: u8,
+    value: u16 where 0..=1000,
+    sum: u8 = checksum(ByteSum, ..),
+}, validate |view| {
+    view.version().read() == 1,
+}, owned #[derive(Debug, Clone, Copy, PartialEq)] Sample);
+
+// A layout without a fixed size, for which the parallel helpers are generated but panic when called
+binary_layout!(packet, LittleEndian, {
+    len: u16,
+    payload: [u8],
+});
+
+fn encode(values: impl IntoIterator<Item = u16>) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    let samples = values.into_iter().map(|value| sample::Sample {
+        version: 1,
+        value,
+        sum: 0,
+    });
+    sample::encode_all(samples, &mut buffer).unwrap();
+    for frame in buffer.chunks_exact_mut(4) {
+        sample::View::new(frame).finalize().unwrap();
+    }
+    buffer
+}
+
+#[test]
+fn par_decode_all() {
+    let buffer = encode(0..1000);
+    assert_eq!(
+        sample::decode_all(&buffer).unwrap(),
+        sample::par_decode_all(&buffer).unwrap()
+    );
+    assert_eq!(999, sample::par_decode_all(&buffer).unwrap()[999].value);
+    assert!(sample::par_decode_all(&[]).unwrap().is_empty());
+}
+
+#[test]
+fn par_decode_all_returns_first_error() {
+    let mut buffer = encode(0..1000);
+    buffer[401..403].copy_from_slice(&2000u16.to_le_bytes());
+    buffer[801..803].copy_from_slice(&3000u16.to_le_bytes());
+    let error = sample::par_decode_all(&buffer).unwrap_err();
+    assert_eq!(ErrorKind::OutOfRange { range: "0..=1000" }, error.kind());
+    assert_eq!(sample::decode_all(&buffer).unwrap_err(), error);
+}
+
+#[test]
+fn par_decode_all_partial_frame() {
+    let buffer = encode(0..10);
+    let error = sample::par_decode_all(&buffer[..38]).unwrap_err();
+    assert_eq!(
+        ErrorKind::OutOfBounds {
+            needed: 40,
+            available: 38
+        },
+        error.kind()
+    );
+}
+
+#[test]
+fn par_validate_all() {
+    let mut buffer = encode(0..1000);
+    sample::par_validate_all(&buffer).unwrap();
+
+    buffer[2001] ^= 1;
+    buffer[1000] = 2;
+    let error = sample::par_validate_all(&buffer).unwrap_err();
+    assert_eq!(
+        ErrorKind::Validation {
+            check: "view.version().read() == 1"
+        },
+        error.kind()
+    );
+
+    buffer[1000] = 1;
+    let error = sample::par_validate_all(&buffer).unwrap_err();
+    assert_eq!(ErrorKind::ChecksumMismatch, error.kind());
+
+    assert!(sample::par_validate_all(&buffer[..5]).is_err());
+}
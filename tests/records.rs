@@ -0,0 +1,352 @@
+use binary_layout::{prelude::*, FieldKind, Records, RecordsFullError};
+
+binary_layout!(entry, BigEndian, {
+    id: u16,
+    flags: u8,
+});
+
+binary_layout!(table, LittleEndian, {
+    version: u8,
+    entries: Records<u16, entry::NestedView>,
+});
+
+binary_layout!(small_table, LittleEndian, {
+    entries: Records<u8, entry::NestedView>,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(1, table::entries::OFFSET);
+    assert_eq!(None, table::entries::SIZE);
+    assert_eq!(None, table::SIZE);
+    assert_eq!(
+        FieldKind::Records(&entry::LAYOUT),
+        table::LAYOUT.field("entries").unwrap().kind()
+    );
+}
+
+#[test]
+fn read() {
+    let storage = [1, 2, 0, 0, 10, 1, 0, 20, 2, 0xff];
+    let view = table::View::new(&storage);
+    let entries = view.entries();
+    assert_eq!(2, entries.len());
+    assert!(!entries.is_empty());
+    assert_eq!(2, entries.capacity());
+    assert_eq!(10, entries.get(0).unwrap().id().read());
+    assert_eq!(2, entries.get(1).unwrap().flags().read());
+    assert!(entries.get(2).is_none());
+    let ids: Vec<u16> = entries.iter().map(|entry| entry.id().read()).collect();
+    assert_eq!(vec![10, 20], ids);
+    assert_eq!(2, entries.iter().len());
+}
+
+#[test]
+fn count_larger_than_storage() {
+    let storage = [1, 5, 0, 0, 10, 1, 0, 20];
+    let view = table::View::new(&storage);
+    let entries = view.entries();
+    assert_eq!(5, entries.len());
+    assert_eq!(1, entries.capacity());
+    assert!(entries.get(0).is_some());
+    assert!(entries.get(1).is_none());
+    assert_eq!(1, entries.iter().count());
+}
+
+#[cfg(feature = "alloc")]
+fn ten_entries() -> Vec<u8> {
+    let mut storage = vec![0; 2];
+    let mut entries = small_table::View::new(&mut storage).into_entries();
+    for id in 0..10 {
+        entries.push_record().unwrap().id_mut().write(id);
+    }
+    storage
+}
+
+#[cfg(feature = "alloc")]
+fn ids<'a>(entries: impl Iterator<Item = entry::View<&'a [u8]>>) -> Vec<u16> {
+    entries.map(|entry| entry.id().read()).collect()
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn iter_step_by() {
+    let storage = ten_entries();
+    let view = small_table::View::new(&storage);
+    let entries = view.entries();
+    assert_eq!(vec![0, 3, 6, 9], ids(entries.iter_step_by(3)));
+    assert_eq!(4, entries.iter_step_by(3).len());
+    assert_eq!(vec![0, 4, 8], ids(entries.iter_step_by(4)));
+    assert_eq!(vec![0], ids(entries.iter_step_by(10)));
+    assert_eq!(vec![0], ids(entries.iter_step_by(usize::MAX)));
+    assert_eq!(vec![0, 6], ids(entries.iter_step_by(2).step_by(3)));
+    let mut iter = entries.iter();
+    iter.next();
+    assert_eq!(vec![1, 5, 9], ids(iter.step_by(4)));
+    assert_eq!(
+        Some(8),
+        entries
+            .iter_step_by(2)
+            .nth(4)
+            .map(|entry| entry.id().read())
+    );
+    assert!(entries.iter_step_by(2).nth(5).is_none());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic(expected = "Step must not be zero")]
+fn iter_step_by_zero() {
+    let storage = ten_entries();
+    small_table::View::new(&storage).entries().iter_step_by(0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn iter_range() {
+    let storage = ten_entries();
+    let view = small_table::View::new(&storage);
+    let entries = view.entries();
+    assert_eq!(vec![2, 3, 4], ids(entries.iter_range(2..5)));
+    assert_eq!(vec![7, 8, 9], ids(entries.iter_range(7..)));
+    assert_eq!(vec![0, 1], ids(entries.iter_range(..=1)));
+    assert_eq!(vec![8, 9], ids(entries.iter_range(8..100)));
+    assert!(entries.iter_range(20..30).next().is_none());
+    assert_eq!(vec![1, 4, 7], ids(entries.iter_range(1..9).step_by(3)));
+}
+
+#[test]
+fn iter_range_count_larger_than_storage() {
+    let storage = [1, 5, 0, 0, 10, 1, 0, 20];
+    let view = table::View::new(&storage);
+    assert_eq!(1, view.entries().iter_range(0..3).count());
+    assert_eq!(0, view.entries().iter_range(1..3).count());
+}
+
+#[test]
+fn find() {
+    let mut storage = [1, 3, 0, 0, 10, 1, 0, 20, 2, 0, 20, 3];
+    let view = table::View::new(&storage);
+    let entries = view.entries();
+    let (index, entry) = entries.find(|entry| entry.id().read() == 20).unwrap();
+    assert_eq!(1, index);
+    assert_eq!(2, entry.flags().read());
+    assert!(entries.find(|entry| entry.id().read() == 30).is_none());
+
+    let mut view = table::View::new(&mut storage);
+    let index = view
+        .entries()
+        .find(|entry| entry.flags().read() == 3)
+        .unwrap()
+        .0;
+    view.entries_mut()
+        .get_mut(index)
+        .unwrap()
+        .id_mut()
+        .write(30);
+    assert_eq!([1, 3, 0, 0, 10, 1, 0, 20, 2, 0, 30, 3], storage);
+}
+
+#[test]
+fn find_ignores_records_out_of_bounds() {
+    let storage = [1, 5, 0, 0, 10, 1, 0, 20];
+    let view = table::View::new(&storage);
+    assert!(view
+        .entries()
+        .find(|entry| entry.id().read() == 0)
+        .is_none());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn sort_by_key_is_stable() {
+    let mut storage = [1, 4, 0, 0, 30, 1, 0, 10, 2, 0, 30, 3, 0, 10, 4];
+    table::View::new(&mut storage)
+        .entries_mut()
+        .sort_by_key(|entry| entry.id().read());
+    assert_eq!([1, 4, 0, 0, 10, 2, 0, 10, 4, 0, 30, 1, 0, 30, 3], storage);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn sort_by_key_many() {
+    let mut storage = ten_entries();
+    let mut view = small_table::View::new(&mut storage);
+    let mut entries = view.entries_mut();
+    for index in 0..10 {
+        entries
+            .get_mut(index)
+            .unwrap()
+            .flags_mut()
+            .write(index as u8);
+    }
+    entries.sort_by_key(|entry| (entry.id().read() % 3, core::cmp::Reverse(entry.id().read())));
+    assert_eq!(
+        vec![9, 6, 3, 0, 7, 4, 1, 8, 5, 2],
+        ids(view.entries().iter())
+    );
+    let flags: Vec<u8> = view
+        .entries()
+        .iter()
+        .map(|entry| entry.flags().read())
+        .collect();
+    assert_eq!(vec![9, 6, 3, 0, 7, 4, 1, 8, 5, 2], flags);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn sort_unstable_by_key() {
+    let mut storage = ten_entries();
+    let mut view = small_table::View::new(&mut storage);
+    view.entries_mut()
+        .sort_unstable_by_key(|entry| core::cmp::Reverse(entry.id().read()));
+    assert_eq!(
+        vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+        ids(view.entries().iter())
+    );
+    view.entries_mut()
+        .sort_unstable_by_key(|entry| entry.id().read());
+    assert_eq!(
+        vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+        ids(view.entries().iter())
+    );
+}
+
+#[test]
+fn sort_ignores_records_out_of_bounds() {
+    let mut storage = [1, 5, 0, 0, 20, 1, 0, 10, 2];
+    table::View::new(&mut storage)
+        .entries_mut()
+        .sort_unstable_by_key(|entry| entry.id().read());
+    assert_eq!([1, 5, 0, 0, 10, 2, 0, 20, 1], storage);
+}
+
+#[test]
+fn binary_search_by_key() {
+    let storage = [1, 4, 0, 0, 10, 1, 0, 20, 2, 0, 20, 3, 0, 40, 4];
+    let view = table::View::new(&storage);
+    let entries = view.entries();
+    let search = |id: u16| entries.binary_search_by_key(&id, |entry| entry.id().read());
+    assert_eq!(Ok(0), search(10));
+    assert!(search(20) == Ok(1) || search(20) == Ok(2));
+    assert_eq!(Ok(3), search(40));
+    assert_eq!(Err(0), search(5));
+    assert_eq!(Err(3), search(30));
+    assert_eq!(Err(4), search(50));
+}
+
+#[test]
+fn binary_search_by() {
+    let storage = [1, 3, 0, 0, 10, 1, 0, 20, 2, 0, 30, 3];
+    let view = table::View::new(&storage);
+    let index = view
+        .entries()
+        .binary_search_by(|entry| entry.flags().read().cmp(&3));
+    assert_eq!(Ok(2), index);
+}
+
+#[test]
+fn binary_search_ignores_records_out_of_bounds() {
+    let storage = [1, 5, 0, 0, 10, 1, 0, 20];
+    let view = table::View::new(&storage);
+    let entries = view.entries();
+    assert_eq!(
+        Ok(0),
+        entries.binary_search_by_key(&10, |entry| entry.id().read())
+    );
+    assert_eq!(
+        Err(1),
+        entries.binary_search_by_key(&20, |entry| entry.id().read())
+    );
+    let empty = [1, 0, 0];
+    let view = table::View::new(&empty);
+    assert_eq!(
+        Err(0),
+        view.entries()
+            .binary_search_by_key(&20, |entry| entry.id().read())
+    );
+}
+
+#[test]
+fn push_and_modify() {
+    let mut storage = [0xff; 9];
+    let mut view = table::View::new(&mut storage[..]);
+    view.version_mut().write(1);
+    view.entries_mut().clear();
+    {
+        let mut entries = view.entries_mut();
+        entries.push().unwrap().id_mut().write(0x0102);
+        entries.push().unwrap().flags_mut().write(3);
+        assert_eq!(Err(RecordsFullError), entries.push().map(|_| ()));
+        entries.get_mut(1).unwrap().id_mut().write(7);
+    }
+    assert_eq!([1, 2, 0, 1, 2, 0, 0, 7, 3], storage);
+}
+
+#[test]
+fn truncate() {
+    let mut storage = [1, 2, 0, 0, 10, 1, 0, 20, 2];
+    let mut view = table::View::new(&mut storage);
+    view.entries_mut().truncate(5);
+    assert_eq!(2, view.entries().len());
+    view.entries_mut().truncate(1);
+    assert_eq!(1, view.entries().len());
+    view.entries_mut().clear();
+    assert!(view.entries().is_empty());
+    assert_eq!([1, 0, 0, 0, 10, 1, 0, 20, 2], storage);
+}
+
+#[test]
+fn count_type_full() {
+    let mut storage = vec![0; 1 + 256 * 3];
+    let mut view = small_table::View::new(&mut storage);
+    for _ in 0..255 {
+        view.entries_mut().push().unwrap();
+    }
+    assert_eq!(255, view.entries().len());
+    assert_eq!(256, view.entries().capacity());
+    assert!(view.entries_mut().push().is_err());
+    assert_eq!(255, view.entries().len());
+}
+
+#[test]
+fn into_view() {
+    let storage = vec![1, 1, 0, 0, 10, 1];
+    let view = table::View::new(storage);
+    let entries = view.into_entries();
+    assert_eq!(10, entries.get(0).unwrap().id().read());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn push_record_grows_storage() {
+    let mut storage = vec![1, 0, 0];
+    {
+        let mut entries = table::View::new(&mut storage).into_entries();
+        entries.push_record().unwrap().id_mut().write(0x0102);
+        entries.push_record().unwrap().flags_mut().write(3);
+        assert_eq!(2, entries.len());
+        assert_eq!(2, entries.capacity());
+    }
+    assert_eq!(vec![1, 2, 0, 1, 2, 0, 0, 0, 3], storage);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn push_record_uses_existing_capacity() {
+    let mut storage = vec![0, 0xff, 0xff, 0xff];
+    let mut entries = small_table::View::new(&mut storage).into_entries();
+    entries.push_record().unwrap().id_mut().write(5);
+    entries.push_record().unwrap();
+    assert_eq!(vec![2, 0, 5, 0, 0, 0, 0], storage);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn push_record_count_overflow() {
+    let mut storage = vec![255];
+    storage.resize(1 + 255 * 3, 0);
+    let mut entries = small_table::View::new(&mut storage).into_entries();
+    assert_eq!(Err(RecordsFullError), entries.push_record().map(|_| ()));
+    assert_eq!(1 + 255 * 3, storage.len());
+}
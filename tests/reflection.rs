@@ -0,0 +1,398 @@
+use binary_layout::{prelude::*, EndianKind, FieldKind, LayoutAs};
+use std::convert::Infallible;
+
+/// A custom type that doesn't implement Debug
+struct Opaque(u16);
+impl LayoutAs<u16> for Opaque {
+    type ReadError = Infallible;
+    type WriteError = Infallible;
+
+    fn try_read(v: u16) -> Result<Self, Infallible> {
+        Ok(Opaque(v))
+    }
+
+    fn try_write(v: Self) -> Result<u16, Infallible> {
+        Ok(v.0)
+    }
+}
+
+binary_layout!(header, LittleEndian, {
+    version: u16,
+    flags: i8,
+});
+
+binary_layout!(packet, BigEndian, {
+    head: header::NestedView,
+    length: core::num::NonZeroU32,
+    ratio: f32,
+    valid: bool as u8,
+    opaque: Opaque as u16,
+    unit: (),
+    id: [u8; 3],
+    payload: [u8],
+});
+
+#[test]
+fn field_offsets_and_sizes() {
+    assert_eq!(0, packet::field_offsets::head);
+    assert_eq!(3, packet::field_offsets::length);
+    assert_eq!(11, packet::field_offsets::valid);
+    assert_eq!(17, packet::field_offsets::payload);
+
+    assert_eq!(3, packet::field_sizes::head);
+    assert_eq!(4, packet::field_sizes::length);
+    assert_eq!(0, packet::field_sizes::unit);
+    assert_eq!(3, packet::field_sizes::id);
+    assert_eq!(0, packet::field_sizes::payload);
+}
+
+#[test]
+fn field_names_and_offsets() {
+    assert_eq!(
+        &["head", "length", "ratio", "valid", "opaque", "unit", "id", "payload"],
+        packet::FIELD_NAMES
+    );
+    assert_eq!(&[0, 3, 7, 11, 12, 14, 14, 17], packet::FIELD_OFFSETS);
+    assert_eq!(&["version", "flags"], header::FIELD_NAMES);
+    assert_eq!(&[0, 2], header::FIELD_OFFSETS);
+}
+
+binary_layout!(header_renamed_layout, LittleEndian, {
+    version: u16,
+    flags: i8,
+});
+
+binary_layout!(header_renamed_field, LittleEndian, {
+    version: u16,
+    options: i8,
+});
+
+binary_layout!(header_big_endian, BigEndian, {
+    version: u16,
+    flags: i8,
+});
+
+binary_layout!(outer, BigEndian, {
+    head: header::NestedView,
+});
+
+binary_layout!(outer_changed, BigEndian, {
+    head: header_big_endian::NestedView,
+});
+
+#[test]
+fn schema_hash() {
+    assert_eq!(header::SCHEMA_HASH, header::LAYOUT.schema_hash());
+    assert_eq!(header::SCHEMA_HASH, header_renamed_layout::SCHEMA_HASH);
+    assert_ne!(header::SCHEMA_HASH, header_renamed_field::SCHEMA_HASH);
+    assert_ne!(header::SCHEMA_HASH, header_big_endian::SCHEMA_HASH);
+    assert_ne!(outer::SCHEMA_HASH, outer_changed::SCHEMA_HASH);
+    assert_ne!(packet::SCHEMA_HASH, header::SCHEMA_HASH);
+}
+
+#[test]
+fn schema_hash_is_stable() {
+    // The hash is meant to be persisted, so it must not change between versions of this crate
+    assert_eq!(0x1eac_0807_20ae_3a6c, header::SCHEMA_HASH);
+}
+
+#[test]
+fn layout_info() {
+    assert_eq!("packet", packet::LAYOUT.name());
+    assert_eq!(EndianKind::Big, packet::LAYOUT.endianness());
+    assert_eq!(None, packet::LAYOUT.size());
+    assert_eq!(17, packet::LAYOUT.fixed_size());
+    assert_eq!(8, packet::LAYOUT.fields().len());
+
+    assert_eq!("header", header::LAYOUT.name());
+    assert_eq!(Some(3), header::LAYOUT.size());
+    assert_eq!(3, header::LAYOUT.fixed_size());
+}
+
+#[test]
+fn field_info() {
+    let expected = [
+        (
+            "head",
+            "header::NestedView",
+            0,
+            Some(3),
+            FieldKind::Nested(&header::LAYOUT),
+            EndianKind::Little,
+        ),
+        (
+            "length",
+            "core::num::NonZeroU32",
+            3,
+            Some(4),
+            FieldKind::UnsignedInt,
+            EndianKind::Big,
+        ),
+        (
+            "ratio",
+            "f32",
+            7,
+            Some(4),
+            FieldKind::Float,
+            EndianKind::Big,
+        ),
+        (
+            "valid",
+            "bool as u8",
+            11,
+            Some(1),
+            FieldKind::UnsignedInt,
+            EndianKind::Big,
+        ),
+        (
+            "opaque",
+            "Opaque as u16",
+            12,
+            Some(2),
+            FieldKind::UnsignedInt,
+            EndianKind::Big,
+        ),
+        ("unit", "()", 14, Some(0), FieldKind::Unit, EndianKind::Big),
+        (
+            "id",
+            "[u8; 3]",
+            14,
+            Some(3),
+            FieldKind::ByteArray,
+            EndianKind::Big,
+        ),
+        (
+            "payload",
+            "[u8]",
+            17,
+            None,
+            FieldKind::OpenEndedByteArray,
+            EndianKind::Big,
+        ),
+    ];
+    for (field, (name, type_name, offset, size, kind, endianness)) in
+        packet::LAYOUT.fields().iter().zip(expected)
+    {
+        assert_eq!(name, field.name());
+        assert_eq!(type_name, field.type_name());
+        assert_eq!(offset, field.offset());
+        assert_eq!(size, field.size());
+        assert_eq!(kind, field.kind());
+        assert_eq!(endianness, field.endianness());
+    }
+
+    assert_eq!(
+        Some(packet::ratio::OFFSET),
+        packet::LAYOUT.field("ratio").map(|f| f.offset())
+    );
+    assert!(packet::LAYOUT.field("nonexisting").is_none());
+}
+
+#[test]
+fn field_values() {
+    let mut storage = vec![0; 20];
+    let mut view = packet::View::new(&mut storage);
+    view.head_mut().version_mut().write(3);
+    view.head_mut().flags_mut().write(-1);
+    view.ratio_mut().write(0.5);
+    view.valid_mut().write(true);
+    view.opaque_mut().write(Opaque(0x0102));
+    view.id_mut().copy_from_slice(&[0xab, 0xcd, 0xef]);
+    view.payload_mut().copy_from_slice(&[1, 2, 3]);
+
+    let fields = packet::LAYOUT.fields();
+    let values: Vec<String> = fields
+        .iter()
+        .map(|f| f.value(&storage).to_string())
+        .collect();
+    assert_eq!(
+        vec![
+            "header { version: 3, flags: -1 }",
+            "<invalid: NonZeroIsZeroError { offset: 3 }>",
+            "0.5",
+            "true",
+            "[01 02]",
+            "()",
+            "[ab cd ef]",
+            "[01 02 03]",
+        ],
+        values
+    );
+    assert_eq!(Some(&[0xab, 0xcd, 0xef][..]), fields[6].bytes(&storage));
+
+    assert_eq!(
+        "header { version: 3, flags: -1 }",
+        header::LAYOUT.value(&storage[..3]).to_string()
+    );
+}
+
+#[test]
+fn field_values_out_of_bounds() {
+    let storage = [0; 8];
+    let field = packet::LAYOUT.field("ratio").unwrap();
+    assert_eq!("<out of bounds>", field.value(&storage).to_string());
+    assert_eq!(None, field.bytes(&storage));
+}
+
+#[cfg(feature = "c-header")]
+#[test]
+fn c_header() {
+    let c_header = packet::LAYOUT.to_c_header();
+    let expected_lines = [
+        "#ifndef BINARY_LAYOUT_PACKET_H",
+        "#define HEADER_SIZE 3",
+        "#define PACKET_FIXED_SIZE 17",
+        "#define PACKET_RATIO_OFFSET 7",
+        "#define PACKET_RATIO_SIZE 4",
+        "#define PACKET_PAYLOAD_OFFSET 17",
+        "struct header {",
+        "    uint16_t version; /* little endian */",
+        "    int8_t flags;",
+        "struct packet {",
+        "    struct header head;",
+        "    uint32_t length; /* big endian */",
+        "    float ratio; /* big endian */",
+        "    uint8_t valid;",
+        "    uint16_t opaque; /* big endian */",
+        "    /* unit: zero-sized `()` */",
+        "    uint8_t id[3];",
+        "    uint8_t payload[];",
+        "_Static_assert(offsetof(struct packet, payload) == 17, \"Offset of packet.payload doesn't match the layout\");",
+        "_Static_assert(sizeof(struct header) == 3, \"Size of header doesn't match the layout\");",
+    ];
+    for line in expected_lines {
+        assert!(
+            c_header.lines().any(|l| l == line),
+            "Missing line {:?} in:\n{}",
+            line,
+            c_header
+        );
+    }
+    assert!(c_header.find("struct header {") < c_header.find("struct packet {"));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json() {
+    assert_eq!(
+        concat!(
+            r#"{"name":"header","endianness":"little","size":3,"fixed_size":3,"fields":["#,
+            r#"{"name":"version","type":"u16","offset":0,"size":2,"endianness":"little","kind":"unsigned_int"},"#,
+            r#"{"name":"flags","type":"i8","offset":2,"size":1,"endianness":"little","kind":"signed_int"}"#,
+            r#"]}"#,
+        ),
+        header::LAYOUT.to_json(),
+    );
+
+    let json = packet::LAYOUT.to_json();
+    assert!(json.starts_with(concat!(
+        r#"{"name":"packet","endianness":"big","size":null,"fixed_size":17,"fields":["#,
+        r#"{"name":"head","type":"header::NestedView","offset":0,"size":3,"endianness":"little","kind":"nested","layout":{"name":"header","#,
+    )));
+    assert!(json.contains(
+        r#"{"name":"valid","type":"bool as u8","offset":11,"size":1,"endianness":"big","kind":"unsigned_int"}"#
+    ));
+    assert!(json.ends_with(
+        r#"{"name":"payload","type":"[u8]","offset":17,"size":null,"endianness":"big","kind":"open_ended_byte_array"}]}"#
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hexdump() {
+    let mut storage = vec![0; 40];
+    let mut view = packet::View::new(&mut storage);
+    view.head_mut().version_mut().write(3);
+    view.length_mut()
+        .write(core::num::NonZeroU32::new(0x1234).unwrap());
+    view.valid_mut().write(true);
+    view.id_mut().copy_from_slice(&[0xab, 0xcd, 0xef]);
+    for (index, byte) in view.payload_mut().iter_mut().enumerate() {
+        *byte = index as u8;
+    }
+
+    assert_eq!(
+        concat!(
+            "00000000  03 00                                            head.version (u16) = 3\n",
+            "00000002  00                                               head.flags (i8) = 0\n",
+            "00000003  00 00 12 34                                      length (core::num::NonZeroU32) = 4660\n",
+            "00000007  00 00 00 00                                      ratio (f32) = 0.0\n",
+            "0000000b  01                                               valid (bool as u8) = true\n",
+            "0000000c  00 00                                            opaque (Opaque as u16) = [00 00]\n",
+            "0000000e                                                   unit (()) = ()\n",
+            "0000000e  ab cd ef                                         id ([u8; 3])\n",
+            "00000011  00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  payload ([u8])\n",
+            "00000021  10 11 12 13 14 15 16\n",
+        ),
+        packet::hexdump(&storage),
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hexdump_too_short_or_too_long() {
+    assert_eq!(
+        concat!(
+            "00000000  01 02                                            version (u16) = 513\n",
+            "00000002                                                   flags (i8) <out of bounds>\n",
+        ),
+        header::hexdump(&[1, 2]),
+    );
+    assert_eq!(
+        concat!(
+            "00000000  01 02                                            version (u16) = 513\n",
+            "00000002  ff                                               flags (i8) = -1\n",
+            "00000003  04 05                                            <trailing bytes>\n",
+        ),
+        header::hexdump(&[1, 2, 0xff, 4, 5]),
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn diff() {
+    let mut a = vec![0; 20];
+    let mut view = packet::View::new(&mut a);
+    view.head_mut().version_mut().write(3);
+    view.length_mut()
+        .write(core::num::NonZeroU32::new(10).unwrap());
+    view.payload_mut().copy_from_slice(&[1, 2, 3]);
+
+    let mut b = a.clone();
+    let mut view = packet::View::new(&mut b);
+    view.head_mut().version_mut().write(4);
+    view.valid_mut().write(true);
+    view.payload_mut()[2] = 4;
+
+    let differences: Vec<_> = packet::diff(&a, &b).collect();
+    assert_eq!(3, differences.len());
+    assert_eq!("head.version", differences[0].name());
+    assert_eq!("version", differences[0].field().name());
+    assert_eq!("3", differences[0].old_value().to_string());
+    assert_eq!("4", differences[0].new_value().to_string());
+    assert_eq!("valid: false -> true", differences[1].to_string());
+    assert_eq!(
+        "payload: [01 02 03] -> [01 02 04]",
+        differences[2].to_string()
+    );
+
+    assert_eq!(0, packet::diff(&a, &a).count());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn diff_different_lengths() {
+    let a = [0; 20];
+    let differences: Vec<String> = packet::diff(&a, &a[..18])
+        .map(|diff| diff.to_string())
+        .collect();
+    assert_eq!(vec!["payload: [00 00 00] -> [00]"], differences);
+
+    let differences: Vec<String> = packet::diff(&a[..2], &a[..4])
+        .map(|diff| diff.to_string())
+        .collect();
+    assert_eq!(
+        vec!["head: <out of bounds> -> header { version: 0, flags: 0 }"],
+        differences
+    );
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "alloc")]
+
+use binary_layout::prelude::*;
+
+binary_layout!(packet, BigEndian, {
+    packet_type: u8,
+    payload_len: u16 = len(payload),
+    payload: [u8],
+});
+
+#[test]
+fn resize_tail() {
+    let mut view = packet::View::new(vec![1, 0, 0]);
+    assert!(view.payload().is_empty());
+
+    view.resize_tail(3);
+    assert_eq!(&[0, 0, 0], view.payload());
+    view.payload_mut().copy_from_slice(&[4, 5, 6]);
+
+    view.resize_tail(1);
+    assert_eq!(&[4], view.payload());
+    view.resize_tail(2);
+    assert_eq!(&[4, 0], view.payload());
+    assert_eq!(1, view.packet_type().read());
+    assert_eq!(vec![1, 0, 0, 4, 0], view.into_storage());
+}
+
+#[test]
+fn set_tail() {
+    let mut view = packet::View::new(vec![1, 0, 0, 9, 9, 9, 9]);
+    view.set_tail(&[1, 2]);
+    assert_eq!(&[1, 2], view.payload());
+    view.finalize().unwrap();
+    assert_eq!(vec![1, 0, 2, 1, 2], view.into_storage());
+}
+
+#[test]
+fn borrowed_vec() {
+    let mut storage = vec![0; 3];
+    let mut view = packet::View::new(&mut storage);
+    view.set_tail(b"abc");
+    view.finalize().unwrap();
+    assert_eq!(vec![0, 0, 3, b'a', b'b', b'c'], storage);
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn bytes_mut() {
+    let mut view = packet::View::new(bytes::BytesMut::from(&[2, 0, 0][..]));
+    view.set_tail(b"xy");
+    view.finalize().unwrap();
+    assert_eq!(&[2, 0, 2, b'x', b'y'][..], &view.into_storage()[..]);
+}
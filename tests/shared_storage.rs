@@ -0,0 +1,73 @@
+use binary_layout::{prelude::*, Data};
+use std::convert::TryInto;
+use std::sync::Arc;
+
+mod common;
+use common::data_region;
+
+binary_layout!(header, BigEndian, {
+    field1: u16,
+    data: [u8; 4],
+});
+
+binary_layout!(layout, LittleEndian, {
+    first: u32,
+    nested: header::NestedView,
+    tail: [u8],
+});
+
+#[test]
+fn cloned_views_share_storage() {
+    let storage: Arc<[u8]> = Arc::from(data_region(1024, 0));
+    let view = layout::View::new(Arc::clone(&storage));
+    let clones: Vec<_> = (0..5).map(|_| view.clone()).collect();
+    assert_eq!(7, Arc::strong_count(&storage));
+
+    for clone in &clones {
+        assert_eq!(
+            u32::from_le_bytes(storage[0..4].try_into().unwrap()),
+            clone.first().read()
+        );
+        assert!(std::ptr::eq(clone.tail().as_ptr(), storage[10..].as_ptr()));
+    }
+}
+
+#[test]
+fn views_across_threads() {
+    let view = layout::View::new(Arc::<[u8]>::from(data_region(1024, 1)));
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let view = view.clone();
+            std::thread::spawn(move || view.nested().field1().read())
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(view.nested().field1().read(), handle.join().unwrap());
+    }
+}
+
+#[test]
+fn extract_shared_subregions() {
+    let storage: Arc<[u8]> = Arc::from(data_region(1024, 2));
+    let view = layout::View::new(Arc::clone(&storage));
+
+    let tail: Data<Arc<[u8]>> = view.clone().into_tail();
+    let data = view.clone().into_nested().into_data();
+    let nested = view.into_nested();
+    assert_eq!(&storage[10..], &*tail);
+    assert_eq!(&storage[6..10], &*data);
+    assert_eq!(&storage[6..10], nested.data());
+    assert_eq!(4, Arc::strong_count(&storage));
+
+    let tail_clone = tail.clone();
+    assert!(std::ptr::eq(tail.as_ptr(), tail_clone.as_ptr()));
+}
+
+#[test]
+fn clone_field_view() {
+    let storage: Arc<[u8]> = Arc::from(data_region(1024, 3));
+    let first = layout::View::new(Arc::clone(&storage)).into_first();
+    let first_clone = first.clone();
+    assert_eq!(first.read(), first_clone.read());
+    assert_eq!(3, Arc::strong_count(&storage));
+}
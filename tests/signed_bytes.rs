@@ -0,0 +1,38 @@
+use binary_layout::{prelude::*, FieldKind};
+
+binary_layout!(audio_block, BigEndian, {
+    sample_rate: u16,
+    samples: [i8; 4],
+    gain: i8,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(2, audio_block::samples::OFFSET);
+    assert_eq!(Some(4), audio_block::samples::SIZE);
+    assert_eq!(6, audio_block::gain::OFFSET);
+    assert_eq!(Some(7), audio_block::SIZE);
+    assert_eq!(
+        FieldKind::SignedByteArray,
+        audio_block::LAYOUT.fields()[1].kind()
+    );
+}
+
+#[test]
+fn read_and_write() {
+    let mut view = audio_block::View::new([0; 7]);
+    view.samples_mut().write([-128, -1, 0, 127]);
+    view.gain_mut().write(-3);
+    assert_eq!([-128, -1, 0, 127], view.samples().read());
+    assert_eq!([0, 0, 0x80, 0xff, 0, 0x7f, 0xfd], view.into_storage());
+}
+
+#[test]
+fn field_value() {
+    let storage = [0, 1, 0xff, 2, 0xfe, 3, 0];
+    assert_eq!([-1, 2, -2, 3], audio_block::samples::read(&storage));
+    assert_eq!(
+        "[-1, 2, -2, 3]",
+        audio_block::LAYOUT.fields()[1].value(&storage).to_string()
+    );
+}
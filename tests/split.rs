@@ -0,0 +1,143 @@
+use binary_layout::prelude::*;
+use std::convert::TryInto;
+
+mod common;
+use common::data_region;
+
+binary_layout!(header, BigEndian, {
+    field1: u16,
+    flag: bool as u8,
+});
+
+binary_layout!(layout, LittleEndian, {
+    first: u32,
+    second: [u8; 3],
+    nested: header::NestedView,
+    tail: [u8],
+});
+
+#[test]
+fn split_mut_into_disjoint_views() {
+    let mut storage = data_region(1024, 0);
+    let mut view = layout::View::new(&mut storage[..]);
+    let layout::SplitMut {
+        mut first,
+        second,
+        mut nested,
+        tail,
+        ..
+    } = view.split_mut();
+
+    // All fields can be accessed at the same time
+    first.write(1000);
+    second.copy_from_slice(&[1, 2, 3]);
+    nested.field1_mut().write(50);
+    nested.flag_mut().write(true);
+    tail[..2].copy_from_slice(&[4, 5]);
+    assert_eq!(1000, first.read());
+    assert_eq!(50, nested.field1().read());
+    assert_eq!(1024 - 10, tail.len());
+
+    assert_eq!(1000, view.first().read());
+    assert_eq!(&[1, 2, 3], view.second());
+    assert_eq!(50, view.nested().field1().read());
+    assert!(view.nested().flag().try_read().unwrap());
+    assert_eq!(&[4, 5], &view.tail()[..2]);
+
+    assert_eq!(1000, u32::from_le_bytes(storage[0..4].try_into().unwrap()));
+    assert_eq!(50, u16::from_be_bytes(storage[7..9].try_into().unwrap()));
+    assert_eq!(&data_region(1024, 0)[12..], &storage[12..]);
+}
+
+#[test]
+fn split_mut_fields_in_different_functions() {
+    fn write_header(mut nested: header::View<&mut [u8]>) {
+        nested.field1_mut().write(7);
+    }
+    fn write_tail(tail: &mut [u8]) {
+        tail.fill(9);
+    }
+
+    let mut view = layout::View::new(vec![0; 20]);
+    let split = view.split_mut();
+    write_header(split.nested);
+    write_tail(split.tail);
+
+    assert_eq!(7, view.nested().field1().read());
+    assert_eq!(&[9; 10], view.tail());
+}
+
+#[test]
+fn split_at_tail() {
+    let storage = data_region(1024, 1);
+    let view = layout::View::new(&storage);
+    let (header, payload) = view.split_at::<layout::tail>();
+    assert_eq!(
+        u32::from_le_bytes(storage[0..4].try_into().unwrap()),
+        header.first().read()
+    );
+    assert_eq!(
+        u16::from_be_bytes(storage[7..9].try_into().unwrap()),
+        header.nested().field1().read()
+    );
+    assert_eq!(&storage[10..], payload);
+}
+
+#[test]
+fn split_at_mut_tail() {
+    let mut storage = data_region(1024, 2);
+    let mut view = layout::View::new(&mut storage[..]);
+    let (mut header, payload) = view.split_at_mut::<layout::tail>();
+    header.first_mut().write(200);
+    payload[0] = header.first().read() as u8 + 1;
+    assert_eq!(1024 - 10, payload.len());
+
+    assert_eq!(200, u32::from_le_bytes(storage[0..4].try_into().unwrap()));
+    assert_eq!(201, storage[10]);
+}
+
+#[test]
+fn split_at_field_in_the_middle() {
+    let storage = data_region(1024, 3);
+    let view = layout::View::new(&storage);
+    let (prefix, rest) = view.split_at::<layout::nested>();
+    assert_eq!(&storage[4..7], prefix.second());
+    assert_eq!(&storage[7..], rest);
+}
+
+#[test]
+#[should_panic]
+fn split_at_access_after_split() {
+    let storage = data_region(1024, 4);
+    let view = layout::View::new(&storage);
+    let (prefix, _) = view.split_at::<layout::nested>();
+    prefix.nested().field1().read();
+}
+
+#[test]
+fn from_parts() {
+    let mut head = [0; 10];
+    let mut payload = vec![1, 2, 3];
+    let mut view = layout::View::from_parts(&mut head[..], &mut payload[..]);
+    view.head_mut().first_mut().write(100);
+    view.head_mut().nested_mut().field1_mut().write(200);
+    view.tail_mut()[0] = 4;
+    assert_eq!(100, view.head().first().read());
+    assert_eq!(200, view.head().nested().field1().read());
+    assert_eq!(&[4, 2, 3], view.tail());
+
+    let (head_view, tail) = view.into_parts();
+    assert_eq!(100, head_view.first().read());
+    assert_eq!(&[4, 2, 3], tail);
+    assert_eq!(100, u32::from_le_bytes(head[0..4].try_into().unwrap()));
+    assert_eq!(vec![4, 2, 3], payload);
+}
+
+#[test]
+#[should_panic(
+    expected = "Needed 10 bytes to access field `tail` at offset 10 of layout `layout`, but the storage only has 0 bytes"
+)]
+fn from_parts_tail_through_head() {
+    let view = layout::View::from_parts(vec![], vec![1, 2, 3]);
+    view.head().tail();
+}
@@ -0,0 +1,258 @@
+use binary_layout::{prelude::*, Data, Storage, StorageMut};
+use std::convert::TryInto;
+
+mod common;
+use common::data_region;
+
+/// A storage type that doesn't implement `AsRef<[u8]>` but only [Storage] and [StorageMut].
+/// It counts how often it was accessed for writing.
+struct InstrumentedStorage {
+    data: Vec<u8>,
+    num_writes: usize,
+}
+
+impl InstrumentedStorage {
+    fn new(data: Vec<u8>) -> Self {
+        Self {
+            data,
+            num_writes: 0,
+        }
+    }
+}
+
+impl Storage for InstrumentedStorage {
+    fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl StorageMut for InstrumentedStorage {
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        self.num_writes += 1;
+        &mut self.data
+    }
+}
+
+/// A read-only storage type that only implements [Storage]
+struct ReadOnlyStorage(Vec<u8>);
+
+impl Storage for ReadOnlyStorage {
+    fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+binary_layout!(header, BigEndian, {
+    field1: u16,
+});
+
+binary_layout!(layout, LittleEndian, {
+    first: u32,
+    second: [u8; 5],
+    nested: header::NestedView,
+    tail: [u8],
+});
+
+#[test]
+fn view_over_custom_storage() {
+    let mut view = layout::View::new(InstrumentedStorage::new(data_region(1024, 0)));
+
+    view.first_mut().write(1000);
+    view.second_mut().copy_from_slice(&[1, 2, 3, 4, 5]);
+    view.nested_mut().field1_mut().write(20);
+    view.tail_mut()[..2].copy_from_slice(&[6, 7]);
+
+    assert_eq!(1000, view.first().read());
+    assert_eq!(&[1, 2, 3, 4, 5], view.second());
+    assert_eq!(20, view.nested().field1().read());
+    assert_eq!(&[6, 7], &view.tail()[..2]);
+
+    let storage = view.into_storage();
+    assert_eq!(4, storage.num_writes);
+    assert_eq!(
+        1000,
+        u32::from_le_bytes(storage.data[0..4].try_into().unwrap())
+    );
+    assert_eq!(
+        20,
+        u16::from_be_bytes(storage.data[9..11].try_into().unwrap())
+    );
+    assert_eq!(&data_region(1024, 0)[13..], &storage.data[13..]);
+}
+
+#[test]
+fn view_over_readonly_custom_storage() {
+    let view = layout::View::new(ReadOnlyStorage(data_region(1024, 1)));
+
+    assert_eq!(
+        u32::from_le_bytes(data_region(1024, 1)[0..4].try_into().unwrap()),
+        view.first().read()
+    );
+    assert_eq!(&data_region(1024, 1)[4..9], view.second());
+    assert_eq!(
+        u16::from_be_bytes(data_region(1024, 1)[9..11].try_into().unwrap()),
+        view.nested().field1().read()
+    );
+    assert_eq!(&data_region(1024, 1)[11..], view.tail());
+}
+
+#[test]
+fn extract_from_custom_storage() {
+    let view = layout::View::new(InstrumentedStorage::new(data_region(1024, 2)));
+    let tail: Data<InstrumentedStorage> = view.into_tail();
+    assert_eq!(&data_region(1024, 2)[11..], &*tail);
+
+    let view = layout::View::new(InstrumentedStorage::new(data_region(1024, 2)));
+    let mut first = view.into_first();
+    first.write(50);
+    assert_eq!(50, first.read());
+
+    let view = layout::View::new(InstrumentedStorage::new(data_region(1024, 2)));
+    let mut nested = view.into_nested();
+    nested.field1_mut().write(30);
+    assert_eq!(30, nested.field1().read());
+}
+
+#[test]
+#[should_panic(
+    expected = "Needed 11 bytes to access field `nested` at offset 9 of layout `layout`, but the storage only has 10 bytes"
+)]
+fn storage_too_small() {
+    let view = layout::View::new(vec![0; 10]);
+    view.nested();
+}
+
+#[test]
+#[should_panic(
+    expected = "Needed 4 bytes to access field `first` at offset 0 of layout `layout`, but the storage only has 3 bytes"
+)]
+fn storage_too_small_mut() {
+    let mut view = layout::View::new(vec![0; 3]);
+    view.first_mut().write(1);
+}
+
+#[test]
+#[should_panic(
+    expected = "Needed 11 bytes to access field `tail` at offset 11 of layout `layout`, but the storage only has 2 bytes"
+)]
+fn storage_too_small_into() {
+    let view = layout::View::new(vec![0; 2]);
+    view.into_tail();
+}
+
+#[test]
+fn view_at_base_offset() {
+    let mut view = header::View::new_at(vec![1, 2, 3, 4, 5], 2);
+    assert_eq!(0x0304, view.field1().read());
+    view.field1_mut().write(0x0607);
+    let storage = view.into_storage();
+    assert_eq!(2..5, storage.region());
+    assert_eq!(vec![1, 2, 6, 7, 5], storage.into_inner());
+}
+
+#[test]
+fn try_view_at_base_offset() {
+    let view = header::View::try_new_at(&[1, 2, 3, 4][..], 2).unwrap();
+    assert_eq!(0x0304, view.field1().read());
+
+    let error = header::View::try_new_at(&[1, 2, 3, 4][..], 3)
+        .err()
+        .unwrap();
+    assert_eq!(
+        binary_layout::ErrorKind::OutOfBounds {
+            needed: 5,
+            available: 4
+        },
+        error.kind()
+    );
+    assert_eq!(Some("header"), error.layout());
+    assert!(header::View::try_new_at(&[1, 2, 3, 4][..], usize::MAX).is_err());
+}
+
+#[test]
+#[should_panic(
+    expected = "Tried to create a view of layout `header` at base offset 5, but the storage only has 4 bytes"
+)]
+fn view_at_base_offset_out_of_bounds() {
+    header::View::new_at(vec![0; 4], 5);
+}
+
+mod interior_mutability {
+    use binary_layout::{prelude::*, FieldSegmentedExt};
+    use std::cell::RefCell;
+    #[cfg(feature = "std")]
+    use std::sync::{Arc, Mutex};
+
+    binary_layout!(shared_layout, BigEndian, {
+        sequence_number: u32,
+        flags: u8,
+        payload: [u8],
+    });
+
+    #[test]
+    fn refcell() {
+        let buffer = RefCell::new(vec![0; 8]);
+        let mut first = &buffer;
+        let mut second = &buffer;
+        shared_layout::sequence_number::try_write_segmented(&mut first, 0x01020304).unwrap();
+        shared_layout::flags::try_write_segmented(&mut second, 5).unwrap();
+
+        assert_eq!(
+            Ok(0x01020304),
+            shared_layout::sequence_number::try_read_segmented(&first)
+        );
+        assert_eq!(Ok(5), shared_layout::flags::try_read_segmented(&buffer));
+        assert_eq!(vec![1, 2, 3, 4, 5, 0, 0, 0], *buffer.borrow());
+    }
+
+    #[test]
+    fn owned_refcell() {
+        let mut buffer = RefCell::new([0; 8]);
+        shared_layout::flags::try_write_segmented(&mut buffer, 7).unwrap();
+        assert_eq!(Ok(7), shared_layout::flags::try_read_segmented(&buffer));
+    }
+
+    #[test]
+    #[should_panic]
+    fn refcell_already_borrowed() {
+        let buffer = RefCell::new(vec![0; 8]);
+        let _guard = buffer.borrow();
+        let _ = shared_layout::flags::try_write_segmented(&mut &buffer, 7);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn mutex() {
+        let buffer: Arc<Mutex<[u8]>> = Arc::new(Mutex::new([0; 8]));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let buffer = Arc::clone(&buffer);
+                std::thread::spawn(move || {
+                    let mut storage = &*buffer;
+                    shared_layout::flags::try_write_segmented(&mut storage, 9).unwrap();
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        shared_layout::sequence_number::try_write_segmented(&mut &*buffer, 1).unwrap();
+
+        assert_eq!(
+            Ok(1),
+            shared_layout::sequence_number::try_read_segmented(&*buffer)
+        );
+        assert_eq!(Ok(9), shared_layout::flags::try_read_segmented(&*buffer));
+        assert_eq!([0, 0, 0, 1, 9, 0, 0, 0], *buffer.lock().unwrap());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn view_over_guard() {
+        let buffer = Mutex::new(vec![0; 8]);
+        shared_layout::View::new(&mut *buffer.lock().unwrap())
+            .payload_mut()
+            .copy_from_slice(&[1, 2, 3]);
+        assert_eq!(vec![0, 0, 0, 0, 0, 1, 2, 3], *buffer.lock().unwrap());
+    }
+}
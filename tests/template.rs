@@ -0,0 +1,61 @@
+use binary_layout::prelude::*;
+
+const SECTOR: usize = 512;
+
+binary_layout!(block<const BLOCK_SIZE: usize>, LittleEndian, {
+    checksum: u32,
+    data: [u8; BLOCK_SIZE],
+    next: u64,
+});
+
+binary_layout!(block_small = block<16>);
+binary_layout!(block_sector = block<SECTOR>);
+binary_layout!(block_large = block<{ 4 * SECTOR }>);
+
+binary_layout!(header, LittleEndian, {
+    magic: u32,
+});
+
+binary_layout!(record<const NAME_LEN: usize, const COUNT: u8>, LittleEndian, {
+    include header,
+    name: [u8; NAME_LEN],
+    kind: u8 where ..COUNT,
+}, optional {
+    extension: u16,
+});
+
+binary_layout!(short_record = record<4, 3>);
+
+#[test]
+fn metadata() {
+    assert_eq!(16, block_small::BLOCK_SIZE);
+    assert_eq!(Some(28), block_small::SIZE);
+    assert_eq!(20, block_small::next::OFFSET);
+    assert_eq!(Some(524), block_sector::SIZE);
+    assert_eq!(516, block_sector::next::OFFSET);
+    assert_eq!(Some(2060), block_large::SIZE);
+    assert_eq!(2052, block_large::next::OFFSET);
+}
+
+#[test]
+fn view() {
+    let mut storage = [0; 28];
+    let mut view = block_small::View::new(&mut storage);
+    view.checksum_mut().write(1);
+    view.data_mut()[0] = 2;
+    view.next_mut().write(3);
+    assert_eq!(1, storage[0]);
+    assert_eq!(2, storage[4]);
+    assert_eq!(3, storage[20]);
+}
+
+#[test]
+fn template_with_sections() {
+    assert_eq!(3, short_record::COUNT);
+    assert_eq!(4, short_record::name::OFFSET);
+    assert_eq!(9, short_record::FIXED_SIZE);
+    let view = short_record::View::new([1, 0, 0, 0, b'a', b'b', b'c', b'd', 3, 5, 0]);
+    assert_eq!(1, view.magic().read());
+    assert!(view.kind().try_read().is_err());
+    assert_eq!(5, view.extension().unwrap().read());
+}
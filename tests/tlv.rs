@@ -0,0 +1,93 @@
+use binary_layout::{prelude::*, Tlv, TlvWriteError};
+
+binary_layout!(packet, BigEndian, {
+    packet_type: u8,
+    options: [u8],
+});
+
+type Options = Tlv<u8, u16, BigEndian>;
+type LittleEndianOptions = Tlv<u16, u8, LittleEndian>;
+
+#[test]
+fn iterate() {
+    let storage = [5, 1, 0, 2, 10, 20, 2, 0, 0, 3, 0, 1, 30];
+    let view = packet::View::new(&storage);
+    let entries: Vec<(u8, &[u8])> = Options::iter(view.options()).collect();
+    assert_eq!(
+        vec![(1, &[10, 20][..]), (2, &[][..]), (3, &[30][..])],
+        entries
+    );
+}
+
+#[test]
+fn iterate_empty() {
+    let mut iter = Options::iter(&[]);
+    assert_eq!(None, iter.next());
+    assert!(iter.remainder().is_empty());
+}
+
+#[test]
+fn iterate_truncated() {
+    let storage = [1, 0, 1, 10, 2, 0, 5, 20, 30];
+    let mut iter = Options::iter(&storage);
+    assert_eq!(Some((1, &[10][..])), iter.next());
+    assert_eq!(None, iter.next());
+    assert_eq!(&[2, 0, 5, 20, 30], iter.remainder());
+
+    let mut iter = Options::iter(&[1, 0]);
+    assert_eq!(None, iter.next());
+    assert_eq!(&[1, 0], iter.remainder());
+}
+
+#[test]
+fn build() {
+    let mut storage = [0; 12];
+    let mut view = packet::View::new(&mut storage[..]);
+    view.packet_type_mut().write(5);
+    let mut builder = Options::builder(view.options_mut());
+    assert!(builder.is_empty());
+    builder.append(1, &[10, 20]).unwrap();
+    builder.append(2, &[30]).unwrap();
+    assert_eq!(9, builder.len());
+    assert_eq!(2, builder.remaining_capacity());
+    assert_eq!([5, 1, 0, 2, 10, 20, 2, 0, 1, 30, 0, 0], storage);
+}
+
+#[test]
+fn build_little_endian() {
+    let mut storage = [0; 6];
+    let mut builder = LittleEndianOptions::builder(&mut storage);
+    builder.append(0x0102, &[7, 8, 9]).unwrap();
+    assert_eq!([2, 1, 3, 7, 8, 9], storage);
+    let entries: Vec<(u16, &[u8])> = LittleEndianOptions::iter(&storage).collect();
+    assert_eq!(vec![(0x0102, &[7, 8, 9][..])], entries);
+}
+
+#[test]
+fn build_storage_too_small() {
+    let mut storage = [0; 5];
+    let mut builder = Options::builder(&mut storage);
+    builder.append(1, &[1]).unwrap();
+    assert_eq!(
+        Err(TlvWriteError::StorageTooSmall {
+            needed: 4,
+            available: 1
+        }),
+        builder.append(2, &[2])
+    );
+    assert_eq!(4, builder.len());
+    assert_eq!([1, 0, 1, 1, 0], storage);
+}
+
+#[test]
+fn build_value_too_long() {
+    let mut storage = [0; 300];
+    let mut builder = Tlv::<u8, u8, BigEndian>::builder(&mut storage);
+    assert_eq!(
+        Err(TlvWriteError::ValueTooLong { len: 256 }),
+        builder.append(1, &[0; 256])
+    );
+    assert!(builder.is_empty());
+    builder.append(1, &[0; 255]).unwrap();
+    assert_eq!(257, builder.len());
+}
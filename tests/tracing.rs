@@ -0,0 +1,101 @@
+#![cfg(feature = "tracing")]
+
+use binary_layout::prelude::*;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+binary_layout!(packet, BigEndian, {
+    kind: u8,
+    sequence: u16,
+    flag: bool as u8,
+    payload: [u8],
+});
+
+/// Records the fields of each event as `name=value` strings
+#[derive(Default, Clone)]
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+struct EventVisitor(Vec<String>);
+
+impl Visit for EventVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.push(format!("{}={:?}", field.name(), value));
+    }
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push(format!("{}={}", field.name(), value));
+    }
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.push(format!("{}={}", field.name(), value));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target() == "binary_layout"
+    }
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+    fn event(&self, event: &Event<'_>) {
+        let mut visitor = EventVisitor(Vec::new());
+        event.record(&mut visitor);
+        self.0.lock().unwrap().push(visitor.0.join(" "));
+    }
+    fn enter(&self, _span: &Id) {}
+    fn exit(&self, _span: &Id) {}
+}
+
+fn record(f: impl FnOnce()) -> Vec<String> {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), f);
+    let events = recorder.0.lock().unwrap().clone();
+    events
+}
+
+#[test]
+fn reads_and_writes() {
+    let mut storage = [0; 6];
+    let events = record(|| {
+        let mut view = packet::View::new(&mut storage[..]);
+        view.sequence_mut().write(0x0102);
+        assert_eq!(0x0102, view.sequence().read());
+        view.flag_mut().try_write(true).unwrap();
+        view.kind_mut().update(|kind| kind + 3);
+    });
+    assert_eq!(
+        vec![
+            "message=write layout=packet field=sequence offset=1 old=0 new=258",
+            "message=read layout=packet field=sequence offset=1 value=258",
+            "message=write layout=packet field=flag offset=3 old=false new=true",
+            "message=read layout=packet field=kind offset=0 value=0",
+            "message=write layout=packet field=kind offset=0 old=0 new=3",
+        ],
+        events
+    );
+}
+
+#[test]
+fn slices_are_not_traced() {
+    let mut storage = [0; 6];
+    let events = record(|| {
+        let mut view = packet::View::new(&mut storage[..]);
+        view.payload_mut().copy_from_slice(&[1, 2]);
+        assert_eq!(&[1, 2], view.payload());
+    });
+    assert!(events.is_empty());
+}
+
+#[test]
+fn field_api_is_not_traced() {
+    let mut storage = [0; 6];
+    let events = record(|| {
+        packet::sequence::write(&mut storage, 5);
+        assert_eq!(5, packet::sequence::read(&storage));
+    });
+    assert!(events.is_empty());
+}
@@ -0,0 +1,141 @@
+use binary_layout::{prelude::*, LayoutAs, UpdateError, WrappedFieldError};
+use std::convert::{Infallible, TryInto};
+use std::num::NonZeroU16;
+
+mod common;
+use common::data_region;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Even(u32);
+impl LayoutAs<u32> for Even {
+    type ReadError = Infallible;
+    type WriteError = &'static str;
+
+    fn try_read(v: u32) -> Result<Self, Infallible> {
+        Ok(Even(v))
+    }
+
+    fn try_write(v: Self) -> Result<u32, &'static str> {
+        if v.0 % 2 == 0 {
+            Ok(v.0)
+        } else {
+            Err("odd")
+        }
+    }
+}
+
+binary_layout!(layout, LittleEndian, {
+    counter: u64,
+    nonzero: NonZeroU16,
+    flag: bool as u8,
+    even: Even as u32,
+});
+
+#[test]
+fn update() {
+    let mut storage = data_region(1024, 0);
+    let before = u64::from_le_bytes(storage[0..8].try_into().unwrap());
+    let mut view = layout::View::new(&mut storage);
+    view.counter_mut().update(|v| v.wrapping_add(1));
+    view.counter_mut().update(|v| v.wrapping_add(1));
+    assert_eq!(before.wrapping_add(2), view.counter().read());
+    assert_eq!(
+        before.wrapping_add(2),
+        u64::from_le_bytes(storage[0..8].try_into().unwrap())
+    );
+}
+
+#[test]
+fn try_update_success() {
+    let mut storage = vec![0; 15];
+    let mut view = layout::View::new(&mut storage);
+    view.nonzero_mut().write(NonZeroU16::new(5).unwrap());
+    view.nonzero_mut()
+        .try_update(|v| NonZeroU16::new(v.get() * 3).unwrap())
+        .unwrap();
+    assert_eq!(15, view.nonzero().try_read().unwrap().get());
+
+    view.flag_mut().try_update(|v| !v).unwrap();
+    assert!(view.flag().try_read().unwrap());
+
+    view.even_mut().try_update(|v| Even(v.0 + 2)).unwrap();
+    assert_eq!(Even(2), view.even().try_read().unwrap());
+}
+
+#[test]
+fn try_update_read_error() {
+    let mut storage = vec![0; 15];
+    let mut view = layout::View::new(&mut storage);
+    let mut called = false;
+    let result = view.nonzero_mut().try_update(|v| {
+        called = true;
+        v
+    });
+    assert!(matches!(result, Err(UpdateError::ReadError(_))));
+    assert!(!called);
+}
+
+#[test]
+fn try_update_write_error() {
+    let mut storage = vec![0; 15];
+    let mut view = layout::View::new(&mut storage);
+    let result = view.even_mut().try_update(|v| Even(v.0 + 1));
+    assert!(matches!(
+        result,
+        Err(UpdateError::WriteError(WrappedFieldError::LayoutAsError(
+            "odd"
+        )))
+    ));
+    assert_eq!(Even(0), view.even().try_read().unwrap());
+}
+
+#[test]
+fn replace() {
+    let mut storage = vec![0; 15];
+    let mut view = layout::View::new(&mut storage);
+    view.counter_mut().write(5);
+    assert_eq!(5, view.counter_mut().replace(7));
+    assert_eq!(7, view.counter_mut().replace(9));
+    assert_eq!(9, view.counter().read());
+}
+
+#[test]
+fn try_replace_success() {
+    let mut storage = vec![0; 15];
+    let mut view = layout::View::new(&mut storage);
+    view.nonzero_mut().write(NonZeroU16::new(5).unwrap());
+    assert_eq!(
+        5,
+        view.nonzero_mut()
+            .try_replace(NonZeroU16::new(6).unwrap())
+            .unwrap()
+            .get()
+    );
+    assert_eq!(6, view.nonzero().try_read().unwrap().get());
+
+    assert!(!view.flag_mut().try_replace(true).unwrap());
+    assert!(view.flag().try_read().unwrap());
+}
+
+#[test]
+fn try_replace_read_error() {
+    let mut storage = vec![0; 15];
+    let mut view = layout::View::new(&mut storage);
+    let result = view.nonzero_mut().try_replace(NonZeroU16::new(6).unwrap());
+    assert!(matches!(result, Err(UpdateError::ReadError(_))));
+    assert_eq!(0, u16::from_le_bytes(storage[8..10].try_into().unwrap()));
+}
+
+#[test]
+fn try_replace_write_error() {
+    let mut storage = vec![0; 15];
+    let mut view = layout::View::new(&mut storage);
+    let result = view.even_mut().try_replace(Even(3));
+    assert!(matches!(
+        result,
+        Err(UpdateError::WriteError(WrappedFieldError::LayoutAsError(
+            "odd"
+        )))
+    ));
+    assert_eq!(Even(0), view.even().try_read().unwrap());
+}
@@ -0,0 +1,76 @@
+use binary_layout::{prelude::*, FieldKind, Utf16};
+
+binary_layout!(partition_entry, LittleEndian, {
+    first_lba: u64,
+    name: Utf16<6>,
+    label: Utf16<4, true>,
+});
+
+binary_layout!(partition_entry_be, BigEndian, {
+    first_lba: u64,
+    name: Utf16<6>,
+    label: Utf16<4, true>,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(8, partition_entry::name::OFFSET);
+    assert_eq!(Some(12), partition_entry::name::SIZE);
+    assert_eq!(20, partition_entry::label::OFFSET);
+    assert_eq!(Some(28), partition_entry::SIZE);
+    assert_eq!(
+        FieldKind::Utf16String,
+        partition_entry::LAYOUT.fields()[1].kind()
+    );
+}
+
+#[test]
+fn view() {
+    let mut view = partition_entry::View::new(vec![0xff; 28]);
+    view.name_mut().set("EFI ä").unwrap();
+    view.label_mut().set("sys").unwrap();
+    assert!(view.label_mut().set("boot").is_err());
+
+    assert_eq!("EFI ä", view.name());
+    assert_eq!(5, view.name().len());
+    #[cfg(feature = "alloc")]
+    assert_eq!(Ok("sys".to_string()), view.label().try_to_string());
+    assert_eq!(
+        vec!['E', 'F', 'I', ' ', 'ä'],
+        view.name().chars().collect::<Result<Vec<_>, _>>().unwrap()
+    );
+    assert_eq!(
+        &[b'E', 0, b'F', 0, b'I', 0, b' ', 0, 0xe4, 0, 0, 0],
+        &view.into_storage()[8..20]
+    );
+}
+
+#[test]
+fn field_api() {
+    let mut storage = [0; 28];
+    partition_entry_be::name::data_mut(&mut storage)
+        .set("data")
+        .unwrap();
+    assert_eq!(&[0, b'd', 0, b'a'], &storage[8..12]);
+    assert_eq!("data", partition_entry_be::name::data(&storage));
+    assert_eq!("data", partition_entry_be::name::data(&storage).to_string());
+}
+
+#[test]
+fn convert_between_endianness() {
+    let mut view = partition_entry::View::new([0; 28]);
+    view.first_lba_mut().write(34);
+    view.name_mut().set("root").unwrap();
+    view.label_mut().set("/").unwrap();
+
+    let mut converted = [0; 28];
+    partition_entry::convert_to::<partition_entry_be::NestedView>(
+        &view.into_storage(),
+        &mut converted,
+    )
+    .unwrap();
+    let view = partition_entry_be::View::new(&converted);
+    assert_eq!(34, view.first_lba().read());
+    assert_eq!("root", view.name());
+    assert_eq!("/", view.label());
+}
@@ -0,0 +1,97 @@
+use binary_layout::{prelude::*, ValidationError};
+
+binary_layout!(no_checks, LittleEndian, {
+    validate: u16,
+});
+
+binary_layout!(header, BigEndian, {
+    version: u8,
+    header_len: u16,
+}, validate |view| {
+    view.version().read() == 2,
+    view.header_len().read() >= 20,
+});
+
+binary_layout!(frame, BigEndian, {
+    checksum: u8,
+    payload: [u8],
+}, validate |frame_view| {
+    frame_view.payload().iter().fold(0u8, |acc, byte| acc.wrapping_add(*byte)) == frame_view.checksum().read(),
+});
+
+binary_layout!(packet, LittleEndian, {
+    head: header::NestedView,
+    flag: bool as u8,
+}, validate |view| {
+    view.head().validate().is_ok(),
+    view.flag().try_read().is_ok(),
+});
+
+#[test]
+fn without_checks() {
+    // Layouts without checks don't have a `validate()` method, so fields can be named like it
+    let mut view = no_checks::View::new([0, 0]);
+    view.validate_mut().write(5);
+    assert_eq!(5, view.validate().read());
+}
+
+#[test]
+fn valid() {
+    assert_eq!(Ok(()), header::View::new([2, 0, 20]).validate());
+    assert_eq!(Ok(()), frame::View::new([6, 1, 2, 3]).validate());
+}
+
+#[test]
+fn reports_first_failed_check() {
+    let check = |data: &[u8]| header::View::new(data).validate().unwrap_err().check();
+    assert_eq!("view.version().read() == 2", check(&[1, 0, 20]));
+    assert_eq!("view.version().read() == 2", check(&[1, 0, 10]));
+    assert_eq!("view.header_len().read() >= 20", check(&[2, 0, 10]));
+
+    assert!(frame::View::new([5, 1, 2, 3])
+        .validate()
+        .unwrap_err()
+        .check()
+        .starts_with("frame_view.payload().iter()"));
+}
+
+#[test]
+fn storage_too_short() {
+    let error = header::View::new([2, 0]).validate().unwrap_err();
+    assert_eq!("len(storage) >= FIXED_SIZE", error.check());
+    assert_eq!("header", error.layout());
+
+    let error = frame::View::new(&[][..]).validate().unwrap_err();
+    assert_eq!("len(storage) >= FIXED_SIZE", error.check());
+    assert_eq!(Ok(()), frame::View::new([0]).validate());
+}
+
+#[test]
+fn error() {
+    let error: ValidationError = header::View::new([3, 0, 20]).validate().unwrap_err();
+    assert_eq!("view.version().read() == 2", error.check());
+    assert_eq!("header", error.layout());
+    assert_eq!(
+        "Validation of layout `header` failed: `view.version().read() == 2`",
+        error.to_string()
+    );
+}
+
+#[test]
+fn nested() {
+    assert_eq!(Ok(()), packet::View::new([2, 0, 20, 1]).validate());
+    assert_eq!(
+        "view.head().validate().is_ok()",
+        packet::View::new([1, 0, 20, 1])
+            .validate()
+            .unwrap_err()
+            .check()
+    );
+    assert_eq!(
+        "view.flag().try_read().is_ok()",
+        packet::View::new([2, 0, 20, 2])
+            .validate()
+            .unwrap_err()
+            .check()
+    );
+}
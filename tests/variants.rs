@@ -0,0 +1,124 @@
+use binary_layout::prelude::*;
+
+binary_layout!(echo, BigEndian, {
+    identifier: u16,
+    sequence_number: u16,
+});
+
+binary_layout!(redirect, LittleEndian, {
+    gateway: u32,
+    payload: [u8],
+});
+
+binary_layout!(packet, BigEndian, {
+    packet_type: u8,
+    body: [u8],
+}, variants body by packet_type {
+    0 => EchoReply(echo),
+    5 => Redirect(redirect),
+    8 => EchoRequest(echo),
+});
+
+layout_enum! {
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum Kind: u16 {
+        Echo = 1,
+        Redirect = 2,
+    }
+}
+
+binary_layout!(fixed_body, LittleEndian, {
+    kind: Kind as u16,
+    body: [u8; 4],
+    trailer: u8,
+}, validate |view| {
+    view.trailer().read() == 0xff,
+}, variants body by kind {
+    Kind::Echo => Echo(echo),
+    Kind::Redirect => Redirect(redirect),
+});
+
+#[test]
+fn read() {
+    let storage = [8, 0, 1, 0, 2];
+    match packet::View::new(&storage).variant() {
+        packet::Variant::EchoRequest(echo) => {
+            assert_eq!(1, echo.identifier().read());
+            assert_eq!(2, echo.sequence_number().read());
+        }
+        _ => panic!("Wrong variant"),
+    }
+
+    let storage = [5, 1, 0, 0, 0, 10, 11];
+    match packet::View::new(&storage).variant() {
+        packet::Variant::Redirect(redirect) => {
+            assert_eq!(1, redirect.gateway().read());
+            assert_eq!(&[10, 11], redirect.payload());
+        }
+        _ => panic!("Wrong variant"),
+    }
+}
+
+#[test]
+fn unknown() {
+    let storage = [1, 2, 3];
+    match packet::View::new(&storage).variant() {
+        packet::Variant::Unknown(body) => assert_eq!(&[2, 3], body),
+        _ => panic!("Wrong variant"),
+    }
+}
+
+#[test]
+fn short_storage() {
+    match packet::View::new(&[][..]).variant() {
+        packet::Variant::Unknown(body) => assert!(body.is_empty()),
+        _ => panic!("Wrong variant"),
+    }
+
+    let mut storage = [1, 0, 2, 0];
+    match fixed_body::View::new(&storage[..1]).variant() {
+        fixed_body::Variant::Unknown(body) => assert!(body.is_empty()),
+        _ => panic!("Wrong variant"),
+    }
+    match fixed_body::View::new(&mut storage[..]).variant_mut() {
+        fixed_body::Variant::Unknown(body) => assert_eq!(&[2, 0], body),
+        _ => panic!("Wrong variant"),
+    }
+}
+
+#[test]
+fn write() {
+    let mut storage = [0; 5];
+    let mut view = packet::View::new(&mut storage);
+    match view.variant_mut() {
+        packet::Variant::EchoReply(mut echo) => {
+            echo.identifier_mut().write(0x0102);
+            echo.sequence_number_mut().write(0x0304);
+        }
+        _ => panic!("Wrong variant"),
+    }
+    assert_eq!([0, 1, 2, 3, 4], storage);
+}
+
+#[test]
+fn fixed_size_body_with_fallible_tag() {
+    let mut storage = [2, 0, 1, 2, 3, 4, 0xff];
+    let mut view = fixed_body::View::new(&mut storage);
+    assert_eq!(Ok(()), view.validate());
+    match view.variant_mut() {
+        fixed_body::Variant::Redirect(mut redirect) => {
+            assert_eq!(0x04030201, redirect.gateway().read());
+            assert_eq!(0, redirect.payload().len());
+            redirect.gateway_mut().write(0);
+        }
+        _ => panic!("Wrong variant"),
+    }
+    assert_eq!([2, 0, 0, 0, 0, 0, 0xff], storage);
+
+    // Invalid enum values are treated like unknown variants
+    let storage = [3, 0, 1, 2, 3, 4, 0xff];
+    match fixed_body::View::new(&storage).variant() {
+        fixed_body::Variant::Unknown(body) => assert_eq!(&[1, 2, 3, 4], body),
+        _ => panic!("Wrong variant"),
+    }
+}
@@ -0,0 +1,56 @@
+use binary_layout::prelude::*;
+
+mod layouts {
+    use binary_layout::prelude::*;
+
+    binary_layout!(public, LittleEndian, {
+        field: u16,
+    });
+
+    binary_layout!(pub crate_visible, LittleEndian, {
+        field: u16,
+    });
+
+    binary_layout!(pub(crate) restricted, LittleEndian, {
+        field: u16,
+    });
+
+    binary_layout!(pub(super) super_visible, LittleEndian, {
+        field: u32,
+    });
+
+    binary_layout!(pub(self) private, LittleEndian, {
+        field: u8,
+    });
+
+    binary_layout!(pub(crate) block<const BLOCK_SIZE: usize>, LittleEndian, {
+        data: [u8; BLOCK_SIZE],
+    });
+
+    binary_layout!(pub(crate) block_2 = block<2>);
+
+    binary_layout!(pub(crate) with_include, LittleEndian, {
+        include private,
+        other: u8,
+    });
+
+    pub fn private_size() -> Option<usize> {
+        private::SIZE
+    }
+}
+
+binary_layout!(pub(crate) top_level, BigEndian, {
+    field: u8,
+});
+
+#[test]
+fn accessible() {
+    assert_eq!(Some(2), layouts::public::SIZE);
+    assert_eq!(Some(2), layouts::crate_visible::SIZE);
+    assert_eq!(Some(2), layouts::restricted::SIZE);
+    assert_eq!(Some(4), layouts::super_visible::SIZE);
+    assert_eq!(Some(1), layouts::private_size());
+    assert_eq!(Some(2), layouts::block_2::SIZE);
+    assert_eq!(Some(2), layouts::with_include::SIZE);
+    assert_eq!(5, top_level::View::new([5]).field().read());
+}
@@ -339,3 +339,188 @@ fn view_vec_readwrite() {
         i32::from_le_bytes((&extracted_storage[11..15]).try_into().unwrap())
     );
 }
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Uuid([u8; 16]);
+impl LayoutAs<[u8; 16]> for Uuid {
+    type ReadError = Infallible;
+    type WriteError = Infallible;
+
+    fn try_read(v: [u8; 16]) -> Result<Uuid, Infallible> {
+        Ok(Uuid(v))
+    }
+
+    fn try_write(v: Uuid) -> Result<[u8; 16], Infallible> {
+        Ok(v.0)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct AsciiName([u8; 4]);
+impl LayoutAs<[u8; 4]> for AsciiName {
+    type ReadError = &'static str;
+    type WriteError = &'static str;
+
+    fn try_read(v: [u8; 4]) -> Result<AsciiName, &'static str> {
+        if v.is_ascii() {
+            Ok(AsciiName(v))
+        } else {
+            Err("not ascii")
+        }
+    }
+
+    fn try_write(v: AsciiName) -> Result<[u8; 4], &'static str> {
+        if v.0.is_ascii() {
+            Ok(v.0)
+        } else {
+            Err("not ascii")
+        }
+    }
+}
+
+binary_layout!(byte_arrays, BigEndian, {
+    id: Uuid as [u8; 16],
+    name: AsciiName as [u8; 4],
+    raw: [u8; 2],
+});
+
+#[test]
+fn byte_array_metadata() {
+    assert_eq!(0, byte_arrays::id::OFFSET);
+    assert_eq!(Some(16), byte_arrays::id::SIZE);
+    assert_eq!(16, byte_arrays::name::OFFSET);
+    assert_eq!(Some(4), byte_arrays::name::SIZE);
+    assert_eq!(Some(22), byte_arrays::SIZE);
+}
+
+#[test]
+fn byte_array_fields() {
+    let mut storage = [0; 22];
+
+    byte_arrays::id::write(&mut storage, Uuid([7; 16]));
+    byte_arrays::name::try_write(&mut storage, AsciiName(*b"abcd")).unwrap();
+    byte_arrays::raw::write(&mut storage, [1, 2]);
+
+    assert_eq!(Uuid([7; 16]), byte_arrays::id::read(&storage));
+    assert_eq!(
+        AsciiName(*b"abcd"),
+        byte_arrays::name::try_read(&storage).unwrap()
+    );
+    assert_eq!([1, 2], byte_arrays::raw::read(&storage));
+    assert_eq!(&[1, 2], byte_arrays::raw::data(&storage));
+    assert_eq!(b"abcd", &storage[16..20]);
+
+    assert!(byte_arrays::name::try_write(&mut storage, AsciiName([0xff; 4])).is_err());
+    storage[16] = 0xff;
+    assert!(byte_arrays::name::try_read(&storage).is_err());
+}
+
+#[test]
+fn byte_array_view() {
+    let mut view = byte_arrays::View::new([0; 22]);
+    view.id_mut().write(Uuid([3; 16]));
+    view.name_mut().try_write(AsciiName(*b"wxyz")).unwrap();
+    view.raw_mut().copy_from_slice(&[5, 6]);
+
+    assert_eq!(Uuid([3; 16]), view.id().read());
+    assert_eq!(AsciiName(*b"wxyz"), view.name().try_read().unwrap());
+    assert_eq!(&[5, 6], view.raw());
+
+    let formatted = format!("{:?}", byte_arrays::LAYOUT.value(&view.into_storage()));
+    assert!(formatted.contains("id: Uuid([3, 3,"), "{}", formatted);
+    assert!(formatted.contains("raw: [05 06]"), "{}", formatted);
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ReadFallible(u8);
+impl LayoutAs<u8> for ReadFallible {
+    type ReadError = &'static str;
+    type WriteError = Infallible;
+
+    fn try_read(v: u8) -> Result<ReadFallible, &'static str> {
+        if v < 100 {
+            Ok(ReadFallible(v))
+        } else {
+            Err("too large")
+        }
+    }
+
+    fn try_write(v: ReadFallible) -> Result<u8, Infallible> {
+        Ok(v.0)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct WriteFallible(u8);
+impl LayoutAs<u8> for WriteFallible {
+    type ReadError = Infallible;
+    type WriteError = &'static str;
+
+    fn try_read(v: u8) -> Result<WriteFallible, Infallible> {
+        Ok(WriteFallible(v))
+    }
+
+    fn try_write(v: WriteFallible) -> Result<u8, &'static str> {
+        if v.0 < 100 {
+            Ok(v.0)
+        } else {
+            Err("too large")
+        }
+    }
+}
+
+binary_layout!(asymmetric, LittleEndian, {
+    read_fallible: ReadFallible as u8,
+    write_fallible: WriteFallible as u8,
+});
+
+#[test]
+fn asymmetric_fields() {
+    let mut storage = [0; 2];
+
+    asymmetric::read_fallible::write(&mut storage, ReadFallible(150));
+    assert!(asymmetric::read_fallible::try_read(&storage).is_err());
+    asymmetric::read_fallible::write(&mut storage, ReadFallible(50));
+    assert_eq!(
+        ReadFallible(50),
+        asymmetric::read_fallible::try_read(&storage).unwrap()
+    );
+
+    assert!(asymmetric::write_fallible::try_write(&mut storage, WriteFallible(150)).is_err());
+    asymmetric::write_fallible::try_write(&mut storage, WriteFallible(60)).unwrap();
+    assert_eq!(
+        WriteFallible(60),
+        asymmetric::write_fallible::read(&storage)
+    );
+}
+
+#[test]
+fn asymmetric_view() {
+    let mut view = asymmetric::View::new([0; 2]);
+
+    view.read_fallible_mut().write(ReadFallible(150));
+    assert!(view.read_fallible().try_read().is_err());
+    view.read_fallible_mut().write(ReadFallible(50));
+    assert_eq!(ReadFallible(50), view.read_fallible().try_read().unwrap());
+
+    assert!(view
+        .write_fallible_mut()
+        .try_write(WriteFallible(150))
+        .is_err());
+    view.write_fallible_mut()
+        .try_write(WriteFallible(60))
+        .unwrap();
+    assert_eq!(WriteFallible(60), view.write_fallible().read());
+}
+
+#[test]
+fn infallible_update() {
+    let mut view = noslice::View::new([0; 15]);
+    view.third_mut().write(Wrapped(10));
+    view.third_mut().update(|Wrapped(v)| Wrapped(v * 2));
+    assert_eq!(Wrapped(20), view.third().read());
+    assert_eq!(
+        Wrapped(20),
+        noslice::third::try_read(&view.into_storage()).infallible_unwrap()
+    );
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "std")]
+
+use binary_layout::{prelude::*, InternetChecksum, WriteTracked};
+
+binary_layout!(packet, BigEndian, {
+    kind: u8,
+    flags: u8,
+    payload_len: u16 = len(payload),
+    crc: u16 = checksum(InternetChecksum, ..),
+    payload: [u8],
+});
+
+binary_layout!(header, LittleEndian, {
+    kind: u8,
+    sequence: u32,
+});
+
+binary_layout!(ranged, LittleEndian, {
+    priority: u8 where 0..=7,
+});
+
+#[test]
+fn read_after_write() {
+    let mut tracked = WriteTracked::new(vec![0; 10]);
+    tracked.write::<packet::kind>(1);
+    tracked.write::<packet::flags>(6);
+    assert_eq!(1, tracked.read::<packet::kind>());
+    assert_eq!(6, tracked.read::<packet::flags>());
+    assert_eq!(&vec![1, 6, 0, 0, 0, 0, 0, 0, 0, 0], tracked.inner());
+    assert_eq!(vec![1, 6, 0, 0, 0, 0, 0, 0, 0, 0], tracked.into_inner());
+}
+
+#[test]
+fn finalize_through_inner_view() {
+    let mut tracked = WriteTracked::new(vec![0; 8]);
+    tracked.write::<packet::kind>(1);
+    tracked.write::<packet::flags>(0);
+    let mut view = packet::View::new(tracked.inner_mut());
+    view.payload_mut().copy_from_slice(&[1, 2]);
+    view.finalize().unwrap();
+    tracked.mark_field_written::<packet::payload>();
+    tracked.mark_field_written::<packet::payload_len>();
+    tracked.mark_field_written::<packet::crc>();
+    assert_eq!(2, tracked.read::<packet::payload_len>());
+    tracked.read::<packet::crc>();
+}
+
+#[test]
+fn fallible_access() {
+    let mut tracked = WriteTracked::new([0; 1]);
+    tracked.try_write::<ranged::priority>(5).unwrap();
+    assert_eq!(5, tracked.try_read::<ranged::priority>().unwrap());
+}
+
+#[test]
+fn mark_written() {
+    let mut tracked = WriteTracked::new([0; 5]);
+    tracked.mark_written(1..5);
+    assert_eq!(0, tracked.read::<header::sequence>());
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn mark_written_out_of_bounds() {
+    WriteTracked::new([0; 5]).mark_written(3..6);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn mark_field_written_out_of_bounds() {
+    WriteTracked::new([0; 3]).mark_field_written::<header::sequence>();
+}
+
+#[test]
+fn views_arent_instrumented() {
+    let view = header::View::new(WriteTracked::new([0; 5]).into_inner());
+    assert_eq!(0, view.sequence().read());
+}
+
+#[cfg(debug_assertions)]
+mod debug {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "Read of the field at offset 1 with 4 bytes before it was written")]
+    fn read_before_write() {
+        let mut tracked = WriteTracked::new([0; 5]);
+        tracked.write::<header::kind>(1);
+        tracked.read::<header::sequence>();
+    }
+
+    #[test]
+    #[should_panic(expected = "before it was written")]
+    fn forgot_finalize() {
+        let mut tracked = WriteTracked::new(vec![0; 10]);
+        tracked.write::<packet::kind>(1);
+        tracked.write::<packet::flags>(0);
+        tracked.write::<packet::payload_len>(4);
+        tracked.read::<packet::crc>();
+    }
+
+    #[test]
+    #[should_panic(expected = "before it was written")]
+    fn partially_written() {
+        let mut tracked = WriteTracked::new([0; 5]);
+        tracked.mark_written(0..3);
+        tracked.read::<header::sequence>();
+    }
+
+    #[test]
+    #[should_panic(expected = "before it was written")]
+    fn fallible_read_before_write() {
+        let tracked = WriteTracked::new([0; 1]);
+        let _ = tracked.try_read::<ranged::priority>();
+    }
+
+    #[test]
+    #[should_panic(expected = "before it was written")]
+    fn untracked_write() {
+        let mut tracked = WriteTracked::new([0; 5]);
+        header::View::new(tracked.inner_mut())
+            .sequence_mut()
+            .write(7);
+        tracked.read::<header::sequence>();
+    }
+
+    #[test]
+    fn failed_write_isnt_recorded() {
+        let mut tracked = WriteTracked::new([0; 1]);
+        assert!(tracked.try_write::<ranged::priority>(8).is_err());
+        let result = std::panic::catch_unwind(move || tracked.try_read::<ranged::priority>());
+        assert!(result.is_err());
+    }
+}
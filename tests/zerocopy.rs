@@ -0,0 +1,94 @@
+#![cfg(feature = "zerocopy")]
+
+use binary_layout::prelude::*;
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+binary_layout!(header, NativeEndian, {
+    version: u8,
+    flags: u8,
+    length: u16,
+    checksum: u32,
+});
+
+#[derive(FromBytes, IntoBytes, KnownLayout, Immutable, Debug, PartialEq)]
+#[repr(C)]
+struct Header {
+    version: u8,
+    flags: u8,
+    length: u16,
+    checksum: u32,
+}
+
+binary_layout!(packed_header, NativeEndian, {
+    version: u8,
+    magic: [u8; 3],
+});
+
+#[derive(FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned)]
+#[repr(C)]
+struct PackedHeader {
+    version: u8,
+    magic: [u8; 3],
+}
+
+#[repr(C, align(4))]
+struct Aligned([u8; 12]);
+
+#[test]
+fn view_as_zerocopy() {
+    let mut storage = Aligned([0; 12]);
+    let mut view = header::View::new(&mut storage.0[..]);
+    view.version_mut().write(1);
+    view.length_mut().write(1000);
+    view.checksum_mut().write(0xdeadbeef);
+
+    let value: &Header = view.as_zerocopy().unwrap();
+    assert_eq!(
+        &Header {
+            version: 1,
+            flags: 0,
+            length: 1000,
+            checksum: 0xdeadbeef,
+        },
+        value
+    );
+
+    let value: &mut Header = view.as_zerocopy_mut().unwrap();
+    value.flags = 5;
+    assert_eq!(5, view.flags().read());
+}
+
+#[test]
+fn view_as_zerocopy_misaligned() {
+    let storage = Aligned([0; 12]);
+    let view = header::View::new(&storage.0[1..9]);
+    assert!(view.as_zerocopy::<Header>().is_none());
+
+    let view = packed_header::View::new(&storage.0[1..5]);
+    assert!(view.as_zerocopy::<PackedHeader>().is_some());
+}
+
+#[test]
+fn view_as_zerocopy_too_small() {
+    let storage = Aligned([0; 12]);
+    let view = header::View::new(&storage.0[..7]);
+    assert!(view.as_zerocopy::<Header>().is_none());
+}
+
+#[test]
+fn view_from_zerocopy() {
+    let mut value = Header {
+        version: 2,
+        flags: 3,
+        length: 500,
+        checksum: 7,
+    };
+    let view = header::View::from_zerocopy(&value);
+    assert_eq!(2, view.version().read());
+    assert_eq!(500, view.length().read());
+    assert_eq!(7, view.checksum().read());
+
+    let mut view = header::View::from_zerocopy_mut(&mut value);
+    view.checksum_mut().write(8);
+    assert_eq!(8, value.checksum);
+}